@@ -54,11 +54,61 @@ static const ble_uuid128_t gatt_svr_chr_sec_test_static_uuid =
 
 static uint8_t gatt_svr_sec_test_static_val;
 
+/**
+ * The vendor specific logo upload service writes a boot logo or animation
+ * container into a logo slot in SPI Flash.  The chunk reassembly, CRC
+ * checking and commit are done in Rust: rust/pinetime-logo/src/upload.rs
+ *     o control: begin, commit or abort the upload.
+ *     o data: a chunk of the container with its offset and CRC32.
+ *       Control and data require an encrypted, authenticated (MITM) link.
+ *     o status: state of the upload and the first missing chunk.
+ */
+
+/* 52451941-0dc2-49fd-a085-c700e832dece */
+static const ble_uuid128_t gatt_svr_svc_logo_upload_uuid =
+    BLE_UUID128_INIT(0xce, 0xde, 0x32, 0xe8, 0x00, 0xc7, 0x85, 0xa0,
+                     0xfd, 0x49, 0xc2, 0x0d, 0x41, 0x19, 0x45, 0x52);
+
+/* 52451942-0dc2-49fd-a085-c700e832dece */
+static const ble_uuid128_t gatt_svr_chr_logo_upload_control_uuid =
+        BLE_UUID128_INIT(0xce, 0xde, 0x32, 0xe8, 0x00, 0xc7, 0x85, 0xa0,
+                         0xfd, 0x49, 0xc2, 0x0d, 0x42, 0x19, 0x45, 0x52);
+
+/* 52451943-0dc2-49fd-a085-c700e832dece */
+static const ble_uuid128_t gatt_svr_chr_logo_upload_data_uuid =
+        BLE_UUID128_INIT(0xce, 0xde, 0x32, 0xe8, 0x00, 0xc7, 0x85, 0xa0,
+                         0xfd, 0x49, 0xc2, 0x0d, 0x43, 0x19, 0x45, 0x52);
+
+/* 52451944-0dc2-49fd-a085-c700e832dece */
+static const ble_uuid128_t gatt_svr_chr_logo_upload_status_uuid =
+        BLE_UUID128_INIT(0xce, 0xde, 0x32, 0xe8, 0x00, 0xc7, 0x85, 0xa0,
+                         0xfd, 0x49, 0xc2, 0x0d, 0x44, 0x19, 0x45, 0x52);
+
+/* Max size of a control command or data chunk: 8-byte chunk header plus
+ * 512 bytes of data.  Must match rust/pinetime-logo/src/upload.rs
+ */
+#define LOGO_UPLOAD_MAX_WRITE 520
+
+/* Size of the upload status */
+#define LOGO_UPLOAD_STATUS_SIZE 8
+
+/* Defined in rust/app/src/logo_upload.rs */
+int logo_upload_control(const uint8_t *data, uint16_t len);
+int logo_upload_data(const uint8_t *data, uint16_t len);
+int logo_upload_status(uint8_t *buf, uint16_t len);
+
+static uint8_t gatt_svr_logo_upload_buf[LOGO_UPLOAD_MAX_WRITE];
+
 static int
 gatt_svr_chr_access_sec_test(uint16_t conn_handle, uint16_t attr_handle,
                              struct ble_gatt_access_ctxt *ctxt,
                              void *arg);
 
+static int
+gatt_svr_chr_access_logo_upload(uint16_t conn_handle, uint16_t attr_handle,
+                                struct ble_gatt_access_ctxt *ctxt,
+                                void *arg);
+
 static const struct ble_gatt_svc_def gatt_svr_svcs[] = {
     {
         /*** Service: Security test. */
@@ -80,6 +130,32 @@ static const struct ble_gatt_svc_def gatt_svr_svcs[] = {
         } },
     },
 
+    {
+        /*** Service: Logo upload. */
+        .type = BLE_GATT_SVC_TYPE_PRIMARY,
+        .uuid = &gatt_svr_svc_logo_upload_uuid.u,
+        .characteristics = (struct ble_gatt_chr_def[]) { {
+            /*** Characteristic: Control. */
+            .uuid = &gatt_svr_chr_logo_upload_control_uuid.u,
+            .access_cb = gatt_svr_chr_access_logo_upload,
+            .flags = BLE_GATT_CHR_F_WRITE |
+                     BLE_GATT_CHR_F_WRITE_ENC | BLE_GATT_CHR_F_WRITE_AUTHEN,
+        }, {
+            /*** Characteristic: Data. */
+            .uuid = &gatt_svr_chr_logo_upload_data_uuid.u,
+            .access_cb = gatt_svr_chr_access_logo_upload,
+            .flags = BLE_GATT_CHR_F_WRITE | BLE_GATT_CHR_F_WRITE_NO_RSP |
+                     BLE_GATT_CHR_F_WRITE_ENC | BLE_GATT_CHR_F_WRITE_AUTHEN,
+        }, {
+            /*** Characteristic: Status. */
+            .uuid = &gatt_svr_chr_logo_upload_status_uuid.u,
+            .access_cb = gatt_svr_chr_access_logo_upload,
+            .flags = BLE_GATT_CHR_F_READ,
+        }, {
+            0, /* No more characteristics in this service. */
+        } },
+    },
+
     {
         0, /* No more services. */
     },
@@ -156,6 +232,52 @@ gatt_svr_chr_access_sec_test(uint16_t conn_handle, uint16_t attr_handle,
     return BLE_ATT_ERR_UNLIKELY;
 }
 
+static int
+gatt_svr_chr_access_logo_upload(uint16_t conn_handle, uint16_t attr_handle,
+                                struct ble_gatt_access_ctxt *ctxt,
+                                void *arg)
+{
+    const ble_uuid_t *uuid;
+    uint8_t status[LOGO_UPLOAD_STATUS_SIZE];
+    uint16_t len;
+    int rc;
+
+    uuid = ctxt->chr->uuid;
+
+    if (ble_uuid_cmp(uuid, &gatt_svr_chr_logo_upload_status_uuid.u) == 0) {
+        assert(ctxt->op == BLE_GATT_ACCESS_OP_READ_CHR);
+
+        /* Respond with the upload status. */
+        len = logo_upload_status(status, sizeof status);
+        rc = os_mbuf_append(ctxt->om, status, len);
+        return rc == 0 ? 0 : BLE_ATT_ERR_INSUFFICIENT_RES;
+    }
+
+    assert(ctxt->op == BLE_GATT_ACCESS_OP_WRITE_CHR);
+    rc = gatt_svr_chr_write(ctxt->om, 1, sizeof gatt_svr_logo_upload_buf,
+                            gatt_svr_logo_upload_buf, &len);
+    if (rc != 0) {
+        return rc;
+    }
+
+    if (ble_uuid_cmp(uuid, &gatt_svr_chr_logo_upload_control_uuid.u) == 0) {
+        rc = logo_upload_control(gatt_svr_logo_upload_buf, len);
+    } else if (ble_uuid_cmp(uuid, &gatt_svr_chr_logo_upload_data_uuid.u) == 0) {
+        rc = logo_upload_data(gatt_svr_logo_upload_buf, len);
+    } else {
+        /* Unknown characteristic; the nimble stack should not have called
+         * this function.
+         */
+        assert(0);
+        return BLE_ATT_ERR_UNLIKELY;
+    }
+
+    /* Reject the write if the upload failed.  The client reads the status
+     * to find the missing chunks.
+     */
+    return rc == 0 ? 0 : BLE_ATT_ERR_UNLIKELY;
+}
+
 void
 gatt_svr_register_cb(struct ble_gatt_register_ctxt *ctxt, void *arg)
 {
@@ -270,6 +270,23 @@ bleprph_gap_event(struct ble_gap_event *event, void *arg)
         MODLOG_DFLT_FLUSH();
         return 0;
 
+    case BLE_GAP_EVENT_PASSKEY_ACTION:
+        /* Pairing with MITM protection: show a random passkey for the peer
+         * to enter.  Required by the Logo Upload characteristics.
+         */
+        if (event->passkey.params.action == BLE_SM_IOACT_DISP) {
+            struct ble_sm_io pkey = { 0 };
+            pkey.action = event->passkey.params.action;
+            rc = ble_hs_hci_util_rand(&pkey.passkey, sizeof(pkey.passkey));
+            assert(rc == 0);
+            pkey.passkey %= 1000000;
+            MODLOG_DFLT_INFO("passkey=%06lu\n", (unsigned long) pkey.passkey);
+            rc = ble_sm_inject_io(event->passkey.conn_handle, &pkey);
+            MODLOG_DFLT_INFO("ble_sm_inject_io result: %d\n", rc);
+        }
+        MODLOG_DFLT_FLUSH();
+        return 0;
+
     case BLE_GAP_EVENT_REPEAT_PAIRING:
         /* We already have a bond with the peer, but it is attempting to
          * establish a new secure link.  This app sacrifices security for
@@ -27,21 +27,39 @@
 #include <hal/hal_spi.h>
 #include <stdio.h>
 #include <string.h>
+#include "pinetime_logo/pinetime_logo.h"
 
 #define BATCH_SIZE  4096  //  Max number of data bytes to be written in a batch
 
 //  Flash Device for Image
 #define FLASH_DEVICE 1  //  0 for Internal Flash ROM, 1 for External SPI Flash
 
+//  Image Size
+#define IMAGE_WIDTH  240
+#define IMAGE_HEIGHT 240
 
-//  Converted from PNG file by https://github.com/lupyuen/pinetime-graphic
+
+//  Converted from PNG file by rust/pinetime-graphic: cargo run -p pinetime-graphic --target x86_64-unknown-linux-gnu -- logos/pinetime-green.png
 static const uint8_t image_data[] = {  //  Should be 115,200 bytes
 #include "write_graphic.inc"
 };
 
-/// Write a converted graphic file to SPI Flash
+/// Write a converted graphic file to SPI Flash, preceded by the image header. The header is written last,
+/// so that an interrupted write leaves no valid header and the bootloader displays the fallback screen.
 int write_graphic(void) {
     console_printf("Writing graphic to flash...\n"); console_flush();
+    uint32_t total = PINETIME_LOGO_HEADER_SIZE + sizeof(image_data);
+
+    //  Erase the header and the pixels.
+    for (uint32_t offset = 0; offset < total; offset += BATCH_SIZE) {
+        uint32_t len = BATCH_SIZE;
+        if (offset + len >= total) {
+            len = total - offset;
+        }
+        int rc = hal_flash_erase(FLASH_DEVICE, offset, len); assert(rc == 0);
+    }
+
+    //  Write the pixels after the header.
     uint32_t offset = 0;
     for (;;) {
         if (offset >= sizeof(image_data)) { break; }
@@ -50,13 +68,24 @@ int write_graphic(void) {
         if (offset + len >= sizeof(image_data)) {
             len = sizeof(image_data) - offset;
         }        
-        //  Erase the bytes.
-        int rc = hal_flash_erase(FLASH_DEVICE, offset, len); assert(rc == 0);
-
         //  Write the bytes.
-        rc = hal_flash_write(FLASH_DEVICE, offset, (void *) &image_data[offset], len); assert(rc == 0);
+        int rc = hal_flash_write(FLASH_DEVICE, PINETIME_LOGO_HEADER_SIZE + offset, (void *) &image_data[offset], len); assert(rc == 0);
         offset += len;
     }
+
+    //  Write the header.
+    struct pinetime_logo_header header = {
+        .magic       = PINETIME_LOGO_MAGIC,
+        .version     = PINETIME_LOGO_VERSION,
+        .width       = IMAGE_WIDTH,
+        .height      = IMAGE_HEIGHT,
+        .format      = PINETIME_LOGO_FORMAT_RGB565_BE,
+        .codec       = PINETIME_LOGO_CODEC_RAW,
+        .payload_len = sizeof(image_data),
+        .crc32       = pinetime_logo_crc32(0, image_data, sizeof(image_data)),
+    };
+    int rc = hal_flash_write(FLASH_DEVICE, 0, &header, sizeof(header)); assert(rc == 0);
     console_printf("Graphic written to flash\n"); console_flush();
     return 0;
-}
\ No newline at end of file
+}
+
@@ -17,9 +17,9 @@
  * under the License.
  */
 //  Used for generating Rust bindings for HAL APIs
-//#include <hal/hal_bsp.h>
-//#include <hal/hal_flash.h>
-//#include <hal/hal_flash_int.h>
+#include <hal/hal_bsp.h>
+#include <hal/hal_flash.h>
+#include <hal/hal_flash_int.h>
 #include <hal/hal_gpio.h>
 #include <hal/hal_i2c.h>
 //#include <hal/hal_nvreg.h>
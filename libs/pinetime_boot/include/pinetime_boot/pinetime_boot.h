@@ -20,20 +20,87 @@
 #ifndef __PINETIME_BOOT_H__
 #define __PINETIME_BOOT_H__
 #include <stdint.h>
+#include "pinetime_logo/pinetime_logo.h"  //  Boot logo header and pinetime_logo_crc32()
 
 #ifdef __cplusplus
 extern "C" {  //  Expose the types and functions below to C functions.
 #endif
 
+/// Animation header, written ahead of the frames in SPI Flash. Must match `rust/pinetime-logo/src/anim.rs`.
+/// All fields are little-endian.
+struct pinetime_anim_header {
+    uint8_t  magic[4];     //  "PTAN"
+    uint16_t version;      //  PINETIME_ANIM_VERSION
+    uint16_t width;        //  Width in pixels
+    uint16_t height;       //  Height in pixels
+    uint16_t frame_count;  //  Number of frames
+    uint32_t payload_len;  //  Payload Length in bytes: all frames
+    uint32_t crc32;        //  CRC32 of the payload
+} __attribute__((packed));
+
+/// Animation frame header, followed by the RGB565 pixels of the dirty rectangle
+struct pinetime_anim_frame {
+    uint16_t delay_ms;     //  Delay in milliseconds after displaying the frame
+    uint8_t  left;         //  Left column of the dirty rectangle, relative to the animation
+    uint8_t  top;          //  Top row of the dirty rectangle
+    uint8_t  right;        //  Right column of the dirty rectangle (inclusive)
+    uint8_t  bottom;       //  Bottom row of the dirty rectangle (inclusive)
+    uint8_t  codec;        //  Codec: 0 for Raw, 1 for RLE
+    uint8_t  reserved;     //  0
+    uint32_t data_len;     //  Data Length in bytes. 0 if no pixels changed.
+} __attribute__((packed));
+
+#define PINETIME_ANIM_MAGIC       "PTAN"  //  Magic number at the start of the animation header
+#define PINETIME_ANIM_VERSION     1       //  Current version of the animation header
+#define PINETIME_ANIM_HEADER_SIZE 20      //  Size of the animation header. The frames follow the header.
+#define PINETIME_ANIM_FRAME_SIZE  12      //  Size of the frame header. The frame data follows the frame header.
+
+/// Boot logo slot table, stored in the last sector of the Bootloader Assets area in SPI Flash.
+/// Must match `rust/pinetime-logo/src/slots.rs`. All fields are little-endian.
+struct pinetime_logo_table {
+    uint8_t  magic[4];     //  "PTST"
+    uint16_t version;      //  PINETIME_LOGO_TABLE_VERSION
+    uint8_t  active;       //  Active slot
+    uint8_t  slot_count;   //  PINETIME_LOGO_SLOT_COUNT
+} __attribute__((packed));
+
+#define PINETIME_LOGO_SLOT_COUNT     2        //  Number of logo slots
+#define PINETIME_LOGO_SLOT_SIZE      0x1D000  //  Size of each slot. Slot 0 starts at offset 0.
+#define PINETIME_LOGO_TABLE_OFFSET   0x3F000  //  Offset of the slot table: last sector of the Bootloader Assets area
+#define PINETIME_LOGO_TABLE_MAGIC    "PTST"   //  Magic number at the start of the slot table
+#define PINETIME_LOGO_TABLE_VERSION  1        //  Current version of the slot table
+#define PINETIME_LOGO_SECTOR_SIZE    4096     //  Size of an erasable SPI Flash sector
+
 /// Init the display and render the boot graphic. Called by sysinit() during startup, defined in pkg.yml.
 void pinetime_boot_init(void);
 
 /// Write a converted graphic file to SPI Flash
 int pinetime_boot_write_image(void);
 
-/// Display the image in SPI Flash to ST7789 display controller
+/// Display the image in the active slot of SPI Flash to ST7789 display controller
 int pinetime_boot_display_image(void);
 
+/// Display the image or play the animation in the slot to ST7789 display controller. The display must have been initialised.
+int pinetime_boot_display_slot(uint8_t slot);
+
+/// Return 0 if the slot contains a valid image or animation
+int pinetime_boot_check_slot(uint8_t slot);
+
+/// Read and verify the image header in the slot. Return 0 if the image may be displayed.
+int pinetime_boot_verify_slot(uint8_t slot, struct pinetime_logo_header *header);
+
+/// Return the active slot recorded in the slot table, or slot 0 if the table is missing
+uint8_t pinetime_boot_active_slot(void);
+
+/// Record the slot as the active slot in the slot table
+int pinetime_boot_set_active_slot(uint8_t slot);
+
+/// Return the next slot after the slot that contains a valid image or animation, or -1 if none
+int pinetime_boot_next_slot(uint8_t slot);
+
+/// After the rollback window, press and hold the button to cycle through the slots, and save the selected slot
+void pinetime_boot_select_slot(void);
+
 /// Check whether the watch button is pressed
 void pinetime_boot_check_button(void);
 
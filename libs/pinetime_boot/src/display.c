@@ -27,6 +27,7 @@
 #include <hal/hal_spi.h>
 #include <stdio.h>
 #include <string.h>
+#include "defs/error.h"
 #include "pinetime_boot/pinetime_boot.h"
 
 //  GPIO Pins. From rust\piet-embedded\piet-embedded-graphics\src\display.rs
@@ -43,13 +44,21 @@
 #define COL_COUNT 240
 #define BYTES_PER_PIXEL 2
 
-//  ST7789 Colour Settings
-#define INVERTED 1  //  Display colours are inverted
-#define RGB      1  //  Display colours are RGB    
+//  ST7789 Display Settings from syscfg.yml. Same as `pinetime_display::st7789::DisplayConfig` in Rust.
+#define ROTATION MYNEWT_VAL(PINETIME_BOOT_DISPLAY_ROTATION)  //  Clockwise rotation in degrees: 0, 90, 180 or 270
+#define MIRROR   MYNEWT_VAL(PINETIME_BOOT_DISPLAY_MIRROR)    //  1 if the picture is mirrored left to right
+#define INVERTED MYNEWT_VAL(PINETIME_BOOT_DISPLAY_INVERTED)  //  1 if display colours are inverted
+#define RGB      MYNEWT_VAL(PINETIME_BOOT_DISPLAY_RGB)       //  1 if display colours are RGB, 0 if BGR
+
+//  ST7789 Frame Memory has 320 rows, but only the first ROW_COUNT rows are displayed
+#define MEMORY_ROWS 320
 
 //  Flash Device for Image
 #define FLASH_DEVICE 1  //  0 for Internal Flash ROM, 1 for External SPI Flash
 
+//  Colour of the fallback screen when the image is missing or corrupted: Dark blue (RGB565)
+#define FALLBACK_COLOR 0x0010
+
 //  ST7789 Commands. From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/instruction.rs
 #define NOP 0x00
 #define SWRESET 0x01
@@ -89,64 +98,156 @@
 #define GMCTRP1 0xE0
 #define GMCTRN1 0xE1
 
-//  ST7789 Orientation. From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/lib.rs#L52-L58
-#define Portrait 0x00
-#define Landscape 0x60
-#define PortraitSwapped 0xC0
-#define LandscapeSwapped 0xA0
+//  ST7789 MADCTL bits: Row address order (MY), column address order (MX), row / column exchange (MV), BGR colour order
+#define MADCTL_MY  0x80
+#define MADCTL_MX  0x40
+#define MADCTL_MV  0x20
+#define MADCTL_BGR 0x08
 
 static int init_display(void);
 static int set_window(uint8_t left, uint8_t top, uint8_t right, uint8_t bottom);
 static int hard_reset(void);
-static int set_orientation(uint8_t orientation);
+static int configure_display(void);
 static int write_command(uint8_t command, const uint8_t *params, uint16_t len);
 static int write_data(const uint8_t *data, uint16_t len);
 static int transmit_spi(const uint8_t *data, uint16_t len);
 static void delay_ms(uint32_t ms);
+static void frame_delay_ms(uint32_t ms);
+static int render_image(uint8_t slot, const struct pinetime_logo_header *header);
+static int verify_animation(uint8_t slot, struct pinetime_anim_header *header);
+static int play_animation(uint8_t slot, const struct pinetime_anim_header *header);
+static int render_pixels(uint32_t offset, uint32_t len, uint8_t codec, uint8_t left, uint8_t top, uint16_t width, uint16_t height);
+static int display_fallback(uint16_t color);
+static uint32_t crc_flash(uint32_t offset, uint32_t len);
+
+/// Reader for the raw or run-length encoded pixels in flash. Same RLE packets as rust/pinetime-logo/src/rle.rs:
+/// 0x80 | (n - 1) followed by 1 pixel for a run of n pixels, (n - 1) followed by n pixels for n literal pixels.
+struct pixel_reader {
+    uint32_t offset;     //  Flash offset of the next batch
+    uint32_t remaining;  //  Number of bytes remaining to be read from flash
+    uint16_t pos;        //  Next byte to be decoded in flash_buffer
+    uint16_t len;        //  Number of bytes in flash_buffer
+    uint8_t  codec;      //  PINETIME_LOGO_CODEC_RAW or PINETIME_LOGO_CODEC_RLE
+    uint8_t  run;        //  1 if the current packet is a run, 0 if literal
+    uint8_t  count;      //  Number of pixels remaining in the current packet
+    uint8_t  pixel[2];   //  Pixel repeated by the current run
+};
+
+static int read_pixels(struct pixel_reader *reader, uint8_t *pixels, uint16_t count);
+static int read_byte(struct pixel_reader *reader, uint8_t *byte);
 
 /// Buffer for reading flash and writing to display
 static uint8_t flash_buffer[BATCH_SIZE];
 
-/// Display the image in SPI Flash to ST7789 display controller. 
+/// Buffer for one row of decoded pixels
+static uint8_t row_buffer[COL_COUNT * BYTES_PER_PIXEL];
+
+/// Offsets of the display window in the ST7789 Frame Memory for the rotation and mirroring
+static uint16_t column_offset, row_offset;
+
+/// Display the image in the active slot of SPI Flash to ST7789 display controller. If the image is missing
+/// or corrupted, display the next valid slot or the fallback screen instead.
 /// Derived from https://github.com/lupyuen/pinetime-rust-mynewt/blob/main/logs/spi-non-blocking.log
 int pinetime_boot_display_image(void) {
     console_printf("Displaying image...\n"); console_flush();
     int rc = init_display();  assert(rc == 0);
-    rc = set_orientation(Landscape);  assert(rc == 0);
-
-    //  Render each row of pixels.
-    for (uint8_t row = 0; row < ROW_COUNT; row++) {
-        uint8_t top = row;
-        uint8_t bottom = row;
-        uint8_t left = 0;
-        //  Screen Buffer: 240 * 240 * 2 / 1024 = 112.5 KB
-        //  Render a batch of columns in that row.
-        for (;;) {
-            if (left >= COL_COUNT) { break; }
-
-            //  How many columns we will render in a batch.
-            uint16_t batch_columns = BATCH_SIZE / BYTES_PER_PIXEL;
-            uint16_t right = left + batch_columns - 1;
-            if (right >= COL_COUNT) { right = COL_COUNT - 1; }
-
-            //  How many bytes we will transmit.
-            uint16_t len = (right - left + 1) * BYTES_PER_PIXEL;
+    rc = configure_display();  assert(rc == 0);
+
+    //  Verify the header and CRC before rendering. Erased flash or a half-written image will fail here.
+    uint8_t slot = pinetime_boot_active_slot();
+    rc = pinetime_boot_display_slot(slot);
+    if (rc != 0) {
+        //  Display the next valid slot, or the fallback screen if none.
+        console_printf("Bad image %d in slot %d\n", rc, slot); console_flush();
+        int next = pinetime_boot_next_slot(slot);
+        if (next >= 0) { rc = pinetime_boot_display_slot(next); }
+        if (rc != 0) {
+            console_printf("Displaying fallback\n"); console_flush();
+            rc = display_fallback(FALLBACK_COLOR); assert(rc == 0);
+        }
+    }
+    console_printf("Image displayed\n"); console_flush();
+    return 0;
+}
 
-            //  Read the bytes from flash memory.
-            uint32_t offset = ((top * COL_COUNT) + left) * BYTES_PER_PIXEL;
-            int rc = hal_flash_read(FLASH_DEVICE, offset, flash_buffer, len); assert(rc == 0);
+/// Display the image or play the animation in the slot to ST7789 display controller. The display must have been
+/// initialised. Return 0 if displayed, else the verification error from pinetime_boot_check_slot().
+int pinetime_boot_display_slot(uint8_t slot) {
+    struct pinetime_logo_header header;
+    int rc = pinetime_boot_verify_slot(slot, &header);
+    if (rc == 0) { return render_image(slot, &header); }
+    if (rc != SYS_ENOENT) { return rc; }
+
+    //  Not an image, check for animation.
+    struct pinetime_anim_header anim_header;
+    rc = verify_animation(slot, &anim_header);
+    if (rc != 0) { return rc; }
+    return play_animation(slot, &anim_header);
+}
 
-            //  console_printf("%lx: ", offset); console_dump(flash_buffer, len); console_printf("\n"); console_flush();
+/// Return 0 if the slot contains a valid image or animation, else the verification error
+int pinetime_boot_check_slot(uint8_t slot) {
+    struct pinetime_logo_header header;
+    int rc = pinetime_boot_verify_slot(slot, &header);
+    if (rc != SYS_ENOENT) { return rc; }
 
-            //  Set the display window.
-            rc = set_window(left, top, right, bottom); assert(rc == 0);
+    //  Not an image, check for animation.
+    struct pinetime_anim_header anim_header;
+    return verify_animation(slot, &anim_header);
+}
 
-            //  Write Pixels (RAMWR): st7735_lcd::draw() → set_pixel()
-            rc = write_command(RAMWR, NULL, 0); assert(rc == 0);
-            rc = write_data(flash_buffer, len); assert(rc == 0);
+/// Render the verified image in the slot with the header, centred on the screen
+static int render_image(uint8_t slot, const struct pinetime_logo_header *header) {
+    uint32_t slot_offset = (uint32_t) slot * PINETIME_LOGO_SLOT_SIZE;
+    uint8_t left_margin = (COL_COUNT - header->width) / 2;
+    uint8_t top_margin  = (ROW_COUNT - header->height) / 2;
+    return render_pixels(
+        slot_offset + PINETIME_LOGO_HEADER_SIZE, header->payload_len, header->codec,
+        left_margin, top_margin, header->width, header->height
+    );
+}
 
-            left = right + 1;
+/// Play the verified animation in the slot with the header, centred on the screen. Each frame after the first
+/// contains only the dirty rectangle that changed since the previous frame.
+static int play_animation(uint8_t slot, const struct pinetime_anim_header *header) {
+    console_printf("Playing animation...\n"); console_flush();
+    uint32_t offset = (uint32_t) slot * PINETIME_LOGO_SLOT_SIZE + PINETIME_ANIM_HEADER_SIZE;
+    uint8_t left_margin = (COL_COUNT - header->width) / 2;
+    uint8_t top_margin  = (ROW_COUNT - header->height) / 2;
+    for (uint16_t i = 0; i < header->frame_count; i++) {
+        struct pinetime_anim_frame frame;
+        int rc = hal_flash_read(FLASH_DEVICE, offset, &frame, sizeof(frame)); assert(rc == 0);
+
+        //  Render the dirty rectangle, unless no pixels changed.
+        if (frame.data_len > 0) {
+            rc = render_pixels(
+                offset + PINETIME_ANIM_FRAME_SIZE, frame.data_len, frame.codec,
+                left_margin + frame.left, top_margin + frame.top,
+                frame.right - frame.left + 1, frame.bottom - frame.top + 1
+            );
+            if (rc != 0) { return rc; }
         }
+        frame_delay_ms(frame.delay_ms);
+        offset += PINETIME_ANIM_FRAME_SIZE + frame.data_len;
+    }
+    return 0;
+}
+
+/// Decode the len bytes of raw or RLE pixels at offset in flash and render them row by row into the display window
+/// at (left, top) with the width and height. Return SYS_EINVAL if there are not enough pixels.
+static int render_pixels(uint32_t offset, uint32_t len, uint8_t codec, uint8_t left, uint8_t top, uint16_t width, uint16_t height) {
+    struct pixel_reader reader = { .offset = offset, .remaining = len, .codec = codec };
+    for (uint16_t row = 0; row < height; row++) {
+        int rc = read_pixels(&reader, row_buffer, width);
+        if (rc != 0) { return rc; }
+        uint8_t y = top + row;
+
+        //  Set the display window.
+        rc = set_window(left, y, left + width - 1, y); assert(rc == 0);
+
+        //  Write Pixels (RAMWR): st7735_lcd::draw() → set_pixel()
+        rc = write_command(RAMWR, NULL, 0); assert(rc == 0);
+        rc = write_data(row_buffer, width * BYTES_PER_PIXEL); assert(rc == 0);
     }
 
     /*
@@ -180,8 +281,150 @@ int pinetime_boot_display_image(void) {
     static const uint8_t RAMWR2_PARA[] = { 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0, 0x87, 0xe0 };
     write_data(RAMWR2_PARA, sizeof(RAMWR2_PARA));  //  40 bytes
     */
+    return 0;
+}
 
-    console_printf("Image displayed\n"); console_flush();
+/// Decode count pixels into the pixels buffer. Return SYS_EINVAL if there are not enough pixels.
+static int read_pixels(struct pixel_reader *reader, uint8_t *pixels, uint16_t count) {
+    for (uint16_t i = 0; i < count; i++) {
+        uint8_t *pixel = &pixels[i * BYTES_PER_PIXEL];
+        int rc;
+        if (reader->codec == PINETIME_LOGO_CODEC_RLE) {
+            //  Start the next packet.
+            if (reader->count == 0) {
+                uint8_t control;
+                rc = read_byte(reader, &control); if (rc != 0) { return rc; }
+                reader->run   = (control & 0x80) ? 1 : 0;
+                reader->count = (control & 0x7f) + 1;
+                if (reader->run) {
+                    rc = read_byte(reader, &reader->pixel[0]); if (rc != 0) { return rc; }
+                    rc = read_byte(reader, &reader->pixel[1]); if (rc != 0) { return rc; }
+                }
+            }
+            reader->count--;
+            //  Repeat the pixel for a run.
+            if (reader->run) {
+                pixel[0] = reader->pixel[0];
+                pixel[1] = reader->pixel[1];
+                continue;
+            }
+        }
+        //  Copy the raw or literal pixel.
+        rc = read_byte(reader, &pixel[0]); if (rc != 0) { return rc; }
+        rc = read_byte(reader, &pixel[1]); if (rc != 0) { return rc; }
+    }
+    return 0;
+}
+
+/// Return the next byte of pixel data, reading from flash in batches. Return SYS_EINVAL if no more data.
+static int read_byte(struct pixel_reader *reader, uint8_t *byte) {
+    if (reader->pos >= reader->len) {
+        if (reader->remaining == 0) { return SYS_EINVAL; }
+        uint16_t len = (reader->remaining < BATCH_SIZE) ? reader->remaining : BATCH_SIZE;
+        int rc = hal_flash_read(FLASH_DEVICE, reader->offset, flash_buffer, len); assert(rc == 0);
+        reader->offset    += len;
+        reader->remaining -= len;
+        reader->pos = 0;
+        reader->len = len;
+    }
+    *byte = flash_buffer[reader->pos++];
+    return 0;
+}
+
+/// Read the image header in the slot and check the magic number, version, dimensions, format, codec and CRC32.
+/// Return 0 if the image may be displayed, SYS_ENOENT if there is no image, SYS_EINVAL if the image is corrupted
+/// or the slot doesn't exist, SYS_ENOTSUP if the image format is not supported by the bootloader.
+int pinetime_boot_verify_slot(uint8_t slot, struct pinetime_logo_header *header) {
+    if (slot >= PINETIME_LOGO_SLOT_COUNT) { return SYS_EINVAL; }
+    uint32_t slot_offset = (uint32_t) slot * PINETIME_LOGO_SLOT_SIZE;
+    int rc = hal_flash_read(FLASH_DEVICE, slot_offset, header, sizeof(*header)); assert(rc == 0);
+    if (memcmp(header->magic, PINETIME_LOGO_MAGIC, sizeof(header->magic)) != 0) { return SYS_ENOENT; }  //  Erased or never written
+    if (header->version != PINETIME_LOGO_VERSION) { return SYS_ENOTSUP; }
+    if (header->width == 0 || header->width > COL_COUNT ||
+        header->height == 0 || header->height > ROW_COUNT) { return SYS_EINVAL; }
+    if (header->format != PINETIME_LOGO_FORMAT_RGB565_BE) { return SYS_ENOTSUP; }
+    if (header->payload_len > PINETIME_LOGO_SLOT_SIZE - PINETIME_LOGO_HEADER_SIZE) { return SYS_EINVAL; }
+    switch (header->codec) {
+        case PINETIME_LOGO_CODEC_RAW:  //  Raw payload must contain exactly all the pixels
+            if (header->payload_len != (uint32_t) header->width * header->height * BYTES_PER_PIXEL) { return SYS_EINVAL; }
+            break;
+        case PINETIME_LOGO_CODEC_RLE:  //  RLE payload must not be empty
+            if (header->payload_len == 0) { return SYS_EINVAL; }
+            break;
+        default: return SYS_ENOTSUP;
+    }
+
+    //  Compute the CRC32 of the payload in batches.
+    uint32_t crc = crc_flash(slot_offset + PINETIME_LOGO_HEADER_SIZE, header->payload_len);
+    if (crc != header->crc32) { return SYS_EINVAL; }
+    return 0;
+}
+
+/// Read the animation header in the slot, check the magic number, version, dimensions and CRC32, and check that
+/// every frame fits inside the animation. Return 0 if the animation may be played, SYS_ENOENT if there is no
+/// animation, SYS_EINVAL if the animation is corrupted, SYS_ENOTSUP if the animation format is not supported.
+static int verify_animation(uint8_t slot, struct pinetime_anim_header *header) {
+    if (slot >= PINETIME_LOGO_SLOT_COUNT) { return SYS_EINVAL; }
+    uint32_t slot_offset = (uint32_t) slot * PINETIME_LOGO_SLOT_SIZE;
+    int rc = hal_flash_read(FLASH_DEVICE, slot_offset, header, sizeof(*header)); assert(rc == 0);
+    if (memcmp(header->magic, PINETIME_ANIM_MAGIC, sizeof(header->magic)) != 0) { return SYS_ENOENT; }  //  Erased or never written
+    if (header->version != PINETIME_ANIM_VERSION) { return SYS_ENOTSUP; }
+    if (header->width == 0 || header->width > COL_COUNT ||
+        header->height == 0 || header->height > ROW_COUNT ||
+        header->frame_count == 0) { return SYS_EINVAL; }
+    if (header->payload_len > PINETIME_LOGO_SLOT_SIZE - PINETIME_ANIM_HEADER_SIZE) { return SYS_EINVAL; }
+
+    //  Compute the CRC32 of the payload in batches.
+    uint32_t crc = crc_flash(slot_offset + PINETIME_ANIM_HEADER_SIZE, header->payload_len);
+    if (crc != header->crc32) { return SYS_EINVAL; }
+
+    //  Walk the frames and check that they fill the payload exactly.
+    uint32_t offset = 0;
+    for (uint16_t i = 0; i < header->frame_count; i++) {
+        if (offset + PINETIME_ANIM_FRAME_SIZE > header->payload_len) { return SYS_EINVAL; }
+        struct pinetime_anim_frame frame;
+        rc = hal_flash_read(FLASH_DEVICE, slot_offset + PINETIME_ANIM_HEADER_SIZE + offset, &frame, sizeof(frame)); assert(rc == 0);
+        if (frame.left > frame.right || frame.top > frame.bottom ||
+            frame.right >= header->width || frame.bottom >= header->height) { return SYS_EINVAL; }
+        if (frame.codec != PINETIME_LOGO_CODEC_RAW && frame.codec != PINETIME_LOGO_CODEC_RLE) { return SYS_ENOTSUP; }
+        uint32_t pixels_len = (uint32_t) (frame.right - frame.left + 1) * (frame.bottom - frame.top + 1) * BYTES_PER_PIXEL;
+        if (frame.codec == PINETIME_LOGO_CODEC_RAW && frame.data_len > 0 && frame.data_len != pixels_len) { return SYS_EINVAL; }
+        offset += PINETIME_ANIM_FRAME_SIZE + frame.data_len;
+        if (offset > header->payload_len) { return SYS_EINVAL; }
+    }
+    if (offset != header->payload_len) { return SYS_EINVAL; }
+    return 0;
+}
+
+/// Compute the CRC32 of the len bytes at offset in flash, reading in batches
+static uint32_t crc_flash(uint32_t offset, uint32_t len) {
+    uint32_t crc = 0;
+    for (uint32_t pos = 0; pos < len; pos += BATCH_SIZE) {
+        uint32_t batch_len = len - pos;
+        if (batch_len > BATCH_SIZE) { batch_len = BATCH_SIZE; }
+        int rc = hal_flash_read(FLASH_DEVICE, offset + pos, flash_buffer, batch_len); assert(rc == 0);
+        crc = pinetime_logo_crc32(crc, flash_buffer, batch_len);
+    }
+    return crc;
+}
+
+/// Fill the screen with a solid colour (RGB565). Shown when the image is missing or corrupted.
+static int display_fallback(uint16_t color) {
+    //  Fill the flash buffer with the colour, high byte first.
+    for (int i = 0; i < BATCH_SIZE; i += BYTES_PER_PIXEL) {
+        flash_buffer[i] = color >> 8;
+        flash_buffer[i + 1] = color & 0xff;
+    }
+    //  Render each row of pixels in batches of columns.
+    for (uint8_t row = 0; row < ROW_COUNT; row++) {
+        for (uint16_t left = 0; left < COL_COUNT; left += BATCH_SIZE / BYTES_PER_PIXEL) {
+            uint16_t right = left + BATCH_SIZE / BYTES_PER_PIXEL - 1;
+            if (right >= COL_COUNT) { right = COL_COUNT - 1; }
+            int rc = set_window(left, row, right, row); assert(rc == 0);
+            rc = write_command(RAMWR, NULL, 0); assert(rc == 0);
+            rc = write_data(flash_buffer, (right - left + 1) * BYTES_PER_PIXEL); assert(rc == 0);
+        }
+    }
     return 0;
 }
 
@@ -192,17 +435,24 @@ static int set_window(uint8_t left, uint8_t top, uint8_t right, uint8_t bottom)
     assert(top <= bottom);
     //  Set Address Window Columns (CASET): st7735_lcd::draw() → set_pixel() → set_address_window()
     int rc = write_command(CASET, NULL, 0); assert(rc == 0);
-    uint8_t col_para[4] = { 0x00, left, 0x00, right };
+    uint16_t col_start = left + column_offset, col_end = right + column_offset;
+    uint8_t col_para[4] = { col_start >> 8, col_start & 0xff, col_end >> 8, col_end & 0xff };
     rc = write_data(col_para, 4); assert(rc == 0);
 
     //  Set Address Window Rows (RASET): st7735_lcd::draw() → set_pixel() → set_address_window()
     rc = write_command(RASET, NULL, 0); assert(rc == 0);
-    uint8_t row_para[4] = { 0x00, top, 0x00, bottom };
+    uint16_t row_start = top + row_offset, row_end = bottom + row_offset;
+    uint8_t row_para[4] = { row_start >> 8, row_start & 0xff, row_end >> 8, row_end & 0xff };
     rc = write_data(row_para, 4); assert(rc == 0);
     return 0;
 }
 
-/// Runs commands to initialize the display. From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/lib.rs
+/// ST7789 init sequence, encoded by the ST7789 command module in Rust: rust/pinetime-display/src/st7789.rs
+static const uint8_t init_script[] = {
+#include "st7789_init.inc"
+};
+
+/// Runs commands to initialize the display. The commands are shared with Rust, see rust/pinetime-display/src/st7789.rs
 static int init_display(void) {
     //  Assume that SPI port 0 has been initialised by the SPI Flash Driver at startup.
     int rc;
@@ -213,58 +463,17 @@ static int init_display(void) {
     rc = hal_gpio_init_out(DISPLAY_HIGH, 0); assert(rc == 0);
 
     hard_reset();
-    write_command(SWRESET, NULL, 0);
-    delay_ms(200);
-    write_command(SLPOUT, NULL, 0);
-    delay_ms(200);
-
-    static const uint8_t FRMCTR1_PARA[] = { 0x01, 0x2C, 0x2D };
-    write_command(FRMCTR1, FRMCTR1_PARA, sizeof(FRMCTR1_PARA));
-
-    static const uint8_t FRMCTR2_PARA[] = { 0x01, 0x2C, 0x2D };
-    write_command(FRMCTR2, FRMCTR2_PARA, sizeof(FRMCTR2_PARA));
-
-    static const uint8_t FRMCTR3_PARA[] = { 0x01, 0x2C, 0x2D, 0x01, 0x2C, 0x2D };
-    write_command(FRMCTR3, FRMCTR3_PARA, sizeof(FRMCTR3_PARA));
-
-    static const uint8_t INVCTR_PARA[] = { 0x07 };
-    write_command(INVCTR, INVCTR_PARA, sizeof(INVCTR_PARA));
-
-    static const uint8_t PWCTR1_PARA[] = { 0xA2, 0x02, 0x84 };
-    write_command(PWCTR1, PWCTR1_PARA, sizeof(PWCTR1_PARA));
-
-    static const uint8_t PWCTR2_PARA[] = { 0xC5 };
-    write_command(PWCTR2, PWCTR2_PARA, sizeof(PWCTR2_PARA));
-    
-    static const uint8_t PWCTR3_PARA[] = { 0x0A, 0x00 };
-    write_command(PWCTR3, PWCTR3_PARA, sizeof(PWCTR3_PARA));
-    
-    static const uint8_t PWCTR4_PARA[] = { 0x8A, 0x2A };
-    write_command(PWCTR4, PWCTR4_PARA, sizeof(PWCTR4_PARA));
-    
-    static const uint8_t PWCTR5_PARA[] = { 0x8A, 0xEE };
-    write_command(PWCTR5, PWCTR5_PARA, sizeof(PWCTR5_PARA));
-    
-    static const uint8_t VMCTR1_PARA[] = { 0x0E };
-    write_command(VMCTR1, VMCTR1_PARA, sizeof(VMCTR1_PARA));
-
-    if (INVERTED) {
-        write_command(INVON, NULL, 0);
-    } else {
-        write_command(INVOFF, NULL, 0);
-    }
-    if (RGB) {
-        static const uint8_t MADCTL1_PARA[] = { 0x00 };
-        write_command(MADCTL, MADCTL1_PARA, sizeof(MADCTL1_PARA));
-    } else {
-        static const uint8_t MADCTL2_PARA[] = { 0x08 };
-        write_command(MADCTL, MADCTL2_PARA, sizeof(MADCTL2_PARA));
+
+    //  Replay the init script: opcode, number of parameters, parameters, delay in milliseconds.
+    //  Same as `pinetime_display::st7789::replay_script()` in Rust.
+    for (uint16_t pos = 0; pos + 3 <= sizeof(init_script); ) {
+        uint8_t command = init_script[pos];
+        uint8_t len     = init_script[pos + 1];
+        uint8_t delay   = init_script[pos + 2 + len];
+        rc = write_command(command, &init_script[pos + 2], len); assert(rc == 0);
+        if (delay > 0) { delay_ms(delay); }
+        pos += 3 + len;
     }
-    static const uint8_t COLMOD_PARA[] = { 0x05 };
-    write_command(COLMOD, COLMOD_PARA, sizeof(COLMOD_PARA));
-    
-    write_command(DISPON, NULL, 0);
-    delay_ms(200);
     return 0;
 }
 
@@ -276,17 +485,27 @@ static int hard_reset(void) {
     return 0;
 }
 
-/// Set the display orientation
-static int set_orientation(uint8_t orientation) {
-    if (RGB) {
-        uint8_t orientation_para[1] = { orientation };
-        int rc = write_command(MADCTL, orientation_para, 1);
-        assert(rc == 0);
-    } else {
-        uint8_t orientation_para[1] = { orientation | 0x08 };
-        int rc = write_command(MADCTL, orientation_para, 1);
-        assert(rc == 0);
+/// Apply the rotation, mirroring, colour inversion and colour order from syscfg.yml and compute the window offsets.
+/// Same as `pinetime_display::st7789::configure()` in Rust. Rotation 0 is the Landscape orientation, which matches the touch panel.
+static int configure_display(void) {
+    uint8_t madctl;
+    switch (ROTATION) {
+        case 90:  madctl = MADCTL_MX | MADCTL_MY; break;  //  Portrait Swapped
+        case 180: madctl = MADCTL_MY | MADCTL_MV; break;  //  Landscape Swapped
+        case 270: madctl = 0; break;                      //  Portrait
+        default:  madctl = MADCTL_MX | MADCTL_MV; break;  //  Landscape
     }
+    //  After row / column exchange, columns of the picture are rows of the frame memory
+    if (MIRROR) { madctl ^= (madctl & MADCTL_MV) ? MADCTL_MY : MADCTL_MX; }
+    if (!RGB) { madctl |= MADCTL_BGR; }
+
+    //  When the row address order is reversed, the displayed rows are at the end of the frame memory
+    uint16_t offset = (madctl & MADCTL_MY) ? MEMORY_ROWS - ROW_COUNT : 0;
+    column_offset = (madctl & MADCTL_MV) ? offset : 0;
+    row_offset    = (madctl & MADCTL_MV) ? 0 : offset;
+
+    int rc = write_command(INVERTED ? INVON : INVOFF, NULL, 0); assert(rc == 0);
+    rc = write_command(MADCTL, &madctl, 1); assert(rc == 0);
     return 0;
 }
 
@@ -339,3 +558,18 @@ static void delay_ms(uint32_t ms) {
     }
 #endif  //  MYNEWT_VAL(OS_SCHEDULING)
 }
+
+/// Sleep for the delay after an animation frame, in milliseconds. Unlike delay_ms(), which waits 1 second in MCUBoot
+/// whatever the delay, the busy-wait is scaled to the delay in steps of 1/64 second (same as the button check
+/// in pinetime_boot_init()). Otherwise every frame would be shown for 1 second and the animation would hold up
+/// the boot. The display init delays still use delay_ms().
+static void frame_delay_ms(uint32_t ms) {
+#if MYNEWT_VAL(OS_SCHEDULING)  //  If Task Scheduler is enabled (i.e. not MCUBoot)...
+    delay_ms(ms);
+#else  //  If Task Scheduler is disabled (i.e. MCUBoot)...
+    uint32_t steps = (ms * 64 + 999) / 1000;  //  Round up to the next step
+    for (uint32_t i = 0; i < steps; i++) {
+        for (int delay = 0; delay < 100000; delay++) {}
+    }
+#endif  //  MYNEWT_VAL(OS_SCHEDULING)
+}
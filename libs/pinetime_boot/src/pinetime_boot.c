@@ -75,6 +75,10 @@ void pinetime_boot_init(void) {
         hal_system_reset();
         return;
     }
+
+    //  No rollback. If the button is pressed after the rollback window, cycle through the image slots
+    //  until the button is released. Selecting the slot never triggers a rollback.
+    pinetime_boot_select_slot();
 }
 
 /// Called by MCUBoot when it has completed its work.
@@ -0,0 +1,113 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//  Select the boot image slot in SPI Flash. Must match rust/pinetime-logo/src/slots.rs
+#include <inttypes.h>
+#include "os/mynewt.h"
+#include <console/console.h>
+#include <hal/hal_flash.h>
+#include <hal/hal_gpio.h>
+#include <string.h>
+#include "defs/error.h"
+#include "pinetime_boot/pinetime_boot.h"
+
+#define PUSH_BUTTON_IN  13  //  GPIO Pin P0.13: PUSH BUTTON_IN
+
+//  Flash Device for Image
+#define FLASH_DEVICE 1  //  0 for Internal Flash ROM, 1 for External SPI Flash
+
+//  Number of button samples per second while selecting the slot. Same as the button check in pinetime_boot_init()
+#define SAMPLES_PER_SECOND 64
+
+//  Number of seconds to wait for the button to be pressed after the rollback window
+#define SELECT_WAIT_SECONDS 1
+
+static int wait_for_button(int samples, int pressed);
+
+/// Return the active slot recorded in the slot table, or slot 0 if the table is missing or invalid
+uint8_t pinetime_boot_active_slot(void) {
+    struct pinetime_logo_table table;
+    int rc = hal_flash_read(FLASH_DEVICE, PINETIME_LOGO_TABLE_OFFSET, &table, sizeof(table)); assert(rc == 0);
+    if (memcmp(table.magic, PINETIME_LOGO_TABLE_MAGIC, sizeof(table.magic)) != 0 ||
+        table.version != PINETIME_LOGO_TABLE_VERSION ||
+        table.active >= PINETIME_LOGO_SLOT_COUNT) { return 0; }
+    return table.active;
+}
+
+/// Record the slot as the active slot in the slot table
+int pinetime_boot_set_active_slot(uint8_t slot) {
+    if (slot >= PINETIME_LOGO_SLOT_COUNT) { return SYS_EINVAL; }
+    struct pinetime_logo_table table = {
+        .magic      = PINETIME_LOGO_TABLE_MAGIC,
+        .version    = PINETIME_LOGO_TABLE_VERSION,
+        .active     = slot,
+        .slot_count = PINETIME_LOGO_SLOT_COUNT,
+    };
+    int rc = hal_flash_erase(FLASH_DEVICE, PINETIME_LOGO_TABLE_OFFSET, PINETIME_LOGO_SECTOR_SIZE); assert(rc == 0);
+    rc = hal_flash_write(FLASH_DEVICE, PINETIME_LOGO_TABLE_OFFSET, &table, sizeof(table)); assert(rc == 0);
+    return 0;
+}
+
+/// Return the next slot after the slot that contains a valid image or animation, wrapping around to slot 0.
+/// Return the slot itself if no other slot is valid, or -1 if no slot is valid.
+int pinetime_boot_next_slot(uint8_t slot) {
+    for (int i = 1; i <= PINETIME_LOGO_SLOT_COUNT; i++) {
+        uint8_t next = (slot + i) % PINETIME_LOGO_SLOT_COUNT;
+        if (pinetime_boot_check_slot(next) == 0) { return next; }
+    }
+    return -1;
+}
+
+/// Called by pinetime_boot_init() after the rollback window, so the button is not held. If the button is pressed
+/// within 1 second, display the next valid slot, then the following valid slot every second while the button is held.
+/// When the button is released, save the displayed slot as the active slot. Does nothing if there are fewer than
+/// 2 valid slots. Holding the button during the rollback window still rolls back the firmware.
+void pinetime_boot_select_slot(void) {
+    uint8_t active = pinetime_boot_active_slot();
+    int next = pinetime_boot_next_slot(active);
+    if (next < 0 || next == active) { return; }  //  Nothing to select
+
+    console_printf("Press button to select slot...\n"); console_flush();
+    if (!wait_for_button(SELECT_WAIT_SECONDS * SAMPLES_PER_SECOND, 1)) { return; }  //  Button not pressed
+    uint8_t slot = active;
+    for (;;) {
+        //  Display the next valid slot.
+        next = pinetime_boot_next_slot(slot);
+        if (next < 0) { break; }
+        slot = next;
+        console_printf("Slot %d\n", slot); console_flush();
+        int rc = pinetime_boot_display_slot(slot); assert(rc == 0);
+
+        //  Wait 1 second, stop if the button is released.
+        if (wait_for_button(SAMPLES_PER_SECOND, 0)) { break; }
+    }
+    if (slot != active) {
+        int rc = pinetime_boot_set_active_slot(slot); assert(rc == 0);
+        console_printf("Active slot %d\n", slot); console_flush();
+    }
+}
+
+/// Sample the button up to `samples` times, 64 samples per second. Return 1 as soon as the button is pressed
+/// (if `pressed` is 1) or released (if `pressed` is 0), or 0 if the button doesn't change.
+static int wait_for_button(int samples, int pressed) {
+    for (int i = 0; i < samples; i++) {
+        for (int delay = 0; delay < 100000; delay++);
+        if (hal_gpio_read(PUSH_BUTTON_IN) == pressed) { return 1; }
+    }
+    return 0;
+}
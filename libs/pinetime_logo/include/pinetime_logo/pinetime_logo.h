@@ -0,0 +1,55 @@
+/*
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *  http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+//  Boot logo header and CRC32, shared by the bootloader and the application
+#ifndef __PINETIME_LOGO_H__
+#define __PINETIME_LOGO_H__
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {  //  Expose the types and functions below to C functions.
+#endif
+
+/// Boot logo header, written ahead of the pixels in SPI Flash. Must match `rust/pinetime-logo/src/header.rs`.
+/// All fields are little-endian.
+struct pinetime_logo_header {
+    uint8_t  magic[4];     //  "PTLG"
+    uint16_t version;      //  PINETIME_LOGO_VERSION
+    uint16_t width;        //  Width in pixels
+    uint16_t height;       //  Height in pixels
+    uint8_t  format;       //  Pixel Format: 0 for RGB565 big-endian
+    uint8_t  codec;        //  Codec: 0 for Raw, 1 for RLE
+    uint32_t payload_len;  //  Payload Length in bytes
+    uint32_t crc32;        //  CRC32 of the payload
+} __attribute__((packed));
+
+#define PINETIME_LOGO_MAGIC       "PTLG"  //  Magic number at the start of the header
+#define PINETIME_LOGO_VERSION     1       //  Current version of the header
+#define PINETIME_LOGO_HEADER_SIZE 20      //  Size of the header. The payload follows the header.
+#define PINETIME_LOGO_FORMAT_RGB565_BE 0  //  Pixel Format: 16-bit RGB565, high byte first
+#define PINETIME_LOGO_CODEC_RAW   0       //  Codec: Uncompressed pixels
+#define PINETIME_LOGO_CODEC_RLE   1       //  Codec: Run-length encoded pixels
+
+/// Compute the CRC32 (IEEE 802.3, same as zlib) of `data`, continuing from the previous value `crc`. Start with `crc = 0`.
+uint32_t pinetime_logo_crc32(uint32_t crc, const uint8_t *data, uint32_t len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  //  __PINETIME_LOGO_H__
@@ -0,0 +1,69 @@
+//! PineTime Side Button. The button interrupt forwards an event to the Default Event Queue, which publishes
+//! the press to the subscribers of `events::BUTTON`, e.g. for pausing and resuming the CHIP-8 Emulator.
+use mynewt::{
+    result::*,
+    hw::hal,
+    kernel::os::{
+        self,
+        os_event,
+    },
+    fill_zero,
+};
+use crate::events::{ self, ButtonAction, Event };
+
+/// Side button input: P0.13/PUSH_BUTTON_IN, high when pressed
+const BUTTON_PIN: i32 = 13;
+
+/// Side button output: P0.15/PUSH_BUTTON_OUT, must be high for the button to work
+const BUTTON_ENABLE_PIN: i32 = 15;
+
+/// Ignore presses within this number of milliseconds after the last press, because the button bounces
+const DEBOUNCE_MS: u32 = 300;
+
+/// Time of the last press, in ticks
+static mut LAST_PRESS_TICKS: u32 = 0;
+
+/// Event that will be forwarded to the Event Queue when the button is pressed
+static mut BUTTON_EVENT: os_event = fill_zero!(os_event);  //  Init all fields to 0 or NULL
+
+/// Start monitoring the side button
+pub fn start_button() -> MynewtResult<()> {
+    //  Initialise the button event with the callback function
+    unsafe { BUTTON_EVENT.ev_cb = Some( button_event_callback ) };
+
+    //  Enable the button
+    let rc = unsafe { hal::hal_gpio_init_out(BUTTON_ENABLE_PIN, 1) };
+    if rc != 0 { return Err(MynewtError::SYS_EINVAL); }
+
+    //  Configure the button interrupt (active when high)
+    let rc = unsafe { hal::hal_gpio_irq_init(
+        BUTTON_PIN,                       //  GPIO pin to be configured
+        Some( button_interrupt_handler ), //  Call `button_interrupt_handler()` when the button is pressed
+        core::ptr::null_mut(),            //  No arguments for `button_interrupt_handler()`
+        hal::hal_gpio_irq_trigger_HAL_GPIO_TRIG_RISING,  //  Trigger when button goes from low to high
+        hal::hal_gpio_pull_HAL_GPIO_PULL_DOWN            //  Pull down the GPIO pin
+    ) };
+    if rc != 0 { return Err(MynewtError::SYS_EINVAL); }
+
+    //  Start monitoring for button interrupts
+    unsafe { hal::hal_gpio_irq_enable(BUTTON_PIN) };
+    Ok(())
+}
+
+/// Interrupt handler for the side button, triggered when the button is pressed. Don't do any processing here.
+extern "C" fn button_interrupt_handler(_arg: *mut core::ffi::c_void) {
+    let now = unsafe { os::os_time_get() };
+    unsafe {
+        if now.wrapping_sub(LAST_PRESS_TICKS) < DEBOUNCE_MS * os::OS_TICKS_PER_SEC / 1000 { return; }
+        LAST_PRESS_TICKS = now;
+    }
+    //  Forward the button event to the Default Event Queue for deferred processing
+    let queue = os::eventq_dflt_get()
+        .expect("GET fail");
+    unsafe { os::os_eventq_put(queue, &mut BUTTON_EVENT) };  //  Trigger the callback function `button_event_callback()`
+}
+
+/// Callback for the button event that is triggered when the button is pressed
+extern "C" fn button_event_callback(_event: *mut os_event) {
+    events::publish(Event::Button(ButtonAction::Press));
+}
@@ -0,0 +1,235 @@
+//! Touch calibration for PineTime. The calibration routine draws a target near each corner of the display and records
+//! where the touch panel is touched. It then computes the affine transform from the touch coordinates to the display
+//! panel with `pinetime_touch::calibration`. The calibration is saved in one sector of External SPI Flash, just before
+//! the CHIP8 settings, and loaded at startup:
+//! ```text
+//! Offset  Size  Contents
+//! 0       4     Magic "TCAL"
+//! 4       2     Version (u16, little endian)
+//! 6       2     Reserved, must be 0
+//! 8       24    Calibration, encoded by `pinetime_touch::Calibration::to_bytes()`
+//! ```
+//! The targets are drawn on the rotated and mirrored picture, then mapped back to the display panel with
+//! `st7789::inverse_transform_touch()`. So the calibration is computed for the display panel and stays valid
+//! when the display rotation changes.
+use embedded_graphics::{
+    prelude::*,
+    fonts,
+    pixelcolor::Rgb565,
+    primitives::{
+        Rectangle,
+    },
+};
+use mynewt::{
+    hal::{ Flash, SECTOR_SIZE },
+    kernel::os,
+    result::*,
+    st7789,
+    sys::console,
+};
+use pinetime_touch::calibration::CALIBRATION_SIZE;
+use crate::{
+    draw,
+    events::{ self, Event, Propagation },
+    flash_layout,
+    touch_sensor::{ self, Calibration, TouchAction },
+};
+
+/// Offset of the calibration in SPI Flash: the sector before the CHIP8 settings in the Application Data area
+/// (`FLASH_AREA_APP_DATA` in `hw/bsp/nrf52/bsp.yml`)
+pub const CALIBRATION_OFFSET: u32 = flash_layout::CALIBRATION_OFFSET;
+
+/// SPI Flash device of the Application Data area
+const FLASH_DEVICE: u8 = flash_layout::APP_DATA_DEVICE;
+
+/// Magic number at the start of the calibration: "TCAL"
+const CALIBRATION_MAGIC: [u8; 4] = *b"TCAL";
+
+/// Current version of the calibration format
+const CALIBRATION_VERSION: u16 = 1;
+
+/// Size of the calibration header
+const HEADER_SIZE: usize = 8;
+
+/// Targets drawn by the calibration routine, in picture coordinates: 30 pixels from each corner, clockwise from top left
+const TARGETS: [(u16, u16); 4] = [ (30, 30), (209, 30), (209, 209), (30, 209) ];
+
+/// Half the width of the cross drawn at each target, in pixels
+const TARGET_SIZE: i32 = 10;
+
+/// State of the calibration routine
+static mut ROUTINE: Routine = Routine::new();
+
+/// State of the calibration routine
+#[derive(Clone, Copy)]
+struct Routine {
+    /// True while the calibration routine is waiting for touches
+    active:    bool,
+    /// True if the side button was pressed to cancel the calibration routine
+    cancelled: bool,
+    /// Touch coordinates for each target that has been touched, without calibration, rotation and mirroring
+    touches:   [(u16, u16); TARGETS.len()],
+    /// Number of targets that have been touched
+    count:     usize,
+    /// Sum of the touch x coordinates for the current target
+    sum_x:     u32,
+    /// Sum of the touch y coordinates for the current target
+    sum_y:     u32,
+    /// Number of touch coordinates summed for the current target
+    samples:   u32,
+}
+
+impl Routine {
+    /// Return the state before any target has been touched
+    const fn new() -> Routine {
+        Routine {
+            active: false, cancelled: false, touches: [(0, 0); TARGETS.len()], count: 0,
+            sum_x: 0, sum_y: 0, samples: 0,
+        }
+    }
+}
+
+/// Calibrate the touch panel with the calibration saved in SPI Flash.
+/// Fail with `SYS_ENOENT` if there is no calibration, `SYS_ENOTSUP` if the version is unsupported,
+/// `SYS_EINVAL` if the calibration is invalid.
+pub fn load_calibration() -> MynewtResult<()> {
+    let mut buf = [0u8; HEADER_SIZE + CALIBRATION_SIZE];
+    Flash::new(FLASH_DEVICE) ?
+        .read(CALIBRATION_OFFSET, &mut buf) ? ;
+    if buf[0..4] != CALIBRATION_MAGIC { return Err(MynewtError::SYS_ENOENT); }
+    if u16::from_le_bytes([ buf[4], buf[5] ]) != CALIBRATION_VERSION { return Err(MynewtError::SYS_ENOTSUP); }
+    let calibration = Calibration::from_bytes(&buf[HEADER_SIZE..])
+        .ok_or(MynewtError::SYS_EINVAL) ? ;
+    touch_sensor::set_calibration(calibration);
+    Ok(())
+}
+
+/// Save the `calibration` to SPI Flash, so that it's loaded at startup
+fn save_calibration(calibration: &Calibration) -> MynewtResult<()> {
+    let mut buf = [0u8; HEADER_SIZE + CALIBRATION_SIZE];
+    buf[0..4].copy_from_slice(&CALIBRATION_MAGIC);
+    buf[4..6].copy_from_slice(&CALIBRATION_VERSION.to_le_bytes());
+    buf[HEADER_SIZE..].copy_from_slice(&calibration.to_bytes());
+    let mut flash = Flash::new(FLASH_DEVICE) ? ;
+    flash.erase_range(CALIBRATION_OFFSET, SECTOR_SIZE) ? ;
+    flash.write(CALIBRATION_OFFSET, &buf)
+}
+
+/// Run the calibration routine: draw each target and wait for it to be touched, then calibrate the touch panel and
+/// save the calibration to SPI Flash. Processes the Default Event Queue until done, so it must be called by the main
+/// task after starting the touch sensor and the side button, before launching the apps. Press the side button to
+/// cancel. Fail with `SYS_EINVAL` if cancelled, or if the targets were touched in the wrong places. The previous
+/// calibration is kept if the calibration routine fails.
+pub fn calibrate() -> MynewtResult<()> {
+    console::print("Rust touch calibration\n"); console::flush();
+    let previous = touch_sensor::calibration();
+
+    //  Record the touch coordinates without calibration
+    touch_sensor::set_calibration(Calibration::identity());
+    unsafe { ROUTINE = Routine { active: true, ..Routine::new() } };
+    let subscriber = events::subscribe("calibrate", events::PRIORITY_OVERLAY, events::ALL, handle_event) ? ;
+    draw_target(0);
+
+    //  Handle the touch and button events until every target has been touched or the side button is pressed
+    let queue = os::eventq_dflt_get() ? ;
+    while unsafe { ROUTINE.active } {
+        os::eventq_run(queue) ? ;
+    }
+    events::unsubscribe(subscriber) ? ;
+    draw_background();
+
+    //  Calibrate the touch panel if every target was touched in the right place
+    let routine = unsafe { ROUTINE };
+    let calibration =
+        if routine.cancelled { None }
+        else { compute_calibration(&routine.touches) };
+    match calibration {
+        Some(calibration) => {
+            touch_sensor::set_calibration(calibration);
+            save_calibration(&calibration)
+        }
+        None => {
+            touch_sensor::set_calibration(previous);
+            Err(MynewtError::SYS_EINVAL)
+        }
+    }
+}
+
+/// Compute the calibration for the `touches` at the targets. Return `None` if a target was touched in the wrong place.
+fn compute_calibration(touches: &[(u16, u16)]) -> Option<Calibration> {
+    //  Map the targets back to the display panel, like the touch coordinates
+    let mut targets = TARGETS;
+    for target in targets.iter_mut() {
+        *target = st7789::inverse_transform_touch(target.0, target.1);
+    }
+    Calibration::from_touches(touches, &targets)
+}
+
+/// Record the touches on the targets. Consume all events, so that the apps don't see the touches on the targets.
+fn handle_event(event: &Event) -> Propagation {
+    let routine = unsafe { &mut ROUTINE };
+    if !routine.active { return Propagation::PassThrough; }
+    match *event {
+        //  Average the touch coordinates of the first finger on the current target
+        Event::Touch { finger: 0, action, x, y } if matches!(action, TouchAction::Down | TouchAction::Contact) => {
+            //  Touch coordinates were rotated and mirrored to match the picture, so map them back to the touch panel
+            let (x, y) = st7789::inverse_transform_touch(x, y);
+            routine.sum_x += x as u32;
+            routine.sum_y += y as u32;
+            routine.samples += 1;
+        }
+        //  Record the touch when the finger is lifted, then draw the next target
+        Event::Touch { finger: 0, action: TouchAction::Up, .. } if routine.samples > 0 => {
+            routine.touches[routine.count] = (
+                (routine.sum_x / routine.samples) as u16,
+                (routine.sum_y / routine.samples) as u16,
+            );
+            routine.count += 1;
+            routine.sum_x = 0;
+            routine.sum_y = 0;
+            routine.samples = 0;
+            if routine.count < TARGETS.len() { draw_target(routine.count); }
+            else { routine.active = false; }
+        }
+        //  Cancel the calibration routine
+        Event::Button(_) => {
+            routine.cancelled = true;
+            routine.active = false;
+        }
+        _ => {}
+    }
+    Propagation::Consume
+}
+
+/// Draw a cross at the target `index` with the instructions
+fn draw_target(index: usize) {
+    draw_background();
+    let (x, y) = (TARGETS[index].0 as i32, TARGETS[index].1 as i32);
+    let horizontal = Rectangle::<Rgb565>
+        ::new( Coord::new( x - TARGET_SIZE, y - 1 ), Coord::new( x + TARGET_SIZE, y + 1 ) )  //  Rectangle coordinates
+        .fill( Some( Rgb565::from(( 0xff, 0xff, 0xff )) ) );  //  White
+    let vertical = Rectangle::<Rgb565>
+        ::new( Coord::new( x - 1, y - TARGET_SIZE ), Coord::new( x + 1, y + TARGET_SIZE ) )  //  Rectangle coordinates
+        .fill( Some( Rgb565::from(( 0xff, 0xff, 0xff )) ) );  //  White
+    draw::draw_to_display(horizontal);
+    draw::draw_to_display(vertical);
+    draw_centred_text("Tap the centre of the cross", 108);
+    draw_centred_text("Press the button to cancel", 124);
+}
+
+/// Draw the black background
+fn draw_background() {
+    let background = Rectangle::<Rgb565>
+        ::new( Coord::new( 0, 0 ), Coord::new( 239, 239 ) )   //  Rectangle coordinates
+        .fill( Some( Rgb565::from(( 0x00, 0x00, 0x00 )) ) );  //  Black
+    draw::draw_to_display(background);
+}
+
+/// Draw `text` in grey, centred horizontally at row `top`
+fn draw_centred_text(text: &str, top: i32) {
+    let text = fonts::Font6x8::<Rgb565>
+        ::render_str(text)                                     //  Text to be rendered
+        .stroke( Some( Rgb565::from(( 0x80, 0x80, 0x80 )) ) )  //  Grey text
+        .translate( Coord::new( 120 - 3 * text.len() as i32, top ));  //  Shift the text
+    draw::draw_to_display(text);
+}
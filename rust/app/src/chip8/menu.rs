@@ -0,0 +1,57 @@
+//! CHIP-8 ROM Selection Menu. Lists the ROMs from the ROM Registry, one ROM per row, and maps a touch
+//! to the ROM in the touched row.
+use embedded_graphics::{
+    prelude::*,
+    fonts,
+    pixelcolor::Rgb565,
+    primitives::{
+        Rectangle,
+    },
+};
+use crate::draw;
+use super::roms::{ RomInfo, MAX_ROMS };
+
+/// Top of the first row of the menu, in Physical Pixels
+const MENU_TOP: u16 = 36;
+
+/// Height of each row of the menu, in Physical Pixels
+const ROW_HEIGHT: u16 = 24;
+
+/// Left margin of the ROM names, in Physical Pixels
+const MENU_LEFT: i32 = 20;
+
+/// Render the menu of `roms` to the display, replacing the emulator screen
+pub fn show_menu(roms: &[RomInfo]) {
+    //  Create black background
+    let background = Rectangle::<Rgb565>
+        ::new( Coord::new( 0, 0 ), Coord::new( 239, 239 ) )   //  Rectangle coordinates
+        .fill( Some( Rgb565::from(( 0x00, 0x00, 0x00 )) ) );  //  Black
+    draw::draw_to_display(background);
+
+    //  Create title
+    let title = fonts::Font12x16::<Rgb565>
+        ::render_str(" Select a ROM ")                         //  Text to be rendered
+        .stroke( Some( Rgb565::from(( 0x00, 0x00, 0x00 )) ) )  //  Black text
+        .fill(   Some( Rgb565::from(( 0xff, 0xff, 0x00 )) ) )  //  Yellow background
+        .translate( Coord::new( 36, 8 ));                      //  Shift the text
+    draw::draw_to_display(title);
+
+    //  Create one row per ROM
+    for (index, rom) in roms.iter().take(MAX_ROMS).enumerate() {
+        let top = MENU_TOP + index as u16 * ROW_HEIGHT;
+        let name = fonts::Font12x16::<Rgb565>
+            ::render_str(rom.name)                                 //  Text to be rendered
+            .stroke( Some( Rgb565::from(( 0xff, 0xff, 0xff )) ) )  //  White text
+            .translate( Coord::new( MENU_LEFT, top as i32 + 4 ));  //  Centre the text in the row
+        draw::draw_to_display(name);
+    }
+}
+
+/// Return the index of the ROM in the row touched at `y`, or None if no ROM was touched.
+/// `count` is the number of ROMs in the menu.
+pub fn select_rom(y: u16, count: usize) -> Option<usize> {
+    if y < MENU_TOP { return None; }
+    let index = ((y - MENU_TOP) / ROW_HEIGHT) as usize;
+    if index < count.min(MAX_ROMS) { Some(index) }
+    else { None }
+}
@@ -0,0 +1,175 @@
+//! CHIP-8 ROM Registry. Lists the ROMs bundled into the firmware from `rust/app/roms` and the ROMs stored in
+//! External SPI Flash, with the metadata needed to play each ROM on PineTime.
+//! ROMs in SPI Flash occupy one 4 KB sector each, starting at `FLASH_ROM_OFFSET`:
+//! ```text
+//! Offset  Size  Contents
+//! 0       4     Magic "C8RM"
+//! 4       2     Version (u16, little endian)
+//! 6       2     ROM length in bytes (u16, little endian)
+//! 8       2     Recommended speed in instructions per second (u16, little endian)
+//! 10      1     Keypad layout number, an index into `keypad::LAYOUTS`
+//! 11      2     Reserved, must be 0
+//! 13      1     Length of the name
+//! 14      18    Name (UTF-8)
+//! 32      ...   ROM
+//! ```
+use mynewt::{
+    hal::Flash,
+    result::*,
+};
+use pinetime_chip8::keypad::{ self, KeypadLayout };
+use crate::flash_layout;
+
+/// Max number of ROMs in the menu
+pub const MAX_ROMS: usize = 8;
+
+/// Max size of a CHIP-8 ROM: 4 KB of CHIP-8 memory, less the 512 bytes reserved for the interpreter
+pub const MAX_ROM_SIZE: usize = 4096 - 512;
+
+/// Flash device for the ROMs: External SPI Flash
+pub const FLASH_DEVICE: u8 = flash_layout::APP_DATA_DEVICE;
+
+/// Offset of the first ROM in SPI Flash, in the Application Data area
+pub const FLASH_ROM_OFFSET: u32 = flash_layout::ROMS_OFFSET;
+
+/// Max number of ROMs in SPI Flash, one per sector
+pub const MAX_FLASH_ROMS: usize = (flash_layout::ROMS_SIZE / FLASH_ROM_SIZE) as usize;
+
+/// Size of a ROM entry in SPI Flash
+pub const FLASH_ROM_SIZE: u32 = 4096;
+
+/// Magic number at the start of a ROM in SPI Flash: "C8RM"
+pub const FLASH_ROM_MAGIC: [u8; 4] = *b"C8RM";
+
+/// Current version of the ROM header in SPI Flash
+pub const FLASH_ROM_VERSION: u16 = 1;
+
+/// Size of the ROM header in SPI Flash
+pub const FLASH_ROM_HEADER_SIZE: usize = 32;
+
+/// Max length of a ROM name in SPI Flash
+const MAX_NAME_LEN: usize = FLASH_ROM_HEADER_SIZE - 14;
+
+/// Emulation speed for ROMs that don't recommend one, in instructions per second
+pub const DEFAULT_SPEED: u16 = 500;
+
+/// Where a ROM is stored
+#[derive(Clone, Copy)]
+pub enum RomSource {
+    /// Bundled into the firmware
+    Bundled(&'static [u8]),
+    /// Stored in SPI Flash at this offset, with this length
+    Flash(u32, u16),
+}
+
+/// A ROM that may be selected in the menu
+#[derive(Clone, Copy)]
+pub struct RomInfo {
+    /// Name shown in the menu
+    pub name:   &'static str,
+    /// Where the ROM is stored
+    pub source: RomSource,
+    /// Touch keypad layout
+    pub keypad: &'static KeypadLayout,
+    /// Recommended emulation speed in instructions per second
+    pub speed:  u16,
+}
+
+/// ROMs bundled into the firmware
+pub static BUNDLED_ROMS: &[RomInfo] = &[
+    RomInfo {
+        name:   "Invaders",
+        source: RomSource::Bundled(include_bytes!("../../roms/invaders.ch8")),
+        keypad: &keypad::THIRDS,
+        speed:  500,
+    },
+    RomInfo {
+        name:   "Blinky",
+        source: RomSource::Bundled(include_bytes!("../../roms/blinky.ch8")),
+        keypad: &keypad::DIRECTION_PAD,
+        speed:  700,
+    },
+    RomInfo {
+        name:   "Maze",
+        source: RomSource::Bundled(include_bytes!("../../roms/maze.ch8")),
+        keypad: &keypad::NO_KEYS,
+        speed:  DEFAULT_SPEED,
+    },
+    RomInfo {
+        name:   "Pong",
+        source: RomSource::Bundled(include_bytes!("../../roms/pong.ch8")),
+        keypad: &keypad::PADDLES,
+        speed:  DEFAULT_SPEED,
+    },
+    RomInfo {
+        name:   "Tetris",
+        source: RomSource::Bundled(include_bytes!("../../roms/tetris.ch8")),
+        keypad: &keypad::FALLING_BLOCKS,
+        speed:  DEFAULT_SPEED,
+    },
+];
+
+/// Names of the ROMs found in SPI Flash
+static mut FLASH_NAMES: [[u8; MAX_NAME_LEN]; MAX_FLASH_ROMS] = [[0; MAX_NAME_LEN]; MAX_FLASH_ROMS];
+
+/// Buffer for the ROM loaded from SPI Flash
+static mut ROM_BUFFER: [u8; MAX_ROM_SIZE] = [0; MAX_ROM_SIZE];
+
+/// Return the bundled ROMs followed by the valid ROMs in SPI Flash, up to `MAX_ROMS`.
+/// SPI Flash is skipped if it can't be opened.
+pub fn list_roms() -> heapless::Vec<RomInfo, heapless::consts::U8> {
+    let mut roms = heapless::Vec::new();
+    for rom in BUNDLED_ROMS.iter().take(MAX_ROMS) {
+        let _ = roms.push(*rom);
+    }
+    if let Ok(mut flash) = Flash::new(FLASH_DEVICE) {
+        for index in 0..MAX_FLASH_ROMS {
+            if roms.len() >= MAX_ROMS { break; }
+            if let Ok(rom) = read_flash_rom(&mut flash, index) {
+                let _ = roms.push(rom);
+            }
+        }
+    }
+    roms
+}
+
+/// Return the ROM bytes for `rom`, loading from SPI Flash if necessary
+pub fn load_rom(rom: &RomInfo) -> MynewtResult<&'static [u8]> {
+    match rom.source {
+        RomSource::Bundled(bytes) => Ok(bytes),
+        RomSource::Flash(offset, len) => {
+            let buf = unsafe { &mut ROM_BUFFER[..len as usize] };
+            Flash::new(FLASH_DEVICE) ?
+                .read(offset, buf) ? ;
+            Ok(buf)
+        }
+    }
+}
+
+/// Read and validate the header of ROM number `index` in SPI Flash. Fail with `SYS_ENOENT` if there is no ROM,
+/// `SYS_ENOTSUP` if the version is unsupported, `SYS_EINVAL` if the header is invalid.
+fn read_flash_rom(flash: &mut Flash, index: usize) -> MynewtResult<RomInfo> {
+    let offset = FLASH_ROM_OFFSET + index as u32 * FLASH_ROM_SIZE;
+    let mut header = [0u8; FLASH_ROM_HEADER_SIZE];
+    flash.read(offset, &mut header) ? ;
+    if header[0..4] != FLASH_ROM_MAGIC { return Err(MynewtError::SYS_ENOENT); }
+    if u16::from_le_bytes([ header[4], header[5] ]) != FLASH_ROM_VERSION { return Err(MynewtError::SYS_ENOTSUP); }
+    let len   = u16::from_le_bytes([ header[6], header[7] ]);
+    let speed = u16::from_le_bytes([ header[8], header[9] ]);
+    let name_len = header[13] as usize;
+    if len == 0 || len as usize > MAX_ROM_SIZE || name_len > MAX_NAME_LEN { return Err(MynewtError::SYS_EINVAL); }
+    let keypad = keypad::LAYOUTS.get(header[10] as usize).cloned()
+        .ok_or(MynewtError::SYS_EINVAL) ? ;
+
+    //  Keep the name for the menu
+    let name_buf = unsafe { &mut FLASH_NAMES[index] };
+    name_buf[..name_len].copy_from_slice(&header[14..14 + name_len]);
+    let name = core::str::from_utf8(unsafe { &FLASH_NAMES[index][..name_len] })
+        .map_err(|_| MynewtError::SYS_EINVAL) ? ;
+    Ok(RomInfo {
+        name,
+        source: RomSource::Flash(offset + FLASH_ROM_HEADER_SIZE as u32, len),
+        keypad,
+        speed:  if speed == 0 { DEFAULT_SPEED } else { speed },
+    })
+}
@@ -0,0 +1,73 @@
+//! CHIP-8 Save State in External SPI Flash. When a ROM is paused, the emulator is saved with the format in
+//! `pinetime_chip8::snapshot`, so that the ROM may be resumed after switching apps or after a reboot.
+//! There is one save state, stored just before the ROMs in SPI Flash.
+use libchip8::State;
+use mynewt::{
+    hal::Flash,
+    result::*,
+};
+use pinetime_chip8::snapshot::{ self, SnapshotError, SNAPSHOT_SIZE };
+use super::roms::{ self, RomInfo };
+use crate::flash_layout;
+
+/// Offset of the save state in SPI Flash: the sectors before the ROMs in the Application Data area
+pub const SAVE_STATE_OFFSET: u32 = flash_layout::SAVE_STATE_OFFSET;
+
+/// Size of the save state area in SPI Flash, rounded up to whole sectors
+pub const SAVE_STATE_SIZE: u32 = flash_layout::SAVE_STATE_SIZE;
+
+/// Buffer for saving and restoring. Static because it's too big for the stack.
+static mut BUFFER: [u8; SNAPSHOT_SIZE] = [0; SNAPSHOT_SIZE];
+
+/// Save the emulator `state` and the Virtual Screen to SPI Flash, for the ROM `rom` with contents `program`.
+/// Replaces any save state.
+pub fn save(state: &State, rom: &RomInfo, program: &[u8]) -> MynewtResult<()> {
+    let buf = unsafe { &mut BUFFER };
+    snapshot::save(state, rom.name, program, buf)
+        .map_err(convert_error) ? ;
+    let mut flash = Flash::new(roms::FLASH_DEVICE) ? ;
+    flash.erase_range(SAVE_STATE_OFFSET, SAVE_STATE_SIZE) ? ;
+    flash.write(SAVE_STATE_OFFSET, buf)
+}
+
+/// Read the save state from SPI Flash and return the ROM in `roms` that it belongs to.
+/// Fail with `SYS_ENOENT` if there is no save state or its ROM is not in `roms`, `SYS_ENOTSUP` if the
+/// save state was created by an unknown version, `SYS_EINVAL` if the save state is corrupted.
+pub fn load(roms: &[RomInfo]) -> MynewtResult<RomInfo> {
+    let buf = unsafe { &mut BUFFER };
+    Flash::new(roms::FLASH_DEVICE) ?
+        .read(SAVE_STATE_OFFSET, buf) ? ;
+    let info = snapshot::read_info(buf)
+        .map_err(convert_error) ? ;
+    roms.iter()
+        .find(|rom| rom.name.starts_with(info.rom_name))  //  Name may have been truncated
+        .cloned()
+        .ok_or(MynewtError::SYS_ENOENT)
+}
+
+/// Restore the emulator `state` and the Virtual Screen from the save state read by `load()`, for the ROM
+/// with contents `program`. Fail with `SYS_ENOENT` if the ROM has changed since the save state was created.
+pub fn restore(program: &[u8], state: &mut State) -> MynewtResult<()> {
+    let buf = unsafe { &BUFFER };
+    snapshot::restore(buf, program, state)
+        .map_err(convert_error)
+}
+
+/// Erase the save state from SPI Flash
+pub fn clear() -> MynewtResult<()> {
+    Flash::new(roms::FLASH_DEVICE) ?
+        .erase_range(SAVE_STATE_OFFSET, SAVE_STATE_SIZE) ? ;
+    Ok(())
+}
+
+/// Convert a save state error to a Mynewt error
+fn convert_error(err: SnapshotError) -> MynewtError {
+    match err {
+        SnapshotError::BufferTooSmall     => MynewtError::SYS_ENOMEM,
+        SnapshotError::BadMagic           => MynewtError::SYS_ENOENT,
+        SnapshotError::UnsupportedVersion => MynewtError::SYS_ENOTSUP,
+        SnapshotError::BadChecksum        => MynewtError::SYS_EINVAL,
+        SnapshotError::BadState           => MynewtError::SYS_EINVAL,
+        SnapshotError::RomMismatch        => MynewtError::SYS_ENOENT,
+    }
+}
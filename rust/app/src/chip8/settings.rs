@@ -0,0 +1,132 @@
+//! CHIP-8 Display Settings in External SPI Flash. The colour theme and fade curve chosen for each ROM are
+//! remembered, so that the ROM has the same look when it's started again. The settings are stored in one sector,
+//! just before the save state in SPI Flash:
+//! ```text
+//! Offset  Size  Contents
+//! 0       4     Magic "C8ST"
+//! 4       2     Version (u16, little endian)
+//! 6       1     Number of entries
+//! 7       1     Reserved, must be 0
+//! 8       32    Entries, up to `MAX_ENTRIES`
+//! ```
+//! Each entry is 32 bytes:
+//! ```text
+//! Offset  Size  Contents
+//! 0       1     Length of the ROM name
+//! 1       25    ROM name (UTF-8)
+//! 26      6     Settings, encoded by `pinetime_chip8::palette::Settings::to_bytes()`
+//! ```
+use mynewt::{
+    hal::{ Flash, SECTOR_SIZE },
+    result::*,
+};
+use pinetime_chip8::palette::{ Settings, SETTINGS_SIZE };
+use super::roms;
+use crate::flash_layout;
+
+/// Offset of the settings in SPI Flash: the sector before the save state in the Application Data area
+pub const SETTINGS_OFFSET: u32 = flash_layout::SETTINGS_OFFSET;
+
+/// Max number of ROMs with settings
+pub const MAX_ENTRIES: usize = 16;
+
+/// Magic number at the start of the settings: "C8ST"
+const SETTINGS_MAGIC: [u8; 4] = *b"C8ST";
+
+/// Current version of the settings format
+const SETTINGS_VERSION: u16 = 1;
+
+/// Size of the settings header
+const HEADER_SIZE: usize = 8;
+
+/// Size of each entry
+const ENTRY_SIZE: usize = 32;
+
+/// Max length of a ROM name in an entry
+const MAX_NAME_LEN: usize = ENTRY_SIZE - 1 - SETTINGS_SIZE;
+
+/// Size of the settings table
+const TABLE_SIZE: usize = HEADER_SIZE + MAX_ENTRIES * ENTRY_SIZE;
+
+/// Buffer for reading and writing the settings table
+static mut TABLE: [u8; TABLE_SIZE] = [0; TABLE_SIZE];
+
+/// Return the settings for the ROM named `name`, or the default settings if the ROM has none
+/// or SPI Flash can't be read
+pub fn load(name: &str) -> Settings {
+    let count = match read_table() {
+        Ok(count) => count,
+        Err(_) => return Settings::DEFAULT,
+    };
+    find_entry(name, count)
+        .and_then(|index| Settings::from_bytes(&entry(index)[1 + MAX_NAME_LEN..]))
+        .unwrap_or_default()
+}
+
+/// Remember `settings` for the ROM named `name`, replacing any previous settings for the ROM.
+/// Fail with `SYS_ENOMEM` if there are already `MAX_ENTRIES` ROMs with settings.
+pub fn save(name: &str, settings: &Settings) -> MynewtResult<()> {
+    let count = read_table().unwrap_or(0);
+    let index = match find_entry(name, count) {
+        Some(index) => index,
+        None if count < MAX_ENTRIES => count,
+        None => return Err(MynewtError::SYS_ENOMEM),
+    };
+    let count = count.max(index + 1);
+
+    //  Update the header and the entry
+    let name = truncate_name(name);
+    let header = unsafe { &mut TABLE[..HEADER_SIZE] };
+    header[0..4].copy_from_slice(&SETTINGS_MAGIC);
+    header[4..6].copy_from_slice(&SETTINGS_VERSION.to_le_bytes());
+    header[6] = count as u8;
+    header[7] = 0;
+    let entry = entry(index);
+    for byte in entry.iter_mut() { *byte = 0; }
+    entry[0] = name.len() as u8;
+    entry[1..1 + name.len()].copy_from_slice(name.as_bytes());
+    entry[1 + MAX_NAME_LEN..].copy_from_slice(&settings.to_bytes());
+
+    //  Rewrite the sector
+    let len = HEADER_SIZE + count * ENTRY_SIZE;
+    let mut flash = Flash::new(roms::FLASH_DEVICE) ? ;
+    flash.erase_range(SETTINGS_OFFSET, SECTOR_SIZE) ? ;
+    flash.write(SETTINGS_OFFSET, unsafe { &TABLE[..len] })
+}
+
+/// Read the settings table from SPI Flash and return the number of entries.
+/// Fail with `SYS_ENOENT` if there are no settings, `SYS_ENOTSUP` if the version is unsupported,
+/// `SYS_EINVAL` if the header is invalid.
+fn read_table() -> MynewtResult<usize> {
+    let buf = unsafe { &mut TABLE };
+    Flash::new(roms::FLASH_DEVICE) ?
+        .read(SETTINGS_OFFSET, buf) ? ;
+    if buf[0..4] != SETTINGS_MAGIC { return Err(MynewtError::SYS_ENOENT); }
+    if u16::from_le_bytes([ buf[4], buf[5] ]) != SETTINGS_VERSION { return Err(MynewtError::SYS_ENOTSUP); }
+    let count = buf[6] as usize;
+    if count > MAX_ENTRIES { return Err(MynewtError::SYS_EINVAL); }
+    Ok(count)
+}
+
+/// Return the index of the entry for the ROM named `name` among the first `count` entries
+fn find_entry(name: &str, count: usize) -> Option<usize> {
+    let name = truncate_name(name).as_bytes();
+    (0..count).find(|index| {
+        let entry = entry(*index);
+        let len = entry[0] as usize;
+        len <= MAX_NAME_LEN && &entry[1..1 + len] == name
+    })
+}
+
+/// Return entry number `index` in the settings table
+fn entry(index: usize) -> &'static mut [u8] {
+    let offset = HEADER_SIZE + index * ENTRY_SIZE;
+    unsafe { &mut TABLE[offset..offset + ENTRY_SIZE] }
+}
+
+/// Return the ROM name truncated to `MAX_NAME_LEN` bytes, on a character boundary
+fn truncate_name(name: &str) -> &str {
+    let mut len = name.len().min(MAX_NAME_LEN);
+    while !name.is_char_boundary(len) { len -= 1; }
+    &name[..len]
+}
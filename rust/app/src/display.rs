@@ -12,6 +12,7 @@ use mynewt::{
     result::*,
     sys::console,
 };
+use crate::draw;
 use embedded_hal::{
     self,
     digital::v2::OutputPin,
@@ -45,10 +46,10 @@ pub fn test_display() -> MynewtResult<()> {
         .translate( Coord::new( 20, 16 ));                     //  Shift the text
 
     //  Render background, circle, square and text to display
-    //  druid::draw_to_display(background);
-    //  druid::draw_to_display(circle);
-    //  druid::draw_to_display(square);
-    druid::draw_to_display(text);    
+    //  draw::draw_to_display(background);
+    //  draw::draw_to_display(circle);
+    //  draw::draw_to_display(square);
+    draw::draw_to_display(text);    
 
     //  Test the backlight
     //  test_backlight() ? ;
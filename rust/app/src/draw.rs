@@ -0,0 +1,32 @@
+//! Draw to the display with druid and embedded-graphics, shifted by the window offset of the display settings.
+//! When the row order is reversed (e.g. `Rotation::Deg90` and `Rotation::Deg180`), the picture is at the end of the
+//! ST7789 frame memory, but druid sets the display window without the offset. So the pixels and windows are shifted
+//! by `DisplayConfig::window_offset()` here before druid sets the window, like `st7789::set_window()` does for the
+//! Non-Blocking SPI API. Call these functions instead of `druid::draw_to_display()` and `druid::set_display_pixels()`.
+//! The widgets of the druid UI apps (`ui.rs` and `visual.rs`) are drawn inside druid-embedded, without the offset.
+use embedded_graphics::{
+    prelude::*,
+    pixelcolor::Rgb565,
+};
+use mynewt::st7789;
+
+/// Draw the item to the display, shifted by the window offset
+pub fn draw_to_display<T>(item: T)
+where T: IntoIterator<Item = Pixel<Rgb565>> {
+    let (column_offset, row_offset) = st7789::config().window_offset();
+    let offset = UnsignedCoord::new(column_offset as u32, row_offset as u32);
+    druid::draw_to_display(
+        item.into_iter().map(move |Pixel(coord, color)| Pixel(coord + offset, color))
+    );
+}
+
+/// Draw the pixel colours to the display at the window from (`left`, `top`) to (`right`, `bottom`) inclusive,
+/// shifted by the window offset
+pub fn set_display_pixels<P>(left: u16, top: u16, right: u16, bottom: u16, colors: P) -> Result<(), ()>
+where P: IntoIterator<Item = u16> {
+    let (column_offset, row_offset) = st7789::config().window_offset();
+    druid::set_display_pixels(
+        left + column_offset, top + row_offset, right + column_offset, bottom + row_offset,
+        colors
+    )
+}
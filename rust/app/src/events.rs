@@ -0,0 +1,55 @@
+//! Event bus for touch, button and gesture events. Events are published as typed enums by the touch sensor and the
+//! side button, and delivered to any number of subscribers (apps, debug overlay) in order of priority.
+//! A subscriber may consume the event, so that subscribers with lower priority don't receive it, or pass it through.
+//! Events are published and delivered in the task that runs the Default Event Queue, never in interrupt handlers.
+//! The subscriber table is in `pinetime_touch::events`, which is tested on the host.
+use mynewt::{
+    result::*,
+    sys::console,
+};
+
+pub use pinetime_touch::events::*;  //  Export the events, event kinds, priorities and the subscriber table
+
+/// Subscribers sorted by priority, highest first. Subscribers with the same priority are sorted by subscription time.
+static mut SUBSCRIBERS: Subscribers = Subscribers::new();
+
+/// Subscribe the `handler` to the event `kinds`, e.g. `TOUCH | GESTURE`, with the `priority`.
+/// Fail with `SYS_ENOMEM` if there are too many subscribers.
+pub fn subscribe(name: &'static str, priority: u8, kinds: u8, handler: Handler) -> MynewtResult<SubscriberId> {
+    unsafe { SUBSCRIBERS.subscribe(name, priority, kinds, handler) }
+        .map_err(to_mynewt_error)
+}
+
+/// Stop delivering events to the subscriber `id`. Fail with `SYS_ENOENT` if not subscribed.
+pub fn unsubscribe(id: SubscriberId) -> MynewtResult<()> {
+    unsafe { SUBSCRIBERS.unsubscribe(id) }
+        .map_err(to_mynewt_error)
+}
+
+/// Deliver the `event` to the subscribers of its kind in order of priority, until a subscriber consumes it.
+/// Return true if consumed. Must be called in the task that runs the Default Event Queue.
+pub fn publish(event: Event) -> bool {
+    //  Copy the subscribers, because a handler may subscribe or unsubscribe
+    let subscribers = unsafe { SUBSCRIBERS };
+    subscribers.publish(&event)
+}
+
+/// Display the subscribers in order of priority, for debugging
+#[allow(dead_code)]
+pub fn dump() {
+    for subscriber in unsafe { SUBSCRIBERS }.iter() {
+        console::print(subscriber.name);
+        console::print(": priority "); console::printint(subscriber.priority as i32);
+        console::print(", kinds "); console::printhex(subscriber.kinds);
+        console::print("\n");
+    }
+    console::flush();
+}
+
+/// Convert the subscriber table error to a Mynewt error code
+fn to_mynewt_error(err: Error) -> MynewtError {
+    match err {
+        Error::NoMemory => MynewtError::SYS_ENOMEM,
+        Error::NotFound => MynewtError::SYS_ENOENT,
+    }
+}
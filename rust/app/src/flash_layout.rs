@@ -0,0 +1,45 @@
+//! Layout of the Application Data area in External SPI Flash. The area is reserved by `FLASH_AREA_APP_DATA` in
+//! `hw/bsp/nrf52/bsp.yml`, after the user files in `FLASH_AREA_NFFS`, so the data is never overwritten by files.
+//! The offsets of the application data are derived from the end of the area, one sector or more each:
+//! ```text
+//! Offset    Size   Contents
+//! 0x3F7000  4 KB   Touch calibration       (calibration.rs)
+//! 0x3F8000  4 KB   CHIP8 settings          (chip8/settings.rs)
+//! 0x3F9000  16 KB  CHIP8 save state        (chip8/save_state.rs)
+//! 0x3FD000  12 KB  CHIP8 ROMs, one per sector (chip8/roms.rs)
+//! ```
+use mynewt::hal::SECTOR_SIZE;
+use pinetime_chip8::snapshot::SNAPSHOT_SIZE;
+
+/// Flash device of the Application Data area: External SPI Flash. Same as `device` of `FLASH_AREA_APP_DATA`.
+pub const APP_DATA_DEVICE: u8 = 1;
+
+/// Offset of the Application Data area. Same as `offset` of `FLASH_AREA_APP_DATA`.
+pub const APP_DATA_OFFSET: u32 = 0x3F7000;
+
+/// Size of the Application Data area in bytes. Same as `size` of `FLASH_AREA_APP_DATA`.
+pub const APP_DATA_SIZE: u32 = 36 * 1024;
+
+/// End of the Application Data area, which is the end of External SPI Flash
+pub const APP_DATA_END: u32 = APP_DATA_OFFSET + APP_DATA_SIZE;
+
+/// Size of the CHIP8 ROMs: 3 ROMs, one per sector
+pub const ROMS_SIZE: u32 = 3 * SECTOR_SIZE;
+
+/// Offset of the CHIP8 ROMs: the last sectors of the Application Data area
+pub const ROMS_OFFSET: u32 = APP_DATA_END - ROMS_SIZE;
+
+/// Size of the CHIP8 save state: `SNAPSHOT_SIZE` rounded up to whole sectors
+pub const SAVE_STATE_SIZE: u32 = (SNAPSHOT_SIZE as u32 + SECTOR_SIZE - 1) / SECTOR_SIZE * SECTOR_SIZE;
+
+/// Offset of the CHIP8 save state: the sectors before the ROMs
+pub const SAVE_STATE_OFFSET: u32 = ROMS_OFFSET - SAVE_STATE_SIZE;
+
+/// Offset of the CHIP8 settings: the sector before the save state
+pub const SETTINGS_OFFSET: u32 = SAVE_STATE_OFFSET - SECTOR_SIZE;
+
+/// Offset of the touch calibration: the sector before the CHIP8 settings, which is the first sector of the area
+pub const CALIBRATION_OFFSET: u32 = SETTINGS_OFFSET - SECTOR_SIZE;
+
+//  Fail to compile if the application data doesn't fit in the Application Data area
+const _: [(); 0] = [(); (CALIBRATION_OFFSET < APP_DATA_OFFSET) as usize];
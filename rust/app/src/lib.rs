@@ -32,6 +32,12 @@ extern crate macros as mynewt_macros;   //  Declare the Mynewt Procedural Macros
 //  Declare the modules in our application
 mod app_network;    //  Declare `app_network.rs` as Rust module `app_network` for Application Network functions
 mod app_sensor;     //  Declare `app_sensor.rs` as Rust module `app_sensor` for Application Sensor functions
+mod button;         //  Declare `button.rs` as Rust module `button` for the Side Button
+mod calibration;    //  Declare `calibration.rs` as Rust module `calibration` for Touch Calibration
+mod draw;           //  Declare `draw.rs` as Rust module `draw` for drawing to the display with the window offset
+mod events;         //  Declare `events.rs` as Rust module `events` for publishing touch, button and gesture events
+mod flash_layout;   //  Declare `flash_layout.rs` as Rust module `flash_layout` for the Application Data area in SPI Flash
+mod logo_upload;    //  Declare `logo_upload.rs` as Rust module `logo_upload` for Boot Logo Upload over Bluetooth LE
 mod touch_sensor;   //  Declare `touch_sensor.rs` as Rust module `touch_sensor` for Touch Sensor functions
 
 //  Declare the optional modules depending on the options in `../Cargo.toml`
@@ -56,21 +62,30 @@ use core::panic::PanicInfo; //  Import `PanicInfo` type which is used by `panic(
 use cortex_m::asm::bkpt;    //  Import cortex_m assembly function to inject breakpoint
 use mynewt::{
     kernel::os,             //  Import Mynewt OS API
+    result::*,              //  Import Mynewt Result and Error types
+    st7789,                 //  Import ST7789 Display API
     sys::console,           //  Import Mynewt Console API
 };
+use events::{ Event, Propagation };  //  Import the touch, button and gesture events
+use touch_sensor::TouchAction;       //  Import the touch actions
 
-//  Select the touch handler depending on the options in `../Cargo.toml`
+/// Rotation, mirroring and colours of the display. Change `rotation` to turn the picture and the touch panel together.
+/// Draw with `draw::draw_to_display()` so that the picture is shifted for rotations with a window offset.
+const DISPLAY_CONFIG: st7789::DisplayConfig = st7789::PINETIME_CONFIG;
+
+//  Select the touch handler depending on the options in `../Cargo.toml`. The CHIP8 Emulator app subscribes to events itself.
 #[cfg(feature = "ui_app")]      //  If druid UI app is enabled...
 use ui::handle_touch;           //  Use the touch handler from druid UI app
 
 #[cfg(feature = "visual_app")]  //  If Visual Rust app is enabled...
 use visual::handle_touch;       //  Use the touch handler from the Visual Rust app
 
-#[cfg(feature = "chip8_app")]   //  If CHIP8 Emulator app is enabled...
-use chip8::handle_touch;        //  Use the touch handler from the CHIP8 Emulator app
+/// Show the touch, button and gesture events on the console. Enable this to debug the event subscribers.
+const SHOW_EVENTS: bool = false;
 
-#[cfg(not(any(feature = "ui_app", feature = "visual_app", feature = "chip8_app")))]  //  If neither druid UI app nor Visual Rust app are enabled...
-pub fn handle_touch(_x: u16, _y: u16) { console::print("touch not handled\n"); console::flush(); }  //  Define a touch handler that does nothing
+/// Run the touch calibration routine at startup and save the calibration to SPI Flash. Enable this to calibrate
+/// a touch panel that doesn't match the display. Otherwise the saved calibration is loaded.
+const CALIBRATE_TOUCH: bool = false;
 
 ///  Main program that initialises the sensor, network driver and starts reading and sending sensor data in the background.
 ///  main() will be called at Mynewt startup. It replaces the C version of the main() function.
@@ -109,8 +124,9 @@ extern "C" fn main() -> ! {  //  Declare extern "C" because it will be called by
     #[cfg(feature = "write_graphic")]      //  If writing of boot graphic is enabled...
     unsafe { write_graphic() };
 
-    //  Start the display
-    druid::start_display()
+    //  Start the display with the ST7789 init sequence shared with the bootloader, then apply the display rotation
+    //  and colours. Touch coordinates will be transformed to match.
+    st7789::start_display(DISPLAY_CONFIG)
         .expect("DSP fail");
 
     //  Test the display
@@ -118,10 +134,24 @@ extern "C" fn main() -> ! {  //  Declare extern "C" because it will be called by
     display::test_display()
         .expect("DSP test fail");
 
+    //  Subscribe to the touch, button and gesture events
+    subscribe_events()
+        .expect("EVT fail");
+
     //  Start the touch sensor
     touch_sensor::start_touch_sensor()
         .expect("TCH fail");
 
+    //  Start the side button
+    button::start_button()
+        .expect("BTN fail");
+
+    //  Calibrate the touch panel before launching the apps, because the calibration routine draws on the display
+    let result =
+        if CALIBRATE_TOUCH { calibration::calibrate() }
+        else { calibration::load_calibration() };
+    if result.is_err() { console::print("touch not calibrated\n"); console::flush(); }
+
     //  Test the touch sensor
     //  touch_sensor::test()
     //      .expect("TCH test fail");
@@ -150,6 +180,51 @@ extern "C" fn main() -> ! {  //  Declare extern "C" because it will be called by
     //  Never comes here
 }
 
+/// Subscribe the debug overlay, the touch handler of the druid UI app or Visual Rust app, and the fallback
+/// for events that are not consumed by any app
+fn subscribe_events() -> MynewtResult<()> {
+    if SHOW_EVENTS {
+        events::subscribe("show", events::PRIORITY_OVERLAY, events::ALL, show_event) ? ;
+    }
+    #[cfg(any(feature = "ui_app", feature = "visual_app"))]  //  If druid UI app or Visual Rust app is enabled...
+    events::subscribe("touch", events::PRIORITY_APP, events::TOUCH, handle_touch_event) ? ;
+    events::subscribe("unhandled", events::PRIORITY_FALLBACK, events::TOUCH, handle_unhandled) ? ;
+    Ok(())
+}
+
+/// Pass touch down and contact to the touch handler of the druid UI app or Visual Rust app, not touch up
+#[cfg(any(feature = "ui_app", feature = "visual_app"))]  //  If druid UI app or Visual Rust app is enabled...
+fn handle_touch_event(event: &Event) -> Propagation {
+    match *event {
+        Event::Touch { action: TouchAction::Up, .. } => Propagation::PassThrough,
+        Event::Touch { x, y, .. } => { handle_touch(x, y); Propagation::Consume }
+        _ => Propagation::PassThrough,
+    }
+}
+
+/// Show a touch down that was not consumed by any app
+fn handle_unhandled(event: &Event) -> Propagation {
+    if let Event::Touch { action: TouchAction::Down, .. } = event {
+        console::print("touch not handled\n"); console::flush();
+    }
+    Propagation::Consume
+}
+
+/// Show the event on the console and pass it through to the apps. The button also shows the touch statistics.
+fn show_event(event: &Event) -> Propagation {
+    match *event {
+        Event::Touch { finger, x, y, .. } => {
+            console::print("touch "); console::printint(finger as i32);
+            console::print(": "); console::printint(x as i32);
+            console::print(", "); console::printint(y as i32);
+        }
+        Event::Button(_)  => { touch_sensor::show_stats(); console::print("button") }
+        Event::Gesture(_) => console::print("gesture"),
+    }
+    console::print("\n"); console::flush();
+    Propagation::PassThrough
+}
+
 ///  This function is called on panic, like an assertion failure. We display the filename and line number and pause in the debugger. From https://os.phil-opp.com/freestanding-rust-binary/
 #[panic_handler]
 fn panic(info: &PanicInfo) -> ! {
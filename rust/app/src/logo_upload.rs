@@ -0,0 +1,47 @@
+//! Boot Logo Upload over Bluetooth LE. Called by the Logo Upload GATT service in
+//! `apps/my_sensor_app/src/ble_gatt_svr.c`, which passes the written bytes to the upload state machine
+//! in `mynewt::logo::upload` (from `rust/pinetime-logo`). The logo is written to the logo slots in External SPI Flash.
+use mynewt::{
+    logo::{ logo_flash, upload::{ LogoUpload, STATUS_SIZE } },
+    result::*,
+};
+
+/// State of the logo upload
+static mut LOGO_UPLOAD: LogoUpload = LogoUpload::new();
+
+/// Handle a write to the Control Characteristic. Returns 0 if successful, else a Mynewt error code.
+#[no_mangle]
+extern "C" fn logo_upload_control(data: *const u8, len: u16) -> i32 {
+    let command = unsafe { core::slice::from_raw_parts(data, len as usize) };
+    let result = logo_flash()
+        .and_then(|flash| unsafe { LOGO_UPLOAD.control(flash, command) });
+    to_return_code(result)
+}
+
+/// Handle a write to the Data Characteristic. Returns 0 if successful, else a Mynewt error code.
+#[no_mangle]
+extern "C" fn logo_upload_data(data: *const u8, len: u16) -> i32 {
+    let chunk = unsafe { core::slice::from_raw_parts(data, len as usize) };
+    let result = logo_flash()
+        .and_then(|flash| unsafe { LOGO_UPLOAD.write_chunk(flash, chunk) });
+    to_return_code(result)
+}
+
+/// Copy the upload status for the Status Characteristic into `buf`, which must have `len` bytes.
+/// Returns the number of bytes copied.
+#[no_mangle]
+extern "C" fn logo_upload_status(buf: *mut u8, len: u16) -> i32 {
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf, len as usize) };
+    let status = unsafe { LOGO_UPLOAD.status() };
+    let len = buf.len().min(STATUS_SIZE);
+    buf[..len].copy_from_slice(&status[..len]);
+    len as i32
+}
+
+/// Convert the result to a Mynewt error code
+fn to_return_code(result: MynewtResult<()>) -> i32 {
+    match result {
+        Ok(())   => 0,
+        Err(err) => err as i32,
+    }
+}
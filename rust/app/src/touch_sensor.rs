@@ -1,19 +1,41 @@
-use embedded_hal::{
-    self,
-    blocking::delay::DelayMs,
-    digital::v2::OutputPin,
-};
+//! Touch Sensor for PineTime. Handles the touch controller interrupt and reads the touch data with the CST816S driver
+//! in `rust/pinetime-touch`. The touch data is read by a dedicated touch task with its own event queue, so that touch
+//! latency doesn't depend on the main task. The touch samples are kept in a ring buffer, which is drained on the
+//! Default Event Queue to publish the touch and gesture events. The touch coordinates are calibrated for the display
+//! panel (see `calibration.rs`), then rotated and mirrored to match the picture.
+//!
+//! The events are published on the Default Event Queue and not in the touch task, because the subscribers draw on the
+//! display and expect to run in the main task (see `events.rs`). So the touch events wait behind the events queued
+//! before them, e.g. rendering, and a busy main task delays the touch events even though the touch data was read on
+//! time. The ring buffer holds `SAMPLE_CAPACITY` samples while waiting, older samples are counted in `lost`. The wait
+//! is measured in `queue_latency` of the touch statistics, see `show_stats()`.
+use embedded_hal::blocking::i2c::WriteRead;
 use mynewt::{
     self,
     result::*,
     hw::hal,
     kernel::os::{
         self,
+        os_callout,
         os_event,
+        os_eventq,
+        os_task,
     },
+    st7789,
     sys::console,
-    fill_zero,
+    fill_zero, NULL, Ptr, Strn,
+};
+use mynewt_macros::{
+    init_strn,
 };
+use pinetime_touch::{
+    cst816s::MAX_TOUCH_POINTS,
+    samples::{ LatencyStats, SampleRing },
+    Cst816s, Error, GestureRecognizer, Thresholds,
+};
+pub use pinetime_touch::{ Calibration, TouchAction };  //  Export the touch action for the touch events, and the calibration
+pub use pinetime_touch::samples::{ SampleCursor, TouchSample };  //  Export the touch samples for the consumers
+use crate::events::{ self, Event };
 
 /// Reset Pin for touch controller. Note: NFC antenna pins must be reassigned as GPIO pins for this to work.
 const TOUCH_RESET_PIN: i32 = 10;  //  P0.10/NFC2: TP_RESET
@@ -21,11 +43,92 @@ const TOUCH_RESET_PIN: i32 = 10;  //  P0.10/NFC2: TP_RESET
 /// Interrupt Pin for touch controller. We listen for the touch controller interrupt and trigger an event.
 const TOUCH_INTERRUPT_PIN: i32 = 28;  //  P0.28/AIN4: TP_INT
 
-/// Reset GPIO Pin
-static mut TOUCH_RESET: MynewtGPIO =  fill_zero!(MynewtGPIO);
-static mut TOUCH_DELAY: MynewtDelay = fill_zero!(MynewtDelay);
-type MynewtGPIO = mynewt::GPIO;
-type MynewtDelay = mynewt::Delay;
+/// I2C port for the touch controller, enabled by `I2C_1: 1` in `apps/my_sensor_app/syscfg.yml`
+const TOUCH_I2C_PORT: u8 = 1;
+
+/// I2C operation timeout, in ticks
+const TOUCH_I2C_TIMEOUT: u32 = 1000;
+
+/// Ignore touch interrupts within this number of milliseconds after the last read, because the touch controller
+/// sends bursts of interrupts. Interrupts during the wait are coalesced into a single read.
+const DEBOUNCE_MS: u32 = 10;
+
+/// Touch task priority: highest is 0, lowest is 255 (main task is 127), SPI is 10, CHIP8 Emulator is 20
+const TOUCH_TASK_PRIORITY: u8 = 15;
+
+/// Show the touch statistics on the console every `SHOW_STATS_READS` reads. 0 to disable.
+const SHOW_STATS_READS: u32 = 0;
+
+/// Touch controller driver, created by `start_touch_sensor()`
+static mut TOUCH_CONTROLLER: Option<TouchController> = None;
+type TouchController = Cst816s<mynewt::I2C, mynewt::GPIO, mynewt::Delay>;
+
+/// Affine transform from the touch coordinates to the display panel, before rotating and mirroring
+static mut CALIBRATION: Calibration = Calibration::identity();
+
+/// Last position of each finger after calibration, rotation and mirroring, for touch up actions at (0, 0)
+static mut LAST_POSITIONS: [(u16, u16); MAX_TOUCH_POINTS] = [(0, 0); MAX_TOUCH_POINTS];
+
+/// Touch samples read by the touch task, drained by the dispatcher and other consumers with `read_sample()`
+static mut SAMPLES: SampleRing = SampleRing::new();
+
+/// Read position of the dispatcher, which publishes the touch samples as events
+static mut DISPATCH_CURSOR: SampleCursor = SampleCursor::new();
+
+/// Touch interrupt, read and dispatch statistics
+static mut STATS: TouchStats = TouchStats::new();
+
+/// True if a touch interrupt is waiting to be read by the touch task
+static mut INTERRUPT_PENDING: bool = false;
+
+/// Time of the first touch interrupt since the last read, in `os_cputime` ticks
+static mut INTERRUPT_CPUTIME: u32 = 0;
+
+/// Time of the first touch interrupt since the last read, in milliseconds
+static mut INTERRUPT_MS: u32 = 0;
+
+/// Time of the last read, in `os_cputime` ticks
+static mut READ_CPUTIME: u32 = 0;
+
+/// Time of the last read, in milliseconds
+static mut READ_MS: u32 = 0;
+
+/// Gesture recogniser for the first finger
+static mut GESTURES: GestureRecognizer = GestureRecognizer::new(Thresholds::new());
+
+/// Callout that polls the gesture recogniser at its deadline, for long presses and taps without touch events
+static mut GESTURE_CALLOUT: os_callout = fill_zero!(os_callout);
+
+/// Touch interrupt, read and dispatch statistics
+#[derive(Clone, Copy)]
+pub struct TouchStats {
+    /// Number of touch interrupts
+    pub interrupts:       u32,
+    /// Number of touch interrupts coalesced into an earlier read, because of debouncing
+    pub coalesced:        u32,
+    /// Number of touch data reads
+    pub reads:            u32,
+    /// Number of touch data reads that failed, e.g. touch controller went back to sleep
+    pub read_fails:       u32,
+    /// Number of touch samples overwritten before they were dispatched
+    pub lost:             u32,
+    /// Latency from the touch interrupt to the touch data being read
+    pub read_latency:     LatencyStats,
+    /// Latency from the touch data being read to the dispatch event running on the Default Event Queue
+    pub queue_latency:    LatencyStats,
+    /// Latency from the touch data being read to the touch events being published to every subscriber
+    pub dispatch_latency: LatencyStats,
+}
+
+impl TouchStats {
+    /// Return statistics with nothing counted
+    const fn new() -> TouchStats {
+        TouchStats {
+            interrupts: 0, coalesced: 0, reads: 0, read_fails: 0, lost: 0,
+            read_latency: LatencyStats::new(), queue_latency: LatencyStats::new(), dispatch_latency: LatencyStats::new(),
+        }
+    }
+}
 
 /// Initialise the touch controller. NFC antenna pins must already be reassigned as GPIO pins:
 /// Set `NFC_PINS_AS_GPIO: 1` in hw/bsp/nrf52/syscfg.yml.  To check whether whether NFC antenna 
@@ -39,18 +142,47 @@ pub fn start_touch_sensor() -> MynewtResult<()> {
     console::print("Rust touch sensor\n");
 
     //  Init GPIO for the Reset Pin
-    unsafe { TOUCH_RESET.init(TOUCH_RESET_PIN) ? };
+    let mut reset = mynewt::GPIO::new();
+    reset.init(TOUCH_RESET_PIN) ? ;
+
+    //  Reset the touch controller
+    let i2c = mynewt::I2C::open(TOUCH_I2C_PORT, TOUCH_I2C_TIMEOUT);
+    let mut controller = Cst816s::new(i2c, reset, mynewt::Delay::new());
+    controller.reset()
+        .map_err(convert_error) ? ;
+    unsafe { TOUCH_CONTROLLER = Some(controller) };
+
+    //  Initialise the touch event with the callback function, and the touch event queue
+    unsafe { 
+        TOUCH_EVENT.ev_cb = Some( touch_event_callback );
+        os::os_eventq_init(&mut TOUCH_EVENTQ);
+    };
+
+    //  Initialise the dispatch event, which publishes the touch samples on the Default Event Queue
+    unsafe { DISPATCH_EVENT.ev_cb = Some( dispatch_event_callback ) };
 
-    //  Reset the touch controller by switching the Reset Pin low then high with pauses. Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.c#L1017-L1167
+    //  Initialise the callout that polls the gesture recogniser on the Default Event Queue
+    let queue = os::eventq_dflt_get() ? ;
     unsafe {
-        TOUCH_RESET.set_low() ? ;
-        TOUCH_DELAY.delay_ms(20);
-        TOUCH_RESET.set_high() ? ;
-        TOUCH_DELAY.delay_ms(200); TOUCH_DELAY.delay_ms(200);    
+        os::os_callout_init(
+            &mut GESTURE_CALLOUT,        //  Callout to be initialised
+            queue,                       //  Fire the callout on the default event queue
+            Some(gesture_callback),      //  Function to call when the callout fires
+            core::ptr::null_mut()        //  No arguments for `gesture_callback()`
+        )
     };
 
-    //  Initialise the touch event with the callback function
-    unsafe { TOUCH_EVENT.ev_cb = Some( touch_event_callback ) };
+    //  Start the touch task, which reads the touch controller
+    os::task_init(                  //  Create a new task and start it...
+        unsafe { &mut TOUCH_TASK }, //  Task object will be saved here
+        &init_strn!( "touch" ),     //  Name of task
+        Some( touch_task_func ),    //  Function to execute when task starts
+        NULL,  //  Argument to be passed to above function
+        TOUCH_TASK_PRIORITY,        //  Task priority: higher than the main task and the CHIP8 Emulator
+        os::OS_WAIT_FOREVER as u32,       //  Don't do sanity / watchdog checking
+        unsafe { &mut TOUCH_TASK_STACK }, //  Stack space for the task
+        TOUCH_TASK_STACK_SIZE as u16      //  Size of the stack (in 4-byte units)
+    ) ? ;                                 //  `?` means check for error
 
     //  Configure the touch controller interrupt (active when low) to trigger a touch event
     let rc = unsafe { hal::hal_gpio_irq_init(
@@ -67,256 +199,302 @@ pub fn start_touch_sensor() -> MynewtResult<()> {
     Ok(())
 }
 
+/// Touch task that processes the touch events on the touch event queue
+extern "C" fn touch_task_func(_arg: Ptr) {
+    loop {
+        //  Read the touch data when the touch interrupt fires. Will call `touch_event_callback()`.
+        os::eventq_run(
+            unsafe { &mut TOUCH_EVENTQ }
+        ).expect("eventq fail");
+    }
+}
+
 /// Interrupt handler for the touch controller, triggered when a touch is detected
 extern "C" fn touch_interrupt_handler(arg: *mut core::ffi::c_void) {
-    //  We forward a touch event to the Default Event Queue for deferred processing.  Don't do any processing here.
-    unsafe { TOUCH_EVENT.ev_arg = arg };
-    //  Fetch the Default Event Queue. TODO: Use dedicated Event Queue for higher priority processing.
-    let queue = os::eventq_dflt_get()
-        .expect("GET fail");
-    unsafe { os::os_eventq_put(queue, &mut TOUCH_EVENT) };  //  Trigger the callback function `touch_event_callback()`
+    //  We forward a touch event to the touch task for deferred processing.  Don't do any processing here.
+    unsafe {
+        STATS.interrupts += 1;
+        if INTERRUPT_PENDING {
+            //  Touch data will be read for an earlier interrupt
+            STATS.coalesced += 1;
+            return;
+        }
+        INTERRUPT_PENDING = true;
+        INTERRUPT_CPUTIME = os::os_cputime_get32();
+        INTERRUPT_MS = now_ms();
+        TOUCH_EVENT.ev_arg = arg;
+        os::os_eventq_put(&mut TOUCH_EVENTQ, &mut TOUCH_EVENT);  //  Trigger the callback function `touch_event_callback()`
+    }
     //console::print("touch\n"); ////
 }
 
-/// Callback for the touch event that is triggered when a touch is detected
+/// Callback for the touch event that is triggered when a touch is detected. Runs in the touch task.
 extern "C" fn touch_event_callback(_event: *mut os_event) {
     //  console::printhex(unsafe { os::os_time_get() } as u8); console::print(" touch\n");
-    unsafe { 
-        //  Fetch the touch data from the touch controller
-        read_touchdata(&mut TOUCH_DATA)
-            .expect("touchdata fail");
-        //  Handle each touch data info
-        for i in 0..TOUCH_DATA.count as usize {
-            let TouchInfo{ x, y, action, .. } = TOUCH_DATA.touches[i];
-            //  Skip invalid responses (see note below)
-            if x == 0 && y == 0 { continue; }
-            //  Handle only touch down and contact actions, not touch up (see note below)
-            if action != 0 && action != 2 { continue; }
-            //  Handle the touch data in the UI        
-            super::handle_touch(x, y);
-
-            //  Display the touch data
-            //  druid::show_touch(x, y).expect("show touch fail");
-
-            /* Usually we get responses like:
-            touch
-            count: 1, pt: 0
-            act: 0, fin 0, x: 124, y: 190
-
-            Need to handle unusual responses like: 
-            touch
-            count: 5, pt: 1
-            act: 2, fin 0, x: 166, y: 62
-            act: 0, fin 0, x: 0, y: 0
-            act: 0, fin 0, x: 0, y: 0
-            act: 0, fin 0, x: 0, y: 0
-            act: 0, fin 0, x: 0, y: 0 */
-        }    
+    //  Wait until `DEBOUNCE_MS` after the last read. Interrupts during the wait are coalesced into this read.
+    let since_read = now_ms().wrapping_sub(unsafe { READ_MS });
+    if since_read < DEBOUNCE_MS {
+        unsafe { os::os_time_delay((DEBOUNCE_MS - since_read) * os::OS_TICKS_PER_SEC / 1000) };
+    }
+    //  Interrupts from now on will trigger another read
+    let sr = unsafe { os::os_arch_save_sr() };
+    let (interrupt_cputime, interrupt_ms) = unsafe {
+        INTERRUPT_PENDING = false;
+        (INTERRUPT_CPUTIME, INTERRUPT_MS)
+    };
+    unsafe { os::os_arch_restore_sr(sr) };
+
+    //  Fetch the touch data from the touch controller
+    let controller = match unsafe { TOUCH_CONTROLLER.as_mut() } {
+        Some(controller) => controller,
+        None => return,
+    };
+    let result = controller.read_touch_data();
+    let read_cputime = unsafe { os::os_cputime_get32() };
+    unsafe {
+        READ_MS = now_ms();
+        STATS.reads += 1;
+        STATS.read_latency.record(cputime_to_us(read_cputime.wrapping_sub(interrupt_cputime)));
     }
-    /* unsafe {
+    let data = match result {
+        Ok(data) => data,
+        //  Touch controller went back to sleep before we could read, so there is no touch data
+        Err(_) => { unsafe { STATS.read_fails += 1 }; return; }
+    };
+    //  Add each touch point to the touch samples
+    let calibration = calibration();
+    for point in data.points() {
+        let (x, y, action, finger) = (point.x, point.y, point.action, point.finger);
+        let last_position = unsafe { LAST_POSITIONS.get_mut(finger as usize) };
+        let (x, y) =
+            if x == 0 && y == 0 {
+                //  Skip invalid responses (see note below). Touch up is always handled so that the finger is
+                //  released, at the last position of the finger.
+                if action != TouchAction::Up { continue; }
+                last_position.as_deref().copied().unwrap_or((0, 0))
+            } else {
+                //  Calibrate the touch data, then rotate and mirror to match the picture on the display
+                let (x, y) = calibration.apply(x, y);
+                st7789::transform_touch(x, y)
+            };
+        if let Some(position) = last_position { *position = (x, y); }
+        let sample = TouchSample { finger, action, x, y, time_ms: interrupt_ms };
+        let sr = unsafe { os::os_arch_save_sr() };
+        unsafe { SAMPLES.push(sample) };
+        unsafe { os::os_arch_restore_sr(sr) };
+
+        /* Usually we get responses like:
+        touch
+        count: 1, pt: 0
+        act: 0, fin 0, x: 124, y: 190
+
+        Need to handle unusual responses like: 
+        touch
+        count: 5, pt: 1
+        act: 2, fin 0, x: 166, y: 62
+        act: 0, fin 0, x: 0, y: 0
+        act: 0, fin 0, x: 0, y: 0
+        act: 0, fin 0, x: 0, y: 0
+        act: 0, fin 0, x: 0, y: 0 
+        
+        Register dumps for these responses are replayed by `rust/touch-host`. */
+    }
+    //  Publish the touch samples on the Default Event Queue
+    unsafe { READ_CPUTIME = read_cputime };
+    let queue = os::eventq_dflt_get()
+        .expect("GET fail");
+    unsafe { os::os_eventq_put(queue, &mut DISPATCH_EVENT) };  //  Trigger the callback function `dispatch_event_callback()`
+    /* {
         //  Disable the console output because it may interfere with touch events
-        console::print("count: "); console::printint(TOUCH_DATA.count as i32);
-        console::print(", pt: "); console::printint(TOUCH_DATA.point_num as i32); console::print("\n");
-        for i in 0..TOUCH_DATA.count as usize {
-            let TouchInfo{ x, y, action, finger, .. } = TOUCH_DATA.touches[i];
-            console::print("act: "); console::printint(action as i32); 
-            console::print(", fin "); console::printint(finger as i32); 
-            console::print(", x: "); console::printint(x as i32); 
-            console::print(", y: "); console::printint(y as i32); console::print("\n");
+        console::print("count: "); console::printint(data.points().len() as i32);
+        console::print(", pt: "); console::printint(data.point_num as i32); console::print("\n");
+        for point in data.points() {
+            console::print("act: "); console::printint(point.action as i32); 
+            console::print(", fin "); console::printint(point.finger as i32); 
+            console::print(", x: "); console::printint(point.x as i32); 
+            console::print(", y: "); console::printint(point.y as i32); console::print("\n");
         }
         console::flush();   
     } */
 }
 
-/// Touch data will be populated here
-static mut TOUCH_DATA: TouchEventInfo = fill_zero!(TouchEventInfo);
-
-/// Read touch controller data. This only works when the screen has been tapped and the touch controller wakes up.
-/// Ported from https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.c#L407-L466
-fn read_touchdata(data: &mut TouchEventInfo) -> MynewtResult<()> {
-    read_register_range(           //  Read the range of I2C registers...
-        TOUCH_CONTROLLER_ADDRESS,  //  From the touch controller
-        0,                         //  Starting from register 0
-        POINT_READ_BUF as u8,      //  Number of registers to read
-        unsafe { &mut BUF }        //  Save the read data into `buf`
-    ).expect("read touchdata fail");
-    *data = fill_zero!(TouchEventInfo);
-    data.point_num = unsafe { BUF[FT_TOUCH_POINT_NUM] & 0x0F };
-    data.count     = 0;
-
-    //  Populate the first 5 touch points
-    for i in 0..CFG_MAX_TOUCH_POINTS {
-        let pointid = unsafe { BUF[HYN_TOUCH_ID_POS + HYN_TOUCH_STEP * i] } >> 4;
-        if pointid >= HYN_MAX_ID { break; }
-
-        //  Compute X and Y coordinates
-        data.count += 1;
-        let x_high = unsafe { BUF[HYN_TOUCH_X_H_POS + HYN_TOUCH_STEP * i] & 0x0F } as u16;
-        let x_low  = unsafe { BUF[HYN_TOUCH_X_L_POS + HYN_TOUCH_STEP * i] } as u16;
-        data.touches[i].x  = (x_high << 8) | x_low;
-
-        let y_high = unsafe { BUF[HYN_TOUCH_Y_H_POS + HYN_TOUCH_STEP * i] & 0x0F } as u16;
-        let y_low  = unsafe { BUF[HYN_TOUCH_Y_L_POS + HYN_TOUCH_STEP * i] } as u16;
-        data.touches[i].y  = (y_high << 8) | y_low;
-
-        //  Compute touch action (0 = down, 1 = up, 2 = contact) and finger ID
-        data.touches[i].action =
-            unsafe { BUF[HYN_TOUCH_EVENT_POS + HYN_TOUCH_STEP * i] } >> 6;
-        data.touches[i].finger =
-            unsafe { BUF[HYN_TOUCH_ID_POS    + HYN_TOUCH_STEP * i] } >> 4;
-
-        //  Compute touch pressure and area
-        data.touches[i].pressure =
-            unsafe { BUF[HYN_TOUCH_XY_POS + HYN_TOUCH_STEP * i] };  //  Can't be constant value
-        data.touches[i].area =
-            unsafe { BUF[HYN_TOUCH_MISC   + HYN_TOUCH_STEP * i] } >> 4;
-
-        //  If no more touch points, stop
-        if (data.touches[i].action == 0 || data.touches[i].action == 2)  //  If touch is down or contact
-            && (data.point_num == 0) {
-            break;
-        }
+/// Callback for the dispatch event, which publishes the new touch samples and the recognised gestures as events.
+/// Runs on the Default Event Queue, like the subscribers of the events, so it waits for the events queued before it.
+extern "C" fn dispatch_event_callback(_event: *mut os_event) {
+    let start = unsafe { os::os_cputime_get32() };
+    unsafe { STATS.queue_latency.record(cputime_to_us(start.wrapping_sub(READ_CPUTIME))) };
+    let mut cursor = unsafe { DISPATCH_CURSOR };
+    while let Some(sample) = read_sample(&mut cursor) {
+        let TouchSample { finger, action, x, y, time_ms } = sample;
+        //  Publish the touch action for the finger, including touch up
+        events::publish(Event::Touch { finger, action, x, y });
+        //  Recognise gestures made by the first finger
+        if finger == 0 { unsafe { GESTURES.update(action, x, y, time_ms) }; }
+        //  Log the touch trace for replaying with `touch-host --gestures`
+        //  console::print(match action { TouchAction::Down => "down ", TouchAction::Up => "up ", TouchAction::Contact => "contact " });
+        //  console::printint(x as i32); console::print(" "); console::printint(y as i32); console::print(" "); console::printint(time_ms as i32); console::print("\n");
+
+        //  Display the touch data
+        //  druid::show_touch(x, y).expect("show touch fail");
     }
-    Ok(())
+    let now = unsafe { os::os_cputime_get32() };
+    unsafe {
+        STATS.lost += cursor.lost;
+        cursor.lost = 0;
+        DISPATCH_CURSOR = cursor;
+        STATS.dispatch_latency.record(cputime_to_us(now.wrapping_sub(READ_CPUTIME)));
+    }
+    //  Publish the gestures recognised
+    publish_gestures();
+
+    //  Show the statistics every `SHOW_STATS_READS` reads
+    if SHOW_STATS_READS > 0 && unsafe { STATS.reads } % SHOW_STATS_READS == 0 { show_stats(); }
 }
 
-/// Buffer for raw touch data
-static mut BUF: [u8; POINT_READ_BUF] = [0; POINT_READ_BUF];
+/// Return the calibration of the touch panel
+pub fn calibration() -> Calibration {
+    let sr = unsafe { os::os_arch_save_sr() };
+    let calibration = unsafe { CALIBRATION };
+    unsafe { os::os_arch_restore_sr(sr) };
+    calibration
+}
 
-/// Touch Controller I2C Address: https://github.com/lupyuen/hynitron_i2c_cst0xxse
-const TOUCH_CONTROLLER_ADDRESS: u8 = 0x15;
+/// Calibrate the touch panel with `calibration` from now on. `Calibration::identity()` to use the touch coordinates
+/// without calibration. Touch coordinates are rotated and mirrored after calibration, so the calibration doesn't
+/// change with the display settings.
+pub fn set_calibration(calibration: Calibration) {
+    let sr = unsafe { os::os_arch_save_sr() };
+    unsafe { CALIBRATION = calibration };
+    unsafe { os::os_arch_restore_sr(sr) };
+}
 
-/// Touch Event Info for multiple touches. Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.h#L104-L115
-struct TouchEventInfo {
-    /// Array of touch points
-    touches:   [TouchInfo; HYN_MAX_POINTS],
-    /// How many touch points
-    count:     u8,
-    point_num: u8,
+/// Return a cursor for a consumer of the touch samples, which reads the samples after this call with `read_sample()`
+pub fn new_cursor() -> SampleCursor {
+    let mut cursor = SampleCursor::new();
+    let sr = unsafe { os::os_arch_save_sr() };
+    cursor.skip_to_latest(unsafe { &SAMPLES });
+    unsafe { os::os_arch_restore_sr(sr) };
+    cursor
 }
 
-/// Touch Info for a single touch. Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.h#L104-L115
-struct TouchInfo {
-    /// X coordinate
-    x:          u16,
-    /// Y coordinate
-    y:          u16,
-    /// Action: 0 = down, 1 = up, 2 = contact
-    action:     u8,
-    /// Which finger touched
-    finger:     u8,         
-    /// Pressure of touch
-    pressure:   u8,
-    /// Area touched
-    area:       u8,
+/// Return the next touch sample for the consumer `cursor`, `None` if the consumer has read every sample.
+/// Samples that were overwritten before the consumer read them are counted in `cursor.lost`.
+pub fn read_sample(cursor: &mut SampleCursor) -> Option<TouchSample> {
+    let sr = unsafe { os::os_arch_save_sr() };
+    let sample = unsafe { SAMPLES.read(cursor) };
+    unsafe { os::os_arch_restore_sr(sr) };
+    sample
 }
 
-//  Touch Controller Constants. Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.h
-
-/// Max touch points for the touch controller
-const CFG_MAX_TOUCH_POINTS: usize = 5;
-
-/// Max touch channels for the touch controller
-const HYN_MAX_POINTS: usize = 10;
-
-const HYN_MAX_ID: u8             = 0x0F;
-const HYN_TOUCH_STEP: usize      = 6;
-// const HYN_FACE_DETECT_POS: usize = 1;
-const HYN_TOUCH_X_H_POS: usize   = 3;
-const HYN_TOUCH_X_L_POS: usize   = 4;
-const HYN_TOUCH_Y_H_POS: usize   = 5;
-const HYN_TOUCH_Y_L_POS: usize   = 6;
-const HYN_TOUCH_EVENT_POS: usize = 3;
-const HYN_TOUCH_ID_POS: usize    = 5;
-const FT_TOUCH_POINT_NUM: usize  = 2;
-const HYN_TOUCH_XY_POS: usize    = 7;
-const HYN_TOUCH_MISC: usize      = 8;
-const POINT_READ_BUF: usize      = 3 + ( HYN_TOUCH_STEP * HYN_MAX_POINTS );
-
-/// Event that will be forwarded to the Event Queue when a touch interrupt is triggered
-static mut TOUCH_EVENT: os_event = fill_zero!(os_event);  //  Init all fields to 0 or NULL
+/// Return the touch interrupt, read and dispatch statistics
+pub fn stats() -> TouchStats {
+    let sr = unsafe { os::os_arch_save_sr() };
+    let stats = unsafe { STATS };
+    unsafe { os::os_arch_restore_sr(sr) };
+    stats
+}
 
-/// Read a range of I2C registers from the I2C address `addr` (7-bit address), starting at `start_register` for count `num_registers`. Save into `buffer`.
-fn read_register_range(addr: u8, start_register: u8, num_registers: u8, buffer: &mut[u8]) -> MynewtResult<()> {
-    assert!(buffer.len() >= num_registers as usize, "i2c buf");  //  Buffer too small
-    assert!(start_register + num_registers < 128, "i2c addr");   //  Not 7-bit address
-    //  Step 1: Prepare to read I2C Device Registers:
-    //    System sends Clock Signal on SCL to sync Microcontroller with I2C Device
-    //  Step 2: Transmit the I2C Address and the starting Register Number:
-    //    Send the Start Condition (High to Low SDA Transition)...
-    //    Followed by I2C Address (7 bits)...
-    //    Followed by Write Mode (1 bit, value 0)...
-    //    Followed by starting Register Number (8 bits)
-    unsafe { 
-        I2C_BUFFER[0] = start_register;  //  I2C Packet buffer contains starting Register Number (1 byte)
-        I2C_DATA.address = addr;         //  I2C Packet address (7 bits)
-        I2C_DATA.len = I2C_BUFFER.len() as u16;     //  I2C Packet data size is 1 byte
-        I2C_DATA.buffer = I2C_BUFFER.as_mut_ptr();  //  I2C Packet data points to packet buffer
-    };
-    let _rc1 = unsafe { hal::hal_i2c_master_write(1, &mut I2C_DATA, 1000, 0) };  //  No stop yet, must continue even if we hit an error
-    //  Step 3: Prepare to receive the stream of I2C Device Register values...
-    //    Send the Start Condition (High to Low SDA Transition)...
-    //    Followed by I2C Address (7 bits)...
-    //    Followed by Read Mode (1 bit, value 1)
-    unsafe { 
-        I2C_BUFFER[0] = 0x00;     //  I2C Packet buffer should be empty (provided by caller)
-        I2C_DATA.address = addr;  //  I2C Packet address (7 bits)
-        I2C_DATA.len = num_registers as u16;    //  I2C Packet data size is number of Registers to read
-        I2C_DATA.buffer = buffer.as_mut_ptr();  //  I2C Packet data points to packet buffer
-    };
-    //  Step 4: Receive the requested number of Register values from I2C Device (1 byte per register)
-    //  Step 5: Send the Stop Condition (Low to High SDA Transition)
-    let rc2 = unsafe { hal::hal_i2c_master_read(1, &mut I2C_DATA, 1000, 1) };
-    if rc2 == hal::HAL_I2C_ERR_ADDR_NACK as i32 {
-        ////assert!(false, "i2c fail");  //  I2C read failed
-        console::print("i2c fail\n"); ////
-        return Ok(());               //  TODO: Return an error
+/// Show the touch statistics on the console. Latencies are in microseconds.
+pub fn show_stats() {
+    let stats = stats();
+    console::print("touch int "); console::printint(stats.interrupts as i32);
+    console::print(", coalesced "); console::printint(stats.coalesced as i32);
+    console::print(", reads "); console::printint(stats.reads as i32);
+    console::print(", fails "); console::printint(stats.read_fails as i32);
+    console::print(", lost "); console::printint(stats.lost as i32);
+    console::print("\n");
+    for (name, latency) in &[
+        ("read", stats.read_latency), ("queue", stats.queue_latency), ("dispatch", stats.dispatch_latency),
+    ] {
+        console::print(name);
+        console::print(" us min "); console::printint(latency.min_us as i32);
+        console::print(", mean "); console::printint(latency.mean_us() as i32);
+        console::print(", max "); console::printint(latency.max_us as i32);
+        console::print("\n");
     }
-    Ok(())
+    console::flush();
+}
+
+/// Callback for the gesture callout, fired at the deadline of the gesture recogniser
+extern "C" fn gesture_callback(_event: *mut os_event) {
+    unsafe { GESTURES.poll(now_ms()) };
+    publish_gestures();
+}
+
+/// Publish the recognised gestures, then set the gesture callout to fire at the next deadline
+fn publish_gestures() {
+    while let Some(gesture) = unsafe { GESTURES.next_gesture() } {
+        events::publish(Event::Gesture(gesture));
+    }
+    match unsafe { GESTURES.deadline() } {
+        Some(deadline) => {
+            //  Fire the callout at least 1 tick later
+            let ms = deadline.wrapping_sub(now_ms()) as i32;
+            let ticks = (ms.max(0) as u32 * os::OS_TICKS_PER_SEC / 1000).max(1);
+            let rc = unsafe { os::os_callout_reset(&mut GESTURE_CALLOUT, ticks) };
+            assert_eq!(rc, 0, "callout fail");
+        }
+        None => unsafe { os::os_callout_stop(&mut GESTURE_CALLOUT) },
+    }
+}
+
+/// Return the current time in milliseconds, for the gesture recogniser
+fn now_ms() -> u32 {
+    let ticks = unsafe { os::os_time_get() };
+    (ticks as u64 * 1000 / os::OS_TICKS_PER_SEC as u64) as u32
+}
+
+/// Convert the `os_cputime` ticks to microseconds
+fn cputime_to_us(ticks: u32) -> u32 {
+    (ticks as u64 * 1_000_000 / os::MYNEWT_VAL_OS_CPUTIME_FREQ as u64) as u32
 }
 
-/// Read the I2C register for the specified I2C address (7-bit address)
+/// Convert a touch controller error to a Mynewt error
+fn convert_error(err: Error<MynewtError, MynewtError>) -> MynewtError {
+    match err {
+        Error::I2c(err) => err,
+        Error::Pin(err) => err,
+    }
+}
+
+/// Event that will be forwarded to the touch event queue when a touch interrupt is triggered
+static mut TOUCH_EVENT: os_event = fill_zero!(os_event);  //  Init all fields to 0 or NULL
+
+/// Event that will be forwarded to the Default Event Queue when touch samples have been read
+static mut DISPATCH_EVENT: os_event = fill_zero!(os_event);  //  Init all fields to 0 or NULL
+
+/// Event queue for the touch task
+static mut TOUCH_EVENTQ: os_eventq = fill_zero!(os_eventq);  //  Init all fields to 0 or NULL
+
+/// Touch task
+static mut TOUCH_TASK: os_task = fill_zero!(os_task);
+
+/// Stack space for the touch task, initialised to 0
+static mut TOUCH_TASK_STACK: [os::os_stack_t; TOUCH_TASK_STACK_SIZE] = 
+    [0; TOUCH_TASK_STACK_SIZE];
+
+/// Size of the stack (in 4-byte units)
+const TOUCH_TASK_STACK_SIZE: usize = 256;
+
+/// Read the I2C register for the specified I2C address (7-bit address) and display the value.
+/// Devices that don't acknowledge are skipped, e.g. the touch controller when the screen is not touched.
 #[allow(dead_code)]
 fn read_register(addr: u8, register: u8) -> MynewtResult<()> {
     assert!(register < 128, "i2c addr");  //  Not 7-bit address
-    //  First the register number must be sent in write mode (I2C address xxxxxxx0). 
-    unsafe { 
-        I2C_BUFFER[0] = register;
-        I2C_DATA.address = addr;
-        I2C_DATA.len = I2C_BUFFER.len() as u16;
-        I2C_DATA.buffer = I2C_BUFFER.as_mut_ptr();
-    };
-    //  Then either a stop or a repeated start condition must be generated. 
-    let _rc1 = unsafe { hal::hal_i2c_master_write(1, &mut I2C_DATA, 1000, 0) };  //  No stop yet, must continue even if we hit an error
-    //  After this the slave is addressed in read mode (RW = ‘1’) at I2C address xxxxxxx1, 
-    unsafe { 
-        I2C_BUFFER[0] = 0x00;
-        I2C_DATA.address = addr;
-        I2C_DATA.len = I2C_BUFFER.len() as u16;
-        I2C_DATA.buffer = I2C_BUFFER.as_mut_ptr();
-    };
-    //  After which the slave sends out data from auto-incremented register addresses until a NOACKM and stop condition occurs.
-    let rc2 = unsafe { hal::hal_i2c_master_read(1, &mut I2C_DATA, 1000, 1) };
-    if rc2 == hal::HAL_I2C_ERR_ADDR_NACK as i32 {
-        return Ok(());
+    //  Send the register number, then read the register value
+    let mut i2c = mynewt::I2C::open(TOUCH_I2C_PORT, TOUCH_I2C_TIMEOUT);
+    let mut value = [0u8; 1];
+    match i2c.write_read(addr, &[register], &mut value) {
+        Err(MynewtError::HAL_I2C_ERR_ADDR_NACK) => return Ok(()),
+        result => result ?
     }
     console::print("addr: 0x"); console::printhex(addr); 
     console::print(", reg: 0x"); console::printhex(register); 
-    console::print(" = 0x"); console::printhex(unsafe { I2C_BUFFER[0] }); 
+    console::print(" = 0x"); console::printhex(value[0]); 
     console::print("\n"); console::flush();
     Ok(())
 }
 
-/// I2C packet to be sent
-static mut I2C_DATA: hal::hal_i2c_master_data = hal::hal_i2c_master_data {
-    address: 0,
-    len:     0,
-    buffer:  core::ptr::null_mut(),
-};
-
-/// Buffer containing I2C read/write data
-static mut I2C_BUFFER: [u8; 1] =  [ 0 ];
-
 /// Probe the I2C bus to discover I2C devices
 #[allow(dead_code)]
 pub fn probe() -> MynewtResult<()> {
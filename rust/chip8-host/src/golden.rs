@@ -0,0 +1,31 @@
+//! Save the framebuffer as PNG and compare it with a golden image
+use crate::platform::{ Framebuffer, DISPLAY_SIZE };
+use image::{ Rgb, RgbImage };
+use std::path::Path;
+
+/// Convert the RGB565 framebuffer to an RGB image. Each colour channel is widened to 8 bits.
+pub fn to_image(framebuffer: &Framebuffer) -> RgbImage {
+    RgbImage::from_fn(DISPLAY_SIZE as u32, DISPLAY_SIZE as u32, |x, y| {
+        let color = framebuffer[y as usize * DISPLAY_SIZE + x as usize];
+        let r = ((color >> 11) & 0x1f) as u8;
+        let g = ((color >> 5)  & 0x3f) as u8;
+        let b = ( color        & 0x1f) as u8;
+        Rgb([ (r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2) ])
+    })
+}
+
+/// Compare the `image` with the golden image at `path`. Returns the number of pixels that differ,
+/// or an error if the golden image can't be read or has a different size.
+pub fn compare(image: &RgbImage, path: &Path) -> Result<usize, String> {
+    let golden = image::open(path)
+        .map_err(|err| format!("can't read {}: {}", path.display(), err))?
+        .to_rgb8();
+    if golden.dimensions() != image.dimensions() {
+        return Err(format!("{} is {:?}, expected {:?}", path.display(), golden.dimensions(), image.dimensions()));
+    }
+    let diff = golden.pixels()
+        .zip(image.pixels())
+        .filter(|(expected, actual)| expected != actual)
+        .count();
+    Ok(diff)
+}
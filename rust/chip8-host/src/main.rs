@@ -0,0 +1,239 @@
+//! Run a CHIP8 ROM headlessly with the PineTime renderer, save the display as PNG and compare it with a golden image.
+//! ```bash
+//! cargo run -p chip8-host --target x86_64-unknown-linux-gnu -- rust/app/roms/invaders.ch8 --golden rust/chip8-host/golden/invaders-flat.png
+//! cargo run -p chip8-host --target x86_64-unknown-linux-gnu --features curve -- rust/app/roms/invaders.ch8 --golden rust/chip8-host/golden/invaders-curve.png
+//! cargo run -p chip8-host --target x86_64-unknown-linux-gnu -- rust/app/roms/invaders.ch8 --snapshot-at 10000
+//! ```
+mod golden;    //  Declare `golden.rs` as Rust module `golden` for saving and comparing PNG files
+mod platform;  //  Declare `platform.rs` as Rust module `platform` for the simulated PineTime
+
+use libchip8::{ Chip8, State };
+use pinetime_chip8::{
+    governor::DEFAULT_REFRESH_RATE,
+    palette::{ Fade, Settings, Theme },
+    render,
+    snapshot::{ self, SNAPSHOT_SIZE },
+    Hardware,
+};
+use platform::{ Framebuffer, HostPlatform, DISPLAY_SIZE };
+use std::{
+    env,
+    fs,
+    path::{ Path, PathBuf },
+    process,
+};
+
+/// Usage message shown when the command line is invalid
+const USAGE: &str = "usage: chip8-host ROM [--steps N] [--speed IPS] [--fps N] [--seed N] [--hold KEY]... [--snapshot-at N] [--theme NAME] [--fade NAME] [--colors FG,BG] [--stats] [--output PATH] [--golden PATH]";
+
+/// Default number of emulator steps before the display is captured
+const DEFAULT_STEPS: u32 = 20_000;
+
+/// Default emulation speed in instructions per second. Same as `DEFAULT_SPEED` in `rust/app/src/chip8/roms.rs`
+const DEFAULT_SPEED: u16 = 500;
+
+/// Default seed for the RND instruction
+const DEFAULT_SEED: u32 = 1;
+
+/// Command line options
+struct Options {
+    /// CHIP8 ROM to be run
+    rom:    PathBuf,
+    /// Number of emulator steps before the display is captured
+    steps:  u32,
+    /// Emulation speed in instructions per second
+    speed:  u16,
+    /// Display refresh rate in frames per second
+    refresh_rate: u8,
+    /// Seed for the RND instruction
+    seed:   u32,
+    /// Bit `n` is set if key `n` is held for the entire run
+    keys:   u16,
+    /// If specified, save and restore a save state after this number of steps
+    snapshot_at: Option<u32>,
+    /// Colour palette and fade curve
+    settings: Settings,
+    /// True if the emulator statistics should be printed every second
+    stats:  bool,
+    /// PNG file for the captured display
+    output: PathBuf,
+    /// If specified, compare the captured display with this PNG file
+    golden: Option<PathBuf>,
+}
+
+fn main() {
+    let options = parse_args(env::args().skip(1))
+        .unwrap_or_else(|err| {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(1);
+        });
+    let rom = fs::read(&options.rom)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", options.rom.display(), err);
+            process::exit(1);
+        });
+
+    //  Run the ROM until the steps are used up, or until the save state is due
+    render::set_settings(options.settings);
+    let mut framebuffer: Framebuffer = [0; DISPLAY_SIZE * DISPLAY_SIZE];
+    let steps = options.snapshot_at.unwrap_or(options.steps);
+    let mut platform = HostPlatform::new(&mut framebuffer, steps, options.keys, options.seed);
+    if options.stats { platform.enable_stats(); }
+    let mut hardware = Hardware::new(platform, options.speed);
+    hardware.set_refresh_rate(options.refresh_rate);
+    let mut chip8 = Chip8::new(hardware);
+    chip8.run(&rom);
+    check_error(&chip8, &options.rom);
+
+    //  Save and restore a save state, then run the remaining steps
+    if let Some(snapshot_at) = options.snapshot_at {
+        round_trip(&mut chip8, &rom)
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(3);
+            });
+        println!("save state round trip ok ({} bytes at step {})", SNAPSHOT_SIZE, snapshot_at);
+        let platform = chip8.hardware().platform();
+        platform.add_steps(options.steps - snapshot_at);
+        render::render_screen(platform);
+        chip8.resume();
+        check_error(&chip8, &options.rom);
+    }
+
+    //  Save the captured display
+    let image = golden::to_image(&framebuffer);
+    image.save(&options.output)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", options.output.display(), err);
+            process::exit(1);
+        });
+    println!("{} -> {} ({} steps)", options.rom.display(), options.output.display(), options.steps);
+
+    //  Compare with the golden image
+    if let Some(path) = &options.golden {
+        match golden::compare(&image, path) {
+            Ok(0) => println!("matches {}", path.display()),
+            Ok(diff) => {
+                eprintln!("{} pixels differ from {}", diff, path.display());
+                process::exit(2);
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Exit with status 4 if the ROM `path` failed with an unsupported or invalid instruction
+fn check_error<H: libchip8::Hardware>(chip8: &Chip8<H>, path: &Path) {
+    if let Some(err) = chip8.error() {
+        eprintln!("{}: ROM failed: {:?}", path.display(), err);
+        process::exit(4);
+    }
+}
+
+/// Save the emulator into a save state, wipe the emulator and the Virtual Screen, restore the save state
+/// and check that saving again produces the same bytes
+fn round_trip<H: libchip8::Hardware>(chip8: &mut Chip8<H>, rom: &[u8]) -> Result<(), String> {
+    let mut saved = vec![0; SNAPSHOT_SIZE];
+    snapshot::save(chip8.state(), "host", rom, &mut saved)
+        .map_err(|err| format!("save failed: {:?}", err)) ? ;
+
+    //  Wipe the emulator and restore it
+    *chip8.state_mut() = State::new();
+    render::clear_screen();
+    snapshot::restore(&saved, rom, chip8.state_mut())
+        .map_err(|err| format!("restore failed: {:?}", err)) ? ;
+
+    //  Save again and compare
+    let mut resaved = vec![0; SNAPSHOT_SIZE];
+    snapshot::save(chip8.state(), "host", rom, &mut resaved)
+        .map_err(|err| format!("save failed: {:?}", err)) ? ;
+    if saved != resaved { return Err("save state differs after restoring".to_string()); }
+    Ok(())
+}
+
+/// Parse the command line arguments
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut rom = None;
+    let mut steps = DEFAULT_STEPS;
+    let mut speed = DEFAULT_SPEED;
+    let mut refresh_rate = DEFAULT_REFRESH_RATE;
+    let mut seed = DEFAULT_SEED;
+    let mut keys = 0;
+    let mut snapshot_at = None;
+    let mut settings = Settings::DEFAULT;
+    let mut stats = false;
+    let mut output = None;
+    let mut golden = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--steps" => {
+                let value = args.next().ok_or("missing steps")?;
+                steps = value.parse().map_err(|_| format!("bad steps: {}", value))?;
+            }
+            "--speed" => {
+                let value = args.next().ok_or("missing speed")?;
+                speed = value.parse().map_err(|_| format!("bad speed: {}", value))?;
+            }
+            "--fps" => {
+                let value = args.next().ok_or("missing refresh rate")?;
+                refresh_rate = value.parse().map_err(|_| format!("bad refresh rate: {}", value))?;
+            }
+            "--seed" => {
+                let value = args.next().ok_or("missing seed")?;
+                seed = value.parse().map_err(|_| format!("bad seed: {}", value))?;
+            }
+            "--hold" => {
+                let value = args.next().ok_or("missing key")?;
+                let key = u8::from_str_radix(&value, 16).ok()
+                    .filter(|key| *key < 16)
+                    .ok_or_else(|| format!("bad key: {}", value))?;
+                keys |= 1 << key;
+            }
+            "--snapshot-at" => {
+                let value = args.next().ok_or("missing snapshot step")?;
+                snapshot_at = Some(value.parse().map_err(|_| format!("bad snapshot step: {}", value))?);
+            }
+            "--theme" => {
+                let value = args.next().ok_or("missing theme")?;
+                settings.theme = Theme::from_name(&value).ok_or_else(|| format!("bad theme: {}", value))?;
+            }
+            "--fade" => {
+                let value = args.next().ok_or("missing fade")?;
+                settings.fade = Fade::from_name(&value).ok_or_else(|| format!("bad fade: {}", value))?;
+            }
+            "--colors" => {
+                let value = args.next().ok_or("missing colors")?;
+                let colors: Vec<_> = value.split(',')
+                    .map(|color| u16::from_str_radix(color, 16))
+                    .collect();
+                match colors.as_slice() {
+                    [ Ok(foreground), Ok(background) ] => {
+                        settings.foreground = *foreground;
+                        settings.background = *background;
+                    }
+                    _ => return Err(format!("bad colors: {}", value)),
+                }
+            }
+            "--stats" => stats = true,
+            "--output" => {
+                output = Some(PathBuf::from(args.next().ok_or("missing output")?));
+            }
+            "--golden" => {
+                golden = Some(PathBuf::from(args.next().ok_or("missing golden")?));
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ if rom.is_none() => rom = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+    let rom = rom.ok_or("missing ROM")?;
+    if let Some(step) = snapshot_at {
+        if step == 0 || step >= steps { return Err(format!("snapshot step must be between 1 and {}", steps - 1)); }
+    }
+    //  Derive the output file name from the ROM file name
+    let output = output.unwrap_or_else(|| rom.with_extension("png"));
+    Ok(Options { rom, steps, speed, refresh_rate, seed, keys, snapshot_at, settings, stats, output, golden })
+}
@@ -0,0 +1,105 @@
+//! Simulated PineTime for running the CHIP8 Emulator headlessly. Rendered pixels are stored in a 240 x 240
+//! RGB565 framebuffer. Time is simulated: it advances only when the emulator sleeps, so every run of a ROM
+//! produces the same pixels.
+use pinetime_chip8::{
+    governor::Stats,
+    render::render_screen,
+    PixelIterator, Platform,
+};
+use pinetime_rng::XorShiftRng;
+
+/// Width and height of the PineTime display, in pixels
+pub const DISPLAY_SIZE: usize = 240;
+
+/// RGB565 pixels of the PineTime display, row by row
+pub type Framebuffer = [u16; DISPLAY_SIZE * DISPLAY_SIZE];
+
+/// Simulated PineTime that renders into a framebuffer
+pub struct HostPlatform<'a> {
+    /// Rendered pixels
+    framebuffer: &'a mut Framebuffer,
+    /// Simulated time in milliseconds
+    time_ms: u32,
+    /// Number of emulator steps remaining before the ROM is stopped
+    steps_left: u32,
+    /// Bit `n` is set if key `n` is held for the entire run
+    held_keys: u16,
+    /// Xorshift generator for the RND instruction, same as the fallback generator on PineTime
+    rng: XorShiftRng,
+    /// True if the emulator statistics should be printed
+    print_stats: bool,
+}
+
+impl<'a> HostPlatform<'a> {
+    /// Return a simulated PineTime that stops the ROM after `steps` emulator steps, with the `held_keys` bitmap
+    /// and the `seed` for the RND instruction
+    pub fn new(framebuffer: &'a mut Framebuffer, steps: u32, held_keys: u16, seed: u32) -> HostPlatform<'a> {
+        HostPlatform {
+            framebuffer,
+            time_ms: 0,
+            steps_left: steps,
+            held_keys,
+            rng: XorShiftRng::new(seed),
+            print_stats: false,
+        }
+    }
+
+    /// Print the emulator statistics every simulated second
+    pub fn enable_stats(&mut self) {
+        self.print_stats = true;
+    }
+
+    /// Allow the ROM to run for another `steps` emulator steps
+    pub fn add_steps(&mut self, steps: u32) {
+        self.steps_left += steps;
+    }
+}
+
+impl<'a> Platform for HostPlatform<'a> {
+    /// Copy the Physical Pixels into the framebuffer window, row by row
+    fn set_pixels(&mut self, left: u8, top: u8, right: u8, bottom: u8, pixels: &mut PixelIterator) {
+        for y in top as usize..=bottom as usize {
+            for x in left as usize..=right as usize {
+                let color = pixels.next().expect("too few pixels");
+                self.framebuffer[y * DISPLAY_SIZE + x] = color;
+            }
+        }
+        assert!(pixels.next().is_none(), "too many pixels");
+    }
+
+    /// Advance the simulated time
+    fn sleep_ms(&mut self, ms: u32) {
+        self.time_ms += ms;
+    }
+
+    /// Return the simulated time in nanoseconds
+    fn clock_ns(&mut self) -> u64 {
+        self.time_ms as u64 * 1_000_000
+    }
+
+    /// Return the next byte from the xorshift generator
+    fn rand(&mut self) -> u8 {
+        self.rng.next_u8()
+    }
+
+    /// Return true if the key is held
+    fn key(&mut self, key: u8) -> bool {
+        self.held_keys & (1 << (key & 0x0f)) != 0
+    }
+
+    /// Count the emulator step. When the steps are used up, render the entire Virtual Screen and stop the ROM.
+    fn exit_requested(&mut self) -> bool {
+        if self.steps_left == 0 { return true; }
+        self.steps_left -= 1;
+        if self.steps_left > 0 { return false; }
+        render_screen(self);
+        true
+    }
+
+    /// Print the emulator statistics with the simulated time, if enabled
+    fn show_stats(&mut self, stats: &Stats) {
+        if !self.print_stats { return; }
+        println!("{:>6} ms: {} IPS, {} FPS, {} SPI bytes/frame",
+            self.time_ms, stats.ips(), stats.fps(), stats.bytes_per_frame());
+    }
+}
@@ -0,0 +1,718 @@
+//! # libchip8
+//!
+//! An OS-independent chip8 interpreter library written in Rust (`no_std`).
+//!
+//! Once you implement OS-specific part, i.e. `Hardware` trait, you will get a complete chip8 interpreter for your environment.
+//!
+//! ```ignore
+//! struct Hardware;
+//!
+//! // 1. Implement `libchip8::Hardware`
+//! impl libchip8::Hardware for Hardware {
+//! // ...
+//! }
+//!
+//! // 2. Run `Chip8` giving a rom binary.
+//! let mut chip8 = libchip8::Chip8::new(Hardware);
+//! chip8.run(include_bytes!("roms/invaders.ch8"));
+//! ```
+//!
+
+#![no_std]
+// Lints are not fixed in the code from upstream, to keep the fork close to upstream
+#![allow(clippy::style, clippy::complexity)]
+
+use log::*;
+
+/// Represents environment-specific logic.
+///
+/// Library users need to implement this trait to run the interpreter.
+///
+pub trait Hardware: Sized {
+    /// Return a random value.
+    fn rand(&mut self) -> u8;
+
+    /// Check if the key is pressed.
+    fn key(&mut self, key: u8) -> bool;
+
+    /// Set the state of a pixel in the screen.
+    ///
+    /// `true` for white, and `false` for black.
+    fn vram_set(&mut self, x: usize, y: usize, d: bool);
+
+    /// Get the current state of a pixel in the screen.
+    fn vram_get(&mut self, x: usize, y: usize) -> bool;
+
+    /// Set the size of the screen.
+    ///
+    /// Called with `DISPS` when the interpreter starts or resumes in low resolution mode, and with
+    /// `HIRES_DISPS` in SUPER-CHIP high resolution mode. The screen is cleared after switching modes.
+    fn vram_setsize(&mut self, size: (usize, usize));
+
+    /// Get the size of the screen.
+    fn vram_size(&mut self) -> (usize, usize);
+
+    /// Return the current clock value in nanoseconds.
+    fn clock(&mut self) -> u64;
+
+    /// Play beep sound.
+    fn beep(&mut self);
+
+    /// Called in every step; return `true` for shutdown.
+    fn sched(&mut self) -> bool {
+        false
+    }
+}
+
+/// Memory, registers and timers of the interpreter, which may be saved and restored.
+///
+/// The screen is not included because it's kept by `Hardware`.
+#[derive(Clone)]
+pub struct State {
+    /// Registers V0 to VF
+    pub v: [u8; REGS],
+    /// Index register
+    pub i: u16,
+    /// Delay timer
+    pub dt: u8,
+    /// Sound timer
+    pub st: u8,
+    /// Program counter
+    pub pc: u16,
+    /// Stack pointer
+    pub sp: u8,
+    /// Memory, including the font and the ROM
+    pub mem: [u8; MEMS],
+    /// Stack of return addresses
+    pub stack: [u16; STACKS],
+    /// SUPER-CHIP high resolution (128 x 64) mode
+    pub hires: bool,
+    /// SUPER-CHIP user flags (RPL)
+    pub flags: [u8; FLAGS],
+}
+
+impl State {
+    /// Create a state with memory and registers cleared.
+    pub const fn new() -> Self {
+        Self {
+            v: [0; REGS],
+            i: 0,
+            dt: 0,
+            st: 0,
+            pc: 0,
+            sp: 0,
+            mem: [0; MEMS],
+            stack: [0; STACKS],
+            hires: false,
+            flags: [0; FLAGS],
+        }
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reason the interpreter stopped by itself, instead of `Hardware::sched` returning `true`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// `0nnn` (SYS nnn) at `pc` calls machine code, which is not supported
+    Unsupported { pc: u16, inst: u16 },
+    /// Instruction `inst` at `pc` is not a CHIP-8 or SUPER-CHIP instruction
+    InvalidOp { pc: u16, inst: u16 },
+}
+
+/// Interpreter instance
+pub struct Chip8<T> {
+    state: State,
+    time: Option<u64>,
+    running: bool,
+    error: Option<Error>,
+    hw: T,
+}
+
+/// Number of registers
+pub const REGS: usize = 16;
+/// Size of memory in bytes
+pub const MEMS: usize = 4096;
+/// Depth of the stack
+pub const STACKS: usize = 16;
+/// Number of SUPER-CHIP user flags
+pub const FLAGS: usize = 8;
+/// Screen size in low resolution mode
+pub const DISPS: (usize, usize) = (64, 32);
+/// Screen size in SUPER-CHIP high resolution mode
+pub const HIRES_DISPS: (usize, usize) = (128, 64);
+const ENTRY: u16 = 512;
+const ROMBASE: usize = 512;
+const BIGCHARBASE: usize = 80;
+/// Interval of the 60 Hz timer tick, in nanoseconds
+const TICK_NS: u64 = 1_000_000_000 / 60;
+/// Max number of timer ticks to catch up after a delay. After a longer delay the ticks are skipped.
+const MAX_TICK_LAG: u64 = 6;
+
+static CHARBUF: [u8; 80] = [
+    0xf0, 0x90, 0x90, 0x90, 0xf0, // 0
+    0x20, 0x60, 0x20, 0x20, 0x70, // 1
+    0xf0, 0x10, 0xf0, 0x80, 0xf0, // 2
+    0xf0, 0x10, 0xf0, 0x10, 0xf0, // 3
+    0x90, 0x90, 0xf0, 0x10, 0x10, // 4
+    0xf0, 0x80, 0xf0, 0x10, 0xf0, // 5
+    0xf0, 0x80, 0xf0, 0x90, 0xf0, // 6
+    0xf0, 0x10, 0x20, 0x40, 0x40, // 7
+    0xf0, 0x90, 0xf0, 0x90, 0xf0, // 8
+    0xf0, 0x90, 0xf0, 0x10, 0xf0, // 9
+    0xf0, 0x90, 0xf0, 0x90, 0x90, // a
+    0xe0, 0x90, 0xe0, 0x90, 0xe0, // b
+    0xf0, 0x80, 0x80, 0x80, 0xf0, // c
+    0xe0, 0x90, 0x90, 0x90, 0xe0, // d
+    0xf0, 0x80, 0xf0, 0x80, 0xf0, // e
+    0xf0, 0x80, 0xf0, 0x80, 0x80, // f
+];
+
+static BIGCHARBUF: [u8; 160] = [
+    0xff, 0xff, 0xc3, 0xc3, 0xc3, 0xc3, 0xc3, 0xc3, 0xff, 0xff, // 0
+    0x18, 0x78, 0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0xff, 0xff, // 1
+    0xff, 0xff, 0x03, 0x03, 0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, // 2
+    0xff, 0xff, 0x03, 0x03, 0xff, 0xff, 0x03, 0x03, 0xff, 0xff, // 3
+    0xc3, 0xc3, 0xc3, 0xc3, 0xff, 0xff, 0x03, 0x03, 0x03, 0x03, // 4
+    0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, 0x03, 0x03, 0xff, 0xff, // 5
+    0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, 0xc3, 0xc3, 0xff, 0xff, // 6
+    0xff, 0xff, 0x03, 0x03, 0x06, 0x0c, 0x18, 0x18, 0x18, 0x18, // 7
+    0xff, 0xff, 0xc3, 0xc3, 0xff, 0xff, 0xc3, 0xc3, 0xff, 0xff, // 8
+    0xff, 0xff, 0xc3, 0xc3, 0xff, 0xff, 0x03, 0x03, 0xff, 0xff, // 9
+    0x7e, 0xff, 0xc3, 0xc3, 0xc3, 0xff, 0xff, 0xc3, 0xc3, 0xc3, // a
+    0xfc, 0xfc, 0xc3, 0xc3, 0xfc, 0xfc, 0xc3, 0xc3, 0xfc, 0xfc, // b
+    0x3c, 0xff, 0xc3, 0xc0, 0xc0, 0xc0, 0xc0, 0xc3, 0xff, 0x3c, // c
+    0xfc, 0xfe, 0xc3, 0xc3, 0xc3, 0xc3, 0xc3, 0xc3, 0xfe, 0xfc, // d
+    0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, // e
+    0xff, 0xff, 0xc0, 0xc0, 0xff, 0xff, 0xc0, 0xc0, 0xc0, 0xc0, // f
+];
+
+impl<T: Hardware> Chip8<T> {
+    /// Create an interpreter instance.
+    pub fn new(hw: T) -> Self {
+        Self {
+            state: State::new(),
+            time: None,
+            running: false,
+            error: None,
+            hw,
+        }
+    }
+
+    /// Run the interpreter until `Hardware::sched` returns `true`, or until the ROM fails.
+    ///
+    /// The argument takes the raw ROM binary. Check `error()` after returning to see if the ROM failed.
+    pub fn run(&mut self, rom: &[u8]) {
+        self.setup();
+        self.load(rom);
+        self.resume();
+    }
+
+    /// Continue running the interpreter from the current state until `Hardware::sched` returns `true`,
+    /// or until the ROM fails.
+    ///
+    /// Call this after restoring the state with `state_mut`.
+    pub fn resume(&mut self) {
+        self.hw.vram_setsize(if self.state.hires { HIRES_DISPS } else { DISPS });
+        self.time = None;
+        self.running = true;
+        self.error = None;
+
+        loop {
+            self.sched();
+            if !self.running {
+                break;
+            }
+            self.eval();
+            if self.error.is_some() {
+                // Stop at the failed instruction
+                break;
+            }
+            self.next();
+        }
+    }
+
+    /// Get the reason the ROM failed in the last run, or `None` if the ROM didn't fail.
+    pub fn error(&self) -> Option<Error> {
+        self.error
+    }
+
+    /// Get the memory, registers and timers.
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Get the memory, registers and timers for restoring.
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// Get the hardware.
+    pub fn hardware(&mut self) -> &mut T {
+        &mut self.hw
+    }
+
+    fn setup(&mut self) {
+        self.state.pc = ENTRY;
+        self.state.mem[..CHARBUF.len()].copy_from_slice(&CHARBUF);
+        self.state.mem[BIGCHARBASE..BIGCHARBASE + BIGCHARBUF.len()].copy_from_slice(&BIGCHARBUF);
+        self.state.hires = false;
+        self.running = true;
+    }
+
+    fn shutdown(&mut self) {
+        self.running = false;
+    }
+
+    /// Stop the ROM because of the `error`.
+    fn fail(&mut self, error: Error) {
+        error!("{:?}", error);
+        self.error = Some(error);
+        self.shutdown();
+    }
+
+    fn load(&mut self, rom: &[u8]) {
+        self.state.mem[ROMBASE..ROMBASE + rom.len()].copy_from_slice(&rom);
+    }
+
+    fn push(&mut self, item: u16) {
+        self.state.stack[self.state.sp as usize] = item;
+        self.state.sp = self.state.sp.wrapping_add(1);
+    }
+
+    fn pop(&mut self) -> u16 {
+        self.state.sp = self.state.sp.wrapping_sub(1);
+        let item = self.state.stack[self.state.sp as usize];
+        item
+    }
+
+    fn jump(&mut self, pc: u16) {
+        self.state.pc = pc;
+    }
+
+    fn next(&mut self) {
+        self.jump(self.state.pc.wrapping_add(2));
+    }
+
+    fn sched(&mut self) {
+        if self.hw.sched() {
+            self.shutdown();
+        }
+
+        let now = self.hw.clock();
+        if let Some(t) = self.time {
+            let elapsed = now.wrapping_sub(t);
+            if elapsed >= TICK_NS {
+                self.tick();
+                //  Schedule the next tick exactly 1/60 second later, so the timers don't drift
+                self.time = Some(if elapsed < TICK_NS * MAX_TICK_LAG { t + TICK_NS } else { now });
+            }
+        } else {
+            self.time = Some(now);
+        }
+    }
+
+    /// Event which happens in 60 Hz interval
+    fn tick(&mut self) {
+        if self.state.dt > 0 {
+            self.state.dt -= 1;
+        }
+        if self.state.st > 0 {
+            self.state.st -= 1;
+            if self.state.st == 0 {
+                self.hw.beep();
+            }
+        }
+    }
+
+    /// Wait for a key press. Return `None` if shut down while waiting.
+    fn waitkey(&mut self) -> Option<u8> {
+        while self.running {
+            self.sched();
+
+            for i in 0..=0xf {
+                if self.hw.key(i) {
+                    return Some(i);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Switch between low and high resolution, clearing the screen.
+    fn setres(&mut self, hires: bool) {
+        self.state.hires = hires;
+        self.hw.vram_setsize(if hires { HIRES_DISPS } else { DISPS });
+        self.clear();
+    }
+
+    fn clear(&mut self) {
+        let (w, h) = self.hw.vram_size();
+        for (x, y) in (0..w).map(|w| (0..h).map(move |h| (w, h))).flatten() {
+            self.hw.vram_set(x, y, false);
+        }
+    }
+
+    /// Move the screen contents by (`dx`, `dy`) pixels, filling the uncovered pixels with black.
+    fn scroll(&mut self, dx: isize, dy: isize) {
+        let (w, h) = self.hw.vram_size();
+        let (w, h) = (w as isize, h as isize);
+        // Copy in the opposite direction of the movement, so that the source is read before it's overwritten
+        for j in 0..h {
+            let y = if dy > 0 { h - 1 - j } else { j };
+            for i in 0..w {
+                let x = if dx > 0 { w - 1 - i } else { i };
+                let (srcx, srcy) = (x - dx, y - dy);
+                let d = srcx >= 0 && srcx < w && srcy >= 0 && srcy < h
+                    && self.hw.vram_get(srcx as usize, srcy as usize);
+                if d != self.hw.vram_get(x as usize, y as usize) {
+                    self.hw.vram_set(x as usize, y as usize, d);
+                }
+            }
+        }
+    }
+
+    fn eval(&mut self) {
+        let h = self.state.mem[self.state.pc as usize] as u16;
+        let l = self.state.mem[(self.state.pc + 1) as usize] as u16;
+        let inst = h << 8 | l;
+
+        let nnn = inst & 0xfff;
+        let n = (inst & 0xf) as usize;
+        let x = ((inst >> 8) & 0xf) as usize;
+        let y = ((inst >> 4) & 0xf) as usize;
+        let kk = (inst & 0xff) as u8;
+
+        match (
+            (inst >> 12) & 0xf,
+            (inst >> 8) & 0xf,
+            (inst >> 4) & 0xf,
+            (inst >> 0) & 0xf,
+        ) {
+            (0, 0, 0xe, 0) => {
+                trace!("[{:04x}] CLS", self.state.pc);
+                self.clear();
+            }
+            (0, 0, 0xe, 0xe) => {
+                trace!("[{:04x}] RET", self.state.pc);
+                let addr = self.pop();
+                self.jump(addr);
+            }
+            (0, 0, 0xc, _) => {
+                trace!("[{:04x}] SCD n", self.state.pc);
+                self.scroll(0, n as isize);
+            }
+            (0, 0, 0xf, 0xb) => {
+                trace!("[{:04x}] SCR", self.state.pc);
+                self.scroll(4, 0);
+            }
+            (0, 0, 0xf, 0xc) => {
+                trace!("[{:04x}] SCL", self.state.pc);
+                self.scroll(-4, 0);
+            }
+            (0, 0, 0xf, 0xd) => {
+                trace!("[{:04x}] EXIT", self.state.pc);
+                self.shutdown();
+            }
+            (0, 0, 0xf, 0xe) => {
+                trace!("[{:04x}] LOW", self.state.pc);
+                self.setres(false);
+            }
+            (0, 0, 0xf, 0xf) => {
+                trace!("[{:04x}] HIGH", self.state.pc);
+                self.setres(true);
+            }
+            (0, _, _, _) => {
+                trace!("[{:04x}] SYS nnn", self.state.pc);
+                self.fail(Error::Unsupported { pc: self.state.pc, inst });
+            }
+            (1, _, _, _) => {
+                trace!("[{:04x}] JP nnn", self.state.pc);
+                self.jump(nnn.wrapping_sub(2));
+            }
+            (2, _, _, _) => {
+                trace!("[{:04x}] CALL nnn", self.state.pc);
+                self.push(self.state.pc);
+                self.jump(nnn.wrapping_sub(2));
+            }
+            (3, _, _, _) => {
+                trace!("[{:04x}] SE Vx kk", self.state.pc);
+                if self.state.v[x] == kk {
+                    self.next();
+                }
+            }
+            (4, _, _, _) => {
+                trace!("[{:04x}] SNE Vx, kk", self.state.pc);
+                if self.state.v[x] != kk {
+                    self.next();
+                }
+            }
+            (5, _, _, 0) => {
+                trace!("[{:04x}] SE Vx, Vy", self.state.pc);
+                if self.state.v[x] == self.state.v[y] {
+                    self.next();
+                }
+            }
+            (6, _, _, _) => {
+                trace!("[{:04x}] LD Vx, kk", self.state.pc);
+                self.state.v[x] = kk;
+            }
+            (7, _, _, _) => {
+                trace!("[{:04x}] ADD Vx, kk", self.state.pc);
+                self.state.v[x] = self.state.v[x].wrapping_add(kk);
+            }
+            (8, _, _, 0) => {
+                trace!("[{:04x}] LD Vx, Vy", self.state.pc);
+                self.state.v[x] = self.state.v[y];
+            }
+            (8, _, _, 1) => {
+                trace!("[{:04x}] OR Vx, Vy", self.state.pc);
+                self.state.v[x] |= self.state.v[y];
+            }
+            (8, _, _, 2) => {
+                trace!("[{:04x}] AND Vx, Vy", self.state.pc);
+                self.state.v[x] &= self.state.v[y];
+            }
+            (8, _, _, 3) => {
+                trace!("[{:04x}] XOR Vx, Vy", self.state.pc);
+                self.state.v[x] ^= self.state.v[y];
+            }
+            (8, _, _, 4) => {
+                trace!("[{:04x}] ADD Vx, Vy", self.state.pc);
+                let (v, c) = self.state.v[x].overflowing_add(self.state.v[y]);
+                self.state.v[x] = v;
+                self.state.v[0xf] = c as u8;
+            }
+            (8, _, _, 5) => {
+                trace!("[{:04x}] SUB Vx, Vy", self.state.pc);
+                let (v, b) = self.state.v[x].overflowing_sub(self.state.v[y]);
+                self.state.v[x] = v;
+                self.state.v[0xf] = !b as u8;
+            }
+            (8, _, _, 6) => {
+                trace!("[{:04x}] SHR Vx, Vy", self.state.pc);
+                self.state.v[0xf] = self.state.v[x] & 1;
+                self.state.v[x] = self.state.v[x].wrapping_shr(1);
+            }
+            (8, _, _, 7) => {
+                trace!("[{:04x}] SUBN Vx, Vy", self.state.pc);
+                let (v, b) = self.state.v[y].overflowing_sub(self.state.v[x]);
+                self.state.v[x] = v;
+                self.state.v[0xf] = !b as u8;
+            }
+            (8, _, _, 0xe) => {
+                trace!("[{:04x}] SHL Vx, Vy", self.state.pc);
+                self.state.v[0xf] = (self.state.v[x] & 0x80) >> 7;
+                self.state.v[x] = self.state.v[x].wrapping_shl(1);
+            }
+            (9, _, _, 0) => {
+                trace!("[{:04x}] SNE Vx, Vy", self.state.pc);
+                if self.state.v[x] != self.state.v[y] {
+                    self.next();
+                }
+            }
+            (0xa, _, _, _) => {
+                trace!("[{:04x}] LD I, nnn", self.state.pc);
+                self.state.i = nnn;
+            }
+            (0xb, _, _, _) => {
+                trace!("[{:04x}] JP V0, nnn", self.state.pc);
+                self.jump(nnn.wrapping_add(self.state.v[0].into()).wrapping_sub(2));
+            }
+            (0xc, _, _, _) => {
+                trace!("[{:04x}] RND Vx, kk", self.state.pc);
+                self.state.v[x] = self.hw.rand() & kk;
+            }
+            (0xd, _, _, _) => {
+                trace!("[{:04x}] DRW Vx, Vy, n", self.state.pc);
+                let basex = self.state.v[x] as usize;
+                let basey = self.state.v[y] as usize;
+                let (w, h) = self.hw.vram_size();
+                // SUPER-CHIP draws a 16 x 16 sprite, 2 bytes per row, when n is 0
+                let (rows, cols) = if n == 0 { (16, 16) } else { (n, 8) };
+
+                self.state.v[0xf] = 0;
+
+                for y in 0..rows {
+                    let b = if cols == 16 {
+                        let addr = self.state.i as usize + y * 2;
+                        (self.state.mem[addr] as u16) << 8 | self.state.mem[addr + 1] as u16
+                    } else {
+                        (self.state.mem[self.state.i as usize + y] as u16) << 8
+                    };
+
+                    let vramy = (y + basey) % h;
+
+                    for x in 0..cols {
+                        let vramx = (x + basex) % w;
+
+                        let src = (b & 1 << (15 - x)) > 0;
+                        let dst = self.hw.vram_get(vramx, vramy);
+
+                        self.state.v[0xf] |= (src && dst) as u8;
+
+                        self.hw.vram_set(vramx, vramy, src ^ dst);
+                    }
+                }
+            }
+            (0xe, _, 9, 0xe) => {
+                trace!("[{:04x}] SKP Vx", self.state.pc);
+                if self.hw.key(self.state.v[x]) {
+                    self.next();
+                }
+            }
+            (0xe, _, 0xa, 0x1) => {
+                trace!("[{:04x}] SKNP Vx", self.state.pc);
+                if !self.hw.key(self.state.v[x]) {
+                    self.next();
+                }
+            }
+            (0xf, _, 0, 7) => {
+                trace!("[{:04x}] LD Vx, DT", self.state.pc);
+                self.state.v[x] = self.state.dt;
+            }
+            (0xf, _, 0, 0xa) => {
+                trace!("[{:04x}] LD Vx, K", self.state.pc);
+                match self.waitkey() {
+                    Some(key) => self.state.v[x] = key,
+                    // Execute this instruction again when resumed
+                    None => self.state.pc = self.state.pc.wrapping_sub(2),
+                }
+            }
+            (0xf, _, 1, 5) => {
+                trace!("[{:04x}] LD DT, Vx", self.state.pc);
+                self.state.dt = self.state.v[x];
+            }
+            (0xf, _, 1, 8) => {
+                trace!("[{:04x}] LD ST, Vx", self.state.pc);
+                self.state.st = self.state.v[x];
+            }
+            (0xf, _, 1, 0xe) => {
+                trace!("[{:04x}] ADD I, Vx", self.state.pc);
+                self.state.i = self.state.i.wrapping_add(self.state.v[x].into());
+            }
+            (0xf, _, 2, 9) => {
+                trace!("[{:04x}] LD F, Vx", self.state.pc);
+                self.state.i = (self.state.v[x] * 5).into();
+            }
+            (0xf, _, 3, 0) => {
+                trace!("[{:04x}] LD HF, Vx", self.state.pc);
+                self.state.i = (BIGCHARBASE + (self.state.v[x] & 0xf) as usize * 10) as u16;
+            }
+            (0xf, _, 3, 3) => {
+                trace!("[{:04x}] LD B, Vx", self.state.pc);
+                let bcd = self.state.v[x];
+                self.state.mem[self.state.i as usize] = (bcd / 100) % 10;
+                self.state.mem[self.state.i as usize + 1] = (bcd / 10) % 10;
+                self.state.mem[self.state.i as usize + 2] = bcd % 10;
+            }
+            (0xf, _, 5, 5) => {
+                trace!("[{:04x}] LD [I], Vx", self.state.pc);
+                let x = x as usize;
+                for i in 0..(x + 1) {
+                    self.state.mem[self.state.i as usize + i] = self.state.v[i];
+                }
+            }
+            (0xf, _, 6, 5) => {
+                trace!("[{:04x}] LD Vx, [I]", self.state.pc);
+                let x = x as usize;
+                for i in 0..(x + 1) {
+                    self.state.v[i] = self.state.mem[self.state.i as usize + i];
+                }
+            }
+            (0xf, _, 7, 5) if x < FLAGS => {
+                trace!("[{:04x}] LD R, Vx", self.state.pc);
+                self.state.flags[..x + 1].copy_from_slice(&self.state.v[..x + 1]);
+            }
+            (0xf, _, 8, 5) if x < FLAGS => {
+                trace!("[{:04x}] LD Vx, R", self.state.pc);
+                self.state.v[..x + 1].copy_from_slice(&self.state.flags[..x + 1]);
+            }
+            _ => self.fail(Error::InvalidOp { pc: self.state.pc, inst }), // Bad ops
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hardware that holds the `keys` and stops after `steps` steps
+    struct TestHardware {
+        keys: u16,
+        steps: u32,
+        size: (usize, usize),
+    }
+
+    impl TestHardware {
+        fn new(keys: u16, steps: u32) -> Self {
+            Self { keys, steps, size: DISPS }
+        }
+    }
+
+    impl Hardware for TestHardware {
+        fn rand(&mut self) -> u8 { 0 }
+        fn key(&mut self, key: u8) -> bool { self.keys & (1 << key) != 0 }
+        fn vram_set(&mut self, _x: usize, _y: usize, _d: bool) {}
+        fn vram_get(&mut self, _x: usize, _y: usize) -> bool { false }
+        fn vram_setsize(&mut self, size: (usize, usize)) { self.size = size; }
+        fn vram_size(&mut self) -> (usize, usize) { self.size }
+        fn clock(&mut self) -> u64 { 0 }
+        fn beep(&mut self) {}
+        fn sched(&mut self) -> bool {
+            if self.steps == 0 { return true; }
+            self.steps -= 1;
+            false
+        }
+    }
+
+    #[test]
+    fn waitkey_accepts_key_f() {
+        // LD V0, K then EXIT
+        let rom = [0xf0, 0x0a, 0x00, 0xfd];
+        let mut chip8 = Chip8::new(TestHardware::new(1 << 0xf, 100));
+        chip8.run(&rom);
+        assert_eq!(chip8.state().v[0], 0xf);
+        assert_eq!(chip8.error(), None);
+    }
+
+    #[test]
+    fn sys_call_stops_rom() {
+        // LD V1, 0x23 then SYS 0x123
+        let rom = [0x61, 0x23, 0x01, 0x23];
+        let mut chip8 = Chip8::new(TestHardware::new(0, 100));
+        chip8.run(&rom);
+        assert_eq!(chip8.error(), Some(Error::Unsupported { pc: 0x202, inst: 0x0123 }));
+        assert_eq!(chip8.state().v[1], 0x23);
+        assert_eq!(chip8.state().pc, 0x202);
+    }
+
+    #[test]
+    fn invalid_op_stops_rom() {
+        let rom = [0x00, 0xe0, 0xe0, 0x00];
+        let mut chip8 = Chip8::new(TestHardware::new(0, 100));
+        chip8.run(&rom);
+        assert_eq!(chip8.error(), Some(Error::InvalidOp { pc: 0x202, inst: 0xe000 }));
+
+        // Resuming after fixing the instruction clears the error
+        chip8.state_mut().mem[0x202..0x204].copy_from_slice(&[0x00, 0xfd]);
+        chip8.resume();
+        assert_eq!(chip8.error(), None);
+        assert_eq!(chip8.state().pc, 0x204);
+    }
+
+    #[test]
+    fn sched_stops_rom_without_error() {
+        // JP 0x200 forever
+        let rom = [0x12, 0x00];
+        let mut chip8 = Chip8::new(TestHardware::new(0, 100));
+        chip8.run(&rom);
+        assert_eq!(chip8.error(), None);
+        assert_eq!(chip8.state().pc, 0x200);
+    }
+}
@@ -1,6 +1,19 @@
 use crate::{hw::hal, kernel::os, result::*};
 use embedded_hal;
 
+mod flash;  //  Import module `flash` for Flash HAL functions
+pub use flash::{ Flash, FlashGeometry, RamFlash, ERASED_VALUE, SECTOR_SIZE };  //  Export Flash API. `RamFlash` is in `pinetime-logo`.
+
+mod rng;  //  Import module `rng` for Random Number Generator functions
+pub use rng::{ RandomSource, Rng, XorShiftRng };  //  Export Random Number Generator API
+#[cfg(feature = "trng")]
+pub use rng::HardwareRng;  //  Export nRF52 hardware RNG
+#[cfg(feature = "hmac_prng")]
+pub use rng::HmacPrng;  //  Export HMAC PRNG
+
+mod vibrator;  //  Import module `vibrator` for Vibration Motor functions
+pub use vibrator::{ Vibrator, MOTOR_PIN, BACKLIGHT_HIGH_PIN, MAX_STEPS, SHORT, DOUBLE, LONG };  //  Export Vibration Motor API
+
 /// Rust Embedded HAL interface for Mynewt I2C
 impl I2C {
     /// Create a new I2C port
@@ -8,6 +21,11 @@ impl I2C {
         I2C { i2c_num: 0, timeout: 1000 }
     }
 
+    /// Use an I2C port that has been configured and enabled by the BSP, e.g. `I2C_1: 1` in `syscfg.yml`
+    pub fn open(i2c_num: u8, operation_timeout_in_ticks: u32) -> Self {
+        I2C { i2c_num, timeout: operation_timeout_in_ticks }
+    }
+
     /// Initiaise the I2C port
     pub fn init(
         &mut self,
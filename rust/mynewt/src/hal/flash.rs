@@ -0,0 +1,181 @@
+//! Safe Rust interface for Mynewt Flash HAL (`hal_flash_*`). Erases are widened to whole sectors and writes
+//! are checked against the erased value, so that callers don't clobber data by accident.
+//! Also implements the `embedded-storage` NOR Flash traits. `RamFlash` in `rust/pinetime-logo` implements the same
+//! traits in RAM for running flash logic without hardware, and is tested on the host.
+use crate::{
+    hw::hal,
+    result::*,
+};
+use core::ops::Range;
+use embedded_storage::nor_flash::{
+    ErrorType, NorFlash, NorFlashError, NorFlashErrorKind, ReadNorFlash,
+};
+use pinetime_logo::flash::{ align_to_sectors, check_erase_range };
+
+pub use pinetime_logo::flash::{ RamFlash, ERASED_VALUE, SECTOR_SIZE };  //  Export the sector size and the in-memory flash
+
+/// Size of the buffer for checking that bytes are erased before writing
+const CHECK_BUFFER_SIZE: usize = 64;
+
+/// Geometry of a flash device, queried from the Mynewt BSP
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FlashGeometry {
+    /// Address of the first byte
+    pub base:         u32,
+    /// Size in bytes
+    pub size:         u32,
+    /// Number of sectors
+    pub sector_count: u32,
+    /// Size of each sector in bytes
+    pub sector_size:  u32,
+    /// Writes must be aligned to this number of bytes
+    pub align:        u32,
+    /// Value of an erased byte
+    pub erased_value: u8,
+}
+
+/// Flash device accessed through Mynewt Flash HAL. Offsets are relative to the start of the device.
+pub struct Flash {
+    /// Mynewt flash device ID: 0 for Internal Flash ROM, 1 for External SPI Flash
+    device:   u8,
+    /// Geometry of the device
+    geometry: FlashGeometry,
+}
+
+impl Flash {
+    /// Open the flash device and query its geometry. Fail with `SYS_ENODEV` if the device doesn't exist,
+    /// `SYS_ENOTSUP` if the sectors are not all `SECTOR_SIZE` bytes.
+    pub fn new(device: u8) -> MynewtResult<Flash> {
+        let dev = unsafe { hal::hal_bsp_flash_dev(device) };
+        if dev.is_null() { return Err(MynewtError::SYS_ENODEV); }
+        let dev = unsafe { &*dev };
+        let itf = unsafe { &*dev.hf_itf };
+        let sector_info = itf.hff_sector_info.ok_or(MynewtError::SYS_ENOTSUP) ? ;
+
+        //  Every sector must be `SECTOR_SIZE` bytes, so that erases can be aligned
+        for idx in 0..dev.hf_sector_cnt {
+            let mut address = 0;
+            let mut size = 0;
+            let rc = unsafe { sector_info(dev, idx, &mut address, &mut size) };
+            check_return_code(rc) ? ;
+            if size != SECTOR_SIZE || address != dev.hf_base_addr + idx as u32 * SECTOR_SIZE {
+                return Err(MynewtError::SYS_ENOTSUP);
+            }
+        }
+        let geometry = FlashGeometry {
+            base:         dev.hf_base_addr,
+            size:         dev.hf_size,
+            sector_count: dev.hf_sector_cnt as u32,
+            sector_size:  SECTOR_SIZE,
+            align:        (dev.hf_align as u32).max(1),
+            erased_value: dev.hf_erased_val,
+        };
+        Ok(Flash { device, geometry })
+    }
+
+    /// Return the Mynewt flash device ID
+    pub fn device(&self) -> u8 {
+        self.device
+    }
+
+    /// Return the geometry of the flash device
+    pub fn geometry(&self) -> FlashGeometry {
+        self.geometry
+    }
+
+    /// Read `buf.len()` bytes at `offset` into `buf`. Fail with `SYS_ERANGE` if outside the device.
+    pub fn read(&mut self, offset: u32, buf: &mut [u8]) -> MynewtResult<()> {
+        let address = self.address(offset, buf.len()) ? ;
+        let rc = unsafe { hal::hal_flash_read(self.device, address, buf.as_mut_ptr() as *mut ::cty::c_void, buf.len() as u32) };
+        check_return_code(rc)
+    }
+
+    /// Erase the sectors covering `len` bytes at `offset`. The range is widened to sector boundaries,
+    /// so bytes before `offset` and after `offset + len` in the same sectors are erased too.
+    /// Returns the range of offsets that were erased. Fail with `SYS_ERANGE` if outside the device.
+    pub fn erase_range(&mut self, offset: u32, len: u32) -> MynewtResult<Range<u32>> {
+        self.address(offset, len as usize) ? ;
+        let range = align_to_sectors(offset, len);
+        for sector in range.clone().step_by(SECTOR_SIZE as usize) {
+            let rc = unsafe { hal::hal_flash_erase_sector(self.device, self.geometry.base + sector) };
+            check_return_code(rc) ? ;
+        }
+        Ok(range)
+    }
+
+    /// Write `data` at `offset`. Fail with `SYS_EINVAL` if `offset` or the length is not aligned to the
+    /// device alignment, `SYS_ERANGE` if outside the device, `SYS_EALREADY` if the bytes have not been erased.
+    pub fn write(&mut self, offset: u32, data: &[u8]) -> MynewtResult<()> {
+        let address = self.address(offset, data.len()) ? ;
+        let align = self.geometry.align;
+        if offset % align != 0 || data.len() as u32 % align != 0 { return Err(MynewtError::SYS_EINVAL); }
+
+        //  NOR Flash can only clear bits, so the bytes must be erased before writing
+        let mut buf = [0u8; CHECK_BUFFER_SIZE];
+        for (i, chunk) in data.chunks(CHECK_BUFFER_SIZE).enumerate() {
+            let check = &mut buf[..chunk.len()];
+            self.read(offset + (i * CHECK_BUFFER_SIZE) as u32, check) ? ;
+            if check.iter().any(|byte| *byte != self.geometry.erased_value) {
+                return Err(MynewtError::SYS_EALREADY);
+            }
+        }
+        let rc = unsafe { hal::hal_flash_write(self.device, address, data.as_ptr() as *const ::cty::c_void, data.len() as u32) };
+        check_return_code(rc)
+    }
+
+    /// Return the flash address of `len` bytes at `offset`. Fail with `SYS_ERANGE` if outside the device.
+    fn address(&self, offset: u32, len: usize) -> MynewtResult<u32> {
+        if offset as usize + len > self.geometry.size as usize { return Err(MynewtError::SYS_ERANGE); }
+        Ok(self.geometry.base + offset)
+    }
+}
+
+impl ErrorType for Flash {
+    /// Reuse Mynewt error codes
+    type Error = MynewtError;
+}
+
+impl ReadNorFlash for Flash {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        Flash::read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.geometry.size as usize
+    }
+}
+
+impl NorFlash for Flash {
+    const WRITE_SIZE: usize = 1;
+    const ERASE_SIZE: usize = SECTOR_SIZE as usize;
+
+    /// Erase the sectors from `from` to `to`. Both must be aligned to `SECTOR_SIZE`.
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        check_erase_range(from, to) ? ;
+        self.erase_range(from, to - from) ? ;
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        Flash::write(self, offset, bytes)
+    }
+}
+
+/// Map Mynewt error codes to NOR Flash error kinds
+impl NorFlashError for MynewtError {
+    fn kind(&self) -> NorFlashErrorKind {
+        match self {
+            MynewtError::SYS_EINVAL => NorFlashErrorKind::NotAligned,
+            MynewtError::SYS_ERANGE => NorFlashErrorKind::OutOfBounds,
+            _                       => NorFlashErrorKind::Other,
+        }
+    }
+}
+
+/// Convert the Mynewt Flash HAL return code to a result
+fn check_return_code(rc: i32) -> MynewtResult<()> {
+    if rc == 0 { Ok(()) }
+    else { Err(MynewtError::from(rc)) }
+}
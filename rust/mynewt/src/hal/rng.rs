@@ -0,0 +1,150 @@
+//! Random Number Generator API for Rust. `Rng` draws random bytes from the first source that is available:
+//! 1. nRF52 hardware RNG through the Mynewt `trng` driver (feature `trng`, needs `TRNG: 1` in `apps/my_sensor_app/syscfg.yml`)
+//! 2. TinyCrypt HMAC PRNG in `libs/hmac_prng` (feature `hmac_prng`, needs `HMAC_PRNG: 1` in `syscfg.yml`)
+//! 3. Seeded xorshift generator in `rust/pinetime-rng`, which is shared with `chip8-host` and tested on the host
+use crate::result::*;
+#[cfg(feature = "trng")]
+use crate::kernel::os;
+
+pub use pinetime_rng::XorShiftRng;  //  Export the xorshift generator
+
+/// Max number of times to poll the hardware RNG when it has no random bytes
+#[cfg(feature = "trng")]
+const MAX_RETRIES: u32 = 1000;
+
+/// Source of random bytes
+pub trait RandomSource {
+    /// Fill `buf` with random bytes
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> MynewtResult<()>;
+
+    /// Return a random byte
+    fn next_u8(&mut self) -> MynewtResult<u8> {
+        let mut buf = [0u8; 1];
+        self.fill_bytes(&mut buf) ? ;
+        Ok(buf[0])
+    }
+
+    /// Return a random 32-bit number
+    fn next_u32(&mut self) -> MynewtResult<u32> {
+        let mut buf = [0u8; 4];
+        self.fill_bytes(&mut buf) ? ;
+        Ok(u32::from_le_bytes(buf))
+    }
+}
+
+impl RandomSource for XorShiftRng {
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> MynewtResult<()> {
+        XorShiftRng::fill_bytes(self, buf);
+        Ok(())
+    }
+}
+
+/// nRF52 hardware RNG, accessed through the Mynewt `trng` driver
+#[cfg(feature = "trng")]
+pub struct HardwareRng {
+    /// Mynewt `trng` device
+    dev: *mut TrngDev,
+}
+
+#[cfg(feature = "trng")]
+impl HardwareRng {
+    /// Open the `trng` device. Fail with `SYS_ENODEV` if the device doesn't exist.
+    pub fn new() -> MynewtResult<HardwareRng> {
+        let dev = unsafe {
+            os::os_dev_open(b"trng\0".as_ptr() as *const ::cty::c_char, 0, core::ptr::null_mut())
+        };
+        if dev.is_null() { return Err(MynewtError::SYS_ENODEV); }
+        Ok(HardwareRng { dev: dev as *mut TrngDev })
+    }
+}
+
+#[cfg(feature = "trng")]
+impl RandomSource for HardwareRng {
+    /// Fill `buf` with random bytes, waiting for the hardware RNG if necessary. Fail with `SYS_EAGAIN` if the
+    /// hardware RNG doesn't produce enough bytes.
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> MynewtResult<()> {
+        let mut pos = 0;
+        let mut retries = 0;
+        while pos < buf.len() {
+            let len = unsafe {
+                trng_read(self.dev, buf[pos..].as_mut_ptr() as *mut ::cty::c_void, buf.len() - pos)
+            };
+            if len == 0 {
+                //  Entropy pool is empty. Give up if the hardware RNG is too slow.
+                retries += 1;
+                if retries > MAX_RETRIES { return Err(MynewtError::SYS_EAGAIN); }
+            }
+            pos += len;
+        }
+        Ok(())
+    }
+}
+
+/// TinyCrypt HMAC PRNG in `libs/hmac_prng`, seeded during startup
+#[cfg(feature = "hmac_prng")]
+pub struct HmacPrng;
+
+#[cfg(feature = "hmac_prng")]
+impl RandomSource for HmacPrng {
+    /// Fill `buf` with pseudorandom bytes. Fail with `SYS_EIO` if the PRNG needs to be reseeded.
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> MynewtResult<()> {
+        let rc = unsafe { hmac_prng_generate(buf.as_mut_ptr(), buf.len() as ::cty::c_uint) };
+        if rc != 0 { return Err(MynewtError::SYS_EIO); }
+        Ok(())
+    }
+}
+
+/// Random number generator that uses the best available source, and falls back to xorshift upon error
+pub struct Rng {
+    /// nRF52 hardware RNG, if opened
+    #[cfg(feature = "trng")]
+    hardware: Option<HardwareRng>,
+    /// Fallback generator
+    fallback: XorShiftRng,
+}
+
+impl Rng {
+    /// Open the best available source. `seed` seeds the fallback generator, e.g. with the current time.
+    pub fn new(seed: u32) -> Rng {
+        Rng {
+            #[cfg(feature = "trng")]
+            hardware: HardwareRng::new().ok(),
+            fallback: XorShiftRng::new(seed),
+        }
+    }
+}
+
+impl RandomSource for Rng {
+    fn fill_bytes(&mut self, buf: &mut [u8]) -> MynewtResult<()> {
+        #[cfg(feature = "trng")]
+        {
+            if let Some(hardware) = &mut self.hardware {
+                if hardware.fill_bytes(buf).is_ok() { return Ok(()); }
+            }
+        }
+        #[cfg(feature = "hmac_prng")]
+        {
+            if HmacPrng.fill_bytes(buf).is_ok() { return Ok(()); }
+        }
+        RandomSource::fill_bytes(&mut self.fallback, buf)
+    }
+}
+
+/// Mynewt `trng` device, only used as an opaque pointer
+#[cfg(feature = "trng")]
+#[repr(C)]
+pub struct TrngDev {
+    _private: [u8; 0],
+}
+
+#[cfg(feature = "trng")]
+extern "C" {
+    /// Read up to `len` random bytes into `ptr` from the `trng` device. Returns the number of bytes read.
+    fn trng_read(trng: *mut TrngDev, ptr: *mut ::cty::c_void, len: usize) -> usize;
+}
+
+#[cfg(feature = "hmac_prng")]
+extern "C" {
+    /// Generate `rndlen` pseudorandom bytes into `rnd`. Returns 0 if successful.
+    fn hmac_prng_generate(rnd: *mut u8, rndlen: ::cty::c_uint) -> ::cty::c_int;
+}
@@ -0,0 +1,147 @@
+//! Vibration Motor API for PineTime. The motor is switched on and off through a GPIO pin according to a
+//! pattern of on and off durations. Patterns are played in the background by a Mynewt callout on the
+//! default event queue, so the caller doesn't block. The same driver may pulse any GPIO output, such as
+//! a backlight pin for flashing the display.
+use crate::{
+    hw::hal,
+    kernel::os,
+    result::*,
+};
+
+/// GPIO pin for the PineTime vibration motor: P0.16, active low
+pub const MOTOR_PIN: i32 = 16;
+
+/// GPIO pin for the high brightness PineTime backlight: P0.23, active low
+pub const BACKLIGHT_HIGH_PIN: i32 = 23;
+
+/// Max number of durations in a pattern
+pub const MAX_STEPS: usize = 8;
+
+/// Short pulse, like a key click. Patterns are durations in milliseconds to switch the output on, off, on, off, ...
+/// The output is switched off at the end of the pattern.
+pub const SHORT: &[u16] = &[ 40 ];
+
+/// Two short pulses
+pub const DOUBLE: &[u16] = &[ 40, 80, 40 ];
+
+/// Long pulse, like an alert
+pub const LONG: &[u16] = &[ 400 ];
+
+/// Vibration motor, or other GPIO output, that plays a pattern in the background
+pub struct Vibrator {
+    /// Mynewt GPIO pin number
+    pin:      i32,
+    /// GPIO level that switches the output on: 0 for active low, 1 for active high
+    on_level: i32,
+    /// Durations of the pattern being played, in milliseconds
+    pattern:  [u16; MAX_STEPS],
+    /// Number of durations in the pattern
+    len:      usize,
+    /// Index of the next duration in the pattern
+    step:     usize,
+    /// True if `init()` has been called
+    ready:    bool,
+    /// Callout that fires at the end of each duration
+    callout:  os::os_callout,
+}
+
+impl Vibrator {
+    /// Create a vibrator for the GPIO `pin`, switched on by the GPIO level `on_level`. Call `init()` before use.
+    pub const fn new(pin: i32, on_level: i32) -> Vibrator {
+        Vibrator {
+            pin,
+            on_level,
+            pattern:  [0; MAX_STEPS],
+            len:      0,
+            step:     0,
+            ready:    false,
+            callout:  fill_zero!(os::os_callout),
+        }
+    }
+
+    /// Configure the GPIO pin as output, switched off, and prepare the callout on the default event queue.
+    /// The vibrator must be `static` because the callout refers to it.
+    pub fn init(&'static mut self) -> MynewtResult<()> {
+        let rc = unsafe { hal::hal_gpio_init_out(self.pin, 1 - self.on_level) };
+        if rc != 0 { return Err(MynewtError::SYS_EINVAL); }
+        let queue = os::eventq_dflt_get() ? ;
+        unsafe {
+            os::os_callout_init(
+                &mut self.callout,           //  Callout to be initialised
+                queue,                       //  Fire the callout on the default event queue
+                Some(vibrator_callback),     //  Function to call when the callout fires
+                self as *mut Vibrator as *mut ::cty::c_void  //  Pass this vibrator to the function
+            )
+        };
+        self.ready = true;
+        Ok(())
+    }
+
+    /// Switch on the output for `ms` milliseconds, without blocking
+    pub fn vibrate(&mut self, ms: u16) -> MynewtResult<()> {
+        self.play(&[ ms ])
+    }
+
+    /// Play the `pattern` from the start, without blocking. Any pattern being played is stopped.
+    /// Fail with `SYS_EINVAL` if `init()` has not been called or the pattern has more than `MAX_STEPS` durations.
+    pub fn play(&mut self, pattern: &[u16]) -> MynewtResult<()> {
+        if !self.ready || pattern.len() > MAX_STEPS { return Err(MynewtError::SYS_EINVAL); }
+        //  Stop the callout before changing the pattern, because the callout may fire in another task
+        unsafe { os::os_callout_stop(&mut self.callout) };
+        self.pattern[..pattern.len()].copy_from_slice(pattern);
+        self.len = pattern.len();
+        self.step = 0;
+        self.next_step()
+    }
+
+    /// Stop the pattern being played and switch off the output
+    pub fn stop(&mut self) {
+        if !self.ready { return; }
+        unsafe { os::os_callout_stop(&mut self.callout) };
+        self.len = 0;
+        self.step = 0;
+        self.switch(false);
+    }
+
+    /// Return true if a pattern is being played
+    pub fn is_playing(&self) -> bool {
+        self.step > 0 && self.step <= self.len
+    }
+
+    /// Switch the output on or off for the next duration in the pattern, and set the callout to fire
+    /// at the end of the duration. Switch off the output at the end of the pattern.
+    fn next_step(&mut self) -> MynewtResult<()> {
+        let ms = match self.pattern[..self.len].get(self.step) {
+            Some(ms) => *ms as u32,
+            None => {
+                //  End of pattern
+                self.step = self.len + 1;
+                self.switch(false);
+                return Ok(());
+            }
+        };
+        self.switch(self.step % 2 == 0);  //  Even steps are on, odd steps are off
+        self.step += 1;
+        //  Fire the callout at least 1 tick later
+        let ticks = (ms * os::OS_TICKS_PER_SEC / 1000).max(1);
+        let rc = unsafe { os::os_callout_reset(&mut self.callout, ticks) };
+        if rc != 0 {
+            self.switch(false);
+            return Err(MynewtError::from(rc));
+        }
+        Ok(())
+    }
+
+    /// Switch the output on or off
+    fn switch(&mut self, on: bool) {
+        let level = if on { self.on_level } else { 1 - self.on_level };
+        unsafe { hal::hal_gpio_write(self.pin, level) };
+    }
+}
+
+/// Callback for the callout, called by the task that runs the default event queue
+extern "C" fn vibrator_callback(event: *mut os::os_event) {
+    let vibrator = unsafe { &mut *((*event).ev_arg as *mut Vibrator) };
+    vibrator.next_step()
+        .unwrap_or(());  //  Output has been switched off upon error
+}
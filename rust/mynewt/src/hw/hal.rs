@@ -4,6 +4,13 @@
 // super::*;
 
 pub const HAL_ADC_MODULE_ENABLED: u32 = 1;
+pub const HAL_BSP_POWER_ON: u32 = 1;
+pub const HAL_BSP_POWER_WFI: u32 = 2;
+pub const HAL_BSP_POWER_SLEEP: u32 = 3;
+pub const HAL_BSP_POWER_DEEP_SLEEP: u32 = 4;
+pub const HAL_BSP_POWER_OFF: u32 = 5;
+pub const HAL_BSP_POWER_PERUSER: u32 = 128;
+pub const HAL_BSP_MAX_ID_LEN: u32 = 32;
 pub const HAL_I2C_ERR_UNKNOWN: u32 = 1;
 pub const HAL_I2C_ERR_INVAL: u32 = 2;
 pub const HAL_I2C_ERR_TIMEOUT: u32 = 3;
@@ -19,6 +26,182 @@ pub const HAL_SPI_MSB_FIRST: u32 = 0;
 pub const HAL_SPI_LSB_FIRST: u32 = 1;
 pub const HAL_SPI_WORD_SIZE_8BIT: u32 = 0;
 pub const HAL_SPI_WORD_SIZE_9BIT: u32 = 1;
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Initializes BSP; registers flash_map with the system."]
+    pub fn hal_bsp_init();
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " De-initializes BSP. Intended to be called from bootloader"]
+    #[doc = " before it jumps to start running the application. Should"]
+    #[doc = " be implemented by the BSP."]
+    pub fn hal_bsp_deinit();
+}
+#[repr(C)]
+pub struct hal_flash {
+    pub hf_itf: *const hal_flash_funcs,
+    pub hf_base_addr: u32,
+    pub hf_size: u32,
+    pub hf_sector_cnt: ::cty::c_int,
+    pub hf_align: ::cty::c_int,
+    pub hf_erased_val: u8,
+}
+impl Default for hal_flash {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Return pointer to flash device structure, given BSP specific"]
+    #[doc = " flash id."]
+    pub fn hal_bsp_flash_dev(flash_id: u8) -> *const hal_flash;
+}
+#[doc = " Report which memory areas should be included inside a coredump."]
+#[repr(C)]
+pub struct hal_bsp_mem_dump {
+    pub hbmd_start: *mut ::cty::c_void,
+    pub hbmd_size: u32,
+}
+impl Default for hal_bsp_mem_dump {
+    fn default() -> Self {
+        unsafe { ::core::mem::zeroed() }
+    }
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_bsp_core_dump(area_cnt: *mut ::cty::c_int) -> *const hal_bsp_mem_dump;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Retrieves the configured priority for the given interrupt. If no priority"]
+    #[doc = " is configured, returns the priority passed in."]
+    #[doc = ""]
+    #[doc = " - __`irq_num`__:               The IRQ being queried."]
+    #[doc = " - __`pri`__:                   The default priority if none is configured."]
+    #[doc = ""]
+    #[doc = " Return: uint32_t              The specified IRQ's priority."]
+    pub fn hal_bsp_get_nvic_priority(irq_num: ::cty::c_int, pri: u32) -> u32;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Move the system into the specified power state"]
+    #[doc = ""]
+    #[doc = " - __`state`__: The power state to move the system into, this is one of"]
+    #[doc = "                 * HAL_BSP_POWER_ON: Full system on"]
+    #[doc = "                 * HAL_BSP_POWER_WFI: Processor off, peripherals on"]
+    #[doc = "                 * HAL_BSP_POWER_SLEEP: Processor off, peripherals on"]
+    #[doc = "                 * HAL_BSP_POWER_DEEP_SLEEP: Processor off, RAM retained"]
+    #[doc = "                 * HAL_BSP_POWER_OFF: Processor and peripherals off"]
+    #[doc = "                 * >= HAL_BSP_POWER_PERUSER: User defined power states."]
+    #[doc = ""]
+    #[doc = " Return: 0 on success, non-zero if power state requested not supported."]
+    pub fn hal_bsp_power_state(state: ::cty::c_int) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Returns the length of the hardware ID."]
+    #[doc = ""]
+    #[doc = " Return: Length of the hardware ID."]
+    pub fn hal_bsp_hw_id_len() -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Returns the hardware id of the device."]
+    #[doc = ""]
+    #[doc = " - __`id`__: Pointer to the buffer to copy the hardware ID into."]
+    #[doc = " - __`max_len`__: Maximum length of the ID (size of buffer)."]
+    #[doc = ""]
+    #[doc = " Return: The number of bytes copied into the ID buffer."]
+    pub fn hal_bsp_hw_id(id: *mut u8, max_len: ::cty::c_int) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_ioctl(flash_id: u8, cmd: u32, args: *mut ::cty::c_void) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_read(
+        flash_id: u8,
+        address: u32,
+        dst: *mut ::cty::c_void,
+        num_bytes: u32,
+    ) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_write(
+        flash_id: u8,
+        address: u32,
+        src: *const ::cty::c_void,
+        num_bytes: u32,
+    ) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_erase_sector(flash_id: u8, sector_address: u32) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_erase(flash_id: u8, address: u32, num_bytes: u32) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Determines if the given flash region has been erased."]
+    #[doc = ""]
+    #[doc = " - __`flash_id`__: The ID of the flash device to read from."]
+    #[doc = " - __`address`__: The address to start checking from."]
+    #[doc = " - __`dst`__: A buffer to hold the bytes read from flash."]
+    #[doc = " - __`num_bytes`__: The number of bytes to check."]
+    #[doc = ""]
+    #[doc = " Return: 1 if the region is empty (erased), 0 if not, negative"]
+    #[doc = "                      on error."]
+    pub fn hal_flash_isempty(
+        flash_id: u8,
+        address: u32,
+        dst: *mut ::cty::c_void,
+        num_bytes: u32,
+    ) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    #[doc = " Determines if the given flash region has been erased, without reading"]
+    #[doc = " into a caller-supplied buffer."]
+    pub fn hal_flash_isempty_no_buf(id: u8, address: u32, num_bytes: u32) -> ::cty::c_int;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_align(flash_id: u8) -> u8;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_erased_val(flash_id: u8) -> u8;
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_init() -> ::cty::c_int;
+}
+#[repr(C)]
+#[derive(Default)]
+pub struct hal_flash_funcs {
+    pub hff_read: ::core::option::Option<
+        unsafe extern "C" fn(
+            dev: *const hal_flash,
+            address: u32,
+            dst: *mut ::cty::c_void,
+            num_bytes: u32,
+        ) -> ::cty::c_int,
+    >,
+    pub hff_write: ::core::option::Option<
+        unsafe extern "C" fn(
+            dev: *const hal_flash,
+            address: u32,
+            src: *const ::cty::c_void,
+            num_bytes: u32,
+        ) -> ::cty::c_int,
+    >,
+    pub hff_erase_sector: ::core::option::Option<
+        unsafe extern "C" fn(dev: *const hal_flash, sector_address: u32) -> ::cty::c_int,
+    >,
+    pub hff_sector_info: ::core::option::Option<
+        unsafe extern "C" fn(
+            dev: *const hal_flash,
+            idx: ::cty::c_int,
+            address: *mut u32,
+            size: *mut u32,
+        ) -> ::cty::c_int,
+    >,
+    pub hff_init: ::core::option::Option<unsafe extern "C" fn(dev: *const hal_flash) -> ::cty::c_int>,
+    pub hff_erase: ::core::option::Option<
+        unsafe extern "C" fn(dev: *const hal_flash, address: u32, num_bytes: u32) -> ::cty::c_int,
+    >,
+}
+#[mynewt_macros::safe_wrap(attr)] extern "C" {
+    pub fn hal_flash_sector_size(hf: *const hal_flash, sec_idx: ::cty::c_int) -> u32;
+}
 #[doc = " Not connected"]
 pub const hal_gpio_mode_e_HAL_GPIO_MODE_NC: hal_gpio_mode_e = -1;
 #[doc = " Input"]
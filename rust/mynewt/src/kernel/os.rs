@@ -40,6 +40,7 @@ impl<T> ::core::clone::Clone for __IncompleteArrayField<T> {
         Self::new()
     }
 }
+pub const MYNEWT_VAL_OS_CPUTIME_FREQ: u32 = 32768;
 pub const OS_WAIT_FOREVER: i32 = -1;
 pub const OS_IDLE_PRIO: u32 = 255;
 pub const OS_TICKS_PER_SEC: u32 = 1000;
@@ -31,11 +31,15 @@ pub mod util;                     //  Mynewt Utility API. Export folder `encodin
 #[allow(non_upper_case_globals)]  //  Allow globals to have lowercase letters
 pub mod libs;                     //  Mynewt Custom API. Export folder `libs` as Rust module `mynewt::libs`
 
-mod hal;                            //  Import module `hal` for Embedded HAL functions but don't export it
+pub mod hal;                        //  Export module `hal` for Embedded HAL functions and Flash API
 pub use hal::{ Delay, GPIO, SPI, I2C };  //  Export `hal` types GPIO, SPI and I2C
 
 pub mod spi;  //  Export Non-Blocking SPI API
 
+pub mod logo;  //  Export Boot Logo API
+
+pub mod st7789;  //  Export ST7789 Display Controller commands
+
 ///  Initialise the Mynewt system.  Start the Mynewt drivers and libraries.  Equivalent to `sysinit()` macro in C.
 pub fn sysinit() {
     unsafe { rust_sysinit(); }
@@ -100,6 +104,32 @@ pub mod result {
         }
     }
 
+    /// Cast `pinetime_logo::Error` to `MynewtError`
+    impl From<pinetime_logo::Error> for MynewtError {
+        /// Cast `pinetime_logo::Error` to `MynewtError`
+        fn from(err: pinetime_logo::Error) -> Self {
+            match err {
+                pinetime_logo::Error::NotFound    => MynewtError::SYS_ENOENT,
+                pinetime_logo::Error::Unsupported => MynewtError::SYS_ENOTSUP,
+                pinetime_logo::Error::Invalid     => MynewtError::SYS_EINVAL,
+                pinetime_logo::Error::OutOfRange  => MynewtError::SYS_ERANGE,
+                pinetime_logo::Error::AlreadyWritten => MynewtError::SYS_EALREADY,
+                pinetime_logo::Error::NoMemory    => MynewtError::SYS_ENOMEM,
+            }
+        }
+    }
+
+    /// Cast `pinetime_display::Error` to `MynewtError`
+    impl From<pinetime_display::Error> for MynewtError {
+        /// Cast `pinetime_display::Error` to `MynewtError`
+        fn from(err: pinetime_display::Error) -> Self {
+            match err {
+                pinetime_display::Error::Invalid  => MynewtError::SYS_EINVAL,
+                pinetime_display::Error::NoMemory => MynewtError::SYS_ENOMEM,
+            }
+        }
+    }
+
     /// Cast `()` to `MynewtError`
     impl From<()> for MynewtError {
         /// Cast `()` to `MynewtError`
@@ -0,0 +1,126 @@
+//! Boot Logo API for Rust. Decodes the boot logo container stored in SPI Flash and streams
+//! the decoded rows into the ST7789 display window through the Non-Blocking SPI API.
+//! The container is created by the host tool `rust/pinetime-graphic`. The container header, the RLE codec and the
+//! renderer are in `rust/pinetime-logo`, shared with the host tool and tested on the host. So are the animation
+//! container, the logo slots and the logo upload over Bluetooth LE, which access SPI Flash through `hal::Flash`.
+
+pub mod anim;    //  Export `logo/anim.rs` as Rust module `mynewt::logo::anim`
+
+pub mod blit;    //  Export `logo/blit.rs` as Rust module `mynewt::logo::blit`
+
+use crate::{
+    hal::Flash,
+    result::*,
+    spi,
+    st7789::{ self, Command, CommandSink, NonBlockingSpi },
+    sys::console,
+};
+use header::LogoHeader;
+use render::{ Buffers, LogoDisplay };
+
+use embedded_storage::nor_flash::NorFlash;
+
+pub use pinetime_logo::{ crc32, header, render, rle, slots, upload, BYTES_PER_PIXEL, MAX_HEIGHT, MAX_WIDTH };  //  Export the container header, RLE codec, renderer, logo slots and logo upload
+
+pub use pinetime_logo::LogoFlash;  //  Export the flash storage for the logo slots
+
+pub use render::{ logo_origin, Decoder, PixelReader, FALLBACK_COLOR };  //  Export the renderer helpers
+
+/// Flash device for the logo slots: External SPI Flash
+const LOGO_FLASH_DEVICE: u8 = 1;
+
+/// Buffers for reading the logo from flash and decoding one row
+static mut BUFFERS: Buffers = Buffers::new();
+
+/// External SPI Flash for the logo slots, opened on first use
+static mut LOGO_FLASH: Option<Flash> = None;
+
+/// Return the External SPI Flash that contains the logo slots, opening it if necessary
+pub fn logo_flash() -> MynewtResult<&'static mut Flash> {
+    unsafe {
+        if LOGO_FLASH.is_none() { LOGO_FLASH = Some(Flash::new(LOGO_FLASH_DEVICE) ?); }
+        Ok(LOGO_FLASH.as_mut().unwrap())
+    }
+}
+
+/// ST7789 display window, written through the Non-Blocking SPI API
+pub struct SpiDisplay;
+
+impl LogoDisplay for SpiDisplay {
+    type Error = MynewtError;
+
+    /// Set the ST7789 display window to the row `y` from `left` to `right`, and write the pixels
+    fn write_row(&mut self, left: u8, y: u8, right: u8, pixels: &[u8]) -> MynewtResult<()> {
+        let mut display = NonBlockingSpi;
+        st7789::set_window(&mut display, left as u16, y as u16, right as u16, y as u16) ? ;
+        st7789::send(&mut display, Command::MemoryWrite) ? ;
+        display.write_data(pixels)
+    }
+
+    /// Wait until the Non-Blocking SPI queue has been sent
+    fn flush(&mut self) -> MynewtResult<()> {
+        spi::spi_noblock_write_flush()
+    }
+}
+
+/// Logo slots in SPI Flash, accessed through Mynewt Flash HAL
+impl LogoFlash for Flash {
+    type Error = MynewtError;
+
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> MynewtResult<()> {
+        Flash::read(self, offset, buf)
+    }
+
+    fn write(&mut self, offset: u32, data: &[u8]) -> MynewtResult<()> {
+        Flash::write(self, offset, data)
+    }
+
+    fn erase(&mut self, offset: u32, len: u32) -> MynewtResult<()> {
+        NorFlash::erase(self, offset, offset + len)
+    }
+}
+
+/// Display the logo container that begins at `offset` in flash. If the logo is missing or corrupted,
+/// display a solid screen of `FALLBACK_COLOR` instead. Returns the verification error, if any.
+pub fn display_logo_or_fallback<R>(offset: u32, mut read: R) -> MynewtResult<()>
+where R: FnMut(u32, &mut [u8]) -> MynewtResult<()> {
+    match verify_logo(offset, &mut read) {
+        Ok(header) => render_logo(offset, &header, &mut read),
+        Err(err) => {
+            console::print("Bad logo, displaying fallback\n"); console::flush();
+            display_fallback(FALLBACK_COLOR) ? ;
+            Err(err)
+        }
+    }
+}
+
+/// Display the logo container that begins at `offset` in flash. `read` is called to read the bytes from flash
+/// in batches of 256 bytes. Each decoded row is sent to the display window with Non-Blocking SPI.
+/// Nothing is displayed if the logo fails verification.
+pub fn display_logo<R>(offset: u32, mut read: R) -> MynewtResult<()>
+where R: FnMut(u32, &mut [u8]) -> MynewtResult<()> {
+    let header = verify_logo(offset, &mut read) ? ;
+    render_logo(offset, &header, &mut read)
+}
+
+/// Verify the logo container that begins at `offset` in flash and return the header.
+/// Fail with `SYS_ENOENT` if there is no logo, `SYS_ENOTSUP` if the container is unsupported,
+/// `SYS_EINVAL` if the payload length is inconsistent or the CRC32 doesn't match.
+pub fn verify_logo<R>(offset: u32, read: &mut R) -> MynewtResult<LogoHeader>
+where R: FnMut(u32, &mut [u8]) -> MynewtResult<()> {
+    render::verify_logo(offset, read, unsafe { &mut BUFFERS.batch })
+}
+
+/// Fill the entire display with the RGB565 `color`. Shown when the logo is missing or corrupted.
+pub fn display_fallback(color: u16) -> MynewtResult<()> {
+    render::display_fallback(&mut SpiDisplay, color, unsafe { &mut BUFFERS.row })
+}
+
+/// Render the verified logo with the `header` that begins at `offset` in flash
+fn render_logo<R>(offset: u32, header: &LogoHeader, read: &mut R) -> MynewtResult<()>
+where R: FnMut(u32, &mut [u8]) -> MynewtResult<()> {
+    console::print("Displaying logo...\n"); console::flush();
+    render::render_logo(&mut SpiDisplay, offset, header, read, unsafe { &mut BUFFERS }) ? ;
+    console::print("Logo displayed\n"); console::flush();
+    Ok(())
+}
@@ -0,0 +1,25 @@
+//! Animated boot logo. The animation container holds a sequence of frames. The first frame covers the entire
+//! animation, each following frame contains only the dirty rectangle that changed since the previous frame.
+//! The container is parsed, verified and rendered by `pinetime_logo::anim`, which is tested on the host.
+use crate::{
+    result::*,
+    sys::console,
+};
+use super::{ SpiDisplay, BUFFERS };
+
+pub use pinetime_logo::anim::*;  //  Export the animation container
+
+/// Play the animation container that begins at `offset` in flash, centred on the display. `read` is called
+/// to read the bytes from flash. After displaying each frame, `delay` is called with the frame delay in milliseconds.
+/// Nothing is displayed if the animation fails verification.
+pub fn play_animation<R, D>(offset: u32, mut read: R, mut delay: D) -> MynewtResult<()>
+where
+    R: FnMut(u32, &mut [u8]) -> MynewtResult<()>,
+    D: FnMut(u32),
+{
+    let header = verify_animation(offset, &mut read, unsafe { &mut BUFFERS.batch }) ? ;
+    console::print("Playing animation...\n"); console::flush();
+    render_animation(&mut SpiDisplay, offset, &header, &mut read, &mut delay, unsafe { &mut BUFFERS }) ? ;
+    console::print("Animation done\n"); console::flush();
+    Ok(())
+}
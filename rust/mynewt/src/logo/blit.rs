@@ -0,0 +1,15 @@
+//! Partial redraw of the boot logo. Streams a rectangle of the logo stored in a slot into the display window
+//! through the Non-Blocking SPI API, e.g. to restore the logo behind a toast without redrawing the whole screen.
+//! The clipping and streaming are done by `pinetime_logo::render::blit_region`, which is tested on the host.
+use crate::result::*;
+use super::{ render, logo_flash, SpiDisplay, BUFFERS };
+
+/// Copy the rectangle `src_rect` (left, top, right, bottom inclusive, in logo pixels) of the logo in `slot`
+/// to the display at `dst_point` (left, top, in display pixels), clipped to the logo and the display.
+/// The logo is read from the logo slots in External SPI Flash. The header is checked but the CRC32 is not,
+/// so call `slots::slot_status()` once before blitting repeatedly. Fail with `SYS_ENOENT` if the slot contains
+/// no logo (animations can't be blitted), `SYS_EINVAL` if the rectangle is entirely outside the logo or the display.
+pub fn blit_flash_region(slot: usize, src_rect: (u8, u8, u8, u8), dst_point: (u8, u8)) -> MynewtResult<()> {
+    let flash = logo_flash() ? ;
+    render::blit_region(&mut SpiDisplay, flash, slot, src_rect, dst_point, unsafe { &mut BUFFERS })
+}
@@ -5,6 +5,7 @@ use crate::{
     result::*,
     hw::hal,
     kernel::os,
+    st7789,
     NULL, Ptr, Strn,
 };
 use mynewt_macros::{
@@ -260,11 +261,10 @@ extern "C" fn spi_event_callback(_event: *mut os::os_event) {
                     true
                 ).expect("int spi fail");
 
-                //  These commands require a delay. TODO: Move to caller
-                if  unsafe { *data } == 0x01 || //  SWRESET
-                    unsafe { *data } == 0x11 || //  SLPOUT
-                    unsafe { *data } == 0x29 {  //  DISPON
-                    delay_ms(200);
+                //  Some commands require a delay, e.g. SWRESET, SLPOUT and DISPON
+                let delay = st7789::delay_after(unsafe { *data });
+                if delay > 0 {
+                    delay_ms(delay);
                 }
 
                 //  Then write the Data Bytes.
@@ -0,0 +1,101 @@
+//! ST7789 Display Controller for the application. The typed commands, the init sequence and the display settings
+//! are in `rust/pinetime-display`, shared with the init script replayed by the bootloader and tested on the host.
+//! This module starts the display and sends the commands through the Non-Blocking SPI API. It also keeps the
+//! current display settings, which decide how windows and touch coordinates are transformed to match the picture.
+use crate::{
+    hw::hal,
+    result::*,
+    spi,
+};
+
+pub use pinetime_display::st7789::{
+    delay_after, encode_script, init_display, init_sequence, replay_script, send,
+    Command, CommandSink, DisplayConfig, Op, Recorder, Rotation,
+    DISPLAY_HEIGHT, DISPLAY_WIDTH, INIT_SEQUENCE_LEN, MAX_PARAMS, PINETIME_CONFIG, RESET_DELAY_MS,
+    CASET, COLMOD, DISPOFF, DISPON, FRMCTR1, FRMCTR2, FRMCTR3, INVCTR, INVOFF, INVON, MADCTL, NOP, NORON,
+    PWCTR1, PWCTR2, PWCTR3, PWCTR4, PWCTR5, RAMWR, RASET, SLPIN, SLPOUT, SWRESET, VMCTR1,
+};  //  Export the ST7789 commands and display settings
+
+const DISPLAY_RST: i32  = 26;  //  LCD_RESET (P0.26): Display reset
+const DISPLAY_HIGH: i32 = 23;  //  LCD_BACKLIGHT_{LOW,MID,HIGH} (P0.14, 22, 23): Backlight (active low)
+
+/// Current display settings, updated by `configure()`
+static mut CONFIG: DisplayConfig = PINETIME_CONFIG;
+
+/// Start the display: switch on the backlight, reset the display and send the init sequence that the bootloader
+/// replays from `libs/pinetime_boot/src/st7789_init.inc`. Then apply the settings in `config`.
+pub fn start_display(config: DisplayConfig) -> MynewtResult<()> {
+    spi::spi_noblock_init() ? ;
+    let rc = unsafe { hal::hal_gpio_init_out(DISPLAY_RST, 1) };
+    if rc != 0 { return Err(MynewtError::SYS_EINVAL); }
+    //  Switch on backlight
+    let rc = unsafe { hal::hal_gpio_init_out(DISPLAY_HIGH, 0) };
+    if rc != 0 { return Err(MynewtError::SYS_EINVAL); }
+
+    //  Hard reset, same as `hard_reset()` in the bootloader
+    unsafe {
+        hal::hal_gpio_write(DISPLAY_RST, 1);
+        hal::hal_gpio_write(DISPLAY_RST, 0);
+        hal::hal_gpio_write(DISPLAY_RST, 1);
+    }
+    init_display(&mut NonBlockingSpi, config) ? ;
+    configure_display(config)
+}
+
+/// Set the display window to the coordinates (`left`, `top`), (`right`, `bottom`) inclusive.
+/// The window is shifted for the current rotation and mirroring.
+pub fn set_window<S: CommandSink>(sink: &mut S, left: u16, top: u16, right: u16, bottom: u16) -> Result<(), S::Error> {
+    pinetime_display::st7789::set_window(sink, config(), left, top, right, bottom)
+}
+
+/// Apply the rotation, mirroring, colour inversion and colour order in `config` to the display.
+/// Windows and touch coordinates are transformed according to `config` from now on. When the window is offset in
+/// the frame memory (see `DisplayConfig::window_offset()`), `set_window()` and `draw::draw_to_display()` in the
+/// application shift the window by the offset.
+pub fn configure<S: CommandSink>(sink: &mut S, config: DisplayConfig) -> Result<(), S::Error> {
+    pinetime_display::st7789::configure(sink, config) ? ;
+    unsafe { CONFIG = config };
+    Ok(())
+}
+
+/// Apply the settings in `config` to the display through the Non-Blocking SPI API and wait for the commands to be queued
+pub fn configure_display(config: DisplayConfig) -> MynewtResult<()> {
+    configure(&mut NonBlockingSpi, config) ? ;
+    spi::spi_noblock_write_flush()
+}
+
+/// Return the current display settings
+pub fn config() -> DisplayConfig {
+    unsafe { CONFIG }
+}
+
+/// Convert the touch coordinates (`x`, `y`) to the coordinates of the picture with the current display settings
+pub fn transform_touch(x: u16, y: u16) -> (u16, u16) {
+    config().transform_touch(x, y)
+}
+
+/// Convert the coordinates (`x`, `y`) of the picture to the touch coordinates with the current display settings
+pub fn inverse_transform_touch(x: u16, y: u16) -> (u16, u16) {
+    config().inverse_transform_touch(x, y)
+}
+
+/// Sends commands to the display with the Non-Blocking SPI API. The SPI task waits for the required delay
+/// after transmitting each command, because the commands are queued.
+pub struct NonBlockingSpi;
+
+impl CommandSink for NonBlockingSpi {
+    type Error = MynewtError;
+
+    fn write_command(&mut self, opcode: u8) -> MynewtResult<()> {
+        spi::spi_noblock_write_command(opcode)
+    }
+
+    fn write_data(&mut self, data: &[u8]) -> MynewtResult<()> {
+        spi::spi_noblock_write_data(data)
+    }
+
+    /// Delays are applied by the SPI task with `delay_after()`
+    fn delay_ms(&mut self, _ms: u8) -> MynewtResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+#!/usr/bin/env python3
+#  Generate HIRES_PHYSICAL_TO_VIRTUAL_MAP and HIRES_VIRTUAL_TO_PHYSICAL_MAP in src/map.rs from PHYSICAL_TO_VIRTUAL_MAP
+#  and VIRTUAL_TO_PHYSICAL_MAP, for rendering the high resolution (128 x 64) Virtual Screen as curved surface.
+#  Run after changing the low resolution maps:
+#    python3 rust/pinetime-chip8/scripts/gen-hires-maps.py          # Update src/map.rs
+#    python3 rust/pinetime-chip8/scripts/gen-hires-maps.py --check  # Fail if src/map.rs is out of date
+import os, re, sys
+
+#  Size of the high resolution Virtual To Physical Map: one quadrant of the 128 x 64 Virtual Screen
+HIRES_MAP_WIDTH  = 64
+HIRES_MAP_HEIGHT = 32
+
+MAP_PATH = os.path.join(os.path.dirname(os.path.abspath(__file__)), '..', 'src', 'map.rs')
+
+def parse_map(lines, name):
+    """Return the rows of the map `name` in map.rs, each row a list of tuples"""
+    start = [i for i, line in enumerate(lines) if line.startswith('static ' + name + ':')][0] + 1
+    rows = []
+    for line in lines[start:]:
+        if line.startswith(';'): break
+        if not line.startswith('['): continue  #  Skip comments
+        row = [tuple(map(int, t.split(','))) for t in re.findall(r'\(([\d,]+)\)', line)]
+        if row: rows.append(row)
+    return rows
+
+def half(values, index):
+    """Return 0 if `index` is in the first half of the run of equal `values` containing `index`, 1 if in the second half"""
+    first = index
+    while first > 0 and values[first - 1] == values[index]: first -= 1
+    last = index
+    while last < len(values) - 1 and values[last + 1] == values[index]: last += 1
+    return 1 if index >= first + (last - first + 1) // 2 else 0
+
+def hires_physical_to_virtual(p2v):
+    """Split each low resolution Virtual Pixel into 2 x 2 high resolution Virtual Pixels, by splitting each run of
+    Physical Pixels that map to the same low resolution Virtual X (along the row) or Virtual Y (along the column)
+    into two halves. Physical Pixels outside the Virtual Screen (X >= 32 or Y >= 16) are doubled without splitting."""
+    height, width = len(p2v), len(p2v[0])
+    result = []
+    for y in range(height):
+        xs = [p2v[y][x][0] for x in range(width)]
+        row = []
+        for x in range(width):
+            ys = [p2v[yy][x][1] for yy in range(height)]
+            vx, vy = p2v[y][x]
+            row.append((
+                2 * vx + (half(xs, x) if vx < HIRES_MAP_WIDTH  // 2 else 0),
+                2 * vy + (half(ys, y) if vy < HIRES_MAP_HEIGHT // 2 else 0),
+            ))
+        result.append(row)
+    return result
+
+def hires_virtual_to_physical(hp2v, v2p):
+    """Return the Bounding Box of all Physical Pixels that map to each high resolution Virtual Pixel. If no Physical
+    Pixel maps to the Virtual Pixel, return the Bounding Box of the low resolution Virtual Pixel."""
+    points = {}
+    for y, row in enumerate(hp2v):
+        for x, virtual in enumerate(row):
+            points.setdefault(virtual, []).append((x, y))
+    result = []
+    for vy in range(HIRES_MAP_HEIGHT):
+        row = []
+        for vx in range(HIRES_MAP_WIDTH):
+            pts = points.get((vx, vy))
+            if pts:
+                row.append((min(p[0] for p in pts), min(p[1] for p in pts), max(p[0] for p in pts), max(p[1] for p in pts)))
+            else:
+                row.append(v2p[vy // 2][vx // 2])
+        result.append(row)
+    return result
+
+def format_map(rows):
+    """Format the map rows like the maps in map.rs"""
+    return '[' + '\n'.join('[' + ''.join('(' + ','.join(map(str, t)) + '),' for t in row) + '],' for row in rows) + '\n]'
+
+def replace_map(source, name, rows):
+    """Replace the rows of the map `name` in `source`"""
+    pattern = re.compile(r'(static ' + name + r':[^\n]*\n)\[.*?\n\]\n;', re.S)
+    assert pattern.search(source), name + ' not found'
+    return pattern.sub(lambda m: m.group(1) + format_map(rows) + '\n;', source, count=1)
+
+def main():
+    source = open(MAP_PATH).read()
+    lines = source.split('\n')
+    p2v = parse_map(lines, 'PHYSICAL_TO_VIRTUAL_MAP')
+    v2p = parse_map(lines, 'VIRTUAL_TO_PHYSICAL_MAP')
+    hp2v = hires_physical_to_virtual(p2v)
+    hv2p = hires_virtual_to_physical(hp2v, v2p)
+    updated = replace_map(source, 'HIRES_PHYSICAL_TO_VIRTUAL_MAP', hp2v)
+    updated = replace_map(updated, 'HIRES_VIRTUAL_TO_PHYSICAL_MAP', hv2p)
+    if '--check' in sys.argv[1:]:
+        if updated != source:
+            sys.exit('src/map.rs is out of date, run scripts/gen-hires-maps.py')
+        print('src/map.rs is up to date')
+    elif updated != source:
+        open(MAP_PATH, 'w').write(updated)
+        print('updated src/map.rs')
+
+if __name__ == '__main__':
+    main()
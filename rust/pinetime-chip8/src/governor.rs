@@ -0,0 +1,157 @@
+//! Frame-rate Governor for the CHIP8 Emulator. Paces the emulator to the configured speed in instructions per second,
+//! decides when the dirty region should be pushed to the display at a fixed refresh rate, and counts the instructions,
+//! frames and SPI bytes for the statistics. All times are in nanoseconds from `Platform::clock_ns()`.
+
+/// Default refresh rate of the Physical Screen, in frames per second
+pub const DEFAULT_REFRESH_RATE: u8 = 30;
+
+/// Interval between statistics, in nanoseconds
+const STATS_INTERVAL_NS: u64 = 1_000_000_000;
+
+/// If the emulator falls behind by more than this, in nanoseconds, it restarts pacing instead of catching up,
+/// e.g. after a slow render or after being paused
+const MAX_LAG_NS: u64 = 100_000_000;
+
+/// Min time to sleep when the emulator is ahead, in milliseconds. Sleeping less often lets the emulator run
+/// a few instructions in a burst, with fewer task switches.
+const MIN_SLEEP_MS: u64 = 4;
+
+/// Nanoseconds per second
+const NS_PER_SEC: u64 = 1_000_000_000;
+
+/// Nanoseconds per millisecond
+const NS_PER_MS: u64 = 1_000_000;
+
+/// Emulator statistics for an interval
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// Length of the interval, in milliseconds
+    pub interval_ms:  u32,
+    /// Number of instructions executed
+    pub instructions: u32,
+    /// Number of frames pushed to the display
+    pub frames:       u32,
+    /// Number of bytes sent over SPI to the display
+    pub spi_bytes:    u32,
+}
+
+impl Stats {
+    /// Return the instructions executed per second
+    pub fn ips(&self) -> u32 {
+        per_second(self.instructions, self.interval_ms)
+    }
+
+    /// Return the frames pushed per second
+    pub fn fps(&self) -> u32 {
+        per_second(self.frames, self.interval_ms)
+    }
+
+    /// Return the average SPI bytes per frame
+    pub fn bytes_per_frame(&self) -> u32 {
+        self.spi_bytes.checked_div(self.frames).unwrap_or(0)
+    }
+}
+
+/// Paces the emulator and the display refresh
+pub struct Governor {
+    /// Emulation speed in instructions per second
+    speed: u16,
+    /// Interval between frames, in nanoseconds
+    frame_ns: u64,
+    /// Time when pacing started
+    pace_start: u64,
+    /// Number of instructions executed since pacing started
+    pace_steps: u64,
+    /// Time when the next frame is due
+    next_frame: u64,
+    /// Time when the current statistics interval started
+    stats_start: u64,
+    /// Statistics for the current interval
+    stats: Stats,
+}
+
+impl Governor {
+    /// Return a governor that runs `speed` instructions per second and refreshes at `refresh_rate` frames per second,
+    /// starting at time `now`
+    pub fn new(speed: u16, refresh_rate: u8, now: u64) -> Governor {
+        Governor {
+            speed: speed.max(1),
+            frame_ns: NS_PER_SEC / refresh_rate.max(1) as u64,
+            pace_start: now,
+            pace_steps: 0,
+            next_frame: now,
+            stats_start: now,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Change the emulation speed to `speed` instructions per second
+    pub fn set_speed(&mut self, speed: u16, now: u64) {
+        self.speed = speed.max(1);
+        self.restart(now);
+    }
+
+    /// Change the refresh rate to `refresh_rate` frames per second
+    pub fn set_refresh_rate(&mut self, refresh_rate: u8, now: u64) {
+        self.frame_ns = NS_PER_SEC / refresh_rate.max(1) as u64;
+        self.next_frame = now;
+    }
+
+    /// Restart pacing at time `now`, e.g. after resuming the emulator
+    pub fn restart(&mut self, now: u64) {
+        self.pace_start = now;
+        self.pace_steps = 0;
+        self.next_frame = now;
+    }
+
+    /// Count an instruction executed at time `now`. Return the number of milliseconds to sleep if the emulator
+    /// is running ahead of its speed.
+    pub fn step(&mut self, now: u64) -> Option<u32> {
+        self.pace_steps += 1;
+        self.stats.instructions += 1;
+        let elapsed = now.wrapping_sub(self.pace_start);
+        let scheduled = self.pace_steps * NS_PER_SEC / self.speed as u64;
+        if elapsed > scheduled + MAX_LAG_NS {
+            //  Too far behind, don't run a burst of instructions to catch up
+            self.pace_start = now;
+            self.pace_steps = 0;
+            return None;
+        }
+        //  Sleep only in whole milliseconds, the remainder is slept later
+        let ahead_ms = scheduled.saturating_sub(elapsed) / NS_PER_MS;
+        if ahead_ms < MIN_SLEEP_MS { return None; }
+        Some(ahead_ms as u32)
+    }
+
+    /// Return true if a frame is due at time `now`. Frames are due at a fixed rate: if a frame was missed,
+    /// the next frame is scheduled from `now`.
+    pub fn frame_due(&mut self, now: u64) -> bool {
+        if now < self.next_frame { return false; }
+        self.next_frame += self.frame_ns;
+        if self.next_frame <= now { self.next_frame = now + self.frame_ns; }
+        true
+    }
+
+    /// Count a frame that sent `spi_bytes` bytes to the display
+    pub fn count_frame(&mut self, spi_bytes: usize) {
+        self.stats.frames += 1;
+        self.stats.spi_bytes += spi_bytes as u32;
+    }
+
+    /// Return the statistics if the statistics interval has ended at time `now`, and start a new interval
+    pub fn take_stats(&mut self, now: u64) -> Option<Stats> {
+        let elapsed = now.wrapping_sub(self.stats_start);
+        if elapsed < STATS_INTERVAL_NS { return None; }
+        let mut stats = self.stats;
+        stats.interval_ms = (elapsed / NS_PER_MS) as u32;
+        self.stats = Stats::default();
+        self.stats_start = now;
+        Some(stats)
+    }
+}
+
+/// Return `count` per second, for `count` events in `interval_ms` milliseconds
+fn per_second(count: u32, interval_ms: u32) -> u32 {
+    if interval_ms == 0 { 0 }
+    else { (count as u64 * 1000 / interval_ms as u64) as u32 }
+}
@@ -0,0 +1,153 @@
+//! CHIP8 Hardware API for PineTime. Stores the CHIP8 display in the Virtual Screen Buffer, refreshes the changed
+//! region through the `Platform` at a fixed refresh rate, and runs the ROM at its recommended speed.
+use crate::{
+    governor::{ Governor, DEFAULT_REFRESH_RATE },
+    platform::Platform,
+    region::DirtyRegion,
+    render::{ self, render_region },
+};
+
+/// Hardware API for rendering CHIP8 Emulator
+pub struct Hardware<P: Platform> {
+    /// Display, time, input and randomness
+    platform: P,
+    /// Boundaries of the Virtual Screen region to be refreshed
+    update_region: DirtyRegion,
+    /// True if emulator has started accepting input, i.e. emulator has drawn loading screen
+    is_interactive: bool,
+    /// Paces the emulator and the display refresh
+    governor: Governor,
+}
+
+impl<P: Platform> Hardware<P> {
+    /// Return a new Hardware API for rendering CHIP8 Emulator on the `platform`, running at `speed` instructions per second
+    /// and refreshing the display at `DEFAULT_REFRESH_RATE` frames per second
+    pub fn new(mut platform: P, speed: u16) -> Hardware<P> {
+        let now = platform.clock_ns();
+        Hardware {
+            platform,
+            update_region: DirtyRegion::new(),
+            is_interactive: false,
+            governor: Governor::new(speed, DEFAULT_REFRESH_RATE, now),
+        }
+    }
+
+    /// Change the emulation speed to `speed` instructions per second
+    pub fn set_speed(&mut self, speed: u16) {
+        let now = self.platform.clock_ns();
+        self.governor.set_speed(speed, now);
+    }
+
+    /// Change the display refresh rate to `refresh_rate` frames per second
+    pub fn set_refresh_rate(&mut self, refresh_rate: u8) {
+        let now = self.platform.clock_ns();
+        self.governor.set_refresh_rate(refresh_rate, now);
+    }
+
+    /// Return the platform, e.g. for rendering the Virtual Screen after restoring a save state
+    pub fn platform(&mut self) -> &mut P {
+        &mut self.platform
+    }
+}
+
+impl<P: Platform> libchip8::Hardware for Hardware<P> {
+    /// Return a random value.
+    fn rand(&mut self) -> u8 {
+        self.platform.rand()
+    }
+
+    /// Check if the key is pressed.
+    fn key(&mut self, key: u8) -> bool {
+        //  key is 0-9 for keys "0" to "9", 0xa-0xf to keys "A" to "F"
+        self.is_interactive = true;
+        //  Check whether the key is held, or was tapped since the last check
+        self.platform.key(key)
+    }
+
+    /// Set the state of a pixel in the screen. true for white, and false for black.
+    fn vram_set(&mut self, x: usize, y: usize, d: bool) {
+        let (width, height) = render::screen_size();
+        assert!(x < width, "x overflow");
+        assert!(y < height, "y overflow");
+        render::set_pixel(x, y,
+            if d {
+                if self.is_interactive { 255 }  //  Brighter colour when emulator is active
+                else { 200 }                    //  Darker colour for initial screen
+            } 
+            else { 
+                if self.is_interactive { 127 }  //  Fade to black
+                else { 0 }                      //  Black for initial screen                 
+            }  
+        );
+
+        //  Remember the boundaries of the screen region to be updated
+        self.update_region.add_point(x as u8, y as u8);
+    }
+
+    /// Get the current state of a pixel in the screen.
+    fn vram_get(&mut self, x: usize, y: usize) -> bool {
+        let (width, height) = render::screen_size();
+        assert!(x < width, "x overflow");
+        assert!(y < height, "y overflow");
+        render::get_pixel(x, y) >= 128
+    }
+
+    /// Set the size of the screen: 64 x 32 in low resolution mode, 128 x 64 in SUPER-CHIP high resolution mode.
+    fn vram_setsize(&mut self, size: (usize, usize)) {
+        //  If the size has changed, the Virtual Screen is cleared, so refresh the entire screen
+        if render::set_screen_size(size.0, size.1) {
+            self.update_region.clear();
+            self.update_region.add_rect(0, 0, size.0 as u8 - 1, size.1 as u8 - 1);
+        }
+    }
+
+    /// Get the size of the screen.
+    fn vram_size(&mut self) -> (usize, usize) {
+        render::screen_size()
+    }
+
+    /// Return the current clock value in nanoseconds.
+    fn clock(&mut self) -> u64 {
+        self.platform.clock_ns()
+    }
+
+    /// Play beep sound.
+    fn beep(&mut self) {
+        self.platform.beep()
+    }
+
+    /// Called in every step; return true for shutdown.
+    fn sched(&mut self) -> bool {
+        //  If the ROM was stopped, shut down the emulator
+        if self.platform.exit_requested() { return true; }
+
+        //  Run the ROM at its recommended speed. Sleeping allows other tasks to run, e.g. SPI background task
+        let now = self.platform.clock_ns();
+        if let Some(ms) = self.governor.step(now) {
+            self.platform.sleep_ms(ms);
+        }
+
+        //  Refresh the screen only at the refresh rate
+        if !self.governor.frame_due(now) { return false; }
+
+        //  Tickle the watchdog so that the Watchdog Timer doesn't expire. Mynewt assumes the process is hung if we don't tickle the watchdog.
+        self.platform.tickle_watchdog();
+
+        //  Render the updated region and reset the screen region to be updated.
+        //  If emulator is preparing the initial screen, refresh the screen later.
+        if self.is_interactive {
+            if let Some((left, top, right, bottom)) = self.update_region.take() {
+                let spi_bytes = render_region(&mut self.platform, left, top, right, bottom);
+                self.governor.count_frame(spi_bytes);
+            }
+        }
+
+        //  Show the statistics every second
+        if let Some(stats) = self.governor.take_stats(now) {
+            self.platform.show_stats(&stats);
+        }
+
+        //  Return false to indicate no shutdown
+        false
+    }
+}
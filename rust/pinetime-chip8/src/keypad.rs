@@ -0,0 +1,329 @@
+//! CHIP-8 Touch Keypad Layouts. A layout maps rectangular regions of the 240x240 touch panel to the 16 CHIP-8 keys.
+//! Regions below the emulator screen (rows `OVERLAY_TOP` and below) are drawn as buttons over the unused rows.
+//! `KeyState` tracks the keys held by each finger as a 16-bit bitmap. Hit-testing and key state depend only on `core`,
+//! so they may be checked on the host.
+
+/// Width and height of the touch panel, in Physical Pixels
+pub const PANEL_SIZE: u16 = 240;
+
+/// Top of the overlay area below the emulator screen, in Physical Pixels
+pub const OVERLAY_TOP: u16 = crate::PHYSICAL_HEIGHT as u16;
+
+/// A region of the touch panel that presses a CHIP-8 key
+#[derive(Clone, Copy)]
+pub struct KeyRegion {
+    /// Left column, inclusive, in Physical Pixels
+    pub left:   u8,
+    /// Top row, inclusive, in Physical Pixels
+    pub top:    u8,
+    /// Right column, inclusive, in Physical Pixels
+    pub right:  u8,
+    /// Bottom row, inclusive, in Physical Pixels
+    pub bottom: u8,
+    /// CHIP-8 key: 0-9 for keys "0" to "9", 0xa-0xf for keys "A" to "F"
+    pub key:    u8,
+    /// Label drawn on the button, if the region is in the overlay area
+    pub label:  &'static str,
+}
+
+impl KeyRegion {
+    /// Return true if the region contains the point (`x`, `y`)
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.left as u16 && x <= self.right as u16 &&
+        y >= self.top  as u16 && y <= self.bottom as u16
+    }
+
+    /// Return true if the region is drawn as a button in the overlay area
+    pub fn is_overlay(&self) -> bool {
+        self.top as u16 >= OVERLAY_TOP
+    }
+}
+
+/// Touch keypad layout for a ROM
+pub struct KeypadLayout {
+    /// Name of the layout
+    pub name:    &'static str,
+    /// Regions of the touch panel. If the regions overlap, the first region wins.
+    pub regions: &'static [KeyRegion],
+}
+
+impl KeypadLayout {
+    /// Return the CHIP-8 key for the touch at (`x`, `y`), or None if the touch is outside all regions
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<u8> {
+        if x >= PANEL_SIZE || y >= PANEL_SIZE { return None; }
+        self.regions.iter()
+            .find(|region| region.contains(x, y))
+            .map(|region| region.key)
+    }
+
+    /// Return the regions that are drawn as buttons in the overlay area
+    pub fn overlay_regions(&self) -> impl Iterator<Item = &KeyRegion> {
+        self.regions.iter().filter(|region| region.is_overlay())
+    }
+}
+
+/// Create a key region
+const fn region(left: u8, top: u8, right: u8, bottom: u8, key: u8, label: &'static str) -> KeyRegion {
+    KeyRegion { left, top, right, bottom, key, label }
+}
+
+/// No keys, for ROMs that don't read input
+pub static NO_KEYS: KeypadLayout = KeypadLayout {
+    name:    "None",
+    regions: &[],
+};
+
+/// Left, Centre and Right thirds of the emulator screen press keys 4, 5, 6. For Space Invaders.
+pub static THIRDS: KeypadLayout = KeypadLayout {
+    name:    "Thirds",
+    regions: &[
+        region(  0, 0,  79, 199, 0x4, "<"),  //  Left
+        region( 80, 0, 159, 199, 0x5, "*"),  //  Fire
+        region(160, 0, 239, 199, 0x6, ">"),  //  Right
+    ],
+};
+
+/// Two paddles: left half of the emulator screen for player 1 (keys 1, 4), right half for player 2 (keys C, D).
+/// Touch the upper half to move up, lower half to move down. For Pong.
+pub static PADDLES: KeypadLayout = KeypadLayout {
+    name:    "Paddles",
+    regions: &[
+        region(  0,   0, 119,  99, 0x1, "^"),  //  Player 1 Up
+        region(  0, 100, 119, 199, 0x4, "v"),  //  Player 1 Down
+        region(120,   0, 239,  99, 0xc, "^"),  //  Player 2 Up
+        region(120, 100, 239, 199, 0xd, "v"),  //  Player 2 Down
+    ],
+};
+
+/// Four buttons in the overlay: Left (5), Rotate (4), Right (6), Drop (7). For Tetris.
+pub static FALLING_BLOCKS: KeypadLayout = KeypadLayout {
+    name:    "Falling Blocks",
+    regions: &[
+        region(  0, 200,  59, 239, 0x5, "<"),  //  Left
+        region( 60, 200, 119, 239, 0x4, "@"),  //  Rotate
+        region(120, 200, 179, 239, 0x6, ">"),  //  Right
+        region(180, 200, 239, 239, 0x7, "v"),  //  Drop
+    ],
+};
+
+/// Direction pad in the overlay: Left (7), Up (3), Down (6), Right (8). For Blinky.
+pub static DIRECTION_PAD: KeypadLayout = KeypadLayout {
+    name:    "Direction Pad",
+    regions: &[
+        region(  0, 200,  59, 239, 0x7, "<"),  //  Left
+        region( 60, 200, 119, 239, 0x3, "^"),  //  Up
+        region(120, 200, 179, 239, 0x6, "v"),  //  Down
+        region(180, 200, 239, 239, 0x8, ">"),  //  Right
+    ],
+};
+
+/// Layouts that may be selected by ROMs in SPI Flash, indexed by the layout number in the ROM header
+pub static LAYOUTS: &[&KeypadLayout] = &[
+    &NO_KEYS,
+    &THIRDS,
+    &PADDLES,
+    &FALLING_BLOCKS,
+    &DIRECTION_PAD,
+];
+
+/// Max number of fingers tracked by the touch controller. Finger IDs are 0 to 14.
+pub const MAX_FINGERS: usize = 16;
+
+/// State of the 16 CHIP-8 keys, driven by touch down, contact and up events from each finger
+pub struct KeyState {
+    /// Key held by each finger, None if the finger is lifted or outside all regions
+    fingers: [Option<u8>; MAX_FINGERS],
+    /// Bit `n` is set if key `n` is held by any finger
+    held:    u16,
+    /// Bit `n` is set if key `n` was pressed and has not been read, so that short taps are not missed
+    latched: u16,
+}
+
+impl Default for KeyState {
+    fn default() -> Self {
+        KeyState::new()
+    }
+}
+
+impl KeyState {
+    /// Return the key state with all keys released
+    pub const fn new() -> KeyState {
+        KeyState {
+            fingers: [None; MAX_FINGERS],
+            held:    0,
+            latched: 0,
+        }
+    }
+
+    /// Handle a touch down or contact by `finger` on the `key`, or outside all regions if None.
+    /// A finger that slides into another region releases its previous key.
+    pub fn press(&mut self, finger: u8, key: Option<u8>) {
+        let finger = finger as usize % MAX_FINGERS;
+        self.fingers[finger] = key.map(|key| key & 0x0f);
+        self.update();
+        if let Some(key) = self.fingers[finger] { self.latched |= 1 << key; }
+    }
+
+    /// Handle a touch up by `finger`
+    pub fn release(&mut self, finger: u8) {
+        self.fingers[finger as usize % MAX_FINGERS] = None;
+        self.update();
+    }
+
+    /// Release all keys
+    pub fn clear(&mut self) {
+        *self = KeyState::new();
+    }
+
+    /// Return true if the `key` is held, or was tapped since the last check. Clears the tap.
+    pub fn is_pressed(&mut self, key: u8) -> bool {
+        let bit = 1 << (key & 0x0f);
+        let pressed = (self.held | self.latched) & bit != 0;
+        self.latched &= !bit;
+        pressed
+    }
+
+    /// Return the bitmap of the keys held: bit `n` is set if key `n` is held
+    pub fn held(&self) -> u16 {
+        self.held
+    }
+
+    /// Recompute the bitmap of held keys from the fingers
+    fn update(&mut self) {
+        self.held = self.fingers.iter()
+            .filter_map(|key| *key)
+            .fold(0, |held, key| held | (1 << key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two regions that overlap at columns 50 to 59
+    static OVERLAP: KeypadLayout = KeypadLayout {
+        name:    "Overlap",
+        regions: &[
+            region( 0, 0, 59, 9, 0x1, "1"),
+            region(50, 0, 99, 9, 0x2, "2"),
+        ],
+    };
+
+    #[test]
+    fn hit_test_region_edges() {
+        //  Edges of a region are inclusive
+        assert_eq!(THIRDS.hit_test(0, 0), Some(0x4));
+        assert_eq!(THIRDS.hit_test(79, 199), Some(0x4));
+        assert_eq!(THIRDS.hit_test(80, 0), Some(0x5));
+        assert_eq!(THIRDS.hit_test(159, 199), Some(0x5));
+        assert_eq!(THIRDS.hit_test(160, 100), Some(0x6));
+        assert_eq!(THIRDS.hit_test(239, 199), Some(0x6));
+        //  Below the bottom edge
+        assert_eq!(THIRDS.hit_test(100, 200), None);
+        //  Overlay buttons at the bottom right corner of the panel
+        assert_eq!(FALLING_BLOCKS.hit_test(239, 239), Some(0x7));
+        assert_eq!(FALLING_BLOCKS.hit_test(179, 200), Some(0x6));
+        assert_eq!(FALLING_BLOCKS.hit_test(180, 200), Some(0x7));
+        assert_eq!(FALLING_BLOCKS.hit_test(100, 199), None);
+    }
+
+    #[test]
+    fn hit_test_overlap_order() {
+        //  The first region wins where the regions overlap
+        assert_eq!(OVERLAP.hit_test(49, 5), Some(0x1));
+        assert_eq!(OVERLAP.hit_test(50, 5), Some(0x1));
+        assert_eq!(OVERLAP.hit_test(59, 5), Some(0x1));
+        assert_eq!(OVERLAP.hit_test(60, 5), Some(0x2));
+        assert_eq!(OVERLAP.hit_test(99, 9), Some(0x2));
+        assert_eq!(OVERLAP.hit_test(100, 9), None);
+        assert_eq!(OVERLAP.hit_test(50, 10), None);
+    }
+
+    #[test]
+    fn hit_test_out_of_panel() {
+        assert_eq!(THIRDS.hit_test(PANEL_SIZE, 0), None);
+        assert_eq!(FALLING_BLOCKS.hit_test(0, PANEL_SIZE), None);
+        assert_eq!(DIRECTION_PAD.hit_test(u16::MAX, u16::MAX), None);
+        for layout in LAYOUTS {
+            assert_eq!(layout.hit_test(PANEL_SIZE, PANEL_SIZE), None, "{}", layout.name);
+        }
+        assert_eq!(NO_KEYS.hit_test(0, 0), None);
+    }
+
+    #[test]
+    fn overlay_regions() {
+        assert_eq!(THIRDS.overlay_regions().count(), 0);
+        assert_eq!(DIRECTION_PAD.overlay_regions().count(), 4);
+        for layout in LAYOUTS {
+            for region in layout.regions {
+                assert!(region.left <= region.right && region.top <= region.bottom, "{}", layout.name);
+                assert!(region.key <= 0xf, "{}", layout.name);
+            }
+        }
+    }
+
+    #[test]
+    fn key_state_multiple_fingers() {
+        let mut keys = KeyState::new();
+        keys.press(0, Some(0x4));
+        keys.press(1, Some(0x6));
+        assert_eq!(keys.held(), (1 << 0x4) | (1 << 0x6));
+        //  Releasing one finger keeps the key held by the other finger
+        keys.release(0);
+        assert_eq!(keys.held(), 1 << 0x6);
+        //  Two fingers on the same key: the key is held until both are released
+        keys.press(2, Some(0x6));
+        keys.release(1);
+        assert_eq!(keys.held(), 1 << 0x6);
+        keys.release(2);
+        assert_eq!(keys.held(), 0);
+        //  Releasing a lifted finger does nothing
+        keys.release(3);
+        assert_eq!(keys.held(), 0);
+    }
+
+    #[test]
+    fn key_state_slide_between_regions() {
+        let mut keys = KeyState::new();
+        keys.press(0, Some(0x1));
+        assert!(keys.is_pressed(0x1));
+        //  Sliding into another region releases the previous key
+        keys.press(0, Some(0x4));
+        assert_eq!(keys.held(), 1 << 0x4);
+        assert!(!keys.is_pressed(0x1));
+        //  Sliding outside all regions releases the key
+        keys.press(0, None);
+        assert_eq!(keys.held(), 0);
+    }
+
+    #[test]
+    fn key_state_latches_taps() {
+        let mut keys = KeyState::new();
+        //  A tap that's released before the key is read is reported once
+        keys.press(5, Some(0xa));
+        keys.release(5);
+        assert_eq!(keys.held(), 0);
+        assert!(keys.is_pressed(0xa));
+        assert!(!keys.is_pressed(0xa));
+        //  A held key is reported until released
+        keys.press(5, Some(0xa));
+        assert!(keys.is_pressed(0xa));
+        assert!(keys.is_pressed(0xa));
+        keys.release(5);
+        assert!(!keys.is_pressed(0xa));
+    }
+
+    #[test]
+    fn key_state_finger_and_key_range() {
+        let mut keys = KeyState::new();
+        //  Finger IDs wrap around `MAX_FINGERS` and keys are masked to 4 bits
+        keys.press(MAX_FINGERS as u8 + 1, Some(0x1f));
+        assert_eq!(keys.held(), 1 << 0xf);
+        keys.release(1);
+        assert_eq!(keys.held(), 0);
+        keys.press(0, Some(0x2));
+        keys.clear();
+        assert_eq!(keys.held(), 0);
+        assert!(!keys.is_pressed(0x2));
+    }
+}
@@ -0,0 +1,56 @@
+//! CHIP8 Emulator renderer and input for PineTime, without Mynewt calls. The device services needed by the
+//! emulator (display, time, input and randomness) are provided through the `Platform` trait, implemented by
+//! the PineTime app in `rust/app/src/chip8.rs` and by the host frontend in `rust/chip8-host`.
+
+#![no_std]  //  Don't link with standard Rust library, so that the renderer runs on PineTime
+
+pub mod platform;  //  Export `platform.rs` as Rust module `pinetime_chip8::platform`
+
+pub mod hardware;  //  Export `hardware.rs` as Rust module `pinetime_chip8::hardware`
+
+pub mod render;    //  Export `render.rs` as Rust module `pinetime_chip8::render`
+
+pub mod map;       //  Export `map.rs` as Rust module `pinetime_chip8::map`
+
+pub mod keypad;    //  Export `keypad.rs` as Rust module `pinetime_chip8::keypad`
+
+pub mod region;    //  Export `region.rs` as Rust module `pinetime_chip8::region`
+
+pub mod snapshot;  //  Export `snapshot.rs` as Rust module `pinetime_chip8::snapshot`
+
+pub mod palette;   //  Export `palette.rs` as Rust module `pinetime_chip8::palette`
+
+pub mod governor;  //  Export `governor.rs` as Rust module `pinetime_chip8::governor`
+
+pub use hardware::Hardware;    //  Export the CHIP8 Hardware API
+pub use platform::Platform;    //  Export the Platform trait
+pub use render::PixelIterator; //  Export the Pixel Iterator
+
+/// CHIP8 Physical Screen size, in Physical Pixels
+pub const PHYSICAL_WIDTH: usize = 240;
+pub const PHYSICAL_HEIGHT: usize = 200;
+
+/// CHIP8 Virtual Screen size in low resolution mode, in Virtual Pixels
+pub const SCREEN_WIDTH: usize = 64;
+pub const SCREEN_HEIGHT: usize = 32;
+
+/// SUPER-CHIP Virtual Screen size in high resolution mode, in Virtual Pixels
+pub const HIRES_SCREEN_WIDTH: usize = 128;
+pub const HIRES_SCREEN_HEIGHT: usize = 64;
+
+/// Size of the SPI buffer (PendingDataSize in SPI), in bytes. Each Physical Pixel takes 2 bytes.
+pub const PENDING_DATA_SIZE: usize = 8192;
+
+/// CHIP8 Virtual Block size in low resolution mode. We render the CHIP8 Virtual Screen in blocks of Virtual Pixels, without overflowing the SPI buffer.
+/// (BLOCK_WIDTH * PIXEL_WIDTH * BLOCK_HEIGHT * PIXEL_HEIGHT) * 2 must be less than PENDING_DATA_SIZE.
+/// In high resolution mode the blocks are scaled to cover the same Physical Pixels.
+pub const BLOCK_HEIGHT: usize = 5;  //  Letter height
+#[cfg(not(feature = "curve"))]      //  If we are not rendering CHIP8 Emulator as curved surface...
+pub const BLOCK_WIDTH: usize = 32;  //  Use normal width
+#[cfg(feature = "curve")]           //  If we are rendering CHIP8 Emulator as curved surface...
+pub const BLOCK_WIDTH: usize = 5;   //  Use shorter width because curved regions have more pixels
+
+/// CHIP8 Virtual Pixel size in low resolution mode, in Physical Pixels. In high resolution mode the Virtual Screen
+/// covers the same Physical Pixels, so each Virtual Pixel is 1.5 x 2.5 Physical Pixels.
+pub const PIXEL_WIDTH: usize = 3;
+pub const PIXEL_HEIGHT: usize = 5;
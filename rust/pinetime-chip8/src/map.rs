@@ -0,0 +1,553 @@
+//! Map Virtual Pixels to Physical Pixels. The flat mapping scales the Virtual Screen to `SCREEN_WIDTH * PIXEL_WIDTH` by
+//! `SCREEN_HEIGHT * PIXEL_HEIGHT` Physical Pixels: each Virtual Pixel is `PIXEL_WIDTH` x `PIXEL_HEIGHT` Physical Pixels in
+//! low resolution, and 1.5 x 2.5 Physical Pixels in high resolution. The curved mapping (feature `curve`) renders the
+//! Virtual Screen as a curved surface with lookup tables for one quadrant, flipped for the other quadrants.
+use crate::{
+    render::screen_size,
+    PHYSICAL_HEIGHT, PHYSICAL_WIDTH,
+};
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+use crate::{
+    PIXEL_HEIGHT, PIXEL_WIDTH, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+use crate::{
+    HIRES_SCREEN_HEIGHT, HIRES_SCREEN_WIDTH, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+
+/// Width of the Physical Screen covered by the flat Virtual Screen, in Physical Pixels
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+const VIEW_WIDTH: usize = SCREEN_WIDTH * PIXEL_WIDTH;
+
+/// Height of the Physical Screen covered by the flat Virtual Screen, in Physical Pixels
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+const VIEW_HEIGHT: usize = SCREEN_HEIGHT * PIXEL_HEIGHT;
+
+/// Return Bounding Box of Physical Pixels (left, top, right, bottom) that correspond to the Virtual Pixels
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+pub fn get_bounding_box(virtual_left: u8, virtual_top: u8, virtual_right: u8, virtual_bottom: u8) -> (u8, u8, u8, u8) {
+    let (screen_width, screen_height) = screen_size();
+    let left:   u8 = scale_to_physical(virtual_left, screen_width, VIEW_WIDTH).0;
+    let top:    u8 = scale_to_physical(virtual_top, screen_height, VIEW_HEIGHT).0;
+    let right:  u8 = scale_to_physical(virtual_right, screen_width, VIEW_WIDTH).1;
+    let bottom: u8 = scale_to_physical(virtual_bottom, screen_height, VIEW_HEIGHT).1;
+    assert!(left < PHYSICAL_WIDTH as u8 && top < PHYSICAL_HEIGHT as u8 && right < PHYSICAL_WIDTH as u8 && bottom < PHYSICAL_HEIGHT as u8, "overflow");
+    ( left, top, right, bottom )
+}
+
+/// For Physical (x,y) Coordinates, return the corresponding Virtual (x,y) Coordinates.
+/// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+pub fn map_physical_to_virtual(x: u8, y: u8) -> (u8, u8) {
+    let (screen_width, screen_height) = screen_size();
+    assert!((x as usize) < VIEW_WIDTH && (y as usize) < VIEW_HEIGHT, "overflow");
+    (
+        scale_to_virtual(x, screen_width, VIEW_WIDTH),
+        scale_to_virtual(y, screen_height, VIEW_HEIGHT),
+    )
+}
+
+/// Return the first and last Physical Pixels covered by the Virtual Pixel at `pos`, when `virtual_size` Virtual Pixels
+/// are scaled to `physical_size` Physical Pixels. Virtual Pixel `pos` starts at Physical Pixel `pos * physical_size / virtual_size`.
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+fn scale_to_physical(pos: u8, virtual_size: usize, physical_size: usize) -> (u8, u8) {
+    let first = pos as usize * physical_size / virtual_size;
+    let last  = (pos as usize + 1) * physical_size / virtual_size - 1;
+    ( first as u8, last as u8 )
+}
+
+/// Return the Virtual Pixel that covers the Physical Pixel at `pos`. Inverse of `scale_to_physical()`.
+#[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+fn scale_to_virtual(pos: u8, virtual_size: usize, physical_size: usize) -> u8 {
+    //  Last Virtual Pixel that starts at or before `pos`
+    (((pos as usize + 1) * virtual_size + physical_size - 1) / physical_size - 1) as u8
+}
+
+/// Return Bounding Box of Physical Pixels (left, top, right, bottom) that correspond to the Virtual Pixels
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+pub fn get_bounding_box(virtual_left: u8, virtual_top: u8, virtual_right: u8, virtual_bottom: u8) -> (u8, u8, u8, u8) {
+    //  One Virtual Pixel may map to multiple Physical Pixels, so we lookup the Physical Bounding Box.
+    //  The edges of the Virtual Region are curved, so we enclose the Physical Bounding Boxes of all Virtual Pixels on the edges.
+    let mut physical_box = map_virtual_to_physical(virtual_left, virtual_top);  //  Returns (left,top,right,bottom)
+    let mut enclose = |x: u8, y: u8| {
+        let b = map_virtual_to_physical(x, y);
+        physical_box = (
+            physical_box.0.min(b.0), physical_box.1.min(b.1),
+            physical_box.2.max(b.2), physical_box.3.max(b.3),
+        );
+    };
+    for x in virtual_left..=virtual_right {
+        enclose(x, virtual_top);
+        enclose(x, virtual_bottom);
+    }
+    for y in virtual_top..=virtual_bottom {
+        enclose(virtual_left, y);
+        enclose(virtual_right, y);
+    }
+
+    let left: u8 = physical_box.0;
+    let top: u8 = physical_box.1;
+    let right: u8 = physical_box.2.min(PHYSICAL_WIDTH as u8 - 1);
+    let bottom: u8 = physical_box.3.min(PHYSICAL_HEIGHT as u8 - 1);
+    assert!(left < PHYSICAL_WIDTH as u8 && top < PHYSICAL_HEIGHT as u8 && right < PHYSICAL_WIDTH as u8 && bottom < PHYSICAL_HEIGHT as u8, "overflow");
+    ( left, top, right, bottom )
+}
+
+/// Dimensions of the Physical To Virtual Map and Virtual To Physical Map
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const PHYSICAL_TO_VIRTUAL_MAP_WIDTH: usize = PHYSICAL_WIDTH / 2;
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const PHYSICAL_TO_VIRTUAL_MAP_HEIGHT: usize = PHYSICAL_HEIGHT / 2;
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const VIRTUAL_TO_PHYSICAL_MAP_WIDTH: usize = SCREEN_WIDTH / 2;
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const VIRTUAL_TO_PHYSICAL_MAP_HEIGHT: usize = SCREEN_HEIGHT / 2;
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const HIRES_VIRTUAL_TO_PHYSICAL_MAP_WIDTH: usize = HIRES_SCREEN_WIDTH / 2;
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+const HIRES_VIRTUAL_TO_PHYSICAL_MAP_HEIGHT: usize = HIRES_SCREEN_HEIGHT / 2;
+
+/// For Physical (x,y) Coordinates, return the corresponding Virtual (x,y) Coordinates.
+/// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+pub fn map_physical_to_virtual(x: u8, y: u8) -> (u8, u8) {
+    let (screen_width, screen_height) = screen_size();
+    let (screen_width, screen_height) = (screen_width as u8, screen_height as u8);
+    //  Check which quadrant (x,y) belongs to and flip accordingly
+    let flip =  //  (flip for X, flip for Y)
+        if x < PHYSICAL_WIDTH as u8 / 2 && y < PHYSICAL_HEIGHT as u8 / 2 {
+            (true, true)  //  Top left quadrant: Flip horizontally and vertically
+        } else if x >= PHYSICAL_WIDTH as u8 / 2 && y < PHYSICAL_HEIGHT as u8 / 2 {
+            (false, true)   //  Top right quadrant: Flip vertically
+        } else if x < PHYSICAL_WIDTH as u8 / 2 && y >= PHYSICAL_HEIGHT as u8 / 2 {
+            (true, false)   //  Bottom left quadrant: Flip horizontally
+        } else {
+            (false, false)    //  Bottom right quadrant: Don't flip
+        };
+    let x_normalised = 
+        if flip.0 { PHYSICAL_WIDTH as u8 / 2 - x } 
+        else      { x - PHYSICAL_WIDTH as u8 / 2 };
+    let y_normalised = 
+        if flip.1 { PHYSICAL_HEIGHT as u8 / 2 - y }
+        else      { y - PHYSICAL_HEIGHT as u8 / 2 };
+    let p = map_physical_to_virtual_normalised(x_normalised, y_normalised);  //  Returns (x,y)
+    let p2 = (
+        if flip.0 { screen_width / 2 - p.0 } 
+        else      { p.0 + screen_width / 2 }
+        ,
+        if flip.1 { screen_height / 2 - p.1 } 
+        else      { p.1 + screen_height / 2 }
+    );
+    //  Crop to screen size
+    (
+        p2.0.min(screen_width - 1),
+        p2.1.min(screen_height - 1),
+    )
+}
+
+/// For each Virtual (x,y) Coordinate, return the Bounding Box (left, top, right, bottom) that encloses the corresponding Physical (x,y) Coordinates.
+/// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+pub fn map_virtual_to_physical(x: u8, y: u8) -> (u8, u8, u8, u8) {
+    let (screen_width, screen_height) = screen_size();
+    let (screen_width, screen_height) = (screen_width as u8, screen_height as u8);
+    //  Check which quadrant (x,y) belongs to and flip accordingly
+    let flip =  //  (flip for X, flip for Y)
+        if x < screen_width / 2 && y < screen_height / 2 {
+            (true, true)  //  Top left quadrant: Flip horizontally and vertically
+        } else if x >= screen_width / 2 && y < screen_height / 2 {
+            (false, true)   //  Top right quadrant: Flip vertically
+        } else if x < screen_width / 2 && y >= screen_height / 2 {
+            (true, false)   //  Bottom left quadrant: Flip horizontally
+        } else {
+            (false, false)    //  Bottom right quadrant: Don't flip
+        };
+    let x_normalised = 
+        if flip.0 { screen_width / 2 - x } 
+        else      { x - screen_width / 2 };
+    let y_normalised = 
+        if flip.1 { screen_height / 2 - y }
+        else      { y - screen_height / 2 };
+    let b = map_virtual_to_physical_normalised(x_normalised, y_normalised);  //  Returns (left,top,right,bottom)
+    let b2 = (
+        if flip.0 { PHYSICAL_WIDTH as u8 / 2 - b.0 } 
+        else      { b.0 + PHYSICAL_WIDTH as u8 / 2 }
+        ,
+        if flip.1 { PHYSICAL_HEIGHT as u8 / 2 - b.1 } 
+        else      { b.1 + PHYSICAL_HEIGHT as u8 / 2 }
+        ,
+        if flip.0 { PHYSICAL_WIDTH as u8 / 2 - b.2 } 
+        else      { b.2 + PHYSICAL_WIDTH as u8 / 2 }
+        ,
+        if flip.1 { PHYSICAL_HEIGHT as u8 / 2 - b.3 } 
+        else      { b.3 + PHYSICAL_HEIGHT as u8 / 2 }
+    );
+    //  Crop to screen size
+    let crop = (
+        b2.0.min(PHYSICAL_WIDTH as u8 - 1),   //  Left
+        b2.1.min(PHYSICAL_HEIGHT as u8 - 1),  //  Top
+        b2.2.min(PHYSICAL_WIDTH as u8 - 1),   //  Right
+        b2.3.min(PHYSICAL_HEIGHT as u8 - 1),  //  Bottom
+    );
+    //  Flip left and right, top and bottom if necessary
+    let result = (
+        crop.0.min(crop.2),  //  Left
+        crop.1.min(crop.3),  //  Top
+        crop.0.max(crop.2),  //  Right
+        crop.1.max(crop.3),  //  Bottom
+    );
+    assert!(result.0 <= result.2 && result.1 <= result.3, "flip error");  //  Left <= Right and Top <= Bottom
+    result
+}
+
+/// Same as map_physical_to_virtual, except that (x,y) belongs to the X >= 0, Y >= 0 quadrant
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+fn map_physical_to_virtual_normalised(x: u8, y: u8) -> (u8, u8) {
+    let x_index = x.min(PHYSICAL_TO_VIRTUAL_MAP_WIDTH as u8 - 1);
+    let y_index = y.min(PHYSICAL_TO_VIRTUAL_MAP_HEIGHT as u8 - 1);
+    //  Returns (x,y)
+    if is_hires() { HIRES_PHYSICAL_TO_VIRTUAL_MAP[y_index as usize][x_index as usize] }
+    else          { PHYSICAL_TO_VIRTUAL_MAP[y_index as usize][x_index as usize] }
+}
+
+/// Same as map_virtual_to_physical, except that (x,y) belongs to the X >= 0, Y >= 0 quadrant
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+fn map_virtual_to_physical_normalised(x: u8, y: u8) -> (u8, u8, u8, u8) {
+    //  Returns (left,top,right,bottom)
+    if is_hires() {
+        let x_index = x.min(HIRES_VIRTUAL_TO_PHYSICAL_MAP_WIDTH as u8 - 1);
+        let y_index = y.min(HIRES_VIRTUAL_TO_PHYSICAL_MAP_HEIGHT as u8 - 1);
+        HIRES_VIRTUAL_TO_PHYSICAL_MAP[y_index as usize][x_index as usize]
+    } else {
+        let x_index = x.min(VIRTUAL_TO_PHYSICAL_MAP_WIDTH as u8 - 1);
+        let y_index = y.min(VIRTUAL_TO_PHYSICAL_MAP_HEIGHT as u8 - 1);
+        VIRTUAL_TO_PHYSICAL_MAP[y_index as usize][x_index as usize]
+    }
+}
+
+/// Return true if the Virtual Screen is in high resolution mode
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+fn is_hires() -> bool {
+    screen_size().0 == HIRES_SCREEN_WIDTH
+}
+
+/// For each Physical (x,y) Coordinate, return the corresponding Virtual (x,y) Coordinates.
+/// Used by the CHIP-8 Emulator to decide which Virtual Pixel to fetch the colour value when rendering a Physical Pixel.
+/// Since X and Y are symmetric, this grid only covers one quadrant (X >= 0, Y >= 0)
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+static PHYSICAL_TO_VIRTUAL_MAP: &[[(u8,u8); PHYSICAL_TO_VIRTUAL_MAP_WIDTH]; PHYSICAL_TO_VIRTUAL_MAP_HEIGHT] = &  //  Row=Y, Col=X
+//  Copied from output of https://github.com/lupyuen/interpolate-surface
+[[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,0),(28,0),(28,0),(29,0),(29,0),(29,0),(30,0),(30,0),(30,0),(31,0),(31,0),(31,0),(31,0),(32,0),(32,0),(32,0),(32,0),(32,0),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,0),(28,0),(29,0),(29,0),(29,0),(30,0),(30,0),(30,0),(30,0),(31,0),(31,0),(31,0),(32,0),(32,0),(32,0),(32,0),(32,0),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,0),(28,0),(29,0),(29,0),(29,0),(30,0),(30,0),(30,0),(30,0),(31,0),(31,0),(31,0),(32,0),(32,0),(32,0),(32,0),(32,0),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,0),(28,0),(29,0),(29,0),(29,0),(30,0),(30,0),(30,0),(30,0),(31,0),(31,0),(31,0),(32,0),(32,0),(32,0),(32,0),(32,0),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,0),(28,0),(29,0),(29,0),(29,0),(30,0),(30,0),(30,0),(31,0),(31,0),(31,0),(31,0),(32,0),(32,0),(32,0),(32,0),(32,0),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,0),(22,0),(23,0),(23,0),(23,0),(23,0),(24,0),(24,0),(24,0),(25,0),(25,0),(25,0),(26,0),(26,0),(26,0),(27,0),(27,0),(27,0),(28,0),(28,1),(28,1),(29,1),(29,1),(29,1),(30,1),(30,1),(30,1),(31,1),(31,1),(31,1),(31,1),(32,1),(32,1),(32,1),(32,1),(32,1),],
+[(0,0),(0,0),(0,0),(0,0),(0,0),(1,0),(1,0),(1,0),(1,0),(2,0),(2,0),(2,0),(2,0),(3,0),(3,0),(3,0),(3,0),(4,0),(4,0),(4,0),(4,0),(5,0),(5,0),(5,0),(5,0),(6,0),(6,0),(6,0),(7,0),(7,0),(7,0),(7,0),(8,0),(8,0),(8,0),(8,0),(9,0),(9,0),(9,0),(9,0),(10,0),(10,0),(10,0),(10,0),(11,0),(11,0),(11,0),(12,0),(12,0),(12,0),(12,0),(13,0),(13,0),(13,0),(13,0),(14,0),(14,0),(14,0),(15,0),(15,0),(15,0),(15,0),(16,0),(16,0),(16,0),(17,0),(17,0),(17,0),(17,0),(18,0),(18,0),(18,0),(18,0),(19,0),(19,0),(19,0),(20,0),(20,0),(20,0),(20,0),(21,0),(21,0),(21,0),(22,0),(22,1),(22,1),(23,1),(23,1),(23,1),(24,1),(24,1),(24,1),(24,1),(25,1),(25,1),(25,1),(26,1),(26,1),(26,1),(27,1),(27,1),(27,1),(28,1),(28,1),(28,1),(29,1),(29,1),(29,1),(30,1),(30,1),(30,1),(31,1),(31,1),(31,1),(31,1),(32,1),(32,1),(32,1),(32,1),(32,1),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,1),(13,1),(14,1),(14,1),(14,1),(15,1),(15,1),(15,1),(15,1),(16,1),(16,1),(16,1),(17,1),(17,1),(17,1),(17,1),(18,1),(18,1),(18,1),(18,1),(19,1),(19,1),(19,1),(20,1),(20,1),(20,1),(20,1),(21,1),(21,1),(21,1),(22,1),(22,1),(22,1),(23,1),(23,1),(23,1),(24,1),(24,1),(24,1),(24,1),(25,1),(25,1),(25,1),(26,1),(26,1),(26,1),(27,1),(27,1),(27,1),(28,1),(28,1),(28,1),(29,1),(29,1),(29,1),(30,1),(30,1),(30,1),(31,1),(31,1),(31,1),(32,1),(32,1),(32,1),(32,1),(32,1),(32,1),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,1),(14,1),(14,1),(14,1),(14,1),(15,1),(15,1),(15,1),(15,1),(16,1),(16,1),(16,1),(17,1),(17,1),(17,1),(17,1),(18,1),(18,1),(18,1),(19,1),(19,1),(19,1),(19,1),(20,1),(20,1),(20,1),(20,1),(21,1),(21,1),(21,1),(22,1),(22,1),(22,1),(23,1),(23,1),(23,1),(24,1),(24,1),(24,1),(25,1),(25,1),(25,1),(25,1),(26,1),(26,1),(26,1),(27,1),(27,1),(27,1),(28,1),(28,1),(28,1),(29,1),(29,1),(29,1),(30,1),(30,1),(30,1),(31,1),(31,1),(31,1),(32,1),(32,1),(32,1),(32,1),(32,1),(32,1),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,1),(14,1),(14,1),(14,1),(14,1),(15,1),(15,1),(15,1),(15,1),(16,1),(16,1),(16,1),(17,1),(17,1),(17,1),(17,1),(18,1),(18,1),(18,1),(19,1),(19,1),(19,1),(19,1),(20,1),(20,1),(20,1),(20,1),(21,1),(21,1),(21,1),(22,1),(22,1),(22,1),(23,1),(23,1),(23,1),(24,1),(24,1),(24,1),(25,1),(25,1),(25,1),(25,1),(26,1),(26,1),(26,1),(27,1),(27,1),(27,1),(28,1),(28,1),(28,1),(29,1),(29,1),(29,1),(30,1),(30,1),(30,1),(31,1),(31,1),(31,1),(32,1),(32,1),(32,1),(32,1),(32,1),(32,1),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,1),(14,1),(14,1),(14,1),(14,1),(15,1),(15,1),(15,1),(15,1),(16,1),(16,1),(16,1),(17,1),(17,1),(17,1),(17,1),(18,1),(18,1),(18,1),(19,1),(19,1),(19,1),(19,1),(20,1),(20,1),(20,1),(21,1),(21,1),(21,1),(21,1),(22,1),(22,1),(22,1),(23,1),(23,1),(23,1),(24,1),(24,1),(24,1),(25,1),(25,1),(25,1),(26,1),(26,1),(26,1),(26,1),(27,1),(27,2),(27,2),(28,2),(28,2),(28,2),(29,2),(29,2),(29,2),(30,2),(30,2),(30,2),(31,2),(31,2),(31,2),(32,2),(32,2),(32,2),(32,2),(32,2),(32,2),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,1),(14,1),(14,1),(14,1),(14,1),(15,1),(15,1),(15,1),(16,1),(16,1),(16,1),(16,1),(17,1),(17,1),(17,1),(17,1),(18,1),(18,1),(18,1),(19,1),(19,1),(19,1),(19,1),(20,1),(20,1),(20,1),(21,1),(21,1),(21,1),(21,1),(22,1),(22,1),(22,1),(23,1),(23,2),(23,2),(24,2),(24,2),(24,2),(25,2),(25,2),(25,2),(26,2),(26,2),(26,2),(26,2),(27,2),(27,2),(27,2),(28,2),(28,2),(28,2),(29,2),(29,2),(29,2),(30,2),(30,2),(30,2),(31,2),(31,2),(31,2),(32,2),(32,2),(32,2),(32,2),(32,2),(32,2),],
+[(0,1),(0,1),(0,1),(0,1),(0,1),(1,1),(1,1),(1,1),(1,1),(2,1),(2,1),(2,1),(2,1),(3,1),(3,1),(3,1),(3,1),(4,1),(4,1),(4,1),(4,1),(5,1),(5,1),(5,1),(6,1),(6,1),(6,1),(6,1),(7,1),(7,1),(7,1),(7,1),(8,1),(8,1),(8,1),(8,1),(9,1),(9,1),(9,1),(9,1),(10,1),(10,1),(10,1),(11,1),(11,1),(11,1),(11,1),(12,1),(12,1),(12,1),(12,1),(13,1),(13,1),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(21,2),(21,2),(21,2),(21,2),(22,2),(22,2),(22,2),(23,2),(23,2),(23,2),(24,2),(24,2),(24,2),(25,2),(25,2),(25,2),(26,2),(26,2),(26,2),(26,2),(27,2),(27,2),(27,2),(28,2),(28,2),(28,2),(29,2),(29,2),(29,2),(30,2),(30,2),(30,2),(31,2),(31,2),(31,2),(32,2),(32,2),(32,2),(32,2),(32,2),(32,2),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(21,2),(21,2),(21,2),(21,2),(22,2),(22,2),(22,2),(23,2),(23,2),(23,2),(24,2),(24,2),(24,2),(25,2),(25,2),(25,2),(26,2),(26,2),(26,2),(27,2),(27,2),(27,2),(27,2),(28,2),(28,2),(28,2),(29,2),(29,2),(29,2),(30,2),(30,2),(30,2),(31,2),(31,2),(31,2),(32,2),(32,2),(32,2),(32,2),(32,2),(32,2),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(21,2),(21,2),(21,2),(21,2),(22,2),(22,2),(22,2),(23,2),(23,2),(23,2),(24,2),(24,2),(24,2),(25,2),(25,2),(25,2),(26,2),(26,2),(26,2),(27,2),(27,2),(27,2),(27,2),(28,2),(28,2),(28,2),(29,2),(29,2),(29,2),(30,2),(30,2),(30,2),(31,3),(31,3),(31,3),(32,3),(32,3),(32,3),(32,3),(32,3),(32,2),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,2),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(21,2),(21,2),(21,2),(22,2),(22,2),(22,2),(22,2),(23,2),(23,2),(23,2),(24,2),(24,2),(24,2),(25,2),(25,2),(25,2),(26,2),(26,2),(26,2),(27,2),(27,3),(27,3),(27,3),(28,3),(28,3),(28,3),(29,3),(29,3),(29,3),(30,3),(30,3),(30,3),(31,3),(31,3),(31,3),(32,3),(32,3),(32,3),(32,3),(32,3),(32,3),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,2),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(21,2),(21,2),(21,2),(22,2),(22,2),(22,2),(22,2),(23,2),(23,2),(23,2),(24,3),(24,3),(24,3),(25,3),(25,3),(25,3),(26,3),(26,3),(26,3),(27,3),(27,3),(27,3),(28,3),(28,3),(28,3),(28,3),(29,3),(29,3),(29,3),(30,3),(30,3),(30,3),(31,3),(31,3),(31,3),(32,3),(32,3),(32,3),(32,3),(32,3),(32,3),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,2),(13,2),(14,2),(14,2),(14,2),(14,2),(15,2),(15,2),(15,2),(16,2),(16,2),(16,2),(16,2),(17,2),(17,2),(17,2),(18,2),(18,2),(18,2),(18,2),(19,2),(19,2),(19,2),(20,2),(20,2),(20,2),(20,3),(21,3),(21,3),(21,3),(22,3),(22,3),(22,3),(22,3),(23,3),(23,3),(23,3),(24,3),(24,3),(24,3),(25,3),(25,3),(25,3),(26,3),(26,3),(26,3),(27,3),(27,3),(27,3),(28,3),(28,3),(28,3),(28,3),(29,3),(29,3),(29,3),(30,3),(30,3),(30,3),(31,3),(31,3),(31,3),(32,3),(32,3),(32,3),(32,3),(32,3),(32,3),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,2),(1,2),(1,2),(1,2),(2,2),(2,2),(2,2),(2,2),(3,2),(3,2),(3,2),(3,2),(4,2),(4,2),(4,2),(5,2),(5,2),(5,2),(5,2),(6,2),(6,2),(6,2),(6,2),(7,2),(7,2),(7,2),(7,2),(8,2),(8,2),(8,2),(8,2),(9,2),(9,2),(9,2),(10,2),(10,2),(10,2),(10,2),(11,2),(11,2),(11,2),(11,2),(12,2),(12,2),(12,2),(13,2),(13,2),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(18,3),(18,3),(18,3),(18,3),(19,3),(19,3),(19,3),(20,3),(20,3),(20,3),(20,3),(21,3),(21,3),(21,3),(22,3),(22,3),(22,3),(22,3),(23,3),(23,3),(23,3),(24,3),(24,3),(24,3),(25,3),(25,3),(25,3),(26,3),(26,3),(26,3),(27,3),(27,3),(27,3),(28,3),(28,3),(28,3),(28,3),(29,3),(29,3),(29,3),(30,3),(30,3),(30,3),(31,3),(31,3),(31,3),(32,3),(32,3),(32,3),(32,3),(32,3),(32,3),],
+[(0,2),(0,2),(0,2),(0,2),(0,2),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(18,3),(18,3),(18,3),(18,3),(19,3),(19,3),(19,3),(20,3),(20,3),(20,3),(20,3),(21,3),(21,3),(21,3),(22,3),(22,3),(22,3),(23,3),(23,3),(23,3),(23,3),(24,3),(24,3),(24,3),(25,3),(25,3),(25,3),(26,3),(26,3),(26,3),(27,3),(27,3),(27,3),(28,3),(28,3),(28,3),(29,3),(29,3),(29,3),(29,3),(30,4),(30,4),(30,4),(31,4),(31,4),(31,4),(32,4),(32,4),(32,4),(32,4),(32,4),(32,4),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(17,3),(18,3),(18,3),(18,3),(18,3),(19,3),(19,3),(19,3),(20,3),(20,3),(20,3),(20,3),(21,3),(21,3),(21,3),(22,3),(22,3),(22,3),(23,3),(23,3),(23,3),(23,3),(24,3),(24,3),(24,3),(25,3),(25,3),(25,3),(26,3),(26,3),(26,3),(27,3),(27,4),(27,4),(28,4),(28,4),(28,4),(29,4),(29,4),(29,4),(29,4),(30,4),(30,4),(30,4),(31,4),(31,4),(31,4),(32,4),(32,4),(32,4),(32,4),(32,4),(32,4),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(17,3),(18,3),(18,3),(18,3),(18,3),(19,3),(19,3),(19,3),(20,3),(20,3),(20,3),(20,3),(21,3),(21,3),(21,3),(22,3),(22,3),(22,3),(23,3),(23,3),(23,3),(24,3),(24,3),(24,4),(24,4),(25,4),(25,4),(25,4),(26,4),(26,4),(26,4),(27,4),(27,4),(27,4),(28,4),(28,4),(28,4),(29,4),(29,4),(29,4),(30,4),(30,4),(30,4),(30,4),(31,4),(31,4),(31,4),(32,4),(32,4),(32,4),(32,4),(32,4),(32,4),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(17,3),(18,3),(18,3),(18,3),(18,3),(19,3),(19,3),(19,3),(20,3),(20,3),(20,3),(20,3),(21,3),(21,3),(21,3),(22,3),(22,4),(22,4),(23,4),(23,4),(23,4),(24,4),(24,4),(24,4),(25,4),(25,4),(25,4),(25,4),(26,4),(26,4),(26,4),(27,4),(27,4),(27,4),(28,4),(28,4),(28,4),(29,4),(29,4),(29,4),(30,4),(30,4),(30,4),(30,4),(31,4),(31,4),(31,4),(32,4),(32,4),(32,4),(32,4),(32,4),(32,4),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,3),(13,3),(14,3),(14,3),(14,3),(15,3),(15,3),(15,3),(15,3),(16,3),(16,3),(16,3),(17,3),(17,3),(17,3),(17,3),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,4),(20,4),(21,4),(21,4),(21,4),(22,4),(22,4),(22,4),(23,4),(23,4),(23,4),(24,4),(24,4),(24,4),(25,4),(25,4),(25,4),(26,4),(26,4),(26,4),(26,4),(27,4),(27,4),(27,4),(28,4),(28,4),(28,4),(29,4),(29,4),(29,4),(30,4),(30,4),(30,4),(30,4),(31,4),(31,4),(31,4),(32,4),(32,4),(32,4),(32,4),(32,4),(32,4),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,3),(6,3),(6,3),(7,3),(7,3),(7,3),(8,3),(8,3),(8,3),(8,3),(9,3),(9,3),(9,3),(9,3),(10,3),(10,3),(10,3),(10,3),(11,3),(11,3),(11,3),(12,3),(12,3),(12,3),(12,3),(13,3),(13,3),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,4),(17,4),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,4),(20,4),(21,4),(21,4),(21,4),(22,4),(22,4),(22,4),(23,4),(23,4),(23,4),(24,4),(24,4),(24,4),(25,4),(25,4),(25,4),(26,4),(26,4),(26,4),(27,4),(27,4),(27,4),(27,4),(28,4),(28,4),(28,4),(29,4),(29,4),(29,5),(30,5),(30,5),(30,5),(31,5),(31,5),(31,5),(31,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),],
+[(0,3),(0,3),(0,3),(0,3),(0,3),(1,3),(1,3),(1,3),(1,3),(2,3),(2,3),(2,3),(2,3),(3,3),(3,3),(3,3),(4,3),(4,3),(4,3),(4,3),(5,3),(5,3),(5,3),(5,3),(6,3),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,4),(17,4),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,4),(21,4),(21,4),(21,4),(21,4),(22,4),(22,4),(22,4),(23,4),(23,4),(23,4),(24,4),(24,4),(24,4),(25,4),(25,4),(25,4),(26,4),(26,4),(26,4),(27,4),(27,5),(27,5),(28,5),(28,5),(28,5),(28,5),(29,5),(29,5),(29,5),(30,5),(30,5),(30,5),(31,5),(31,5),(31,5),(31,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,4),(17,4),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,4),(21,4),(21,4),(21,4),(21,4),(22,4),(22,4),(22,4),(23,4),(23,4),(23,4),(24,4),(24,4),(24,4),(25,5),(25,5),(25,5),(26,5),(26,5),(26,5),(27,5),(27,5),(27,5),(28,5),(28,5),(28,5),(28,5),(29,5),(29,5),(29,5),(30,5),(30,5),(30,5),(31,5),(31,5),(31,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,4),(17,4),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,4),(21,4),(21,4),(21,4),(21,4),(22,4),(22,4),(22,5),(23,5),(23,5),(23,5),(24,5),(24,5),(24,5),(25,5),(25,5),(25,5),(26,5),(26,5),(26,5),(27,5),(27,5),(27,5),(28,5),(28,5),(28,5),(29,5),(29,5),(29,5),(29,5),(30,5),(30,5),(30,5),(31,5),(31,5),(31,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,4),(18,4),(18,4),(18,4),(18,4),(19,4),(19,4),(19,4),(19,4),(20,4),(20,4),(20,5),(21,5),(21,5),(21,5),(21,5),(22,5),(22,5),(22,5),(23,5),(23,5),(23,5),(24,5),(24,5),(24,5),(25,5),(25,5),(25,5),(26,5),(26,5),(26,5),(27,5),(27,5),(27,5),(28,5),(28,5),(28,5),(29,5),(29,5),(29,5),(30,5),(30,5),(30,5),(30,5),(31,5),(31,5),(31,6),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),(32,5),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(13,4),(14,4),(14,4),(14,4),(14,4),(15,4),(15,4),(15,4),(16,4),(16,4),(16,4),(16,4),(17,4),(17,4),(17,5),(18,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(21,5),(21,5),(21,5),(22,5),(22,5),(22,5),(23,5),(23,5),(23,5),(24,5),(24,5),(24,5),(25,5),(25,5),(25,5),(26,5),(26,5),(26,5),(26,5),(27,5),(27,5),(27,5),(28,5),(28,5),(28,5),(29,5),(29,6),(29,6),(30,6),(30,6),(30,6),(31,6),(31,6),(31,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,5),(32,5),(32,5),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,4),(10,4),(10,4),(10,4),(11,4),(11,4),(11,4),(11,4),(12,4),(12,4),(12,4),(13,4),(13,4),(13,4),(13,4),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(18,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(20,5),(21,5),(21,5),(21,5),(22,5),(22,5),(22,5),(23,5),(23,5),(23,5),(24,5),(24,5),(24,5),(25,5),(25,5),(25,5),(26,5),(26,5),(26,5),(27,5),(27,6),(27,6),(27,6),(28,6),(28,6),(28,6),(29,6),(29,6),(29,6),(30,6),(30,6),(30,6),(31,6),(31,6),(31,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),],
+[(0,4),(0,4),(0,4),(0,4),(0,4),(1,4),(1,4),(1,4),(1,4),(2,4),(2,4),(2,4),(2,4),(3,4),(3,4),(3,4),(4,4),(4,4),(4,4),(4,4),(5,4),(5,4),(5,4),(5,4),(6,4),(6,4),(6,4),(7,4),(7,4),(7,4),(7,4),(8,4),(8,4),(8,4),(8,4),(9,4),(9,4),(9,4),(9,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(17,5),(18,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(20,5),(21,5),(21,5),(21,5),(22,5),(22,5),(22,5),(23,5),(23,5),(23,5),(24,5),(24,5),(24,5),(25,5),(25,6),(25,6),(26,6),(26,6),(26,6),(27,6),(27,6),(27,6),(28,6),(28,6),(28,6),(29,6),(29,6),(29,6),(29,6),(30,6),(30,6),(30,6),(31,6),(31,6),(31,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(9,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(17,5),(18,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(20,5),(21,5),(21,5),(21,5),(22,5),(22,5),(22,5),(23,5),(23,6),(23,6),(24,6),(24,6),(24,6),(25,6),(25,6),(25,6),(26,6),(26,6),(26,6),(27,6),(27,6),(27,6),(28,6),(28,6),(28,6),(29,6),(29,6),(29,6),(30,6),(30,6),(30,6),(31,6),(31,6),(31,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(10,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(17,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(20,5),(21,5),(21,5),(21,5),(22,6),(22,6),(22,6),(23,6),(23,6),(23,6),(24,6),(24,6),(24,6),(25,6),(25,6),(25,6),(26,6),(26,6),(26,6),(27,6),(27,6),(27,6),(28,6),(28,6),(28,6),(29,6),(29,6),(29,6),(30,6),(30,6),(30,6),(31,6),(31,6),(31,7),(32,7),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),(32,6),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(10,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(17,5),(18,5),(18,5),(18,5),(19,5),(19,5),(19,5),(19,5),(20,5),(20,5),(20,5),(21,6),(21,6),(21,6),(22,6),(22,6),(22,6),(23,6),(23,6),(23,6),(24,6),(24,6),(24,6),(25,6),(25,6),(25,6),(26,6),(26,6),(26,6),(27,6),(27,6),(27,6),(27,6),(28,6),(28,6),(28,6),(29,6),(29,7),(29,7),(30,7),(30,7),(30,7),(31,7),(31,7),(31,7),(32,7),(32,7),(32,7),(32,7),(32,6),(32,6),(32,6),(32,6),(32,6),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(9,5),(10,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,5),(14,5),(15,5),(15,5),(15,5),(16,5),(16,5),(16,5),(16,5),(17,5),(17,5),(17,5),(17,5),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(21,6),(21,6),(21,6),(22,6),(22,6),(22,6),(23,6),(23,6),(23,6),(24,6),(24,6),(24,6),(25,6),(25,6),(25,6),(26,6),(26,6),(26,6),(27,6),(27,7),(27,7),(28,7),(28,7),(28,7),(29,7),(29,7),(29,7),(30,7),(30,7),(30,7),(31,7),(31,7),(31,7),(31,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,6),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(9,5),(10,5),(10,5),(10,5),(10,5),(11,5),(11,5),(11,5),(12,5),(12,5),(12,5),(12,5),(13,5),(13,5),(13,5),(14,5),(14,5),(14,6),(14,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(20,6),(21,6),(21,6),(21,6),(22,6),(22,6),(22,6),(23,6),(23,6),(23,6),(24,6),(24,6),(24,6),(25,6),(25,6),(25,7),(26,7),(26,7),(26,7),(27,7),(27,7),(27,7),(28,7),(28,7),(28,7),(29,7),(29,7),(29,7),(30,7),(30,7),(30,7),(31,7),(31,7),(31,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),],
+[(0,5),(0,5),(0,5),(0,5),(0,5),(1,5),(1,5),(1,5),(1,5),(2,5),(2,5),(2,5),(3,5),(3,5),(3,5),(3,5),(4,5),(4,5),(4,5),(4,5),(5,5),(5,5),(5,5),(6,5),(6,5),(6,5),(6,5),(7,5),(7,5),(7,5),(7,5),(8,5),(8,5),(8,5),(9,5),(9,5),(9,5),(9,6),(10,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(20,6),(21,6),(21,6),(21,6),(22,6),(22,6),(22,6),(23,6),(23,6),(23,6),(24,6),(24,7),(24,7),(25,7),(25,7),(25,7),(26,7),(26,7),(26,7),(27,7),(27,7),(27,7),(28,7),(28,7),(28,7),(29,7),(29,7),(29,7),(30,7),(30,7),(30,7),(31,7),(31,7),(31,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(9,6),(9,6),(9,6),(9,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(21,6),(21,6),(21,6),(21,6),(22,6),(22,6),(22,6),(23,6),(23,7),(23,7),(24,7),(24,7),(25,7),(25,7),(25,7),(26,7),(26,7),(26,7),(27,7),(27,7),(27,7),(28,7),(28,7),(28,7),(29,7),(29,7),(29,7),(30,7),(30,7),(30,7),(30,7),(31,7),(31,8),(31,8),(32,8),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(8,6),(9,6),(9,6),(9,6),(9,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(21,6),(21,6),(21,6),(22,6),(22,6),(22,7),(23,7),(23,7),(23,7),(24,7),(24,7),(24,7),(25,7),(25,7),(25,7),(26,7),(26,7),(26,7),(27,7),(27,7),(27,7),(28,7),(28,7),(28,7),(29,7),(29,8),(29,8),(30,8),(30,8),(30,8),(31,8),(31,8),(31,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,7),(32,7),(32,7),(32,7),(32,7),(32,7),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(8,6),(9,6),(9,6),(9,6),(9,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(19,6),(19,6),(19,6),(19,6),(20,6),(20,6),(20,6),(21,6),(21,7),(21,7),(22,7),(22,7),(22,7),(23,7),(23,7),(23,7),(24,7),(24,7),(24,7),(25,7),(25,7),(25,7),(26,7),(26,7),(26,7),(27,7),(27,7),(27,8),(28,8),(28,8),(28,8),(29,8),(29,8),(29,8),(30,8),(30,8),(30,8),(31,8),(31,8),(31,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,7),(32,7),(32,7),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(8,6),(9,6),(9,6),(9,6),(10,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,6),(15,6),(16,6),(16,6),(16,6),(17,6),(17,6),(17,6),(17,6),(18,6),(18,6),(18,6),(19,6),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,7),(22,7),(22,7),(22,7),(23,7),(23,7),(23,7),(24,7),(24,7),(24,7),(25,7),(25,7),(25,7),(26,7),(26,7),(26,8),(27,8),(27,8),(27,8),(28,8),(28,8),(28,8),(29,8),(29,8),(29,8),(30,8),(30,8),(30,8),(31,8),(31,8),(31,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(8,6),(9,6),(9,6),(9,6),(10,6),(10,6),(10,6),(10,6),(11,6),(11,6),(11,6),(12,6),(12,6),(12,6),(12,6),(13,6),(13,6),(13,6),(14,6),(14,6),(14,6),(14,6),(15,6),(15,6),(15,7),(16,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,7),(22,7),(22,7),(22,7),(23,7),(23,7),(23,7),(24,7),(24,7),(24,7),(25,7),(25,8),(25,8),(26,8),(26,8),(27,8),(27,8),(27,8),(28,8),(28,8),(28,8),(29,8),(29,8),(29,8),(30,8),(30,8),(30,8),(31,8),(31,8),(31,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,6),(1,6),(1,6),(1,6),(2,6),(2,6),(2,6),(3,6),(3,6),(3,6),(3,6),(4,6),(4,6),(4,6),(5,6),(5,6),(5,6),(5,6),(6,6),(6,6),(6,6),(7,6),(7,6),(7,6),(7,6),(8,6),(8,6),(8,6),(8,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,7),(21,7),(22,7),(22,7),(22,7),(23,7),(23,7),(23,7),(24,7),(24,8),(25,8),(25,8),(25,8),(26,8),(26,8),(26,8),(27,8),(27,8),(27,8),(28,8),(28,8),(28,8),(29,8),(29,8),(29,8),(30,8),(30,8),(30,8),(31,9),(31,9),(31,9),(32,9),(32,9),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),],
+[(0,6),(0,6),(0,6),(0,6),(0,6),(1,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(7,7),(8,7),(8,7),(8,7),(9,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,7),(22,7),(22,7),(22,7),(23,7),(23,7),(23,8),(24,8),(24,8),(24,8),(25,8),(25,8),(25,8),(26,8),(26,8),(26,8),(27,8),(27,8),(27,8),(28,8),(28,8),(28,8),(29,8),(29,8),(29,9),(30,9),(30,9),(30,9),(31,9),(31,9),(31,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),(32,8),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(7,7),(8,7),(8,7),(8,7),(9,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,7),(22,7),(22,7),(22,7),(23,8),(23,8),(23,8),(24,8),(24,8),(24,8),(25,8),(25,8),(25,8),(26,8),(26,8),(26,8),(27,8),(27,8),(27,8),(28,8),(28,9),(28,9),(29,9),(29,9),(30,9),(30,9),(30,9),(31,9),(31,9),(31,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,8),(32,8),(32,8),(32,8),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(7,7),(8,7),(8,7),(8,7),(9,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(19,7),(20,7),(20,7),(20,7),(21,7),(21,7),(21,8),(22,8),(22,8),(22,8),(23,8),(23,8),(23,8),(24,8),(24,8),(24,8),(25,8),(25,8),(25,8),(26,8),(26,8),(27,8),(27,8),(27,9),(28,9),(28,9),(28,9),(29,9),(29,9),(29,9),(30,9),(30,9),(30,9),(31,9),(31,9),(31,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,8),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(7,7),(8,7),(8,7),(8,7),(9,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(17,7),(17,7),(17,7),(17,7),(18,7),(18,7),(18,7),(19,7),(19,7),(19,7),(20,7),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(22,8),(22,8),(22,8),(23,8),(23,8),(23,8),(24,8),(24,8),(24,8),(25,8),(25,8),(26,8),(26,8),(26,9),(27,9),(27,9),(27,9),(28,9),(28,9),(28,9),(29,9),(29,9),(29,9),(30,9),(30,9),(30,9),(31,9),(31,9),(31,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(7,7),(8,7),(8,7),(8,7),(9,7),(9,7),(9,7),(9,7),(10,7),(10,7),(10,7),(11,7),(11,7),(11,7),(11,7),(12,7),(12,7),(12,7),(13,7),(13,7),(13,7),(13,7),(14,7),(14,7),(14,7),(15,7),(15,7),(15,7),(16,7),(16,7),(16,7),(16,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(22,8),(22,8),(22,8),(23,8),(23,8),(23,8),(24,8),(24,8),(25,8),(25,8),(25,9),(26,9),(26,9),(26,9),(27,9),(27,9),(27,9),(28,9),(28,9),(28,9),(29,9),(29,9),(29,9),(30,9),(30,9),(30,9),(31,10),(31,10),(31,10),(32,10),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(2,7),(2,7),(3,7),(3,7),(3,7),(4,7),(4,7),(4,7),(4,7),(5,7),(5,7),(5,7),(6,7),(6,7),(6,7),(6,7),(7,7),(7,7),(7,7),(8,7),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(21,8),(22,8),(22,8),(22,8),(23,8),(23,8),(24,8),(24,8),(24,9),(25,9),(25,9),(25,9),(26,9),(26,9),(26,9),(27,9),(27,9),(27,9),(28,9),(28,9),(29,9),(29,9),(29,9),(30,10),(30,10),(30,10),(31,10),(31,10),(31,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),(32,9),],
+[(0,7),(0,7),(0,7),(0,7),(0,7),(1,7),(1,7),(1,7),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(8,8),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(22,8),(22,8),(22,8),(23,8),(23,8),(23,8),(24,9),(24,9),(24,9),(25,9),(25,9),(25,9),(26,9),(26,9),(27,9),(27,9),(27,9),(28,9),(28,9),(28,9),(29,10),(29,10),(29,10),(30,10),(30,10),(30,10),(31,10),(31,10),(31,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,9),(32,9),(32,9),(32,9),(32,9),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(8,8),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(22,8),(22,8),(22,8),(23,9),(23,9),(23,9),(24,9),(24,9),(24,9),(25,9),(25,9),(26,9),(26,9),(26,9),(27,9),(27,9),(27,9),(28,10),(28,10),(28,10),(29,10),(29,10),(29,10),(30,10),(30,10),(30,10),(31,10),(31,10),(31,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,9),(32,9),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(8,8),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(21,8),(21,8),(21,8),(22,8),(22,9),(22,9),(23,9),(23,9),(23,9),(24,9),(24,9),(25,9),(25,9),(25,9),(26,9),(26,9),(26,9),(27,10),(27,10),(27,10),(28,10),(28,10),(28,10),(29,10),(29,10),(30,10),(30,10),(30,10),(31,10),(31,10),(31,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(8,8),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(17,8),(18,8),(18,8),(18,8),(19,8),(19,8),(19,8),(19,8),(20,8),(20,8),(20,8),(21,9),(21,9),(21,9),(22,9),(22,9),(22,9),(23,9),(23,9),(23,9),(24,9),(24,9),(25,9),(25,9),(25,9),(26,9),(26,10),(26,10),(27,10),(27,10),(28,10),(28,10),(28,10),(29,10),(29,10),(29,10),(30,10),(30,10),(30,10),(31,10),(31,11),(31,11),(32,11),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(7,8),(8,8),(8,8),(8,8),(9,8),(9,8),(9,8),(9,8),(10,8),(10,8),(10,8),(11,8),(11,8),(11,8),(11,8),(12,8),(12,8),(12,8),(13,8),(13,8),(13,8),(13,8),(14,8),(14,8),(14,8),(15,8),(15,8),(15,8),(15,8),(16,8),(16,8),(16,8),(17,8),(17,8),(17,8),(17,8),(18,8),(18,9),(18,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(22,9),(22,9),(22,9),(23,9),(23,9),(24,9),(24,9),(24,9),(25,9),(25,10),(25,10),(26,10),(26,10),(27,10),(27,10),(27,10),(28,10),(28,10),(28,10),(29,10),(29,10),(29,10),(30,10),(30,11),(30,11),(31,11),(31,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,8),(2,8),(3,8),(3,8),(3,8),(4,8),(4,8),(4,8),(4,8),(5,8),(5,8),(5,8),(6,8),(6,8),(6,8),(7,8),(7,8),(7,8),(7,8),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,9),(16,9),(16,9),(16,9),(16,9),(17,9),(17,9),(17,9),(18,9),(18,9),(18,9),(18,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(21,9),(22,9),(22,9),(23,9),(23,9),(23,9),(24,9),(24,9),(24,10),(25,10),(25,10),(26,10),(26,10),(26,10),(27,10),(27,10),(27,10),(28,10),(28,10),(28,10),(29,10),(29,11),(30,11),(30,11),(30,11),(31,11),(31,11),(31,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),],
+[(0,8),(0,8),(0,8),(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(2,9),(2,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,9),(7,9),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,9),(16,9),(16,9),(16,9),(16,9),(17,9),(17,9),(17,9),(18,9),(18,9),(18,9),(18,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(22,9),(22,9),(22,9),(23,9),(23,9),(23,9),(24,10),(24,10),(25,10),(25,10),(25,10),(26,10),(26,10),(26,10),(27,10),(27,10),(27,10),(28,10),(28,11),(29,11),(29,11),(29,11),(30,11),(30,11),(30,11),(31,11),(31,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),(32,10),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(2,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,9),(7,9),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,9),(16,9),(16,9),(16,9),(17,9),(17,9),(17,9),(17,9),(18,9),(18,9),(18,9),(19,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(22,9),(22,9),(22,9),(23,9),(23,10),(23,10),(24,10),(24,10),(25,10),(25,10),(25,10),(26,10),(26,10),(26,10),(27,10),(27,11),(28,11),(28,11),(28,11),(29,11),(29,11),(29,11),(30,11),(30,11),(31,11),(31,11),(31,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,10),(32,10),(32,10),(32,10),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(2,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,9),(7,9),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,9),(15,9),(16,9),(16,9),(16,9),(17,9),(17,9),(17,9),(17,9),(18,9),(18,9),(18,9),(19,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(22,9),(22,9),(22,10),(23,10),(23,10),(24,10),(24,10),(24,10),(25,10),(25,10),(25,10),(26,10),(26,10),(27,11),(27,11),(27,11),(28,11),(28,11),(28,11),(29,11),(29,11),(30,11),(30,11),(30,11),(31,11),(31,12),(31,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,10),(32,10),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(2,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,9),(8,9),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,9),(15,9),(16,9),(16,9),(16,9),(17,9),(17,9),(17,9),(17,9),(18,9),(18,9),(18,9),(19,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(21,9),(21,9),(21,9),(22,10),(22,10),(22,10),(23,10),(23,10),(24,10),(24,10),(24,10),(25,10),(25,10),(26,11),(26,11),(26,11),(27,11),(27,11),(27,11),(28,11),(28,11),(29,11),(29,11),(29,11),(30,11),(30,12),(30,12),(31,12),(31,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(2,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,9),(8,9),(8,9),(8,9),(8,9),(9,9),(9,9),(9,9),(10,9),(10,9),(10,9),(10,9),(11,9),(11,9),(11,9),(12,9),(12,9),(12,9),(13,9),(13,9),(13,9),(13,9),(14,9),(14,9),(14,9),(15,9),(15,9),(15,10),(16,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,9),(18,9),(19,9),(19,9),(19,9),(20,9),(20,9),(20,9),(20,10),(21,10),(21,10),(21,10),(22,10),(22,10),(23,10),(23,10),(23,10),(24,10),(24,10),(25,10),(25,11),(25,11),(26,11),(26,11),(26,11),(27,11),(27,11),(28,11),(28,11),(28,11),(29,11),(29,12),(30,12),(30,12),(30,12),(31,12),(31,12),(31,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(3,9),(3,9),(3,9),(3,9),(4,9),(4,9),(4,9),(5,9),(5,9),(5,9),(6,9),(6,9),(6,9),(6,9),(7,9),(7,9),(7,10),(8,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(11,10),(11,10),(11,10),(11,10),(12,10),(12,10),(12,10),(13,10),(13,10),(13,10),(14,10),(14,10),(14,10),(14,10),(15,10),(15,10),(15,10),(16,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,10),(18,10),(19,10),(19,10),(19,10),(20,10),(20,10),(20,10),(20,10),(21,10),(21,10),(21,10),(22,10),(22,10),(23,10),(23,10),(23,10),(24,10),(24,11),(25,11),(25,11),(25,11),(26,11),(26,11),(27,11),(27,11),(27,11),(28,11),(28,11),(29,12),(29,12),(29,12),(30,12),(30,12),(30,12),(31,12),(31,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),],
+[(0,9),(0,9),(0,9),(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(2,9),(3,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,10),(6,10),(6,10),(7,10),(7,10),(7,10),(8,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(11,10),(11,10),(11,10),(11,10),(12,10),(12,10),(12,10),(13,10),(13,10),(13,10),(14,10),(14,10),(14,10),(15,10),(15,10),(15,10),(15,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,10),(19,10),(19,10),(19,10),(19,10),(20,10),(20,10),(20,10),(20,10),(21,10),(21,10),(22,10),(22,10),(22,10),(23,10),(23,10),(24,11),(24,11),(24,11),(25,11),(25,11),(26,11),(26,11),(26,11),(27,11),(27,11),(27,12),(28,12),(28,12),(29,12),(29,12),(29,12),(30,12),(30,12),(31,12),(31,12),(31,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),(32,11),(32,11),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(2,10),(3,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,10),(6,10),(6,10),(7,10),(7,10),(7,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(11,10),(11,10),(11,10),(12,10),(12,10),(12,10),(12,10),(13,10),(13,10),(13,10),(14,10),(14,10),(14,10),(15,10),(15,10),(15,10),(15,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,10),(19,10),(19,10),(19,10),(19,10),(20,10),(20,10),(20,10),(21,10),(21,10),(21,10),(22,10),(22,10),(22,10),(23,11),(23,11),(24,11),(24,11),(24,11),(25,11),(25,11),(26,11),(26,11),(26,11),(27,12),(27,12),(28,12),(28,12),(28,12),(29,12),(29,12),(30,12),(30,12),(30,12),(31,13),(31,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),(32,11),(32,11),(32,11),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(2,10),(3,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,10),(6,10),(6,10),(7,10),(7,10),(7,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(11,10),(11,10),(11,10),(12,10),(12,10),(12,10),(13,10),(13,10),(13,10),(13,10),(14,10),(14,10),(14,10),(15,10),(15,10),(15,10),(16,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,10),(18,10),(19,10),(19,10),(19,10),(20,10),(20,10),(20,10),(20,10),(21,10),(21,10),(21,10),(22,10),(22,10),(23,11),(23,11),(23,11),(24,11),(24,11),(25,11),(25,11),(25,11),(26,11),(26,12),(27,12),(27,12),(27,12),(28,12),(28,12),(29,12),(29,12),(29,12),(30,12),(30,13),(31,13),(31,13),(31,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,11),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(2,10),(3,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,10),(6,10),(7,10),(7,10),(7,10),(7,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(11,10),(11,10),(11,10),(12,10),(12,10),(12,10),(13,10),(13,10),(13,10),(14,10),(14,10),(14,10),(14,10),(15,10),(15,10),(15,10),(16,10),(16,10),(16,10),(17,10),(17,10),(17,10),(17,10),(18,10),(18,10),(18,10),(18,10),(19,10),(19,10),(19,10),(20,10),(20,10),(20,10),(20,10),(21,10),(21,10),(21,10),(22,11),(22,11),(23,11),(23,11),(23,11),(24,11),(24,11),(25,11),(25,11),(26,12),(26,12),(26,12),(27,12),(27,12),(28,12),(28,12),(28,12),(29,12),(29,13),(30,13),(30,13),(30,13),(31,13),(31,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(2,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,10),(6,10),(7,10),(7,10),(7,10),(7,10),(8,10),(8,10),(8,10),(9,10),(9,10),(9,10),(10,10),(10,10),(10,10),(10,10),(11,11),(11,11),(11,11),(12,11),(12,11),(12,11),(13,11),(13,11),(13,11),(14,11),(14,11),(14,11),(15,11),(15,11),(15,11),(15,11),(16,11),(16,11),(16,11),(17,11),(17,11),(17,11),(17,11),(18,11),(18,11),(18,11),(19,11),(19,11),(19,11),(19,11),(20,11),(20,11),(20,11),(21,11),(21,11),(21,11),(22,11),(22,11),(22,11),(23,11),(23,11),(24,11),(24,11),(24,12),(25,12),(25,12),(26,12),(26,12),(26,12),(27,12),(27,12),(28,12),(28,12),(28,13),(29,13),(29,13),(30,13),(30,13),(30,13),(31,13),(31,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(2,10),(3,10),(3,10),(3,10),(4,10),(4,10),(4,10),(4,10),(5,10),(5,10),(5,10),(6,10),(6,11),(6,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,11),(8,11),(9,11),(9,11),(9,11),(10,11),(10,11),(10,11),(11,11),(11,11),(11,11),(12,11),(12,11),(12,11),(12,11),(13,11),(13,11),(13,11),(14,11),(14,11),(14,11),(15,11),(15,11),(15,11),(16,11),(16,11),(16,11),(16,11),(17,11),(17,11),(17,11),(18,11),(18,11),(18,11),(18,11),(19,11),(19,11),(19,11),(20,11),(20,11),(20,11),(20,11),(21,11),(21,11),(21,11),(22,11),(22,11),(23,11),(23,11),(23,11),(24,12),(24,12),(25,12),(25,12),(25,12),(26,12),(26,12),(27,12),(27,12),(27,12),(28,13),(28,13),(29,13),(29,13),(29,13),(30,13),(30,13),(31,13),(31,13),(31,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),(32,12),],
+[(0,10),(0,10),(0,10),(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,11),(2,11),(3,11),(3,11),(3,11),(4,11),(4,11),(4,11),(4,11),(5,11),(5,11),(5,11),(6,11),(6,11),(6,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,11),(8,11),(9,11),(9,11),(9,11),(10,11),(10,11),(10,11),(11,11),(11,11),(11,11),(12,11),(12,11),(12,11),(13,11),(13,11),(13,11),(13,11),(14,11),(14,11),(14,11),(15,11),(15,11),(15,11),(16,11),(16,11),(16,11),(17,11),(17,11),(17,11),(17,11),(18,11),(18,11),(18,11),(19,11),(19,11),(19,11),(19,11),(20,11),(20,11),(20,11),(21,11),(21,11),(21,11),(22,11),(22,11),(22,11),(23,11),(23,12),(24,12),(24,12),(24,12),(25,12),(25,12),(26,12),(26,12),(26,12),(27,13),(27,13),(28,13),(28,13),(28,13),(29,13),(29,13),(30,13),(30,13),(30,13),(31,14),(31,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),(32,12),(32,12),],
+[(0,11),(0,11),(0,11),(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(2,11),(3,11),(3,11),(3,11),(4,11),(4,11),(4,11),(5,11),(5,11),(5,11),(5,11),(6,11),(6,11),(6,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,11),(9,11),(9,11),(9,11),(9,11),(10,11),(10,11),(10,11),(11,11),(11,11),(11,11),(12,11),(12,11),(12,11),(13,11),(13,11),(13,11),(14,11),(14,11),(14,11),(14,11),(15,11),(15,11),(15,11),(16,11),(16,11),(16,11),(17,11),(17,11),(17,11),(17,11),(18,11),(18,11),(18,11),(19,11),(19,11),(19,11),(19,11),(20,11),(20,11),(20,11),(21,11),(21,11),(21,11),(22,11),(22,12),(23,12),(23,12),(23,12),(24,12),(24,12),(25,12),(25,12),(25,12),(26,12),(26,13),(27,13),(27,13),(27,13),(28,13),(28,13),(29,13),(29,13),(29,13),(30,14),(30,14),(31,14),(31,14),(31,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),(32,12),(32,12),],
+[(0,11),(0,11),(0,11),(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(2,11),(3,11),(3,11),(3,11),(4,11),(4,11),(4,11),(5,11),(5,11),(5,11),(5,11),(6,11),(6,11),(6,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,11),(9,11),(9,11),(9,11),(10,11),(10,11),(10,11),(10,11),(11,11),(11,11),(11,11),(12,11),(12,11),(12,11),(13,11),(13,11),(13,11),(14,11),(14,11),(14,11),(15,11),(15,11),(15,11),(16,11),(16,11),(16,11),(16,11),(17,11),(17,11),(17,11),(18,11),(18,11),(18,11),(18,11),(19,11),(19,11),(19,11),(20,11),(20,11),(20,11),(21,12),(21,12),(21,12),(22,12),(22,12),(22,12),(23,12),(23,12),(24,12),(24,12),(24,12),(25,12),(25,13),(26,13),(26,13),(26,13),(27,13),(27,13),(28,13),(28,13),(28,13),(29,13),(29,14),(30,14),(30,14),(30,14),(31,14),(31,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,12),],
+[(0,11),(0,11),(0,11),(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(2,11),(3,11),(3,11),(3,11),(4,11),(4,11),(4,11),(5,11),(5,11),(5,11),(6,11),(6,11),(6,11),(6,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,11),(9,11),(9,11),(9,11),(10,11),(10,11),(10,11),(11,11),(11,11),(11,11),(12,12),(12,12),(12,12),(12,12),(13,12),(13,12),(13,12),(14,12),(14,12),(14,12),(15,12),(15,12),(15,12),(16,12),(16,12),(16,12),(17,12),(17,12),(17,12),(17,12),(18,12),(18,12),(18,12),(19,12),(19,12),(19,12),(19,12),(20,12),(20,12),(20,12),(21,12),(21,12),(21,12),(22,12),(22,12),(23,12),(23,12),(23,12),(24,12),(24,12),(25,13),(25,13),(25,13),(26,13),(26,13),(27,13),(27,13),(27,13),(28,13),(28,14),(29,14),(29,14),(29,14),(30,14),(30,14),(31,14),(31,14),(31,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),],
+[(0,11),(0,11),(0,11),(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(2,11),(3,11),(3,11),(3,11),(4,11),(4,11),(4,11),(5,11),(5,11),(5,11),(6,11),(6,11),(6,11),(7,11),(7,11),(7,11),(7,11),(8,11),(8,11),(8,12),(9,12),(9,12),(9,12),(10,12),(10,12),(10,12),(11,12),(11,12),(11,12),(12,12),(12,12),(12,12),(13,12),(13,12),(13,12),(14,12),(14,12),(14,12),(14,12),(15,12),(15,12),(15,12),(16,12),(16,12),(16,12),(17,12),(17,12),(17,12),(18,12),(18,12),(18,12),(18,12),(19,12),(19,12),(19,12),(20,12),(20,12),(20,12),(21,12),(21,12),(21,12),(22,12),(22,12),(22,12),(23,12),(23,12),(24,13),(24,13),(24,13),(25,13),(25,13),(26,13),(26,13),(26,13),(27,13),(27,13),(28,14),(28,14),(28,14),(29,14),(29,14),(30,14),(30,14),(30,14),(31,14),(31,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),],
+[(0,11),(0,11),(0,11),(0,11),(1,11),(1,11),(1,11),(1,11),(2,11),(2,11),(2,11),(3,11),(3,11),(3,11),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,12),(6,12),(6,12),(7,12),(7,12),(7,12),(8,12),(8,12),(8,12),(8,12),(9,12),(9,12),(9,12),(10,12),(10,12),(10,12),(11,12),(11,12),(11,12),(12,12),(12,12),(12,12),(13,12),(13,12),(13,12),(14,12),(14,12),(14,12),(15,12),(15,12),(15,12),(16,12),(16,12),(16,12),(16,12),(17,12),(17,12),(17,12),(18,12),(18,12),(18,12),(19,12),(19,12),(19,12),(19,12),(20,12),(20,12),(20,12),(21,12),(21,12),(21,12),(22,12),(22,12),(23,13),(23,13),(23,13),(24,13),(24,13),(25,13),(25,13),(25,13),(26,13),(26,13),(27,14),(27,14),(27,14),(28,14),(28,14),(29,14),(29,14),(29,14),(30,14),(30,14),(31,15),(31,15),(31,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),(32,13),],
+[(0,12),(0,12),(0,12),(0,12),(1,12),(1,12),(1,12),(1,12),(2,12),(2,12),(2,12),(3,12),(3,12),(3,12),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,12),(6,12),(6,12),(7,12),(7,12),(7,12),(8,12),(8,12),(8,12),(9,12),(9,12),(9,12),(9,12),(10,12),(10,12),(10,12),(11,12),(11,12),(11,12),(12,12),(12,12),(12,12),(13,12),(13,12),(13,12),(14,12),(14,12),(14,12),(15,12),(15,12),(15,12),(16,12),(16,12),(16,12),(17,12),(17,12),(17,12),(17,12),(18,12),(18,12),(18,12),(19,12),(19,12),(19,12),(20,12),(20,12),(20,12),(21,12),(21,12),(21,13),(22,13),(22,13),(22,13),(23,13),(23,13),(24,13),(24,13),(24,13),(25,13),(25,13),(25,14),(26,14),(26,14),(27,14),(27,14),(27,14),(28,14),(28,14),(29,14),(29,14),(29,15),(30,15),(30,15),(31,15),(31,15),(31,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),(32,13),(32,13),(32,13),],
+[(0,12),(0,12),(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(2,12),(2,12),(3,12),(3,12),(3,12),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,12),(6,12),(6,12),(7,12),(7,12),(7,12),(8,12),(8,12),(8,12),(9,12),(9,12),(9,12),(10,12),(10,12),(10,12),(11,12),(11,12),(11,12),(12,12),(12,12),(12,12),(13,12),(13,12),(13,12),(14,12),(14,12),(14,12),(14,12),(15,12),(15,12),(15,12),(16,12),(16,12),(16,13),(17,13),(17,13),(17,13),(18,13),(18,13),(18,13),(19,13),(19,13),(19,13),(19,13),(20,13),(20,13),(20,13),(21,13),(21,13),(21,13),(22,13),(22,13),(23,13),(23,13),(23,13),(24,13),(24,13),(24,13),(25,14),(25,14),(26,14),(26,14),(26,14),(27,14),(27,14),(28,14),(28,14),(28,14),(29,15),(29,15),(30,15),(30,15),(30,15),(31,15),(31,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,13),(32,13),],
+[(0,12),(0,12),(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(2,12),(2,12),(3,12),(3,12),(3,12),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,12),(6,12),(6,12),(7,12),(7,12),(7,12),(8,12),(8,12),(8,12),(9,12),(9,12),(9,12),(10,12),(10,12),(10,12),(11,12),(11,12),(11,12),(12,12),(12,12),(12,13),(13,13),(13,13),(13,13),(14,13),(14,13),(14,13),(15,13),(15,13),(15,13),(16,13),(16,13),(16,13),(16,13),(17,13),(17,13),(17,13),(18,13),(18,13),(18,13),(19,13),(19,13),(19,13),(20,13),(20,13),(20,13),(21,13),(21,13),(21,13),(22,13),(22,13),(22,13),(23,13),(23,13),(23,13),(24,14),(24,14),(25,14),(25,14),(25,14),(26,14),(26,14),(27,14),(27,14),(27,14),(28,15),(28,15),(29,15),(29,15),(29,15),(30,15),(30,15),(31,15),(31,15),(31,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),],
+[(0,12),(0,12),(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(2,12),(3,12),(3,12),(3,12),(4,12),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,12),(6,12),(6,12),(7,12),(7,12),(7,12),(8,12),(8,12),(8,12),(9,12),(9,12),(9,12),(10,13),(10,13),(10,13),(11,13),(11,13),(11,13),(12,13),(12,13),(12,13),(13,13),(13,13),(13,13),(14,13),(14,13),(14,13),(15,13),(15,13),(15,13),(16,13),(16,13),(16,13),(17,13),(17,13),(17,13),(18,13),(18,13),(18,13),(18,13),(19,13),(19,13),(19,13),(20,13),(20,13),(20,13),(21,13),(21,13),(21,13),(22,13),(22,13),(22,13),(23,14),(23,14),(24,14),(24,14),(24,14),(25,14),(25,14),(26,14),(26,14),(26,14),(27,15),(27,15),(28,15),(28,15),(28,15),(29,15),(29,15),(30,15),(30,15),(30,15),(31,16),(31,16),(31,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),],
+[(0,12),(0,12),(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(2,12),(3,12),(3,12),(3,12),(4,12),(4,12),(4,12),(5,12),(5,12),(5,12),(6,13),(6,13),(6,13),(6,13),(7,13),(7,13),(7,13),(8,13),(8,13),(8,13),(9,13),(9,13),(9,13),(10,13),(10,13),(10,13),(11,13),(11,13),(11,13),(12,13),(12,13),(12,13),(13,13),(13,13),(13,13),(14,13),(14,13),(14,13),(15,13),(15,13),(15,13),(16,13),(16,13),(16,13),(17,13),(17,13),(17,13),(18,13),(18,13),(18,13),(19,13),(19,13),(19,13),(20,13),(20,13),(20,13),(21,13),(21,13),(21,13),(22,13),(22,14),(22,14),(23,14),(23,14),(23,14),(24,14),(24,14),(25,14),(25,14),(25,14),(26,14),(26,15),(27,15),(27,15),(27,15),(28,15),(28,15),(29,15),(29,15),(29,15),(30,15),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),],
+[(0,12),(0,12),(0,12),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(2,13),(3,13),(3,13),(3,13),(4,13),(4,13),(4,13),(5,13),(5,13),(5,13),(6,13),(6,13),(6,13),(7,13),(7,13),(7,13),(8,13),(8,13),(8,13),(8,13),(9,13),(9,13),(9,13),(10,13),(10,13),(10,13),(11,13),(11,13),(11,13),(12,13),(12,13),(13,13),(13,13),(13,13),(14,13),(14,13),(14,13),(15,13),(15,13),(15,13),(16,13),(16,13),(16,13),(17,13),(17,13),(17,13),(17,13),(18,13),(18,13),(18,13),(19,13),(19,13),(19,13),(20,13),(20,13),(20,14),(21,14),(21,14),(21,14),(22,14),(22,14),(22,14),(23,14),(23,14),(24,14),(24,14),(24,14),(25,14),(25,15),(26,15),(26,15),(26,15),(27,15),(27,15),(28,15),(28,15),(28,15),(29,15),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),(32,14),(32,14),],
+[(0,13),(0,13),(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(2,13),(3,13),(3,13),(3,13),(4,13),(4,13),(4,13),(5,13),(5,13),(5,13),(6,13),(6,13),(6,13),(7,13),(7,13),(7,13),(8,13),(8,13),(8,13),(9,13),(9,13),(9,13),(10,13),(10,13),(10,13),(11,13),(11,13),(11,13),(12,13),(12,13),(12,13),(13,13),(13,13),(13,13),(14,13),(14,13),(14,13),(15,13),(15,13),(15,13),(16,13),(16,14),(16,14),(17,14),(17,14),(17,14),(18,14),(18,14),(18,14),(19,14),(19,14),(19,14),(20,14),(20,14),(20,14),(20,14),(21,14),(21,14),(22,14),(22,14),(22,14),(23,14),(23,14),(23,14),(24,14),(24,15),(25,15),(25,15),(25,15),(26,15),(26,15),(27,15),(27,15),(27,15),(28,15),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),(32,14),(32,14),],
+[(0,13),(0,13),(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(2,13),(3,13),(3,13),(3,13),(4,13),(4,13),(4,13),(5,13),(5,13),(5,13),(6,13),(6,13),(6,13),(7,13),(7,13),(7,13),(8,13),(8,13),(8,13),(9,13),(9,13),(9,13),(10,13),(10,13),(10,13),(11,13),(11,13),(11,13),(12,13),(12,13),(12,13),(13,13),(13,14),(13,14),(14,14),(14,14),(14,14),(15,14),(15,14),(15,14),(16,14),(16,14),(16,14),(17,14),(17,14),(17,14),(18,14),(18,14),(18,14),(19,14),(19,14),(19,14),(20,14),(20,14),(20,14),(21,14),(21,14),(21,14),(22,14),(22,14),(22,14),(23,14),(23,15),(24,15),(24,15),(24,15),(25,15),(25,15),(26,15),(26,15),(26,15),(27,15),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,14),(32,14),],
+[(0,13),(0,13),(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(2,13),(3,13),(3,13),(3,13),(4,13),(4,13),(4,13),(5,13),(5,13),(5,13),(6,13),(6,13),(6,13),(7,13),(7,13),(7,13),(8,13),(8,13),(8,13),(9,13),(9,13),(9,13),(10,13),(10,13),(10,13),(11,13),(11,14),(11,14),(12,14),(12,14),(12,14),(13,14),(13,14),(13,14),(14,14),(14,14),(14,14),(15,14),(15,14),(15,14),(16,14),(16,14),(17,14),(17,14),(17,14),(18,14),(18,14),(18,14),(18,14),(19,14),(19,14),(19,14),(20,14),(20,14),(20,14),(21,14),(21,14),(21,14),(22,14),(22,14),(23,15),(23,15),(23,15),(24,15),(24,15),(25,15),(25,15),(25,15),(26,15),(26,15),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),],
+[(0,13),(0,13),(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(2,13),(3,13),(3,13),(3,13),(4,13),(4,13),(4,13),(5,13),(5,13),(5,13),(6,13),(6,13),(6,13),(7,13),(7,14),(7,14),(8,14),(8,14),(8,14),(9,14),(9,14),(9,14),(10,14),(10,14),(10,14),(11,14),(11,14),(11,14),(12,14),(12,14),(12,14),(13,14),(13,14),(14,14),(14,14),(14,14),(15,14),(15,14),(15,14),(16,14),(16,14),(16,14),(17,14),(17,14),(17,14),(18,14),(18,14),(18,14),(19,14),(19,14),(19,14),(20,14),(20,14),(20,14),(21,14),(21,14),(21,15),(22,15),(22,15),(22,15),(23,15),(23,15),(24,15),(24,15),(24,15),(25,15),(25,15),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),],
+[(0,13),(0,13),(0,13),(0,13),(1,13),(1,13),(1,13),(2,14),(2,14),(2,14),(3,14),(3,14),(3,14),(4,14),(4,14),(4,14),(5,14),(5,14),(5,14),(6,14),(6,14),(6,14),(7,14),(7,14),(7,14),(8,14),(8,14),(8,14),(9,14),(9,14),(9,14),(10,14),(10,14),(10,14),(11,14),(11,14),(11,14),(12,14),(12,14),(13,14),(13,14),(13,14),(14,14),(14,14),(14,14),(15,14),(15,14),(15,14),(16,14),(16,14),(16,14),(17,14),(17,14),(17,14),(18,14),(18,14),(18,14),(19,15),(19,15),(19,15),(20,15),(20,15),(20,15),(21,15),(21,15),(21,15),(22,15),(22,15),(23,15),(23,15),(23,15),(24,15),(24,15),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),(32,15),],
+[(0,14),(0,14),(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(2,14),(2,14),(3,14),(3,14),(3,14),(4,14),(4,14),(4,14),(5,14),(5,14),(5,14),(6,14),(6,14),(6,14),(7,14),(7,14),(7,14),(8,14),(8,14),(9,14),(9,14),(9,14),(10,14),(10,14),(10,14),(11,14),(11,14),(11,14),(12,14),(12,14),(12,14),(13,14),(13,14),(13,14),(14,14),(14,14),(14,14),(15,14),(15,14),(15,14),(16,14),(16,15),(16,15),(17,15),(17,15),(17,15),(18,15),(18,15),(19,15),(19,15),(19,15),(20,15),(20,15),(20,15),(21,15),(21,15),(21,15),(22,15),(22,15),(22,15),(23,15),(23,15),(24,15),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),(32,15),(32,15),],
+[(0,14),(0,14),(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(2,14),(2,14),(3,14),(3,14),(3,14),(4,14),(4,14),(4,14),(5,14),(5,14),(5,14),(6,14),(6,14),(7,14),(7,14),(7,14),(8,14),(8,14),(8,14),(9,14),(9,14),(9,14),(10,14),(10,14),(10,14),(11,14),(11,14),(11,14),(12,14),(12,14),(12,14),(13,14),(13,14),(13,14),(14,14),(14,15),(14,15),(15,15),(15,15),(16,15),(16,15),(16,15),(17,15),(17,15),(17,15),(18,15),(18,15),(18,15),(19,15),(19,15),(19,15),(20,15),(20,15),(20,15),(21,15),(21,15),(21,15),(22,15),(22,15),(23,15),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),(32,15),(32,15),],
+[(0,14),(0,14),(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(2,14),(2,14),(3,14),(3,14),(3,14),(4,14),(4,14),(4,14),(5,14),(5,14),(6,14),(6,14),(6,14),(7,14),(7,14),(7,14),(8,14),(8,14),(8,14),(9,14),(9,14),(9,14),(10,14),(10,14),(10,14),(11,14),(11,14),(11,14),(12,15),(12,15),(12,15),(13,15),(13,15),(13,15),(14,15),(14,15),(15,15),(15,15),(15,15),(16,15),(16,15),(16,15),(17,15),(17,15),(17,15),(18,15),(18,15),(18,15),(19,15),(19,15),(19,15),(20,15),(20,15),(20,15),(21,15),(21,15),(22,15),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,15),],
+[(0,14),(0,14),(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(2,14),(2,14),(3,14),(3,14),(3,14),(4,14),(4,14),(4,14),(5,14),(5,14),(6,14),(6,14),(6,14),(7,14),(7,14),(7,14),(8,14),(8,14),(8,14),(9,14),(9,15),(9,15),(10,15),(10,15),(10,15),(11,15),(11,15),(11,15),(12,15),(12,15),(12,15),(13,15),(13,15),(14,15),(14,15),(14,15),(15,15),(15,15),(15,15),(16,15),(16,15),(16,15),(17,15),(17,15),(17,15),(18,15),(18,15),(18,15),(19,15),(19,15),(20,15),(20,15),(20,15),(21,15),(21,16),(21,16),(22,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,14),(0,14),(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(2,14),(2,14),(3,14),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(5,15),(6,15),(6,15),(6,15),(7,15),(7,15),(7,15),(8,15),(8,15),(8,15),(9,15),(9,15),(9,15),(10,15),(10,15),(10,15),(11,15),(11,15),(12,15),(12,15),(12,15),(13,15),(13,15),(13,15),(14,15),(14,15),(14,15),(15,15),(15,15),(15,15),(16,15),(16,15),(16,15),(17,15),(17,15),(17,15),(18,15),(18,15),(19,15),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,15),(0,15),(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(2,15),(2,15),(3,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(5,15),(6,15),(6,15),(6,15),(7,15),(7,15),(7,15),(8,15),(8,15),(8,15),(9,15),(9,15),(9,15),(10,15),(10,15),(11,15),(11,15),(11,15),(12,15),(12,15),(12,15),(13,15),(13,15),(13,15),(14,15),(14,15),(14,15),(15,15),(15,15),(15,15),(16,15),(16,15),(17,15),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,15),(0,15),(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(2,15),(2,15),(3,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(5,15),(6,15),(6,15),(6,15),(7,15),(7,15),(7,15),(8,15),(8,15),(8,15),(9,15),(9,15),(10,15),(10,15),(10,15),(11,15),(11,15),(11,15),(12,15),(12,15),(12,15),(13,15),(13,15),(13,15),(14,15),(14,15),(14,15),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,15),(0,15),(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(2,15),(2,15),(3,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(5,15),(6,15),(6,15),(6,15),(7,15),(7,15),(7,15),(8,15),(8,15),(9,15),(9,15),(9,15),(10,15),(10,15),(10,15),(11,15),(11,15),(11,15),(12,15),(12,15),(12,15),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,15),(0,15),(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(2,15),(2,15),(3,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(5,15),(6,15),(6,15),(6,15),(7,15),(7,15),(7,15),(8,15),(8,15),(9,15),(9,15),(9,15),(10,15),(10,15),(10,15),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,15),(0,15),(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(2,15),(2,15),(3,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(9,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,16),(0,16),(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(2,16),(2,16),(3,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(9,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,16),(0,16),(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(2,16),(2,16),(3,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(9,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,16),(0,16),(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(2,16),(2,16),(3,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(9,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,16),(0,16),(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(2,16),(2,16),(3,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(8,16),(9,16),(9,16),(10,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+[(0,16),(0,16),(0,16),(0,16),(0,16),(1,16),(1,16),(2,16),(2,16),(2,16),(3,16),(3,16),(3,16),(4,16),(4,16),(4,16),(5,16),(5,16),(6,16),(6,16),(6,16),(7,16),(7,16),(7,16),(8,16),(8,16),(8,16),(9,16),(9,16),(10,16),(10,16),(10,16),(10,16),(11,16),(11,16),(11,16),(12,16),(12,16),(12,16),(13,16),(13,16),(13,16),(14,16),(14,16),(14,16),(15,16),(15,16),(15,16),(16,16),(16,16),(16,16),(17,16),(17,16),(17,16),(18,16),(18,16),(18,16),(19,16),(19,16),(19,16),(20,16),(20,16),(20,16),(21,16),(21,16),(21,16),(21,16),(21,16),(21,16),(22,16),(22,16),(22,16),(23,16),(23,16),(23,16),(24,16),(24,16),(24,16),(24,16),(25,16),(25,16),(25,16),(26,16),(26,16),(26,16),(26,16),(27,16),(27,16),(27,16),(28,16),(28,16),(28,16),(28,16),(29,16),(29,16),(29,16),(30,16),(30,16),(30,16),(31,16),(31,16),(31,16),(31,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),(32,16),],
+]
+;
+
+/// For each Virtual (x,y) Coordinate, return the Bounding Box (left, top, right, bottom) that encloses the corresponding Physical (x,y) Coordinates.
+/// Used by the CHIP-8 Emulator to decide which Physical Pixels to redraw when a Virtual Pixel is updated.
+/// Since X and Y are symmetric, this grid only covers one quadrant (X >= 0, Y >= 0)
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+static VIRTUAL_TO_PHYSICAL_MAP: &[[(u8,u8,u8,u8); VIRTUAL_TO_PHYSICAL_MAP_WIDTH]; VIRTUAL_TO_PHYSICAL_MAP_HEIGHT] = &  //  Row=Y, Col=X
+//  Copied from output of https://github.com/lupyuen/interpolate-surface
+[[(0,0,4,6),(5,0,8,6),(9,0,12,6),(13,0,16,6),(17,0,21,6),(22,0,25,6),(26,0,29,6),(29,0,33,6),(33,0,36,6),(37,0,40,6),(42,0,45,6),(46,0,49,6),(49,0,52,6),(53,0,56,6),(56,0,60,6),(60,0,64,6),(64,0,68,6),(67,0,71,6),(71,0,75,6),(74,0,79,6),(77,0,82,6),(81,0,86,6),(85,0,90,6),(88,0,93,5),(92,0,96,5),(95,0,99,5),(99,0,102,5),(102,0,106,5),(106,0,109,5),(110,0,112,4),(113,0,116,4),(116,0,119,4),],
+[(0,7,4,12),(5,7,8,12),(9,7,12,12),(13,7,16,12),(17,7,21,12),(22,7,25,12),(25,7,28,12),(29,7,32,12),(33,7,36,12),(37,7,40,12),(42,7,45,12),(45,7,48,12),(49,7,52,12),(53,7,56,12),(56,7,59,11),(60,7,64,11),(64,7,67,11),(68,7,71,11),(72,7,75,11),(75,7,78,11),(79,7,82,11),(82,7,86,11),(87,6,89,11),(90,6,92,11),(93,6,96,10),(96,6,99,10),(99,6,102,10),(103,6,106,10),(107,5,109,9),(110,5,112,9),(113,5,115,9),(116,5,119,9),],
+[(0,13,4,19),(5,13,8,18),(9,13,12,18),(13,13,16,18),(17,13,21,18),(21,13,24,18),(25,13,28,18),(29,13,32,18),(33,13,36,18),(37,13,40,18),(40,13,44,18),(45,13,48,18),(49,13,52,18),(52,12,55,18),(56,12,59,17),(60,12,63,17),(64,12,67,17),(68,12,71,17),(71,12,74,17),(75,12,78,17),(78,12,81,17),(82,12,86,16),(86,12,89,16),(90,11,92,16),(93,11,95,15),(96,11,98,15),(99,11,102,15),(102,10,106,15),(107,10,109,14),(110,10,112,14),(113,10,115,14),(116,10,118,13),],
+[(0,21,4,26),(5,19,8,26),(9,19,12,26),(13,19,16,26),(16,19,19,26),(21,19,24,26),(25,19,28,26),(29,19,32,25),(32,19,35,25),(36,19,39,25),(40,19,44,25),(45,19,48,25),(48,19,51,25),(52,18,55,25),(56,18,58,24),(59,18,63,24),(64,18,67,24),(67,18,70,24),(71,18,74,23),(75,18,77,23),(78,17,81,23),(82,17,85,23),(86,17,89,23),(89,17,92,22),(92,16,95,22),(96,16,98,21),(99,16,101,21),(102,15,106,21),(106,15,109,19),(109,15,112,19),(113,15,115,18),(116,14,118,18),],
+[(0,27,4,32),(5,27,8,32),(9,27,12,32),(13,27,15,32),(16,27,19,32),(21,27,24,32),(25,26,28,32),(28,26,31,32),(32,26,35,32),(36,26,39,32),(40,26,44,31),(44,26,47,31),(48,26,51,31),(51,25,54,31),(55,25,58,30),(59,25,63,30),(63,25,66,30),(67,25,70,30),(70,24,73,29),(74,24,77,29),(78,24,81,29),(81,24,85,28),(86,23,88,28),(89,23,91,27),(92,22,95,27),(95,22,98,26),(98,22,101,26),(101,21,105,26),(106,21,108,25),(109,21,112,25),(112,19,115,24),(116,19,118,24),],
+[(0,33,4,38),(5,33,8,38),(9,33,12,38),(12,33,15,38),(16,33,19,38),(21,33,24,38),(24,33,27,38),(28,33,31,38),(32,33,35,38),(35,32,39,38),(39,32,43,37),(44,32,47,37),(47,32,50,37),(51,32,54,37),(54,31,57,37),(58,31,61,36),(61,31,66,36),(66,30,69,36),(70,30,73,35),(73,30,77,35),(77,29,80,35),(81,29,85,34),(85,28,88,33),(88,28,91,33),(91,28,94,32),(94,27,97,32),(97,27,100,31),(100,26,103,31),(105,26,108,30),(108,25,111,30),(111,25,114,29),(115,25,118,29),],
+[(0,39,4,46),(5,39,8,45),(9,39,11,45),(12,39,15,45),(16,39,19,45),(19,39,23,45),(24,39,27,45),(27,39,31,45),(31,39,34,45),(35,38,38,44),(38,38,43,44),(43,38,46,44),(46,38,50,44),(50,38,53,44),(53,37,57,44),(57,37,60,44),(61,37,65,43),(65,37,68,43),(69,36,72,43),(72,36,76,43),(76,36,79,42),(79,35,82,42),(82,34,87,40),(87,33,90,39),(90,33,93,38),(93,32,96,37),(96,32,99,36),(99,31,103,36),(103,31,107,35),(107,30,110,35),(110,30,113,34),(113,29,117,34),],
+[(0,47,4,52),(5,46,8,52),(8,46,11,51),(12,46,15,51),(15,46,18,51),(19,46,23,51),(23,46,26,51),(27,46,30,51),(30,45,34,51),(34,45,37,50),(38,45,42,50),(42,45,45,50),(46,45,49,50),(49,45,52,50),(53,45,56,50),(56,44,59,50),(59,44,64,50),(64,44,67,49),(68,44,71,49),(71,43,75,49),(74,43,78,49),(78,42,81,48),(81,40,85,47),(85,39,89,46),(89,38,92,45),(92,37,95,44),(95,37,98,43),(98,36,101,42),(101,36,105,40),(105,35,109,40),(108,35,112,39),(112,34,115,39),],
+[(0,53,4,58),(5,53,7,58),(8,52,11,58),(12,52,14,57),(15,52,18,57),(19,52,22,57),(23,52,26,57),(26,52,29,57),(30,51,33,56),(33,51,36,56),(37,51,40,56),(40,51,44,56),(45,51,48,56),(48,51,51,56),(52,51,55,56),(55,51,58,56),(59,50,63,56),(63,50,66,56),(67,50,70,56),(70,50,73,55),(74,49,77,55),(77,48,80,54),(80,48,84,54),(84,46,87,52),(87,45,90,51),(90,44,94,50),(93,43,97,49),(96,42,100,48),(100,42,103,47),(103,40,107,46),(107,40,110,45),(110,39,114,44),],
+[(0,59,4,65),(5,59,7,65),(8,58,11,65),(11,58,14,64),(15,58,17,64),(18,58,22,64),(22,58,25,64),(26,58,29,64),(29,57,32,63),(33,57,36,63),(36,57,39,63),(40,57,44,63),(44,57,47,63),(47,57,50,63),(51,57,54,63),(54,57,57,63),(58,57,61,61),(61,57,65,61),(66,56,69,63),(69,56,72,63),(72,56,76,63),(76,55,79,61),(79,54,82,60),(82,53,86,59),(86,51,89,57),(89,50,92,56),(92,49,95,55),(95,48,98,53),(98,47,102,52),(101,46,106,51),(106,46,109,50),(109,45,112,49),],
+[(0,66,4,71),(5,66,7,71),(8,66,10,71),(11,65,14,70),(14,65,17,70),(18,65,21,70),(22,65,25,70),(25,64,28,69),(29,64,32,69),(32,64,35,69),(35,64,38,69),(39,64,43,68),(43,64,46,68),(46,64,49,68),(49,64,53,68),(53,63,56,68),(56,63,60,68),(59,63,64,68),(64,63,68,68),(68,64,71,68),(71,63,75,68),(75,63,78,68),(78,60,81,67),(81,59,85,65),(85,57,88,64),(87,56,91,63),(91,55,94,60),(94,54,97,59),(97,53,100,58),(100,52,103,57),(103,51,107,56),(107,50,111,55),],
+[(0,72,4,76),(4,72,7,76),(8,71,10,76),(11,71,13,76),(14,71,17,75),(17,71,21,75),(21,70,24,75),(24,70,27,75),(28,70,31,75),(31,70,34,74),(34,70,37,74),(37,69,42,74),(42,69,45,73),(45,69,48,73),(48,69,51,73),(51,69,55,73),(54,69,58,73),(58,69,63,73),(61,69,66,73),(66,69,70,73),(69,69,73,73),(73,69,76,72),(76,68,79,72),(79,66,82,71),(82,64,86,69),(86,63,89,68),(89,61,92,67),(92,59,95,65),(95,58,98,64),(98,57,101,63),(101,56,106,61),(105,55,109,60),],
+[(0,77,3,82),(4,77,7,81),(7,77,10,81),(10,77,13,81),(13,76,16,81),(16,76,19,81),(21,76,23,80),(24,76,26,80),(27,75,30,80),(30,75,33,80),(33,75,36,79),(36,75,39,79),(39,74,44,79),(43,74,47,78),(46,74,50,78),(50,74,53,78),(53,74,56,78),(56,74,60,77),(60,74,64,77),(64,74,68,77),(67,74,71,77),(70,73,74,77),(74,72,77,76),(77,71,80,75),(80,69,85,74),(84,69,87,73),(87,67,90,72),(90,65,94,70),(93,65,96,69),(96,63,99,68),(99,61,103,67),(102,60,107,65),],
+[(0,82,3,88),(4,82,6,88),(7,82,9,87),(10,82,12,87),(13,82,15,87),(16,82,18,87),(19,81,23,87),(23,81,26,87),(26,81,29,86),(29,81,32,86),(32,80,35,86),(35,80,38,86),(38,79,42,85),(42,79,45,85),(45,79,48,84),(48,79,51,84),(51,78,55,84),(54,78,58,82),(58,78,61,82),(61,78,66,82),(65,78,69,82),(68,77,72,81),(71,77,75,81),(75,76,78,79),(78,75,81,78),(81,73,85,77),(85,72,88,76),(88,71,91,75),(91,69,95,74),(94,68,97,73),(97,67,100,71),(100,66,103,70),],
+[(0,89,3,93),(4,89,6,93),(7,88,9,93),(10,88,12,93),(13,88,15,92),(16,88,18,92),(18,88,22,92),(22,87,25,92),(25,87,28,92),(28,87,31,92),(31,87,34,91),(34,86,37,91),(37,86,40,90),(40,85,44,90),(44,85,47,90),(47,85,50,89),(50,84,53,89),(53,84,56,88),(56,84,59,88),(59,84,63,87),(63,82,67,87),(66,82,70,87),(70,81,73,86),(73,80,76,85),(76,79,79,84),(79,77,84,82),(82,77,86,81),(86,76,89,79),(89,74,92,78),(92,73,95,77),(95,72,98,76),(98,70,102,75),],
+[(0,94,3,98),(4,94,6,98),(7,94,9,98),(10,93,12,98),(13,93,14,98),(15,93,17,98),(18,93,21,97),(22,93,24,97),(25,93,27,97),(27,92,30,97),(30,92,33,97),(33,92,36,96),(36,91,39,96),(39,91,43,95),(43,90,46,95),(46,90,48,94),(49,89,52,94),(51,89,55,94),(55,89,57,93),(57,88,61,93),(60,88,65,92),(64,87,68,92),(67,87,71,91),(70,85,74,90),(73,84,77,89),(77,82,80,87),(80,81,84,86),(84,80,87,85),(87,79,90,84),(90,77,94,82),(93,77,96,81),(96,75,99,79),],
+]
+;
+
+/// Same as PHYSICAL_TO_VIRTUAL_MAP, for the high resolution (128 x 64) Virtual Screen.
+/// Generated from PHYSICAL_TO_VIRTUAL_MAP by `scripts/gen-hires-maps.py`: each low resolution Virtual Pixel is split
+/// into 2 x 2 high resolution Virtual Pixels, by splitting each run of Physical Pixels that map to the same low resolution
+/// Virtual X (along the row) or Virtual Y (along the column) into two halves.
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+static HIRES_PHYSICAL_TO_VIRTUAL_MAP: &[[(u8,u8); PHYSICAL_TO_VIRTUAL_MAP_WIDTH]; PHYSICAL_TO_VIRTUAL_MAP_HEIGHT] = &  //  Row=Y, Col=X
+[[(0,0),(0,0),(1,0),(1,0),(1,0),(2,0),(2,0),(3,0),(3,0),(4,0),(4,0),(5,0),(5,0),(6,0),(6,0),(7,0),(7,0),(8,0),(8,0),(9,0),(9,0),(10,0),(10,0),(11,0),(11,0),(12,0),(12,0),(13,0),(13,0),(14,0),(14,0),(15,0),(15,0),(16,0),(17,0),(17,0),(18,0),(18,0),(19,0),(19,0),(20,0),(20,0),(21,0),(21,0),(22,0),(23,0),(23,0),(24,0),(24,0),(25,0),(25,0),(26,0),(27,0),(27,0),(28,0),(28,0),(29,0),(29,0),(30,0),(31,0),(31,0),(32,0),(33,0),(33,0),(34,0),(34,0),(35,0),(35,0),(36,0),(37,0),(37,0),(38,0),(39,0),(39,0),(40,0),(40,0),(41,0),(41,0),(42,0),(43,0),(43,0),(44,0),(45,0),(45,0),(46,0),(46,0),(47,0),(47,0),(48,0),(49,0),(49,0),(50,0),(50,0),(51,0),(51,0),(52,0),(53,0),(53,0),(54,0),(55,0),(55,0),(56,0),(56,0),(57,0),(57,0),(58,0),(59,0),(59,0),(60,0),(61,0),(61,0),(62,0),(62,0),(63,0),(63,0),(64,0),(64,0),(64,0),(64,0),(64,0),],
+[(0,0),(0,0),(1,0),(1,0),(1,0),(2,0),(2,0),(3,0),(3,0),(4,0),(4,0),(5,0),(5,0),(6,0),(6,0),(7,0),(7,0),(8,0),(8,0),(9,0),(9,0),(10,0),(10,0),(11,0),(11,0),(12,0),(12,0),(13,0),(13,0),(14,0),(14,0),(15,0),(15,0),(16,0),(17,0),(17,0),(18,0),(18,0),(19,0),(19,0),(20,0),(20,0),(21,0),(21,0),(22,0),(22,0),(23,0),(23,0),(24,0),(25,0),(25,0),(26,0),(26,0),(27,0),(27,0),(28,0),(28,0),(29,0),(29,0),(30,0),(31,0),(31,0),(32,0),(32,0),(33,0),(33,0),(34,0),(35,0),(35,0),(36,0),(36,0),(37,0),(37,0),(38,0),(38,0),(39,0),(39,0),(40,0),(41,0),(41,0),(42,0),(43,0),(43,0),(44,0),(44,0),(45,0),(45,0),(46,0),(47,0),(47,0),(48,0),(49,0),(49,0),(50,0),(51,0),(51,0),(52,0),(53,0),(53,0),(54,0),(55,0),(55,0),(56,0),(57,0),(57,0),(58,0),(59,0),(59,0),(60,0),(60,0),(61,0),(61,0),(62,0),(63,0),(63,0),(64,0),(64,0),(64,0),(64,0),(64,0),],
+[(0,0),(0,0),(1,0),(1,0),(1,0),(2,0),(2,0),(3,0),(3,0),(4,0),(4,0),(5,0),(5,0),(6,0),(6,0),(7,0),(7,0),(8,0),(8,0),(9,0),(9,0),(10,0),(10,0),(11,0),(11,0),(12,0),(12,0),(13,0),(13,0),(14,0),(14,0),(15,0),(15,0),(16,0),(17,0),(17,0),(18,0),(18,0),(19,0),(19,0),(20,0),(20,0),(21,0),(21,0),(22,0),(22,0),(23,0),(23,0),(24,0),(25,0),(25,0),(26,0),(26,0),(27,0),(27,0),(28,0),(29,0),(29,0),(30,0),(30,0),(31,0),(31,0),(32,0),(32,0),(33,0),(33,0),(34,0),(35,0),(35,0),(36,0),(36,0),(37,0),(37,0),(38,0),(38,0),(39,0),(39,0),(40,0),(41,0),(41,0),(42,0),(43,0),(43,0),(44,0),(44,0),(45,0),(45,0),(46,0),(47,0),(47,0),(48,0),(49,0),(49,0),(50,0),(51,0),(51,0),(52,0),(53,0),(53,0),(54,0),(55,0),(55,0),(56,0),(57,1),(57,1),(58,1),(59,1),(59,1),(60,1),(60,1),(61,1),(61,1),(62,1),(63,1),(63,1),(64,1),(64,1),(64,1),(64,1),(64,1),],
+[(0,1),(0,1),(1,1),(1,1),(1,1),(2,1),(2,1),(3,1),(3,1),(4,1),(4,1),(5,1),(5,1),(6,1),(6,1),(7,1),(7,1),(8,1),(8,1),(9,1),(9,1),(10,1),(10,1),(11,1),(11,1),(12,1),(12,1),(13,1),(13,1),(14,1),(15,1),(15,1),(16,1),(16,1),(17,1),(17,1),(18,1),(18,1),(19,1),(19,1),(20,1),(20,1),(21,1),(21,1),(22,1),(22,1),(23,1),(23,1),(24,1),(25,1),(25,1),(26,1),(26,1),(27,1),(27,1),(28,1),(29,1),(29,1),(30,1),(30,1),(31,1),(31,1),(32,1),(32,1),(33,1),(33,1),(34,1),(35,1),(35,1),(36,1),(36,1),(37,1),(37,1),(38,1),(39,1),(39,1),(40,1),(40,1),(41,1),(41,1),(42,1),(43,1),(43,1),(44,1),(45,1),(45,1),(46,1),(46,1),(47,1),(47,1),(48,1),(49,1),(49,1),(50,1),(51,1),(51,1),(52,1),(53,1),(53,1),(54,1),(55,1),(55,1),(56,1),(57,1),(57,1),(58,1),(59,1),(59,1),(60,1),(60,1),(61,1),(61,1),(62,1),(63,1),(63,1),(64,1),(64,1),(64,1),(64,1),(64,1),],
+[(0,1),(0,1),(1,1),(1,1),(1,1),(2,1),(2,1),(3,1),(3,1),(4,1),(4,1),(5,1),(5,1),(6,1),(6,1),(7,1),(7,1),(8,1),(8,1),(9,1),(9,1),(10,1),(10,1),(11,1),(11,1),(12,1),(12,1),(13,1),(13,1),(14,1),(15,1),(15,1),(16,1),(16,1),(17,1),(17,1),(18,1),(18,1),(19,1),(19,1),(20,1),(20,1),(21,1),(21,1),(22,1),(22,1),(23,1),(23,1),(24,1),(25,1),(25,1),(26,1),(26,1),(27,1),(27,1),(28,1),(29,1),(29,1),(30,1),(30,1),(31,1),(31,1),(32,1),(32,1),(33,1),(33,1),(34,1),(35,1),(35,1),(36,1),(36,1),(37,1),(37,1),(38,1),(39,1),(39,1),(40,1),(40,1),(41,1),(41,1),(42,1),(43,1),(43,1),(44,1),(45,1),(45,1),(46,1),(46,1),(47,1),(47,1),(48,1),(49,1),(49,1),(50,1),(51,1),(51,1),(52,1),(53,1),(53,1),(54,1),(55,1),(55,1),(56,1),(57,1),(57,1),(58,1),(59,1),(59,1),(60,1),(61,1),(61,1),(62,1),(62,1),(63,1),(63,1),(64,1),(64,1),(64,1),(64,1),(64,1),],
+[(0,1),(0,1),(1,1),(1,1),(1,1),(2,1),(2,1),(3,1),(3,1),(4,1),(4,1),(5,1),(5,1),(6,1),(6,1),(7,1),(7,1),(8,1),(8,1),(9,1),(9,1),(10,1),(10,1),(11,1),(11,1),(12,1),(12,1),(13,1),(13,1),(14,1),(15,1),(15,1),(16,1),(16,1),(17,1),(17,1),(18,1),(18,1),(19,1),(19,1),(20,1),(20,1),(21,1),(21,1),(22,1),(23,1),(23,1),(24,1),(24,1),(25,1),(25,1),(26,1),(26,1),(27,1),(27,1),(28,1),(29,1),(29,1),(30,1),(30,1),(31,1),(31,1),(32,1),(33,1),(33,1),(34,1),(34,1),(35,1),(35,1),(36,1),(36,1),(37,1),(37,1),(38,1),(39,1),(39,1),(40,1),(40,1),(41,1),(41,1),(42,1),(43,1),(43,1),(44,1),(45,1),(45,1),(46,1),(46,1),(47,1),(47,1),(48,1),(49,1),(49,1),(50,1),(51,1),(51,1),(52,1),(53,1),(53,1),(54,1),(55,1),(55,1),(56,1),(57,2),(57,2),(58,2),(59,2),(59,2),(60,2),(61,2),(61,2),(62,2),(62,2),(63,2),(63,2),(64,2),(64,2),(64,2),(64,2),(64,2),],
+[(0,1),(0,1),(1,1),(1,1),(1,1),(2,1),(2,1),(3,1),(3,1),(4,1),(4,1),(5,1),(5,1),(6,1),(6,1),(7,1),(7,1),(8,1),(8,1),(9,1),(9,1),(10,1),(10,1),(11,1),(11,1),(12,1),(13,1),(13,1),(14,1),(14,1),(15,1),(15,1),(16,1),(16,1),(17,1),(17,1),(18,1),(18,1),(19,1),(19,1),(20,1),(20,1),(21,1),(21,1),(22,1),(23,1),(23,1),(24,1),(24,1),(25,1),(25,1),(26,1),(26,1),(27,1),(27,1),(28,1),(29,1),(29,1),(30,1),(30,1),(31,1),(31,1),(32,1),(33,1),(33,1),(34,1),(34,1),(35,1),(35,1),(36,1),(36,1),(37,1),(37,1),(38,1),(39,1),(39,1),(40,1),(40,1),(41,1),(41,1),(42,1),(43,1),(43,1),(44,1),(45,2),(45,2),(46,2),(47,2),(47,2),(48,2),(48,2),(49,2),(49,2),(50,2),(51,2),(51,2),(52,2),(53,2),(53,2),(54,2),(55,2),(55,2),(56,2),(57,2),(57,2),(58,2),(59,2),(59,2),(60,2),(61,2),(61,2),(62,2),(62,2),(63,2),(63,2),(64,2),(64,2),(64,2),(64,2),(64,2),],
+[(0,2),(0,2),(1,2),(1,2),(1,2),(2,2),(2,2),(3,2),(3,2),(4,2),(4,2),(5,2),(5,2),(6,2),(6,2),(7,2),(7,2),(8,2),(8,2),(9,2),(9,2),(10,2),(10,2),(11,2),(11,2),(12,2),(13,2),(13,2),(14,2),(14,2),(15,2),(15,2),(16,2),(16,2),(17,2),(17,2),(18,2),(18,2),(19,2),(19,2),(20,2),(20,2),(21,2),(21,2),(22,2),(23,2),(23,2),(24,2),(24,2),(25,2),(25,2),(26,2),(26,2),(27,2),(27,2),(28,2),(29,2),(29,2),(30,2),(30,2),(31,2),(31,2),(32,2),(33,2),(33,2),(34,2),(34,2),(35,2),(35,2),(36,2),(36,2),(37,2),(37,2),(38,2),(39,2),(39,2),(40,2),(40,2),(41,2),(41,2),(42,2),(43,2),(43,2),(44,2),(45,2),(45,2),(46,2),(47,2),(47,2),(48,2),(48,2),(49,2),(49,2),(50,2),(51,2),(51,2),(52,2),(53,2),(53,2),(54,2),(55,2),(55,2),(56,2),(57,3),(57,3),(58,3),(59,3),(59,3),(60,3),(61,3),(61,3),(62,3),(63,3),(63,3),(64,3),(64,3),(64,3),(64,3),(64,3),(64,3),],
+[(0,2),(0,2),(1,2),(1,2),(1,2),(2,2),(2,2),(3,2),(3,2),(4,2),(4,2),(5,2),(5,2),(6,2),(6,2),(7,2),(7,2),(8,2),(8,2),(9,2),(9,2),(10,2),(10,2),(11,2),(11,2),(12,2),(13,2),(13,2),(14,2),(14,2),(15,2),(15,2),(16,2),(16,2),(17,2),(17,2),(18,2),(18,2),(19,2),(19,2),(20,2),(20,2),(21,2),(21,2),(22,2),(23,2),(23,2),(24,2),(24,2),(25,2),(25,2),(26,2),(27,2),(27,2),(28,2),(28,2),(29,2),(29,2),(30,2),(30,2),(31,2),(31,2),(32,2),(33,2),(33,2),(34,2),(34,2),(35,2),(35,2),(36,2),(37,2),(37,2),(38,2),(38,2),(39,2),(39,2),(40,2),(40,2),(41,2),(41,2),(42,2),(43,2),(43,2),(44,2),(45,2),(45,2),(46,2),(47,3),(47,3),(48,3),(49,3),(49,3),(50,3),(50,3),(51,3),(51,3),(52,3),(53,3),(53,3),(54,3),(55,3),(55,3),(56,3),(57,3),(57,3),(58,3),(59,3),(59,3),(60,3),(61,3),(61,3),(62,3),(63,3),(63,3),(64,3),(64,3),(64,3),(64,3),(64,3),(64,3),],
+[(0,2),(0,2),(1,2),(1,2),(1,2),(2,2),(2,2),(3,2),(3,2),(4,2),(4,2),(5,2),(5,2),(6,2),(6,2),(7,2),(7,2),(8,2),(8,2),(9,2),(9,2),(10,2),(11,2),(11,2),(12,2),(12,2),(13,2),(13,2),(14,2),(14,2),(15,2),(15,2),(16,2),(16,2),(17,2),(17,2),(18,2),(18,2),(19,2),(19,2),(20,2),(20,2),(21,2),(21,2),(22,2),(23,2),(23,2),(24,2),(24,2),(25,2),(25,2),(26,2),(27,2),(27,3),(28,3),(28,3),(29,3),(29,3),(30,3),(30,3),(31,3),(31,3),(32,3),(33,3),(33,3),(34,3),(34,3),(35,3),(35,3),(36,3),(37,3),(37,3),(38,3),(38,3),(39,3),(39,3),(40,3),(40,3),(41,3),(41,3),(42,3),(43,3),(43,3),(44,3),(45,3),(45,3),(46,3),(47,3),(47,3),(48,3),(49,3),(49,3),(50,3),(50,3),(51,3),(51,3),(52,3),(53,3),(53,3),(54,3),(55,3),(55,3),(56,3),(57,3),(57,3),(58,3),(59,3),(59,3),(60,3),(61,3),(61,3),(62,3),(63,3),(63,3),(64,3),(64,3),(64,3),(64,3),(64,3),(64,3),],
+[(0,3),(0,3),(1,3),(1,3),(1,3),(2,3),(2,3),(3,3),(3,3),(4,3),(4,3),(5,3),(5,3),(6,3),(6,3),(7,3),(7,3),(8,3),(8,3),(9,3),(9,3),(10,3),(11,3),(11,3),(12,3),(12,3),(13,3),(13,3),(14,3),(14,3),(15,3),(15,3),(16,3),(16,3),(17,3),(17,3),(18,3),(18,3),(19,3),(19,3),(20,3),(20,3),(21,3),(21,3),(22,3),(23,3),(23,3),(24,3),(24,3),(25,3),(25,3),(26,3),(27,3),(27,3),(28,3),(28,3),(29,3),(29,3),(30,3),(30,3),(31,3),(31,3),(32,3),(33,3),(33,3),(34,3),(34,3),(35,3),(35,3),(36,3),(37,3),(37,3),(38,3),(38,3),(39,3),(39,3),(40,3),(41,3),(41,3),(42,3),(42,3),(43,3),(43,3),(44,3),(45,3),(45,3),(46,3),(47,3),(47,3),(48,3),(49,3),(49,3),(50,3),(51,3),(51,3),(52,3),(52,3),(53,3),(53,3),(54,3),(55,4),(55,4),(56,4),(57,4),(57,4),(58,4),(59,4),(59,4),(60,4),(61,4),(61,4),(62,4),(63,4),(63,4),(64,4),(64,4),(64,4),(64,4),(64,4),(64,4),],
+[(0,3),(0,3),(1,3),(1,3),(1,3),(2,3),(2,3),(3,3),(3,3),(4,3),(4,3),(5,3),(5,3),(6,3),(6,3),(7,3),(7,3),(8,3),(8,3),(9,3),(9,3),(10,3),(11,3),(11,3),(12,3),(12,3),(13,3),(13,3),(14,3),(14,3),(15,3),(15,3),(16,3),(16,3),(17,3),(17,3),(18,3),(18,3),(19,3),(19,3),(20,3),(21,3),(21,3),(22,3),(22,3),(23,3),(23,3),(24,3),(24,3),(25,3),(25,3),(26,3),(27,3),(27,3),(28,3),(28,3),(29,3),(29,3),(30,3),(31,3),(31,3),(32,3),(32,3),(33,3),(33,3),(34,3),(34,3),(35,3),(35,3),(36,3),(37,3),(37,3),(38,3),(38,3),(39,3),(39,3),(40,3),(41,3),(41,3),(42,3),(42,3),(43,3),(43,3),(44,3),(45,3),(45,3),(46,3),(47,4),(47,4),(48,4),(49,4),(49,4),(50,4),(51,4),(51,4),(52,4),(52,4),(53,4),(53,4),(54,4),(55,4),(55,4),(56,4),(57,4),(57,4),(58,4),(59,4),(59,4),(60,4),(61,4),(61,4),(62,4),(63,4),(63,4),(64,4),(64,4),(64,4),(64,4),(64,4),(64,4),],
+[(0,3),(0,3),(1,3),(1,3),(1,3),(2,3),(2,3),(3,3),(3,3),(4,3),(4,3),(5,3),(5,3),(6,3),(6,3),(7,3),(7,3),(8,3),(8,3),(9,3),(9,3),(10,3),(11,3),(11,3),(12,3),(12,3),(13,3),(13,3),(14,3),(14,3),(15,3),(15,3),(16,3),(16,3),(17,3),(17,3),(18,3),(18,3),(19,3),(19,3),(20,3),(21,3),(21,3),(22,3),(22,3),(23,3),(23,3),(24,3),(24,3),(25,3),(25,3),(26,3),(27,3),(27,4),(28,4),(28,4),(29,4),(29,4),(30,4),(31,4),(31,4),(32,4),(32,4),(33,4),(33,4),(34,4),(34,4),(35,4),(35,4),(36,4),(37,4),(37,4),(38,4),(38,4),(39,4),(39,4),(40,4),(41,4),(41,4),(42,4),(42,4),(43,4),(43,4),(44,4),(45,4),(45,4),(46,4),(47,4),(47,4),(48,4),(49,4),(49,4),(50,4),(51,4),(51,4),(52,4),(52,4),(53,4),(53,4),(54,4),(55,5),(55,5),(56,5),(57,5),(57,5),(58,5),(59,5),(59,5),(60,5),(61,5),(61,5),(62,5),(63,5),(63,5),(64,5),(64,5),(64,5),(64,5),(64,5),(64,5),],
+[(0,4),(0,4),(1,4),(1,4),(1,4),(2,4),(2,4),(3,4),(3,4),(4,4),(4,4),(5,4),(5,4),(6,4),(6,4),(7,4),(7,4),(8,4),(8,4),(9,4),(9,4),(10,4),(11,4),(11,4),(12,4),(12,4),(13,4),(13,4),(14,4),(14,4),(15,4),(15,4),(16,4),(16,4),(17,4),(17,4),(18,4),(18,4),(19,4),(19,4),(20,4),(21,4),(21,4),(22,4),(22,4),(23,4),(23,4),(24,4),(24,4),(25,4),(25,4),(26,4),(27,4),(27,4),(28,4),(28,4),(29,4),(29,4),(30,4),(31,4),(31,4),(32,4),(32,4),(33,4),(33,4),(34,4),(34,4),(35,4),(35,4),(36,4),(37,4),(37,4),(38,4),(38,4),(39,4),(39,4),(40,4),(41,4),(41,4),(42,4),(42,4),(43,4),(43,4),(44,4),(45,4),(45,4),(46,4),(47,4),(47,4),(48,5),(49,5),(49,5),(50,5),(51,5),(51,5),(52,5),(53,5),(53,5),(54,5),(54,5),(55,5),(55,5),(56,5),(57,5),(57,5),(58,5),(59,5),(59,5),(60,5),(61,5),(61,5),(62,5),(63,5),(63,5),(64,5),(64,5),(64,5),(64,5),(64,5),(64,5),],
+[(0,4),(0,4),(1,4),(1,4),(1,4),(2,4),(2,4),(3,4),(3,4),(4,4),(4,4),(5,4),(5,4),(6,4),(6,4),(7,4),(7,4),(8,4),(8,4),(9,4),(9,4),(10,4),(11,4),(11,4),(12,4),(12,4),(13,4),(13,4),(14,4),(14,4),(15,4),(15,4),(16,4),(16,4),(17,4),(17,4),(18,4),(18,4),(19,4),(19,4),(20,4),(21,4),(21,4),(22,4),(22,4),(23,4),(23,4),(24,4),(24,4),(25,4),(25,4),(26,4),(27,4),(27,4),(28,4),(28,4),(29,4),(29,4),(30,4),(31,4),(31,4),(32,4),(32,4),(33,4),(33,4),(34,4),(35,4),(35,4),(36,4),(36,4),(37,4),(37,4),(38,4),(38,4),(39,4),(39,4),(40,4),(41,4),(41,5),(42,5),(42,5),(43,5),(43,5),(44,5),(45,5),(45,5),(46,5),(47,5),(47,5),(48,5),(49,5),(49,5),(50,5),(51,5),(51,5),(52,5),(53,5),(53,5),(54,5),(54,5),(55,5),(55,5),(56,5),(57,5),(57,5),(58,5),(59,5),(59,5),(60,5),(61,5),(61,5),(62,6),(63,6),(63,6),(64,6),(64,6),(64,6),(64,6),(64,6),(64,5),],
+[(0,4),(0,4),(1,4),(1,4),(1,4),(2,4),(2,4),(3,4),(3,4),(4,4),(4,4),(5,4),(5,4),(6,4),(6,4),(7,4),(7,4),(8,4),(9,4),(9,4),(10,4),(10,4),(11,4),(11,4),(12,4),(12,4),(13,4),(13,4),(14,4),(14,4),(15,4),(15,4),(16,4),(16,4),(17,4),(17,4),(18,4),(19,4),(19,4),(20,4),(20,4),(21,4),(21,4),(22,4),(22,4),(23,4),(23,4),(24,4),(25,4),(25,4),(26,4),(26,4),(27,5),(27,5),(28,5),(28,5),(29,5),(29,5),(30,5),(31,5),(31,5),(32,5),(32,5),(33,5),(33,5),(34,5),(35,5),(35,5),(36,5),(36,5),(37,5),(37,5),(38,5),(38,5),(39,5),(39,5),(40,5),(41,5),(41,5),(42,5),(43,5),(43,5),(44,5),(44,5),(45,5),(45,5),(46,5),(47,5),(47,5),(48,5),(49,5),(49,5),(50,5),(51,5),(51,5),(52,5),(53,5),(53,5),(54,5),(54,6),(55,6),(55,6),(56,6),(57,6),(57,6),(58,6),(59,6),(59,6),(60,6),(61,6),(61,6),(62,6),(63,6),(63,6),(64,6),(64,6),(64,6),(64,6),(64,6),(64,6),],
+[(0,5),(0,5),(1,5),(1,5),(1,5),(2,5),(2,5),(3,5),(3,5),(4,5),(4,5),(5,5),(5,5),(6,5),(6,5),(7,5),(7,5),(8,5),(9,5),(9,5),(10,5),(10,5),(11,5),(11,5),(12,5),(12,5),(13,5),(13,5),(14,5),(14,5),(15,5),(15,5),(16,5),(16,5),(17,5),(17,5),(18,5),(19,5),(19,5),(20,5),(20,5),(21,5),(21,5),(22,5),(22,5),(23,5),(23,5),(24,5),(25,5),(25,5),(26,5),(26,5),(27,5),(27,5),(28,5),(28,5),(29,5),(29,5),(30,5),(31,5),(31,5),(32,5),(32,5),(33,5),(33,5),(34,5),(35,5),(35,5),(36,5),(36,5),(37,5),(37,5),(38,5),(38,5),(39,5),(39,5),(40,5),(41,5),(41,5),(42,5),(43,5),(43,5),(44,5),(44,5),(45,5),(45,5),(46,5),(47,5),(47,5),(48,6),(49,6),(49,6),(50,6),(51,6),(51,6),(52,6),(53,6),(53,6),(54,6),(55,6),(55,6),(56,6),(56,6),(57,6),(57,6),(58,6),(59,6),(59,6),(60,6),(61,6),(61,6),(62,7),(63,7),(63,7),(64,7),(64,7),(64,7),(64,7),(64,7),(64,6),],
+[(0,5),(0,5),(1,5),(1,5),(1,5),(2,5),(2,5),(3,5),(3,5),(4,5),(4,5),(5,5),(5,5),(6,5),(6,5),(7,5),(7,5),(8,5),(9,5),(9,5),(10,5),(10,5),(11,5),(11,5),(12,5),(12,5),(13,5),(13,5),(14,5),(14,5),(15,5),(15,5),(16,5),(16,5),(17,5),(17,5),(18,5),(19,5),(19,5),(20,5),(20,5),(21,5),(21,5),(22,5),(22,5),(23,5),(23,5),(24,5),(25,5),(25,5),(26,5),(26,5),(27,5),(27,5),(28,5),(28,5),(29,5),(29,5),(30,5),(31,5),(31,5),(32,5),(32,5),(33,5),(33,5),(34,5),(35,5),(35,5),(36,5),(36,5),(37,5),(37,5),(38,5),(39,5),(39,5),(40,5),(40,5),(41,5),(41,6),(42,6),(43,6),(43,6),(44,6),(44,6),(45,6),(45,6),(46,6),(47,6),(47,6),(48,6),(49,6),(49,6),(50,6),(51,6),(51,6),(52,6),(53,6),(53,6),(54,6),(55,7),(55,7),(56,7),(56,7),(57,7),(57,7),(58,7),(59,7),(59,7),(60,7),(61,7),(61,7),(62,7),(63,7),(63,7),(64,7),(64,7),(64,7),(64,7),(64,7),(64,7),],
+[(0,5),(0,5),(1,5),(1,5),(1,5),(2,5),(2,5),(3,5),(3,5),(4,5),(4,5),(5,5),(5,5),(6,5),(6,5),(7,5),(7,5),(8,5),(9,5),(9,5),(10,5),(10,5),(11,5),(11,5),(12,5),(12,5),(13,5),(13,5),(14,5),(14,5),(15,5),(15,5),(16,5),(16,5),(17,5),(17,5),(18,5),(19,5),(19,5),(20,5),(20,5),(21,5),(21,5),(22,5),(22,5),(23,5),(23,5),(24,5),(25,5),(25,5),(26,5),(26,5),(27,6),(27,6),(28,6),(29,6),(29,6),(30,6),(30,6),(31,6),(31,6),(32,6),(32,6),(33,6),(33,6),(34,6),(35,6),(35,6),(36,6),(36,6),(37,6),(37,6),(38,6),(39,6),(39,6),(40,6),(40,6),(41,6),(41,6),(42,6),(43,6),(43,6),(44,6),(44,6),(45,6),(45,6),(46,6),(47,6),(47,6),(48,6),(49,7),(49,7),(50,7),(51,7),(51,7),(52,7),(53,7),(53,7),(54,7),(55,7),(55,7),(56,7),(56,7),(57,7),(57,7),(58,7),(59,7),(59,7),(60,7),(61,7),(61,7),(62,7),(63,7),(63,7),(64,7),(64,7),(64,7),(64,7),(64,7),(64,7),],
+[(0,5),(0,5),(1,5),(1,5),(1,5),(2,6),(2,6),(3,6),(3,6),(4,6),(4,6),(5,6),(5,6),(6,6),(6,6),(7,6),(7,6),(8,6),(9,6),(9,6),(10,6),(10,6),(11,6),(11,6),(12,6),(12,6),(13,6),(13,6),(14,6),(14,6),(15,6),(15,6),(16,6),(17,6),(17,6),(18,6),(18,6),(19,6),(19,6),(20,6),(20,6),(21,6),(21,6),(22,6),(22,6),(23,6),(23,6),(24,6),(25,6),(25,6),(26,6),(26,6),(27,6),(27,6),(28,6),(29,6),(29,6),(30,6),(30,6),(31,6),(31,6),(32,6),(32,6),(33,6),(33,6),(34,6),(35,6),(35,6),(36,6),(36,6),(37,6),(37,6),(38,6),(39,6),(39,6),(40,6),(40,6),(41,6),(41,6),(42,6),(43,6),(43,6),(44,6),(45,7),(45,7),(46,7),(46,7),(47,7),(47,7),(48,7),(49,7),(49,7),(50,7),(51,7),(51,7),(52,7),(53,7),(53,7),(54,7),(55,7),(55,7),(56,7),(57,7),(57,7),(58,7),(58,7),(59,7),(59,7),(60,8),(61,8),(61,8),(62,8),(63,8),(63,8),(64,8),(64,8),(64,8),(64,8),(64,8),(64,8),],
+[(0,6),(0,6),(1,6),(1,6),(1,6),(2,6),(2,6),(3,6),(3,6),(4,6),(4,6),(5,6),(5,6),(6,6),(6,6),(7,6),(7,6),(8,6),(9,6),(9,6),(10,6),(10,6),(11,6),(11,6),(12,6),(12,6),(13,6),(13,6),(14,6),(14,6),(15,6),(15,6),(16,6),(17,6),(17,6),(18,6),(18,6),(19,6),(19,6),(20,6),(20,6),(21,6),(21,6),(22,6),(22,6),(23,6),(23,6),(24,6),(25,6),(25,6),(26,6),(26,6),(27,6),(27,6),(28,6),(29,6),(29,6),(30,6),(30,6),(31,6),(31,6),(32,6),(33,6),(33,6),(34,6),(34,6),(35,6),(35,6),(36,7),(36,7),(37,7),(37,7),(38,7),(39,7),(39,7),(40,7),(40,7),(41,7),(41,7),(42,7),(43,7),(43,7),(44,7),(45,7),(45,7),(46,7),(46,7),(47,7),(47,7),(48,7),(49,7),(49,7),(50,7),(51,7),(51,7),(52,7),(53,7),(53,7),(54,7),(55,8),(55,8),(56,8),(57,8),(57,8),(58,8),(58,8),(59,8),(59,8),(60,8),(61,8),(61,8),(62,8),(63,8),(63,8),(64,8),(64,8),(64,8),(64,8),(64,8),(64,8),],
+[(0,6),(0,6),(1,6),(1,6),(1,6),(2,6),(2,6),(3,6),(3,6),(4,6),(4,6),(5,6),(5,6),(6,6),(6,6),(7,6),(7,6),(8,6),(9,6),(9,6),(10,6),(10,6),(11,6),(11,6),(12,6),(12,6),(13,6),(13,6),(14,6),(14,6),(15,6),(15,6),(16,6),(17,6),(17,6),(18,6),(18,6),(19,6),(19,6),(20,6),(20,6),(21,6),(21,6),(22,6),(23,6),(23,6),(24,6),(24,6),(25,6),(25,6),(26,6),(26,6),(27,7),(27,7),(28,7),(29,7),(29,7),(30,7),(30,7),(31,7),(31,7),(32,7),(33,7),(33,7),(34,7),(34,7),(35,7),(35,7),(36,7),(36,7),(37,7),(37,7),(38,7),(39,7),(39,7),(40,7),(40,7),(41,7),(41,7),(42,7),(43,7),(43,7),(44,7),(45,7),(45,7),(46,7),(47,7),(47,7),(48,7),(48,7),(49,8),(49,8),(50,8),(51,8),(51,8),(52,8),(53,8),(53,8),(54,8),(55,8),(55,8),(56,8),(57,8),(57,8),(58,8),(59,8),(59,8),(60,8),(60,9),(61,9),(61,9),(62,9),(63,9),(63,9),(64,9),(64,9),(64,9),(64,9),(64,9),(64,9),],
+[(0,6),(0,6),(1,6),(1,6),(1,6),(2,7),(2,7),(3,7),(3,7),(4,7),(4,7),(5,7),(5,7),(6,7),(6,7),(7,7),(7,7),(8,7),(9,7),(9,7),(10,7),(10,7),(11,7),(11,7),(12,7),(12,7),(13,7),(13,7),(14,7),(14,7),(15,7),(15,7),(16,7),(17,7),(17,7),(18,7),(18,7),(19,7),(19,7),(20,7),(20,7),(21,7),(21,7),(22,7),(23,7),(23,7),(24,7),(24,7),(25,7),(25,7),(26,7),(26,7),(27,7),(27,7),(28,7),(29,7),(29,7),(30,7),(30,7),(31,7),(31,7),(32,7),(33,7),(33,7),(34,7),(34,7),(35,7),(35,7),(36,7),(36,7),(37,7),(37,7),(38,7),(39,7),(39,7),(40,7),(40,7),(41,7),(41,7),(42,7),(43,7),(43,7),(44,7),(45,8),(45,8),(46,8),(47,8),(47,8),(48,8),(49,8),(49,8),(50,8),(50,8),(51,8),(51,8),(52,8),(53,8),(53,8),(54,8),(55,9),(55,9),(56,9),(57,9),(57,9),(58,9),(59,9),(59,9),(60,9),(60,9),(61,9),(61,9),(62,9),(63,9),(63,9),(64,9),(64,9),(64,9),(64,9),(64,9),(64,9),],
+[(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(3,7),(3,7),(4,7),(4,7),(5,7),(5,7),(6,7),(6,7),(7,7),(7,7),(8,7),(9,7),(9,7),(10,7),(10,7),(11,7),(11,7),(12,7),(12,7),(13,7),(13,7),(14,7),(14,7),(15,7),(15,7),(16,7),(17,7),(17,7),(18,7),(18,7),(19,7),(19,7),(20,7),(20,7),(21,7),(21,7),(22,7),(23,7),(23,7),(24,7),(24,7),(25,7),(25,7),(26,7),(26,7),(27,7),(27,7),(28,7),(29,7),(29,7),(30,7),(30,7),(31,7),(31,7),(32,7),(33,7),(33,7),(34,7),(34,7),(35,7),(35,7),(36,8),(37,8),(37,8),(38,8),(38,8),(39,8),(39,8),(40,8),(40,8),(41,8),(41,8),(42,8),(43,8),(43,8),(44,8),(45,8),(45,8),(46,8),(47,8),(47,8),(48,8),(49,8),(49,8),(50,9),(51,9),(51,9),(52,9),(52,9),(53,9),(53,9),(54,9),(55,9),(55,9),(56,9),(57,9),(57,9),(58,9),(59,9),(59,9),(60,9),(60,9),(61,9),(61,9),(62,9),(63,9),(63,9),(64,9),(64,9),(64,9),(64,9),(64,9),(64,9),],
+[(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(3,7),(3,7),(4,7),(4,7),(5,7),(5,7),(6,7),(7,7),(7,7),(8,7),(8,7),(9,7),(9,7),(10,7),(10,7),(11,7),(11,7),(12,7),(12,7),(13,7),(13,7),(14,7),(15,7),(15,7),(16,7),(16,7),(17,7),(17,7),(18,7),(18,7),(19,7),(19,7),(20,7),(20,7),(21,7),(21,7),(22,7),(23,7),(23,7),(24,7),(24,7),(25,7),(25,7),(26,7),(27,7),(27,8),(28,8),(28,8),(29,8),(29,8),(30,8),(30,8),(31,8),(31,8),(32,8),(33,8),(33,8),(34,8),(34,8),(35,8),(35,8),(36,8),(37,8),(37,8),(38,8),(38,8),(39,8),(39,8),(40,8),(40,8),(41,8),(41,8),(42,8),(43,8),(43,8),(44,8),(45,8),(45,9),(46,9),(47,9),(47,9),(48,9),(49,9),(49,9),(50,9),(51,9),(51,9),(52,9),(53,9),(53,9),(54,9),(54,9),(55,9),(55,9),(56,9),(57,9),(57,9),(58,9),(59,9),(59,10),(60,10),(61,10),(61,10),(62,10),(62,10),(63,10),(63,10),(64,10),(64,10),(64,10),(64,10),(64,10),(64,10),],
+[(0,7),(0,7),(1,7),(1,7),(1,7),(2,7),(2,7),(3,7),(3,7),(4,7),(4,7),(5,7),(5,7),(6,7),(7,7),(7,7),(8,7),(8,7),(9,7),(9,7),(10,7),(10,7),(11,7),(11,7),(12,7),(12,8),(13,8),(13,8),(14,8),(15,8),(15,8),(16,8),(16,8),(17,8),(17,8),(18,8),(18,8),(19,8),(19,8),(20,8),(20,8),(21,8),(21,8),(22,8),(23,8),(23,8),(24,8),(24,8),(25,8),(25,8),(26,8),(27,8),(27,8),(28,8),(28,8),(29,8),(29,8),(30,8),(30,8),(31,8),(31,8),(32,8),(33,8),(33,8),(34,8),(34,8),(35,8),(35,8),(36,8),(37,8),(37,8),(38,8),(38,8),(39,8),(39,8),(40,8),(41,8),(41,9),(42,9),(42,9),(43,9),(43,9),(44,9),(45,9),(45,9),(46,9),(47,9),(47,9),(48,9),(49,9),(49,9),(50,9),(51,9),(51,9),(52,9),(53,9),(53,9),(54,9),(55,10),(55,10),(56,10),(56,10),(57,10),(57,10),(58,10),(59,10),(59,10),(60,10),(61,10),(61,10),(62,10),(62,10),(63,10),(63,10),(64,10),(64,10),(64,10),(64,10),(64,10),(64,10),],
+[(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(3,8),(3,8),(4,8),(4,8),(5,8),(5,8),(6,8),(7,8),(7,8),(8,8),(8,8),(9,8),(9,8),(10,8),(10,8),(11,8),(11,8),(12,8),(12,8),(13,8),(13,8),(14,8),(15,8),(15,8),(16,8),(16,8),(17,8),(17,8),(18,8),(18,8),(19,8),(19,8),(20,8),(20,8),(21,8),(21,8),(22,8),(23,8),(23,8),(24,8),(24,8),(25,8),(25,8),(26,8),(27,8),(27,8),(28,8),(28,8),(29,8),(29,8),(30,8),(31,8),(31,8),(32,8),(32,8),(33,8),(33,8),(34,8),(34,8),(35,9),(35,9),(36,9),(37,9),(37,9),(38,9),(38,9),(39,9),(39,9),(40,9),(41,9),(41,9),(42,9),(42,9),(43,9),(43,9),(44,9),(45,9),(45,9),(46,9),(47,9),(47,9),(48,9),(49,9),(49,9),(50,10),(51,10),(51,10),(52,10),(53,10),(53,10),(54,10),(55,10),(55,10),(56,10),(56,10),(57,10),(57,10),(58,10),(59,10),(59,11),(60,11),(61,11),(61,11),(62,11),(63,11),(63,11),(64,11),(64,11),(64,11),(64,11),(64,10),(64,10),(64,10),],
+[(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(3,8),(3,8),(4,8),(4,8),(5,8),(5,8),(6,8),(7,8),(7,8),(8,8),(8,8),(9,8),(9,8),(10,8),(10,8),(11,8),(11,8),(12,8),(12,8),(13,8),(13,8),(14,8),(15,8),(15,8),(16,8),(16,8),(17,8),(17,8),(18,8),(18,8),(19,8),(19,8),(20,8),(21,8),(21,8),(22,8),(22,8),(23,8),(23,8),(24,8),(24,8),(25,8),(25,8),(26,8),(27,8),(27,9),(28,9),(28,9),(29,9),(29,9),(30,9),(31,9),(31,9),(32,9),(32,9),(33,9),(33,9),(34,9),(34,9),(35,9),(35,9),(36,9),(37,9),(37,9),(38,9),(38,9),(39,9),(39,9),(40,9),(41,9),(41,9),(42,9),(42,9),(43,9),(43,9),(44,9),(45,9),(45,10),(46,10),(47,10),(47,10),(48,10),(49,10),(49,10),(50,10),(51,10),(51,10),(52,10),(53,10),(53,10),(54,10),(55,11),(55,11),(56,11),(57,11),(57,11),(58,11),(58,11),(59,11),(59,11),(60,11),(61,11),(61,11),(62,11),(63,11),(63,11),(64,11),(64,11),(64,11),(64,11),(64,11),(64,11),(64,11),],
+[(0,8),(0,8),(1,8),(1,8),(1,8),(2,8),(2,8),(3,8),(3,8),(4,8),(4,8),(5,8),(5,8),(6,8),(7,8),(7,8),(8,8),(8,8),(9,8),(9,8),(10,8),(10,8),(11,8),(11,8),(12,8),(12,9),(13,9),(13,9),(14,9),(15,9),(15,9),(16,9),(16,9),(17,9),(17,9),(18,9),(18,9),(19,9),(19,9),(20,9),(21,9),(21,9),(22,9),(22,9),(23,9),(23,9),(24,9),(24,9),(25,9),(25,9),(26,9),(27,9),(27,9),(28,9),(28,9),(29,9),(29,9),(30,9),(31,9),(31,9),(32,9),(32,9),(33,9),(33,9),(34,9),(35,9),(35,9),(36,9),(36,9),(37,9),(37,9),(38,9),(38,9),(39,9),(39,9),(40,9),(41,9),(41,10),(42,10),(42,10),(43,10),(43,10),(44,10),(45,10),(45,10),(46,10),(47,10),(47,10),(48,10),(49,10),(49,10),(50,11),(51,11),(51,11),(52,11),(53,11),(53,11),(54,11),(55,11),(55,11),(56,11),(57,11),(57,11),(58,11),(59,11),(59,11),(60,11),(60,11),(61,11),(61,11),(62,11),(63,11),(63,12),(64,11),(64,11),(64,11),(64,11),(64,11),(64,11),(64,11),],
+[(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(3,9),(3,9),(4,9),(4,9),(5,9),(5,9),(6,9),(7,9),(7,9),(8,9),(8,9),(9,9),(9,9),(10,9),(10,9),(11,9),(11,9),(12,9),(13,9),(13,9),(14,9),(14,9),(15,9),(15,9),(16,9),(16,9),(17,9),(17,9),(18,9),(18,9),(19,9),(19,9),(20,9),(21,9),(21,9),(22,9),(22,9),(23,9),(23,9),(24,9),(25,9),(25,9),(26,9),(26,9),(27,9),(27,9),(28,9),(28,9),(29,9),(29,9),(30,9),(31,9),(31,9),(32,9),(32,9),(33,9),(33,9),(34,9),(35,9),(35,10),(36,10),(36,10),(37,10),(37,10),(38,10),(38,10),(39,10),(39,10),(40,10),(41,10),(41,10),(42,10),(43,10),(43,10),(44,10),(45,10),(45,10),(46,10),(47,11),(47,11),(48,11),(49,11),(49,11),(50,11),(51,11),(51,11),(52,11),(52,11),(53,11),(53,11),(54,11),(55,11),(55,11),(56,11),(57,11),(57,11),(58,11),(59,12),(59,12),(60,12),(61,12),(61,12),(62,12),(63,12),(63,12),(64,12),(64,12),(64,12),(64,12),(64,12),(64,11),(64,11),(64,11),],
+[(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(3,9),(3,9),(4,9),(4,9),(5,9),(5,9),(6,9),(7,9),(7,9),(8,9),(8,9),(9,9),(9,9),(10,9),(10,9),(11,9),(11,9),(12,9),(13,9),(13,9),(14,9),(14,9),(15,9),(15,9),(16,9),(16,9),(17,9),(17,9),(18,9),(18,9),(19,9),(19,9),(20,9),(21,9),(21,9),(22,9),(22,9),(23,9),(23,9),(24,9),(25,9),(25,9),(26,9),(26,9),(27,9),(27,9),(28,10),(29,10),(29,10),(30,10),(30,10),(31,10),(31,10),(32,10),(32,10),(33,10),(33,10),(34,10),(35,10),(35,10),(36,10),(36,10),(37,10),(37,10),(38,10),(39,10),(39,10),(40,10),(40,10),(41,10),(41,10),(42,10),(43,10),(43,10),(44,11),(45,11),(45,11),(46,11),(47,11),(47,11),(48,11),(49,11),(49,11),(50,11),(51,11),(51,11),(52,11),(53,11),(53,11),(54,11),(54,12),(55,12),(55,12),(56,12),(57,12),(57,12),(58,12),(59,12),(59,12),(60,12),(61,12),(61,12),(62,12),(63,12),(63,12),(64,12),(64,12),(64,12),(64,12),(64,12),(64,12),(64,12),(64,12),],
+[(0,9),(0,9),(1,9),(1,9),(1,9),(2,9),(2,9),(3,9),(3,9),(4,9),(4,9),(5,9),(5,9),(6,9),(7,9),(7,9),(8,9),(8,9),(9,9),(9,9),(10,9),(10,9),(11,9),(11,9),(12,9),(13,9),(13,9),(14,9),(14,9),(15,9),(15,9),(16,9),(16,9),(17,9),(17,9),(18,9),(18,9),(19,9),(19,10),(20,10),(21,10),(21,10),(22,10),(22,10),(23,10),(23,10),(24,10),(25,10),(25,10),(26,10),(26,10),(27,10),(27,10),(28,10),(29,10),(29,10),(30,10),(30,10),(31,10),(31,10),(32,10),(33,10),(33,10),(34,10),(34,10),(35,10),(35,10),(36,10),(36,10),(37,10),(37,10),(38,10),(39,10),(39,10),(40,10),(40,10),(41,10),(41,11),(42,11),(43,11),(43,11),(44,11),(45,11),(45,11),(46,11),(47,11),(47,11),(48,11),(49,11),(49,11),(50,11),(51,12),(51,12),(52,12),(53,12),(53,12),(54,12),(55,12),(55,12),(56,12),(57,12),(57,12),(58,12),(58,12),(59,13),(59,13),(60,13),(61,13),(61,13),(62,13),(63,13),(63,13),(64,13),(64,13),(64,13),(64,12),(64,12),(64,12),(64,12),(64,12),],
+[(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(3,10),(3,10),(4,10),(4,10),(5,10),(5,10),(6,10),(7,10),(7,10),(8,10),(8,10),(9,10),(9,10),(10,10),(10,10),(11,10),(11,10),(12,10),(13,10),(13,10),(14,10),(14,10),(15,10),(15,10),(16,10),(16,10),(17,10),(17,10),(18,10),(18,10),(19,10),(19,10),(20,10),(21,10),(21,10),(22,10),(22,10),(23,10),(23,10),(24,10),(25,10),(25,10),(26,10),(26,10),(27,10),(27,10),(28,10),(29,10),(29,10),(30,10),(30,10),(31,10),(31,10),(32,10),(33,10),(33,10),(34,10),(34,10),(35,10),(35,11),(36,11),(36,11),(37,11),(37,11),(38,11),(39,11),(39,11),(40,11),(40,11),(41,11),(41,11),(42,11),(43,11),(43,11),(44,11),(45,11),(45,11),(46,11),(47,12),(47,12),(48,12),(49,12),(49,12),(50,12),(51,12),(51,12),(52,12),(53,12),(53,12),(54,12),(55,13),(55,13),(56,13),(57,13),(57,13),(58,13),(59,13),(59,13),(60,13),(61,13),(61,13),(62,13),(63,13),(63,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,12),],
+[(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(3,10),(3,10),(4,10),(5,10),(5,10),(6,10),(6,10),(7,10),(7,10),(8,10),(8,10),(9,10),(9,10),(10,10),(10,10),(11,10),(11,10),(12,10),(13,10),(13,10),(14,10),(14,10),(15,10),(15,10),(16,10),(16,10),(17,10),(17,10),(18,10),(19,10),(19,10),(20,10),(20,10),(21,10),(21,10),(22,10),(22,10),(23,10),(23,10),(24,10),(25,10),(25,10),(26,10),(26,10),(27,10),(27,10),(28,11),(29,11),(29,11),(30,11),(30,11),(31,11),(31,11),(32,11),(33,11),(33,11),(34,11),(34,11),(35,11),(35,11),(36,11),(37,11),(37,11),(38,11),(38,11),(39,11),(39,11),(40,11),(40,11),(41,11),(41,11),(42,11),(43,11),(43,11),(44,12),(45,12),(45,12),(46,12),(47,12),(47,12),(48,12),(49,12),(49,12),(50,12),(51,13),(51,13),(52,13),(53,13),(53,13),(54,13),(55,13),(55,13),(56,13),(57,13),(57,13),(58,13),(59,13),(59,13),(60,13),(61,13),(61,13),(62,13),(63,13),(63,14),(64,14),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),(64,13),],
+[(0,10),(0,10),(1,10),(1,10),(1,10),(2,10),(2,10),(3,10),(3,10),(4,10),(5,10),(5,10),(6,10),(6,10),(7,10),(7,10),(8,10),(8,10),(9,10),(9,10),(10,10),(11,10),(11,10),(12,10),(12,10),(13,10),(13,10),(14,10),(14,10),(15,10),(15,10),(16,10),(16,10),(17,10),(17,10),(18,10),(19,10),(19,11),(20,11),(20,11),(21,11),(21,11),(22,11),(23,11),(23,11),(24,11),(24,11),(25,11),(25,11),(26,11),(27,11),(27,11),(28,11),(28,11),(29,11),(29,11),(30,11),(30,11),(31,11),(31,11),(32,11),(33,11),(33,11),(34,11),(34,11),(35,11),(35,11),(36,11),(37,11),(37,11),(38,11),(38,11),(39,11),(39,11),(40,11),(41,11),(41,11),(42,12),(43,12),(43,12),(44,12),(45,12),(45,12),(46,12),(47,12),(47,12),(48,12),(49,13),(49,13),(50,13),(51,13),(51,13),(52,13),(53,13),(53,13),(54,13),(54,13),(55,13),(55,13),(56,13),(57,13),(57,13),(58,13),(59,14),(59,14),(60,14),(61,14),(61,14),(62,14),(63,14),(63,14),(64,14),(64,14),(64,14),(64,14),(64,13),(64,13),(64,13),(64,13),(64,13),],
+[(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(3,11),(3,11),(4,11),(5,11),(5,11),(6,11),(6,11),(7,11),(7,11),(8,11),(8,11),(9,11),(9,11),(10,11),(11,11),(11,11),(12,11),(12,11),(13,11),(13,11),(14,11),(14,11),(15,11),(15,11),(16,11),(17,11),(17,11),(18,11),(18,11),(19,11),(19,11),(20,11),(20,11),(21,11),(21,11),(22,11),(23,11),(23,11),(24,11),(24,11),(25,11),(25,11),(26,11),(27,11),(27,11),(28,11),(28,11),(29,11),(29,11),(30,11),(31,11),(31,11),(32,11),(32,11),(33,11),(33,11),(34,11),(34,11),(35,11),(35,11),(36,12),(37,12),(37,12),(38,12),(38,12),(39,12),(39,12),(40,12),(41,12),(41,12),(42,12),(43,12),(43,12),(44,12),(45,12),(45,12),(46,12),(47,13),(47,13),(48,13),(49,13),(49,13),(50,13),(51,13),(51,13),(52,13),(53,13),(53,13),(54,13),(55,14),(55,14),(56,14),(57,14),(57,14),(58,14),(59,14),(59,14),(60,14),(61,14),(61,14),(62,14),(62,14),(63,14),(63,15),(64,14),(64,14),(64,14),(64,14),(64,14),(64,14),(64,14),(64,14),(64,13),],
+[(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(3,11),(3,11),(4,11),(5,11),(5,11),(6,11),(6,11),(7,11),(7,11),(8,11),(8,11),(9,11),(9,11),(10,11),(11,11),(11,11),(12,11),(12,11),(13,11),(13,11),(14,11),(14,11),(15,11),(15,11),(16,11),(17,11),(17,11),(18,11),(18,11),(19,11),(19,11),(20,11),(20,11),(21,11),(21,11),(22,11),(23,11),(23,11),(24,11),(24,11),(25,11),(25,11),(26,11),(27,11),(27,11),(28,11),(28,11),(29,12),(29,12),(30,12),(31,12),(31,12),(32,12),(32,12),(33,12),(33,12),(34,12),(35,12),(35,12),(36,12),(36,12),(37,12),(37,12),(38,12),(39,12),(39,12),(40,12),(40,12),(41,12),(41,12),(42,12),(43,12),(43,12),(44,12),(45,13),(45,13),(46,13),(47,13),(47,13),(48,13),(49,13),(49,13),(50,13),(51,13),(51,14),(52,14),(53,14),(53,14),(54,14),(55,14),(55,14),(56,14),(57,14),(57,14),(58,14),(59,14),(59,15),(60,15),(61,15),(61,15),(62,15),(63,15),(63,15),(64,15),(64,15),(64,15),(64,15),(64,14),(64,14),(64,14),(64,14),(64,14),(64,14),],
+[(0,11),(0,11),(1,11),(1,11),(1,11),(2,11),(2,11),(3,11),(3,11),(4,11),(5,11),(5,11),(6,11),(6,11),(7,11),(7,11),(8,11),(8,11),(9,11),(9,11),(10,11),(11,11),(11,11),(12,11),(12,11),(13,11),(13,11),(14,11),(14,11),(15,11),(15,11),(16,11),(17,11),(17,11),(18,11),(18,11),(19,11),(19,12),(20,12),(20,12),(21,12),(21,12),(22,12),(23,12),(23,12),(24,12),(24,12),(25,12),(25,12),(26,12),(27,12),(27,12),(28,12),(28,12),(29,12),(29,12),(30,12),(31,12),(31,12),(32,12),(32,12),(33,12),(33,12),(34,12),(35,12),(35,12),(36,12),(36,12),(37,12),(37,12),(38,12),(39,12),(39,12),(40,12),(40,12),(41,12),(41,12),(42,13),(43,13),(43,13),(44,13),(45,13),(45,13),(46,13),(47,13),(47,13),(48,13),(49,14),(49,14),(50,14),(51,14),(51,14),(52,14),(53,14),(53,14),(54,14),(55,15),(55,15),(56,15),(57,15),(57,15),(58,15),(59,15),(59,15),(60,15),(61,15),(61,15),(62,15),(63,15),(63,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,14),(64,14),(64,14),],
+[(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(3,12),(3,12),(4,12),(5,12),(5,12),(6,12),(6,12),(7,12),(7,12),(8,12),(8,12),(9,12),(9,12),(10,12),(11,12),(11,12),(12,12),(12,12),(13,12),(13,12),(14,12),(14,12),(15,12),(15,12),(16,12),(17,12),(17,12),(18,12),(18,12),(19,12),(19,12),(20,12),(21,12),(21,12),(22,12),(22,12),(23,12),(23,12),(24,12),(25,12),(25,12),(26,12),(26,12),(27,12),(27,12),(28,12),(28,12),(29,12),(29,12),(30,12),(31,12),(31,12),(32,12),(32,12),(33,12),(33,12),(34,12),(35,12),(35,12),(36,12),(36,13),(37,13),(37,13),(38,13),(39,13),(39,13),(40,13),(41,13),(41,13),(42,13),(42,13),(43,13),(43,13),(44,13),(45,13),(45,13),(46,13),(47,14),(47,14),(48,14),(49,14),(50,14),(51,14),(51,14),(52,14),(53,14),(53,15),(54,15),(55,15),(55,15),(56,15),(57,15),(57,15),(58,15),(59,15),(59,15),(60,15),(60,15),(61,15),(61,15),(62,15),(63,16),(63,16),(64,16),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),],
+[(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(3,12),(3,12),(4,12),(5,12),(5,12),(6,12),(6,12),(7,12),(7,12),(8,12),(8,12),(9,12),(9,12),(10,12),(11,12),(11,12),(12,12),(12,12),(13,12),(13,12),(14,12),(15,12),(15,12),(16,12),(16,12),(17,12),(17,12),(18,12),(18,12),(19,12),(19,12),(20,12),(21,12),(21,12),(22,12),(22,12),(23,12),(23,12),(24,12),(25,12),(25,12),(26,12),(26,12),(27,12),(27,12),(28,12),(29,12),(29,13),(30,13),(30,13),(31,13),(31,13),(32,13),(33,13),(33,13),(34,13),(34,13),(35,13),(35,13),(36,13),(37,13),(37,13),(38,13),(38,13),(39,13),(39,13),(40,13),(41,13),(41,13),(42,13),(43,13),(43,13),(44,13),(45,13),(45,14),(46,14),(47,14),(47,14),(48,14),(49,14),(49,14),(50,14),(51,15),(51,15),(52,15),(53,15),(53,15),(54,15),(55,15),(55,15),(56,15),(57,15),(57,15),(58,15),(59,16),(59,16),(60,16),(61,16),(61,16),(62,16),(63,16),(63,16),(64,16),(64,16),(64,16),(64,16),(64,16),(64,15),(64,15),(64,15),(64,15),(64,15),(64,15),],
+[(0,12),(0,12),(1,12),(1,12),(1,12),(2,12),(2,12),(3,12),(3,12),(4,12),(5,12),(5,12),(6,12),(6,12),(7,12),(7,12),(8,12),(9,12),(9,12),(10,12),(10,12),(11,12),(11,12),(12,12),(12,12),(13,12),(13,12),(14,12),(15,12),(15,12),(16,12),(16,12),(17,12),(17,13),(18,13),(18,13),(19,13),(19,13),(20,13),(21,13),(21,13),(22,13),(22,13),(23,13),(23,13),(24,13),(25,13),(25,13),(26,13),(26,13),(27,13),(27,13),(28,13),(29,13),(29,13),(30,13),(30,13),(31,13),(31,13),(32,13),(33,13),(33,13),(34,13),(34,13),(35,13),(35,13),(36,13),(37,13),(37,13),(38,13),(38,13),(39,13),(39,13),(40,13),(41,13),(41,13),(42,13),(43,14),(43,14),(44,14),(45,14),(45,14),(46,14),(47,14),(47,14),(48,14),(49,15),(49,15),(50,15),(51,15),(51,15),(52,15),(53,15),(53,15),(54,15),(55,15),(55,16),(56,16),(57,16),(57,16),(58,16),(59,16),(59,16),(60,16),(61,16),(61,16),(62,16),(63,16),(63,17),(64,17),(64,16),(64,16),(64,16),(64,16),(64,16),(64,16),(64,16),(64,15),(64,15),(64,15),],
+[(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(3,13),(3,13),(4,13),(5,13),(5,13),(6,13),(6,13),(7,13),(7,13),(8,13),(9,13),(9,13),(10,13),(10,13),(11,13),(11,13),(12,13),(12,13),(13,13),(13,13),(14,13),(15,13),(15,13),(16,13),(16,13),(17,13),(17,13),(18,13),(19,13),(19,13),(20,13),(20,13),(21,13),(21,13),(22,13),(22,13),(23,13),(23,13),(24,13),(25,13),(25,13),(26,13),(26,13),(27,13),(27,13),(28,13),(29,13),(29,13),(30,13),(30,13),(31,13),(31,13),(32,13),(33,13),(33,13),(34,13),(34,13),(35,13),(35,13),(36,13),(37,13),(37,13),(38,13),(38,14),(39,14),(39,14),(40,14),(41,14),(41,14),(42,14),(43,14),(43,14),(44,14),(45,14),(45,14),(46,14),(47,15),(47,15),(48,15),(49,15),(49,15),(50,15),(51,15),(51,15),(52,15),(53,15),(53,16),(54,16),(55,16),(55,16),(56,16),(57,16),(57,16),(58,16),(59,17),(59,17),(60,17),(61,17),(61,17),(62,17),(63,17),(63,17),(64,17),(64,17),(64,17),(64,17),(64,16),(64,16),(64,16),(64,16),(64,16),(64,16),(64,16),],
+[(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(3,13),(3,13),(4,13),(5,13),(5,13),(6,13),(6,13),(7,13),(7,13),(8,13),(9,13),(9,13),(10,13),(10,13),(11,13),(11,13),(12,13),(12,13),(13,13),(13,13),(14,13),(15,13),(15,13),(16,13),(16,13),(17,13),(17,13),(18,13),(19,13),(19,13),(20,13),(20,13),(21,13),(21,13),(22,13),(23,13),(23,13),(24,13),(24,13),(25,13),(25,13),(26,13),(27,13),(27,13),(28,13),(28,13),(29,13),(29,13),(30,13),(31,13),(31,14),(32,14),(32,14),(33,14),(33,14),(34,14),(35,14),(35,14),(36,14),(36,14),(37,14),(37,14),(38,14),(39,14),(39,14),(40,14),(40,14),(41,14),(41,14),(42,14),(43,14),(43,14),(44,14),(45,14),(45,15),(46,15),(47,15),(47,15),(48,15),(49,15),(49,15),(50,15),(51,16),(51,16),(52,16),(53,16),(54,16),(55,16),(55,16),(56,16),(57,17),(57,17),(58,17),(59,17),(59,17),(60,17),(61,17),(61,17),(62,17),(63,17),(63,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,16),(64,16),(64,16),(64,16),],
+[(0,13),(0,13),(1,13),(1,13),(1,13),(2,13),(2,13),(3,13),(3,13),(4,13),(5,13),(5,13),(6,13),(6,13),(7,13),(7,13),(8,13),(9,13),(9,13),(10,13),(10,13),(11,13),(11,13),(12,13),(13,13),(13,13),(14,13),(14,13),(15,13),(15,13),(16,13),(16,13),(17,13),(17,14),(18,14),(19,14),(19,14),(20,14),(20,14),(21,14),(21,14),(22,14),(23,14),(23,14),(24,14),(24,14),(25,14),(25,14),(26,14),(27,14),(27,14),(28,14),(28,14),(29,14),(29,14),(30,14),(31,14),(31,14),(32,14),(32,14),(33,14),(33,14),(34,14),(35,14),(35,14),(36,14),(36,14),(37,14),(37,14),(38,14),(39,14),(39,14),(40,14),(41,14),(41,14),(42,14),(42,14),(43,15),(43,15),(44,15),(45,15),(45,15),(46,15),(47,15),(47,15),(48,15),(49,16),(50,16),(51,16),(51,16),(52,16),(53,16),(53,16),(54,16),(55,17),(55,17),(56,17),(57,17),(57,17),(58,17),(59,17),(59,17),(60,17),(61,17),(61,17),(62,18),(63,18),(63,18),(64,18),(64,18),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,16),],
+[(0,13),(0,13),(1,13),(1,13),(1,13),(2,14),(2,14),(3,14),(3,14),(4,14),(5,14),(5,14),(6,14),(6,14),(7,14),(7,14),(8,14),(9,14),(9,14),(10,14),(10,14),(11,14),(11,14),(12,14),(13,14),(13,14),(14,14),(14,14),(15,14),(15,14),(16,14),(17,14),(17,14),(18,14),(18,14),(19,14),(19,14),(20,14),(20,14),(21,14),(21,14),(22,14),(23,14),(23,14),(24,14),(24,14),(25,14),(25,14),(26,14),(27,14),(27,14),(28,14),(28,14),(29,14),(29,14),(30,14),(31,14),(31,14),(32,14),(32,14),(33,14),(33,14),(34,14),(35,14),(35,14),(36,14),(36,14),(37,14),(37,14),(38,14),(39,15),(39,15),(40,15),(41,15),(41,15),(42,15),(43,15),(43,15),(44,15),(45,15),(45,15),(46,15),(47,15),(47,16),(48,16),(49,16),(49,16),(50,16),(51,16),(51,16),(52,16),(53,17),(53,17),(54,17),(55,17),(55,17),(56,17),(57,17),(57,17),(58,17),(59,17),(59,18),(60,18),(61,18),(61,18),(62,18),(63,18),(63,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),(64,17),],
+[(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(3,14),(3,14),(4,14),(4,14),(5,14),(5,14),(6,14),(6,14),(7,14),(7,14),(8,14),(9,14),(9,14),(10,14),(10,14),(11,14),(11,14),(12,14),(13,14),(13,14),(14,14),(14,14),(15,14),(15,14),(16,14),(17,14),(17,14),(18,14),(18,14),(19,14),(19,14),(20,14),(21,14),(21,14),(22,14),(22,14),(23,14),(23,14),(24,14),(25,14),(25,14),(26,14),(26,14),(27,14),(27,14),(28,14),(29,14),(29,14),(30,14),(30,14),(31,14),(31,15),(32,15),(33,15),(33,15),(34,15),(34,15),(35,15),(35,15),(36,15),(37,15),(37,15),(38,15),(38,15),(39,15),(39,15),(40,15),(41,15),(41,15),(42,15),(43,15),(43,15),(44,15),(45,15),(45,15),(46,16),(47,16),(47,16),(48,16),(49,16),(49,16),(50,16),(51,17),(51,17),(52,17),(53,17),(53,17),(54,17),(55,17),(55,17),(56,17),(57,18),(57,18),(58,18),(59,18),(60,18),(61,18),(61,18),(62,18),(63,19),(63,19),(64,19),(64,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,17),(64,17),(64,17),(64,17),],
+[(0,14),(0,14),(1,14),(1,14),(1,14),(2,14),(3,14),(3,14),(4,14),(4,14),(5,14),(5,14),(6,14),(6,14),(7,14),(7,14),(8,14),(9,14),(9,14),(10,14),(10,14),(11,14),(11,14),(12,14),(13,14),(13,14),(14,14),(14,14),(15,14),(15,14),(16,15),(17,15),(17,15),(18,15),(18,15),(19,15),(19,15),(20,15),(21,15),(21,15),(22,15),(22,15),(23,15),(23,15),(24,15),(25,15),(25,15),(26,15),(26,15),(27,15),(27,15),(28,15),(29,15),(29,15),(30,15),(30,15),(31,15),(31,15),(32,15),(33,15),(33,15),(34,15),(34,15),(35,15),(35,15),(36,15),(37,15),(37,15),(38,15),(38,15),(39,15),(39,15),(40,15),(41,15),(41,15),(42,15),(43,15),(43,16),(44,16),(45,16),(45,16),(46,16),(47,16),(47,16),(48,16),(49,17),(49,17),(50,17),(51,17),(51,17),(52,17),(53,17),(54,17),(55,17),(55,18),(56,18),(57,18),(57,18),(58,18),(59,18),(59,18),(60,19),(61,19),(61,19),(62,19),(63,19),(63,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,18),(64,17),],
+[(0,14),(0,14),(1,14),(1,14),(1,14),(2,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(6,15),(7,15),(7,15),(8,15),(8,15),(9,15),(9,15),(10,15),(10,15),(11,15),(11,15),(12,15),(13,15),(13,15),(14,15),(14,15),(15,15),(15,15),(16,15),(17,15),(17,15),(18,15),(18,15),(19,15),(19,15),(20,15),(21,15),(21,15),(22,15),(22,15),(23,15),(23,15),(24,15),(25,15),(25,15),(26,15),(26,15),(27,15),(27,15),(28,15),(29,15),(29,15),(30,15),(30,15),(31,15),(31,15),(32,15),(33,15),(33,15),(34,15),(34,15),(35,15),(35,15),(36,15),(37,15),(37,15),(38,15),(39,15),(39,15),(40,15),(40,16),(41,16),(41,16),(42,16),(43,16),(43,16),(44,16),(45,16),(45,16),(46,16),(47,16),(47,17),(48,17),(49,17),(49,17),(50,17),(51,17),(52,17),(53,17),(53,18),(54,18),(55,18),(55,18),(56,18),(57,18),(57,19),(58,19),(59,19),(59,19),(60,19),(61,19),(61,19),(62,19),(63,19),(63,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,18),(64,18),(64,18),(64,18),(64,18),],
+[(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(6,15),(7,15),(7,15),(8,15),(8,15),(9,15),(9,15),(10,15),(11,15),(11,15),(12,15),(12,15),(13,15),(13,15),(14,15),(14,15),(15,15),(15,15),(16,15),(17,15),(17,15),(18,15),(18,15),(19,15),(19,15),(20,15),(21,15),(21,15),(22,15),(22,15),(23,15),(23,15),(24,15),(25,15),(25,15),(26,15),(26,15),(27,15),(27,15),(28,15),(29,15),(29,15),(30,15),(31,15),(31,15),(32,15),(32,15),(33,15),(33,16),(34,16),(35,16),(35,16),(36,16),(36,16),(37,16),(37,16),(38,16),(39,16),(39,16),(40,16),(40,16),(41,16),(41,16),(42,16),(43,16),(43,16),(44,16),(45,16),(45,17),(46,17),(47,17),(47,17),(48,17),(49,17),(50,17),(51,17),(51,18),(52,18),(53,18),(53,18),(54,18),(55,18),(55,19),(56,19),(57,19),(57,19),(58,19),(59,19),(59,19),(60,19),(61,19),(61,19),(62,20),(63,20),(63,20),(64,20),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,18),(64,18),],
+[(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(3,15),(3,15),(4,15),(4,15),(5,15),(5,15),(6,15),(7,15),(7,15),(8,15),(8,15),(9,15),(9,15),(10,15),(11,15),(11,15),(12,15),(12,15),(13,15),(13,15),(14,15),(15,15),(15,15),(16,15),(16,16),(17,16),(17,16),(18,16),(19,16),(19,16),(20,16),(20,16),(21,16),(21,16),(22,16),(23,16),(23,16),(24,16),(24,16),(25,16),(25,16),(26,16),(27,16),(27,16),(28,16),(28,16),(29,16),(29,16),(30,16),(31,16),(31,16),(32,16),(32,16),(33,16),(33,16),(34,16),(35,16),(35,16),(36,16),(36,16),(37,16),(37,16),(38,16),(39,16),(39,16),(40,16),(41,16),(41,16),(42,16),(42,16),(43,16),(43,17),(44,17),(45,17),(45,17),(46,17),(47,17),(48,17),(49,17),(49,18),(50,18),(51,18),(51,18),(52,18),(53,18),(53,19),(54,19),(55,19),(55,19),(56,19),(57,19),(58,19),(59,19),(59,19),(60,20),(61,20),(61,20),(62,20),(63,20),(63,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),(64,19),],
+[(0,15),(0,15),(1,15),(1,15),(1,15),(2,15),(3,15),(3,15),(4,16),(4,16),(5,16),(5,16),(6,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(10,16),(11,16),(11,16),(12,16),(12,16),(13,16),(13,16),(14,16),(15,16),(15,16),(16,16),(16,16),(17,16),(17,16),(18,16),(19,16),(19,16),(20,16),(20,16),(21,16),(21,16),(22,16),(23,16),(23,16),(24,16),(24,16),(25,16),(25,16),(26,16),(27,16),(27,16),(28,16),(28,16),(29,16),(29,16),(30,16),(31,16),(31,16),(32,16),(32,16),(33,16),(33,16),(34,16),(35,16),(35,16),(36,16),(36,16),(37,16),(37,16),(38,16),(39,16),(39,16),(40,16),(41,17),(41,17),(42,17),(43,17),(43,17),(44,17),(45,17),(45,17),(46,17),(47,17),(47,17),(48,18),(49,18),(49,18),(50,18),(51,18),(51,18),(52,19),(53,19),(54,19),(55,19),(55,19),(56,19),(57,19),(57,19),(58,20),(59,20),(59,20),(60,20),(61,20),(61,20),(62,20),(63,21),(63,21),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,19),(64,19),(64,19),(64,19),(64,19),],
+[(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(6,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(10,16),(11,16),(11,16),(12,16),(12,16),(13,16),(13,16),(14,16),(15,16),(15,16),(16,16),(16,16),(17,16),(17,16),(18,16),(19,16),(19,16),(20,16),(20,16),(21,16),(21,16),(22,16),(23,16),(23,16),(24,16),(24,16),(25,16),(25,16),(26,16),(27,16),(27,16),(28,16),(28,16),(29,16),(29,16),(30,16),(31,16),(31,16),(32,16),(32,16),(33,16),(33,17),(34,17),(35,17),(35,17),(36,17),(37,17),(37,17),(38,17),(38,17),(39,17),(39,17),(40,17),(41,17),(41,17),(42,17),(43,17),(43,17),(44,17),(45,17),(45,17),(46,18),(47,18),(47,18),(48,18),(49,18),(49,18),(50,19),(51,19),(52,19),(53,19),(53,19),(54,19),(55,19),(55,19),(56,20),(57,20),(57,20),(58,20),(59,20),(59,20),(60,21),(61,21),(61,21),(62,21),(63,21),(63,21),(64,21),(64,21),(64,21),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,19),(64,19),],
+[(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(3,16),(3,16),(4,16),(4,16),(5,16),(5,16),(6,16),(7,16),(7,16),(8,16),(8,16),(9,16),(9,16),(10,16),(11,16),(11,16),(12,16),(12,16),(13,16),(13,16),(14,16),(15,16),(15,16),(16,17),(16,17),(17,17),(17,17),(18,17),(19,17),(19,17),(20,17),(20,17),(21,17),(21,17),(22,17),(23,17),(23,17),(24,17),(24,17),(25,17),(25,17),(26,17),(27,17),(27,17),(28,17),(29,17),(29,17),(30,17),(30,17),(31,17),(31,17),(32,17),(33,17),(33,17),(34,17),(34,17),(35,17),(35,17),(36,17),(37,17),(37,17),(38,17),(38,17),(39,17),(39,17),(40,17),(41,17),(41,17),(42,17),(43,17),(43,17),(44,17),(45,18),(45,18),(46,18),(47,18),(47,18),(48,18),(49,19),(50,19),(51,19),(51,19),(52,19),(53,19),(53,19),(54,20),(55,20),(55,20),(56,20),(57,20),(57,20),(58,21),(59,21),(60,21),(61,21),(61,21),(62,21),(63,21),(63,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),(64,20),],
+[(0,16),(0,16),(1,16),(1,16),(1,16),(2,16),(3,16),(3,16),(4,17),(4,17),(5,17),(5,17),(6,17),(7,17),(7,17),(8,17),(8,17),(9,17),(9,17),(10,17),(11,17),(11,17),(12,17),(12,17),(13,17),(13,17),(14,17),(15,17),(15,17),(16,17),(16,17),(17,17),(17,17),(18,17),(19,17),(19,17),(20,17),(21,17),(21,17),(22,17),(22,17),(23,17),(23,17),(24,17),(25,17),(25,17),(26,17),(26,17),(27,17),(27,17),(28,17),(29,17),(29,17),(30,17),(30,17),(31,17),(31,17),(32,17),(33,17),(33,17),(34,17),(34,17),(35,17),(35,17),(36,17),(37,17),(37,17),(38,17),(38,17),(39,17),(39,17),(40,17),(41,17),(41,17),(42,18),(43,18),(43,18),(44,18),(45,18),(45,18),(46,18),(47,18),(47,19),(48,19),(49,19),(50,19),(51,19),(51,19),(52,19),(53,20),(53,20),(54,20),(55,20),(56,20),(57,21),(57,21),(58,21),(59,21),(59,21),(60,21),(61,21),(61,21),(62,21),(63,22),(63,22),(64,22),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,20),(64,20),(64,20),(64,20),(64,20),],
+[(0,17),(0,17),(1,17),(1,17),(1,17),(2,17),(3,17),(3,17),(4,17),(4,17),(5,17),(5,17),(6,17),(7,17),(7,17),(8,17),(8,17),(9,17),(9,17),(10,17),(11,17),(11,17),(12,17),(13,17),(13,17),(14,17),(14,17),(15,17),(15,17),(16,17),(17,17),(17,17),(18,17),(18,17),(19,17),(19,17),(20,17),(21,17),(21,17),(22,17),(22,17),(23,17),(23,17),(24,17),(25,17),(25,17),(26,17),(26,17),(27,17),(27,17),(28,17),(29,17),(29,17),(30,17),(30,17),(31,17),(31,17),(32,17),(33,17),(33,17),(34,17),(34,17),(35,17),(35,17),(36,17),(37,18),(37,18),(38,18),(39,18),(39,18),(40,18),(40,18),(41,18),(41,18),(42,18),(43,18),(43,18),(44,18),(45,18),(45,18),(46,19),(47,19),(48,19),(49,19),(49,19),(50,19),(51,20),(51,20),(52,20),(53,20),(54,20),(55,21),(55,21),(56,21),(57,21),(57,21),(58,21),(59,21),(59,21),(60,21),(61,22),(61,22),(62,22),(63,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,20),(64,20),],
+[(0,17),(0,17),(1,17),(1,17),(1,17),(2,17),(3,17),(3,17),(4,17),(4,17),(5,17),(5,17),(6,17),(7,17),(7,17),(8,17),(8,17),(9,17),(9,17),(10,17),(11,17),(11,17),(12,17),(13,17),(13,17),(14,17),(14,17),(15,17),(15,17),(16,18),(17,18),(17,18),(18,18),(18,18),(19,18),(19,18),(20,18),(21,18),(21,18),(22,18),(22,18),(23,18),(23,18),(24,18),(25,18),(25,18),(26,18),(27,18),(27,18),(28,18),(28,18),(29,18),(29,18),(30,18),(31,18),(31,18),(32,18),(32,18),(33,18),(33,18),(34,18),(35,18),(35,18),(36,18),(36,18),(37,18),(37,18),(38,18),(39,18),(39,18),(40,18),(41,18),(41,18),(42,18),(42,18),(43,18),(43,18),(44,18),(45,19),(46,19),(47,19),(47,19),(48,19),(49,19),(49,20),(50,20),(51,20),(52,20),(53,20),(53,21),(54,21),(55,21),(55,21),(56,21),(57,21),(57,21),(58,21),(59,22),(60,22),(61,22),(61,22),(62,22),(63,22),(63,23),(64,23),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),],
+[(0,17),(0,17),(1,17),(1,17),(1,17),(2,17),(3,17),(3,17),(4,17),(4,17),(5,18),(5,18),(6,18),(7,18),(7,18),(8,18),(9,18),(9,18),(10,18),(10,18),(11,18),(11,18),(12,18),(13,18),(13,18),(14,18),(14,18),(15,18),(15,18),(16,18),(17,18),(17,18),(18,18),(18,18),(19,18),(19,18),(20,18),(21,18),(21,18),(22,18),(22,18),(23,18),(23,18),(24,18),(25,18),(25,18),(26,18),(27,18),(27,18),(28,18),(28,18),(29,18),(29,18),(30,18),(31,18),(31,18),(32,18),(32,18),(33,18),(33,18),(34,18),(35,18),(35,18),(36,18),(36,18),(37,18),(37,18),(38,18),(39,18),(39,18),(40,18),(41,18),(41,18),(42,18),(43,19),(43,19),(44,19),(45,19),(45,19),(46,19),(47,19),(47,19),(48,20),(49,20),(50,20),(51,20),(51,21),(52,21),(53,21),(53,21),(54,21),(55,21),(55,21),(56,21),(57,22),(58,22),(59,22),(59,22),(60,22),(61,22),(61,23),(62,23),(63,23),(64,23),(64,23),(64,23),(64,23),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),(64,21),],
+[(0,18),(0,18),(1,18),(1,18),(1,18),(2,18),(3,18),(3,18),(4,18),(4,18),(5,18),(5,18),(6,18),(7,18),(7,18),(8,18),(9,18),(9,18),(10,18),(10,18),(11,18),(11,18),(12,18),(13,18),(13,18),(14,18),(14,18),(15,18),(15,18),(16,18),(17,18),(17,18),(18,18),(18,18),(19,18),(19,18),(20,18),(21,18),(21,18),(22,18),(23,18),(23,18),(24,18),(24,18),(25,18),(25,18),(26,18),(27,18),(27,18),(28,18),(28,18),(29,18),(29,18),(30,18),(31,19),(31,19),(32,19),(33,19),(33,19),(34,19),(34,19),(35,19),(35,19),(36,19),(37,18),(37,19),(38,19),(38,19),(39,19),(39,19),(40,19),(41,19),(41,19),(42,19),(43,19),(43,19),(44,19),(45,19),(45,19),(46,19),(47,20),(47,20),(48,20),(49,20),(50,21),(51,21),(51,21),(52,21),(53,21),(53,21),(54,21),(55,22),(56,22),(57,22),(57,22),(58,22),(59,22),(59,23),(60,23),(61,23),(62,23),(63,23),(63,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,21),(64,21),(64,21),(64,21),],
+[(0,18),(0,18),(1,18),(1,18),(1,18),(2,18),(3,18),(3,18),(4,18),(4,18),(5,18),(5,18),(6,18),(7,18),(7,18),(8,18),(9,18),(9,18),(10,18),(10,18),(11,18),(11,18),(12,18),(13,18),(13,18),(14,18),(14,18),(15,19),(15,19),(16,19),(17,19),(17,19),(18,19),(19,19),(19,19),(20,19),(20,19),(21,19),(21,19),(22,19),(23,19),(23,19),(24,19),(24,19),(25,19),(25,19),(26,19),(27,19),(27,19),(28,19),(29,19),(29,19),(30,19),(30,19),(31,19),(31,19),(32,19),(33,19),(33,19),(34,19),(34,19),(35,19),(35,19),(36,19),(37,19),(37,19),(38,19),(38,19),(39,19),(39,19),(40,19),(41,19),(41,19),(42,19),(43,19),(43,19),(44,19),(45,19),(45,20),(46,20),(47,20),(48,20),(49,21),(49,21),(50,21),(51,21),(51,21),(52,21),(53,21),(54,22),(55,22),(55,22),(56,22),(57,22),(57,23),(58,23),(59,23),(60,23),(61,23),(61,23),(62,23),(63,24),(63,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),(64,21),(64,21),],
+[(0,18),(0,18),(1,18),(1,18),(1,18),(2,18),(3,18),(3,18),(4,18),(4,18),(5,19),(5,19),(6,19),(7,19),(7,19),(8,19),(9,19),(9,19),(10,19),(10,19),(11,19),(11,19),(12,19),(13,19),(13,19),(14,19),(15,19),(15,19),(16,19),(16,19),(17,19),(17,19),(18,19),(19,19),(19,19),(20,19),(20,19),(21,19),(21,19),(22,19),(23,19),(23,19),(24,19),(25,19),(25,19),(26,19),(26,19),(27,19),(27,19),(28,19),(29,19),(29,19),(30,19),(30,19),(31,19),(31,19),(32,19),(33,19),(33,19),(34,19),(34,19),(35,19),(35,19),(36,19),(37,19),(37,19),(38,19),(38,19),(39,19),(39,19),(40,19),(41,19),(41,19),(42,19),(43,19),(43,19),(44,20),(45,20),(45,20),(46,20),(47,21),(48,21),(49,21),(49,21),(50,21),(51,21),(52,22),(53,22),(53,22),(54,22),(55,22),(55,23),(56,23),(57,23),(58,23),(59,23),(59,23),(60,23),(61,24),(61,24),(62,24),(63,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,22),(64,22),(64,22),(64,22),(64,22),(64,22),],
+[(0,19),(0,19),(1,19),(1,19),(1,19),(2,19),(3,19),(3,19),(4,19),(4,19),(5,19),(5,19),(6,19),(7,19),(7,19),(8,19),(9,19),(9,19),(10,19),(10,19),(11,19),(11,19),(12,19),(13,19),(13,19),(14,19),(15,19),(15,19),(16,19),(16,19),(17,19),(17,19),(18,19),(19,19),(19,19),(20,19),(20,19),(21,19),(21,19),(22,19),(23,19),(23,19),(24,19),(25,19),(25,19),(26,19),(26,19),(27,19),(27,19),(28,19),(29,19),(29,19),(30,19),(31,19),(31,20),(32,20),(32,20),(33,20),(33,20),(34,20),(35,20),(35,20),(36,20),(36,20),(37,19),(37,19),(38,19),(39,19),(39,19),(40,19),(40,19),(41,19),(41,20),(42,20),(43,20),(43,20),(44,20),(45,20),(46,21),(47,21),(47,21),(48,21),(49,21),(50,21),(51,22),(51,22),(52,22),(53,22),(53,22),(54,23),(55,23),(56,23),(57,23),(57,23),(58,23),(59,24),(60,24),(61,24),(61,24),(62,24),(63,24),(63,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,22),(64,22),(64,22),(64,22),],
+[(0,19),(0,19),(1,19),(1,19),(1,19),(2,19),(3,19),(3,19),(4,19),(5,19),(5,19),(6,19),(6,19),(7,19),(7,19),(8,19),(9,19),(9,19),(10,19),(11,19),(11,19),(12,19),(12,19),(13,19),(13,19),(14,19),(15,19),(15,20),(16,20),(16,20),(17,20),(17,20),(18,20),(19,20),(19,20),(20,20),(21,20),(21,20),(22,20),(22,20),(23,20),(23,20),(24,20),(25,20),(25,20),(26,20),(27,20),(27,20),(28,20),(28,20),(29,20),(29,20),(30,20),(31,20),(31,20),(32,20),(32,20),(33,20),(33,20),(34,20),(35,20),(35,20),(36,20),(36,20),(37,20),(37,20),(38,20),(39,20),(39,20),(40,20),(40,20),(41,20),(41,20),(42,20),(43,20),(43,20),(44,20),(45,21),(46,21),(47,21),(47,21),(48,21),(49,22),(50,22),(51,22),(51,22),(52,23),(53,23),(54,23),(55,23),(55,23),(56,23),(57,23),(58,24),(59,24),(59,24),(60,24),(61,24),(61,25),(62,25),(63,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),(64,22),],
+[(0,19),(0,19),(1,19),(1,19),(1,19),(2,19),(3,19),(3,19),(4,19),(5,19),(5,19),(6,20),(6,20),(7,20),(7,20),(8,20),(9,20),(9,20),(10,20),(11,20),(11,20),(12,20),(12,20),(13,20),(13,20),(14,20),(15,20),(15,20),(16,20),(16,20),(17,20),(17,20),(18,20),(19,20),(19,20),(20,20),(21,20),(21,20),(22,20),(22,20),(23,20),(23,20),(24,20),(25,20),(25,20),(26,20),(27,20),(27,20),(28,20),(29,20),(29,20),(30,20),(30,20),(31,20),(31,20),(32,20),(33,20),(33,20),(34,20),(34,20),(35,20),(35,20),(36,20),(37,20),(37,20),(38,20),(38,20),(39,20),(39,20),(40,20),(40,20),(41,20),(41,20),(42,20),(43,20),(44,21),(45,21),(45,21),(46,21),(47,21),(48,22),(49,22),(49,22),(50,22),(51,23),(52,23),(53,23),(53,23),(54,23),(55,23),(55,24),(56,24),(57,24),(58,24),(59,24),(59,25),(60,25),(61,25),(62,25),(63,25),(63,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),(64,23),(64,23),],
+[(0,20),(0,20),(1,20),(1,20),(1,20),(2,20),(3,20),(3,20),(4,20),(5,20),(5,20),(6,20),(6,20),(7,20),(7,20),(8,20),(9,20),(9,20),(10,20),(11,20),(11,20),(12,20),(12,20),(13,20),(13,20),(14,20),(15,20),(15,20),(16,20),(17,20),(17,20),(18,20),(18,20),(19,20),(19,20),(20,20),(21,20),(21,20),(22,21),(23,21),(23,21),(24,21),(24,21),(25,21),(25,21),(26,21),(27,21),(27,21),(28,21),(29,21),(29,21),(30,21),(30,21),(31,21),(31,21),(32,21),(33,21),(33,21),(34,21),(34,21),(35,21),(35,21),(36,21),(37,21),(37,21),(38,21),(38,21),(39,21),(39,21),(40,21),(41,21),(41,21),(42,21),(43,21),(43,21),(44,21),(45,21),(45,21),(46,22),(47,22),(48,22),(49,22),(49,23),(50,23),(51,23),(52,23),(53,23),(53,23),(54,24),(55,24),(56,24),(57,24),(57,24),(58,25),(59,25),(60,25),(61,25),(61,25),(62,26),(63,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),(64,23),(64,23),(64,23),],
+[(0,20),(0,20),(1,20),(1,20),(1,20),(2,20),(3,20),(3,20),(4,20),(5,20),(5,20),(6,20),(6,20),(7,20),(7,20),(8,20),(9,20),(9,20),(10,20),(11,20),(11,20),(12,20),(12,21),(13,21),(13,21),(14,21),(15,21),(15,21),(16,21),(17,21),(17,21),(18,21),(18,21),(19,21),(19,21),(20,21),(21,21),(21,21),(22,21),(23,21),(23,21),(24,21),(25,21),(25,21),(26,21),(26,21),(27,21),(27,21),(28,21),(29,21),(29,21),(30,21),(31,21),(31,21),(32,21),(32,21),(33,21),(33,21),(34,21),(35,21),(35,21),(36,21),(36,21),(37,21),(37,21),(38,21),(39,21),(39,21),(40,21),(40,21),(41,21),(41,21),(42,21),(43,21),(43,21),(44,21),(45,21),(46,22),(47,22),(47,22),(48,23),(49,23),(50,23),(51,23),(51,23),(52,23),(53,24),(54,24),(55,24),(55,24),(56,25),(57,25),(58,25),(59,25),(59,25),(60,25),(61,26),(62,26),(63,26),(63,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,24),(64,23),],
+[(0,20),(0,20),(1,20),(1,20),(1,20),(2,20),(3,20),(3,20),(4,20),(5,21),(5,21),(6,21),(6,21),(7,21),(7,21),(8,21),(9,21),(9,21),(10,21),(11,21),(11,21),(12,21),(13,21),(13,21),(14,21),(14,21),(15,21),(15,21),(16,21),(17,21),(17,21),(18,21),(18,21),(19,21),(19,21),(20,21),(21,21),(21,21),(22,21),(23,21),(23,21),(24,21),(25,21),(25,21),(26,21),(27,21),(27,21),(28,21),(28,21),(29,21),(29,21),(30,21),(31,21),(31,21),(32,21),(33,21),(33,21),(34,21),(34,21),(35,21),(35,21),(36,21),(36,21),(37,21),(37,21),(38,21),(39,21),(39,21),(40,21),(40,21),(41,21),(41,21),(42,21),(43,21),(43,21),(44,22),(45,22),(46,22),(47,23),(47,23),(48,23),(49,23),(50,23),(51,23),(52,24),(53,24),(53,24),(54,24),(55,25),(56,25),(57,25),(57,25),(58,25),(59,26),(60,26),(61,26),(61,26),(62,26),(63,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),(64,24),(64,24),],
+[(0,21),(0,21),(1,21),(1,21),(1,21),(2,21),(3,21),(3,21),(4,21),(5,21),(5,21),(6,21),(7,21),(7,21),(8,21),(8,21),(9,21),(9,21),(10,21),(11,21),(11,21),(12,21),(13,21),(13,21),(14,21),(14,21),(15,21),(15,21),(16,21),(17,21),(17,21),(18,21),(19,21),(19,21),(20,21),(20,21),(21,21),(21,21),(22,22),(23,22),(23,22),(24,22),(25,22),(25,22),(26,22),(27,22),(27,22),(28,22),(29,22),(29,22),(30,22),(30,22),(31,22),(31,22),(32,22),(33,22),(33,22),(34,22),(34,22),(35,22),(35,22),(36,22),(37,22),(37,22),(38,22),(38,22),(39,22),(39,22),(40,22),(41,22),(41,22),(42,22),(43,22),(43,22),(44,22),(45,22),(45,22),(46,23),(47,23),(48,23),(49,23),(49,24),(50,24),(51,24),(52,24),(53,24),(53,25),(54,25),(55,25),(56,25),(57,25),(57,26),(58,26),(59,26),(60,26),(61,26),(61,27),(62,27),(63,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),(64,24),(64,24),],
+[(0,21),(0,21),(1,21),(1,21),(1,21),(2,21),(3,21),(3,21),(4,21),(5,21),(5,21),(6,21),(7,21),(7,21),(8,21),(8,21),(9,21),(9,21),(10,21),(11,21),(11,21),(12,21),(13,22),(13,22),(14,22),(15,22),(15,22),(16,22),(16,22),(17,22),(17,22),(18,22),(19,22),(19,22),(20,22),(21,22),(21,22),(22,22),(23,22),(23,22),(24,22),(24,22),(25,22),(25,22),(26,22),(27,22),(27,22),(28,22),(29,22),(29,22),(30,22),(31,22),(31,22),(32,22),(32,22),(33,22),(33,22),(34,22),(35,22),(35,22),(36,22),(36,22),(37,22),(37,22),(38,22),(39,22),(39,22),(40,22),(40,22),(41,22),(41,22),(42,22),(43,22),(43,22),(44,23),(45,23),(46,23),(47,23),(47,23),(48,24),(49,24),(50,24),(51,24),(51,24),(52,25),(53,25),(54,25),(55,25),(55,25),(56,26),(57,26),(58,26),(59,26),(59,27),(60,27),(61,27),(62,27),(63,27),(63,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,25),(64,24),],
+[(0,21),(0,21),(1,21),(1,21),(1,21),(2,21),(3,21),(3,21),(4,21),(5,22),(5,22),(6,22),(7,22),(7,22),(8,22),(8,22),(9,22),(9,22),(10,22),(11,22),(11,22),(12,22),(13,22),(13,22),(14,22),(15,22),(15,22),(16,22),(16,22),(17,22),(17,22),(18,22),(19,22),(19,22),(20,22),(21,22),(21,22),(22,22),(23,22),(23,23),(24,23),(25,23),(25,23),(26,23),(26,23),(27,23),(27,23),(28,23),(29,23),(29,23),(30,23),(31,23),(31,23),(32,23),(33,23),(33,23),(34,23),(34,23),(35,23),(35,23),(36,23),(37,23),(37,23),(38,23),(38,23),(39,23),(39,23),(40,23),(41,23),(41,23),(42,23),(43,23),(43,23),(44,23),(45,23),(45,23),(46,23),(47,24),(48,24),(49,24),(49,24),(50,25),(51,25),(52,25),(53,25),(53,25),(54,26),(55,26),(56,26),(57,26),(57,26),(58,27),(59,27),(60,27),(61,27),(61,27),(62,28),(63,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),(64,25),(64,25),],
+[(0,22),(0,22),(1,22),(1,22),(1,22),(2,22),(3,22),(3,22),(4,22),(5,22),(5,22),(6,22),(7,22),(7,22),(8,22),(9,22),(9,22),(10,22),(10,22),(11,22),(11,22),(12,22),(13,22),(13,22),(14,22),(15,22),(15,22),(16,22),(17,22),(17,23),(18,23),(18,23),(19,23),(19,23),(20,23),(21,23),(21,23),(22,23),(23,23),(23,23),(24,23),(25,23),(25,23),(26,23),(27,23),(27,23),(28,23),(28,23),(29,23),(29,23),(30,23),(31,23),(31,23),(32,23),(33,23),(33,23),(34,23),(34,23),(35,23),(35,23),(36,23),(37,23),(37,23),(38,23),(38,23),(39,23),(39,23),(40,23),(41,23),(41,23),(42,23),(43,23),(43,23),(44,23),(45,24),(46,24),(47,24),(47,24),(48,24),(49,25),(50,25),(51,25),(51,25),(52,25),(53,26),(54,26),(55,26),(55,26),(56,27),(57,27),(58,27),(59,27),(59,27),(60,28),(61,28),(62,28),(63,28),(63,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),(64,25),(64,25),],
+[(0,22),(0,22),(1,22),(1,22),(1,22),(2,22),(3,22),(3,22),(4,22),(5,22),(5,22),(6,22),(7,22),(7,22),(8,23),(9,23),(9,23),(10,23),(10,23),(11,23),(11,23),(12,23),(13,23),(13,23),(14,23),(15,23),(15,23),(16,23),(17,23),(17,23),(18,23),(19,23),(19,23),(20,23),(20,23),(21,23),(21,23),(22,23),(23,23),(23,23),(24,23),(25,23),(25,23),(26,23),(27,23),(27,23),(28,23),(29,23),(29,23),(30,23),(31,23),(31,23),(32,23),(32,23),(33,23),(33,23),(34,23),(35,23),(35,23),(36,23),(36,23),(37,23),(37,23),(38,23),(39,23),(39,23),(40,23),(41,23),(41,23),(42,24),(43,24),(43,24),(44,24),(45,24),(45,24),(46,24),(47,25),(48,25),(49,25),(49,25),(50,25),(51,26),(52,26),(53,26),(53,26),(54,26),(55,27),(56,27),(57,27),(57,27),(58,27),(59,28),(60,28),(61,28),(61,28),(62,29),(63,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),(64,26),(64,25),],
+[(0,23),(0,23),(1,23),(1,23),(1,23),(2,23),(3,23),(3,23),(4,23),(5,23),(5,23),(6,23),(7,23),(7,23),(8,23),(9,23),(9,23),(10,23),(11,23),(11,23),(12,23),(12,23),(13,23),(13,23),(14,23),(15,23),(15,23),(16,23),(17,23),(17,23),(18,23),(19,23),(19,23),(20,23),(21,23),(21,23),(22,23),(23,23),(23,23),(24,24),(24,24),(25,24),(25,24),(26,24),(27,24),(27,24),(28,24),(29,24),(29,24),(30,24),(31,24),(31,24),(32,24),(33,24),(33,24),(34,24),(34,24),(35,24),(35,24),(36,24),(37,24),(37,24),(38,24),(38,24),(39,24),(39,24),(40,24),(41,24),(41,24),(42,24),(43,24),(43,24),(44,24),(45,25),(46,25),(47,25),(47,25),(48,25),(49,25),(50,26),(51,26),(51,26),(52,26),(53,27),(54,27),(55,27),(55,27),(56,27),(57,28),(58,28),(59,28),(59,28),(60,28),(61,29),(62,29),(63,29),(63,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),(64,26),(64,26),(64,26),],
+[(0,23),(0,23),(1,23),(1,23),(1,23),(2,23),(3,23),(3,23),(4,23),(5,23),(5,23),(6,23),(7,23),(7,23),(8,23),(9,23),(9,23),(10,23),(11,23),(11,23),(12,23),(13,23),(13,23),(14,23),(14,23),(15,23),(15,23),(16,23),(17,23),(17,24),(18,24),(19,24),(19,24),(20,24),(21,24),(21,24),(22,24),(23,24),(23,24),(24,24),(25,24),(25,24),(26,24),(27,24),(27,24),(28,24),(28,24),(29,24),(29,24),(30,24),(31,24),(31,24),(32,24),(33,24),(33,24),(34,24),(35,24),(35,24),(36,24),(36,24),(37,24),(37,24),(38,24),(39,24),(39,24),(40,24),(41,24),(41,24),(42,24),(43,25),(43,25),(44,25),(45,25),(45,25),(46,25),(47,25),(48,26),(49,26),(49,26),(50,26),(51,27),(52,27),(53,27),(53,27),(54,27),(55,27),(56,28),(57,28),(57,28),(58,28),(59,29),(60,29),(61,29),(61,29),(62,29),(63,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,26),(64,26),],
+[(0,23),(0,23),(1,23),(1,23),(2,23),(2,23),(3,23),(3,23),(4,23),(5,23),(5,23),(6,23),(7,23),(7,23),(8,24),(9,24),(9,24),(10,24),(11,24),(11,24),(12,24),(13,24),(13,24),(14,24),(15,24),(15,24),(16,24),(16,24),(17,24),(17,24),(18,24),(19,24),(19,24),(20,24),(21,24),(21,24),(22,24),(23,24),(23,24),(24,24),(25,25),(25,25),(26,25),(27,25),(27,25),(28,25),(29,25),(29,25),(30,25),(31,25),(31,25),(32,25),(32,25),(33,25),(33,25),(34,25),(35,25),(35,25),(36,25),(37,25),(37,25),(38,25),(38,25),(39,25),(39,25),(40,25),(41,25),(41,25),(42,25),(43,25),(43,25),(44,25),(45,25),(46,26),(47,26),(47,26),(48,26),(49,26),(50,27),(51,27),(51,27),(52,27),(53,27),(54,28),(55,28),(55,28),(56,28),(57,28),(58,29),(59,29),(59,29),(60,29),(61,29),(62,30),(63,30),(63,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),(64,27),],
+[(0,24),(0,24),(1,24),(1,24),(2,24),(2,24),(3,24),(3,24),(4,24),(5,24),(5,24),(6,24),(7,24),(7,24),(8,24),(9,24),(9,24),(10,24),(11,24),(11,24),(12,24),(13,24),(13,24),(14,24),(15,24),(15,24),(16,24),(17,24),(17,24),(18,24),(18,24),(19,24),(19,25),(20,25),(21,25),(21,25),(22,25),(23,25),(23,25),(24,25),(25,25),(25,25),(26,25),(27,25),(27,25),(28,25),(29,25),(29,25),(30,25),(31,25),(31,25),(32,25),(33,25),(33,25),(34,25),(34,25),(35,25),(35,25),(36,25),(37,25),(37,25),(38,25),(39,25),(39,25),(40,25),(41,25),(41,25),(42,25),(43,25),(43,26),(44,26),(45,26),(45,26),(46,26),(47,26),(48,27),(49,27),(49,27),(50,27),(51,27),(51,28),(52,28),(53,28),(54,28),(55,28),(55,29),(56,29),(57,29),(58,29),(59,29),(59,30),(60,30),(61,30),(62,30),(63,30),(63,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),(64,27),(64,27),(64,27),],
+[(0,24),(0,24),(1,24),(1,24),(2,24),(3,24),(3,24),(4,24),(4,24),(5,24),(5,24),(6,24),(7,24),(7,24),(8,24),(9,24),(9,24),(10,24),(11,24),(11,25),(12,25),(13,25),(13,25),(14,25),(15,25),(15,25),(16,25),(17,25),(17,25),(18,25),(19,25),(19,25),(20,25),(21,25),(21,25),(22,25),(23,25),(23,25),(24,25),(25,25),(25,25),(26,25),(27,25),(27,25),(28,25),(28,25),(29,25),(29,25),(30,25),(31,25),(31,25),(32,25),(33,25),(33,26),(34,26),(35,26),(35,26),(36,26),(37,26),(37,26),(38,26),(38,26),(39,26),(39,26),(40,26),(41,26),(41,26),(42,26),(43,26),(43,26),(44,26),(45,26),(46,27),(47,27),(47,27),(48,27),(49,27),(49,27),(50,28),(51,28),(52,28),(53,28),(53,29),(54,29),(55,29),(56,29),(57,29),(57,29),(58,30),(59,30),(60,30),(61,30),(61,31),(62,31),(63,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),(64,27),(64,27),],
+[(0,24),(0,24),(1,24),(1,25),(2,25),(3,25),(3,25),(4,25),(4,25),(5,25),(5,25),(6,25),(7,25),(7,25),(8,25),(9,25),(9,25),(10,25),(11,25),(11,25),(12,25),(13,25),(13,25),(14,25),(15,25),(15,25),(16,25),(17,25),(17,25),(18,25),(19,25),(19,25),(20,25),(21,25),(21,25),(22,25),(23,25),(23,25),(24,25),(25,25),(25,26),(26,26),(27,26),(27,26),(28,26),(29,26),(29,26),(30,26),(31,26),(31,26),(32,26),(32,26),(33,26),(33,26),(34,26),(35,26),(35,26),(36,26),(37,26),(37,26),(38,26),(39,26),(39,26),(40,26),(41,26),(41,26),(42,26),(43,26),(43,26),(44,27),(45,27),(45,27),(46,27),(47,27),(47,27),(48,28),(49,28),(50,28),(51,28),(51,28),(52,29),(53,29),(54,29),(55,29),(55,29),(56,30),(57,30),(58,30),(59,30),(59,30),(60,31),(61,31),(62,31),(63,31),(63,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),(64,28),(64,28),],
+[(0,25),(0,25),(1,25),(1,25),(2,25),(3,25),(3,25),(4,25),(5,25),(5,25),(6,25),(7,25),(7,25),(8,25),(8,25),(9,25),(9,25),(10,25),(11,25),(11,25),(12,25),(13,25),(13,25),(14,25),(15,25),(15,25),(16,25),(17,25),(17,25),(18,25),(19,25),(19,25),(20,26),(21,26),(21,26),(22,26),(23,26),(23,26),(24,26),(25,26),(25,26),(26,26),(27,26),(27,26),(28,26),(29,26),(29,26),(30,26),(31,26),(31,26),(32,26),(33,26),(33,26),(34,27),(35,27),(35,27),(36,27),(36,27),(37,27),(37,27),(38,27),(39,27),(39,27),(40,27),(41,27),(41,27),(42,27),(43,27),(43,27),(44,27),(45,27),(45,27),(46,28),(47,28),(48,28),(49,28),(49,28),(50,29),(51,29),(52,29),(53,29),(53,29),(54,30),(55,30),(56,30),(57,30),(57,30),(58,31),(59,31),(60,31),(61,31),(61,31),(62,32),(63,32),(63,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),(64,28),(64,28),],
+[(0,25),(0,25),(1,25),(1,25),(2,25),(3,25),(3,25),(4,25),(5,25),(5,25),(6,25),(7,25),(7,25),(8,25),(9,25),(9,25),(10,25),(11,25),(11,25),(12,26),(12,26),(13,26),(13,26),(14,26),(15,26),(15,26),(16,26),(17,26),(17,26),(18,26),(19,26),(19,26),(20,26),(21,26),(21,26),(22,26),(23,26),(23,26),(24,26),(25,26),(25,26),(26,27),(27,27),(27,27),(28,27),(29,27),(29,27),(30,27),(31,27),(31,27),(32,27),(33,27),(33,27),(34,27),(35,27),(35,27),(36,27),(37,27),(37,27),(38,27),(39,27),(39,27),(40,27),(41,27),(41,27),(42,27),(43,27),(43,27),(44,27),(45,28),(45,28),(46,28),(47,28),(47,28),(48,29),(49,29),(50,29),(51,29),(51,29),(52,29),(53,30),(54,30),(55,30),(55,30),(56,31),(57,31),(58,31),(59,31),(59,31),(60,31),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),(64,28),(64,28),],
+[(0,25),(0,25),(1,25),(1,26),(2,26),(3,26),(3,26),(4,26),(5,26),(5,26),(6,26),(7,26),(7,26),(8,26),(9,26),(9,26),(10,26),(11,26),(11,26),(12,26),(13,26),(13,26),(14,26),(15,26),(15,26),(16,26),(16,26),(17,26),(17,26),(18,26),(19,26),(19,26),(20,26),(21,26),(21,26),(22,27),(23,27),(23,27),(24,27),(25,27),(26,27),(27,27),(27,27),(28,27),(29,27),(29,27),(30,27),(31,27),(31,27),(32,27),(33,27),(33,27),(34,27),(34,27),(35,27),(35,27),(36,27),(37,27),(37,27),(38,27),(39,27),(39,27),(40,27),(41,27),(41,28),(42,28),(43,28),(43,28),(44,28),(45,28),(45,28),(46,29),(47,29),(48,29),(49,29),(49,29),(50,29),(51,30),(52,30),(53,30),(53,30),(54,31),(55,31),(56,31),(57,31),(57,31),(58,31),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),(64,29),(64,29),],
+[(0,26),(0,26),(1,26),(1,26),(2,26),(3,26),(3,26),(4,26),(5,26),(5,26),(6,26),(7,26),(7,26),(8,26),(9,26),(9,26),(10,26),(11,26),(11,26),(12,26),(13,26),(13,26),(14,26),(15,27),(15,27),(16,27),(17,27),(17,27),(18,27),(19,27),(19,27),(20,27),(21,27),(21,27),(22,27),(23,27),(23,27),(24,27),(25,27),(25,27),(26,27),(27,27),(27,27),(28,27),(29,27),(29,27),(30,27),(31,27),(31,27),(32,27),(33,28),(33,28),(34,28),(35,28),(35,28),(36,28),(37,28),(37,28),(38,28),(39,28),(39,28),(40,28),(40,28),(41,28),(41,28),(42,28),(43,28),(44,28),(45,28),(45,29),(46,29),(47,29),(47,29),(48,29),(49,30),(50,30),(51,30),(51,30),(52,30),(53,31),(54,31),(55,31),(55,31),(56,31),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),(64,29),(64,29),],
+[(0,26),(0,26),(1,26),(1,26),(2,26),(3,26),(3,26),(4,27),(5,27),(5,27),(6,27),(7,27),(7,27),(8,27),(9,27),(9,27),(10,27),(11,27),(11,27),(12,27),(13,27),(13,27),(14,27),(15,27),(15,27),(16,27),(17,27),(17,27),(18,27),(19,27),(19,27),(20,27),(21,27),(21,27),(22,27),(23,27),(23,27),(24,27),(25,27),(25,27),(26,27),(27,28),(27,28),(28,28),(29,28),(29,28),(30,28),(31,28),(31,28),(32,28),(33,28),(33,28),(34,28),(35,28),(35,28),(36,28),(37,28),(37,28),(38,28),(39,28),(39,28),(40,28),(41,28),(41,28),(42,29),(43,29),(43,29),(44,29),(45,29),(45,29),(46,29),(47,30),(48,30),(49,30),(49,30),(50,30),(51,31),(52,31),(53,31),(53,31),(54,31),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),(64,30),(64,29),(64,29),],
+[(0,27),(0,27),(1,27),(1,27),(2,27),(3,27),(3,27),(4,27),(5,27),(5,27),(6,27),(7,27),(7,27),(8,27),(9,27),(9,27),(10,27),(11,27),(11,27),(12,27),(13,27),(13,27),(14,27),(15,27),(15,27),(16,27),(17,27),(17,27),(18,27),(19,27),(19,27),(20,27),(21,27),(21,27),(22,27),(23,28),(23,28),(24,28),(25,28),(25,28),(26,28),(27,28),(27,28),(28,28),(29,28),(29,28),(30,28),(31,28),(31,28),(32,28),(33,29),(34,29),(35,29),(35,29),(36,29),(36,29),(37,29),(37,29),(38,29),(39,29),(39,29),(40,29),(41,29),(41,29),(42,29),(43,29),(43,29),(44,29),(45,29),(46,30),(47,30),(47,30),(48,30),(49,31),(50,31),(51,31),(51,31),(52,31),(53,31),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),(64,30),(64,30),],
+[(0,27),(0,27),(1,27),(1,27),(2,27),(3,27),(3,27),(4,27),(5,27),(5,27),(6,27),(7,27),(7,27),(8,27),(9,27),(9,27),(10,27),(11,27),(11,27),(12,27),(13,27),(13,27),(14,27),(15,28),(15,28),(16,28),(17,28),(17,28),(18,28),(19,28),(19,28),(20,28),(21,28),(21,28),(22,28),(23,28),(23,28),(24,28),(25,28),(25,28),(26,28),(27,28),(28,28),(29,29),(29,29),(30,29),(31,29),(31,29),(32,29),(33,29),(33,29),(34,29),(35,29),(35,29),(36,29),(37,29),(37,29),(38,29),(39,29),(39,29),(40,29),(41,29),(41,29),(42,29),(43,29),(43,30),(44,30),(45,30),(45,30),(46,30),(47,30),(48,31),(49,31),(49,31),(50,31),(51,31),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),(64,30),(64,30),],
+[(0,27),(0,27),(1,27),(1,27),(2,27),(3,27),(3,27),(4,28),(5,28),(5,28),(6,28),(7,28),(7,28),(8,28),(9,28),(9,28),(10,28),(11,28),(11,28),(12,28),(13,28),(13,28),(14,28),(15,28),(15,28),(16,28),(17,28),(17,28),(18,28),(19,28),(19,28),(20,28),(21,28),(21,28),(22,28),(23,28),(23,29),(24,29),(25,29),(26,29),(27,29),(27,29),(28,29),(29,29),(29,29),(30,29),(31,29),(31,29),(32,29),(33,29),(33,29),(34,29),(35,29),(35,29),(36,29),(37,29),(37,29),(38,30),(39,30),(39,30),(40,30),(41,30),(41,30),(42,30),(43,30),(43,30),(44,30),(45,30),(46,31),(47,31),(47,31),(48,31),(49,31),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,31),(64,30),],
+[(0,28),(0,28),(1,28),(1,28),(2,28),(3,28),(3,28),(4,28),(5,28),(5,28),(6,28),(7,28),(7,28),(8,28),(9,28),(9,28),(10,28),(11,28),(11,28),(12,28),(13,28),(13,28),(14,28),(15,28),(15,28),(16,28),(17,28),(18,28),(19,29),(19,29),(20,29),(21,29),(21,29),(22,29),(23,29),(23,29),(24,29),(25,29),(25,29),(26,29),(27,29),(27,29),(28,29),(29,29),(29,29),(30,29),(31,29),(31,29),(32,29),(33,30),(33,30),(34,30),(35,30),(35,30),(36,30),(37,30),(38,30),(39,30),(39,30),(40,30),(41,30),(41,30),(42,30),(43,30),(43,30),(44,31),(45,31),(45,31),(46,31),(47,31),(48,31),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),(64,31),(64,31),],
+[(0,28),(0,28),(1,28),(1,28),(2,28),(3,28),(3,28),(4,28),(5,28),(5,28),(6,28),(7,29),(7,29),(8,29),(9,29),(9,29),(10,29),(11,29),(11,29),(12,29),(13,29),(14,29),(15,29),(15,29),(16,29),(17,29),(17,29),(18,29),(19,29),(19,29),(20,29),(21,29),(21,29),(22,29),(23,29),(23,29),(24,29),(25,29),(25,29),(26,29),(27,29),(27,29),(28,29),(29,30),(29,30),(30,30),(31,30),(32,30),(33,30),(33,30),(34,30),(35,30),(35,30),(36,30),(37,30),(37,30),(38,30),(39,31),(39,31),(40,31),(41,31),(41,31),(42,31),(43,31),(43,31),(44,31),(45,31),(46,31),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),(64,31),(64,31),],
+[(0,29),(0,29),(1,29),(1,29),(2,29),(3,29),(3,29),(4,29),(5,29),(5,29),(6,29),(7,29),(7,29),(8,29),(9,29),(9,29),(10,29),(11,29),(12,29),(13,29),(13,29),(14,29),(15,29),(15,29),(16,29),(17,29),(17,29),(18,29),(19,29),(19,29),(20,29),(21,29),(21,29),(22,29),(23,29),(23,29),(24,30),(25,30),(25,30),(26,30),(27,30),(27,30),(28,30),(29,30),(30,30),(31,30),(31,30),(32,30),(33,30),(33,30),(34,31),(35,31),(35,31),(36,31),(37,31),(37,31),(38,31),(39,31),(39,31),(40,31),(41,31),(41,31),(42,31),(43,31),(44,31),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,31),],
+[(0,29),(0,29),(1,29),(1,29),(2,29),(3,29),(3,29),(4,29),(5,29),(5,29),(6,29),(7,29),(7,29),(8,29),(9,29),(9,29),(10,29),(11,29),(12,29),(13,29),(13,29),(14,29),(15,29),(15,29),(16,29),(17,29),(17,29),(18,29),(19,30),(19,30),(20,30),(21,30),(21,30),(22,30),(23,30),(23,30),(24,30),(25,30),(25,30),(26,30),(27,30),(28,30),(29,30),(29,30),(30,31),(31,31),(31,31),(32,31),(33,31),(33,31),(34,31),(35,31),(35,31),(36,31),(37,31),(37,31),(38,31),(39,31),(40,31),(41,31),(41,31),(42,31),(43,32),(43,32),(44,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,29),(0,29),(1,29),(1,29),(2,29),(3,29),(3,29),(4,29),(5,29),(5,29),(6,29),(7,30),(7,30),(8,30),(9,30),(10,30),(11,30),(11,30),(12,30),(13,30),(13,30),(14,30),(15,30),(15,30),(16,30),(17,30),(17,30),(18,30),(19,30),(19,30),(20,30),(21,30),(21,30),(22,30),(23,30),(24,30),(25,30),(25,30),(26,31),(27,31),(27,31),(28,31),(29,31),(29,31),(30,31),(31,31),(31,31),(32,31),(33,31),(33,31),(34,31),(35,31),(35,31),(36,31),(37,31),(38,31),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,30),(0,30),(1,30),(1,30),(2,30),(3,30),(3,30),(4,30),(5,30),(5,30),(6,30),(7,30),(7,30),(8,30),(9,30),(10,30),(11,30),(11,30),(12,30),(13,30),(13,30),(14,30),(15,30),(15,30),(16,30),(17,30),(17,30),(18,30),(19,30),(19,30),(20,30),(21,30),(22,31),(23,31),(23,31),(24,31),(25,31),(25,31),(26,31),(27,31),(27,31),(28,31),(29,31),(29,31),(30,31),(31,31),(31,31),(32,31),(33,31),(34,31),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(40,32),(41,32),(41,32),(42,32),(43,32),(43,32),(44,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,30),(0,30),(1,30),(1,30),(2,30),(3,30),(3,30),(4,30),(5,30),(5,30),(6,30),(7,30),(7,30),(8,30),(9,30),(10,30),(11,31),(11,31),(12,31),(13,31),(13,31),(14,31),(15,31),(15,31),(16,31),(17,31),(17,31),(18,31),(19,31),(20,31),(21,31),(21,31),(22,31),(23,31),(23,31),(24,31),(25,31),(25,31),(26,31),(27,31),(27,31),(28,31),(29,31),(29,31),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,31),(0,31),(1,31),(1,31),(2,31),(3,31),(3,31),(4,31),(5,31),(5,31),(6,31),(7,31),(7,31),(8,31),(9,31),(10,31),(11,31),(11,31),(12,31),(13,31),(13,31),(14,31),(15,31),(15,31),(16,31),(17,31),(18,31),(19,31),(19,31),(20,31),(21,31),(21,31),(22,31),(23,31),(23,31),(24,31),(25,31),(25,31),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,31),(0,31),(1,31),(1,31),(2,31),(3,31),(3,31),(4,31),(5,31),(5,31),(6,31),(7,31),(7,31),(8,31),(9,31),(10,31),(11,31),(11,31),(12,31),(13,31),(13,31),(14,31),(15,31),(15,31),(16,31),(17,31),(18,31),(19,31),(19,31),(20,31),(21,31),(21,31),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(44,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,31),(0,31),(1,31),(1,31),(2,31),(3,31),(3,31),(4,31),(5,31),(5,31),(6,31),(7,31),(7,31),(8,31),(9,31),(10,31),(11,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(18,32),(19,32),(19,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,32),(0,32),(1,32),(1,32),(2,32),(3,32),(3,32),(4,32),(5,32),(5,32),(6,32),(7,32),(7,32),(8,32),(9,32),(10,32),(11,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(18,32),(19,32),(19,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,32),(0,32),(1,32),(1,32),(2,32),(3,32),(3,32),(4,32),(5,32),(5,32),(6,32),(7,32),(7,32),(8,32),(9,32),(10,32),(11,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(18,32),(19,32),(19,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,32),(0,32),(1,32),(1,32),(2,32),(3,32),(3,32),(4,32),(5,32),(5,32),(6,32),(7,32),(7,32),(8,32),(9,32),(10,32),(11,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(18,32),(19,32),(19,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(60,32),(61,32),(61,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,32),(0,32),(1,32),(1,32),(2,32),(3,32),(3,32),(4,32),(5,32),(5,32),(6,32),(7,32),(7,32),(8,32),(9,32),(10,32),(11,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(17,32),(18,32),(19,32),(20,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(49,32),(49,32),(50,32),(50,32),(51,32),(51,32),(52,32),(53,32),(53,32),(54,32),(54,32),(55,32),(55,32),(56,32),(57,32),(57,32),(58,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+[(0,32),(0,32),(1,32),(1,32),(1,32),(2,32),(3,32),(4,32),(5,32),(5,32),(6,32),(7,32),(7,32),(8,32),(9,32),(9,32),(10,32),(11,32),(12,32),(13,32),(13,32),(14,32),(15,32),(15,32),(16,32),(17,32),(17,32),(18,32),(19,32),(20,32),(20,32),(21,32),(21,32),(22,32),(23,32),(23,32),(24,32),(25,32),(25,32),(26,32),(27,32),(27,32),(28,32),(29,32),(29,32),(30,32),(31,32),(31,32),(32,32),(33,32),(33,32),(34,32),(35,32),(35,32),(36,32),(37,32),(37,32),(38,32),(39,32),(39,32),(40,32),(41,32),(41,32),(42,32),(42,32),(42,32),(43,32),(43,32),(43,32),(44,32),(45,32),(45,32),(46,32),(47,32),(47,32),(48,32),(48,32),(49,32),(49,32),(50,32),(51,32),(51,32),(52,32),(52,32),(53,32),(53,32),(54,32),(55,32),(55,32),(56,32),(56,32),(57,32),(57,32),(58,32),(59,32),(59,32),(60,32),(61,32),(61,32),(62,32),(62,32),(63,32),(63,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),(64,32),],
+]
+;
+
+/// Same as VIRTUAL_TO_PHYSICAL_MAP, for the high resolution (128 x 64) Virtual Screen.
+/// Generated from HIRES_PHYSICAL_TO_VIRTUAL_MAP by `scripts/gen-hires-maps.py`: the Bounding Box of all Physical (x,y)
+/// Coordinates that map to each Virtual (x,y) Coordinate.
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+static HIRES_VIRTUAL_TO_PHYSICAL_MAP: &[[(u8,u8,u8,u8); HIRES_VIRTUAL_TO_PHYSICAL_MAP_WIDTH]; HIRES_VIRTUAL_TO_PHYSICAL_MAP_HEIGHT] = &  //  Row=Y, Col=X
+[[(0,0,1,2),(2,0,4,2),(5,0,6,2),(7,0,8,2),(9,0,10,2),(11,0,12,2),(13,0,14,2),(15,0,16,2),(17,0,18,2),(19,0,20,2),(21,0,22,2),(23,0,24,2),(25,0,26,2),(27,0,28,2),(29,0,30,2),(31,0,32,2),(33,0,33,2),(34,0,35,2),(36,0,37,2),(38,0,39,2),(40,0,41,2),(42,0,43,2),(44,0,45,2),(45,0,47,2),(47,0,48,2),(49,0,50,2),(51,0,52,2),(52,0,54,2),(54,0,56,2),(56,0,58,2),(58,0,59,2),(59,0,61,2),(61,0,63,2),(62,0,65,2),(64,0,66,2),(66,0,68,2),(68,0,70,2),(69,0,72,2),(71,0,74,2),(72,0,76,2),(74,0,77,2),(76,0,79,2),(78,0,80,2),(79,0,82,2),(81,0,84,2),(82,0,86,2),(84,0,87,2),(86,0,89,2),(88,0,90,2),(89,0,92,2),(91,0,93,2),(93,0,95,2),(95,0,96,2),(96,0,98,2),(98,0,99,2),(99,0,101,2),(101,0,102,2),(103,0,104,1),(105,0,105,1),(106,0,107,1),(108,0,109,1),(109,0,111,1),(111,0,112,1),(113,0,114,1),],
+[(0,3,1,6),(2,3,4,6),(5,3,6,6),(7,3,8,6),(9,3,10,6),(11,3,12,6),(13,3,14,6),(15,3,16,6),(17,3,18,6),(19,3,20,6),(21,3,22,6),(23,3,24,6),(25,3,26,6),(26,3,28,6),(28,3,29,6),(30,3,31,6),(32,3,33,6),(34,3,35,6),(36,3,37,6),(38,3,39,6),(40,3,41,6),(42,3,43,6),(44,3,45,6),(45,3,47,6),(47,3,48,6),(49,3,50,6),(51,3,52,6),(53,3,54,6),(55,3,55,6),(56,3,57,6),(58,3,59,6),(60,3,61,6),(62,3,63,6),(63,3,65,6),(65,3,66,6),(67,3,68,6),(69,3,70,6),(71,3,72,6),(73,3,73,6),(74,3,75,6),(76,3,77,6),(78,3,79,6),(80,3,80,6),(81,3,82,6),(83,3,83,6),(84,3,85,5),(86,3,87,5),(88,3,89,5),(90,3,90,5),(91,3,92,5),(93,3,93,5),(94,3,95,5),(96,3,96,5),(97,3,98,5),(99,3,99,5),(100,3,101,5),(102,3,102,5),(103,2,104,4),(105,2,105,4),(106,2,107,4),(108,2,109,4),(109,2,111,4),(111,2,112,4),(113,2,114,4),],
+[(0,7,1,9),(2,7,4,9),(5,7,6,9),(7,7,8,9),(9,7,10,9),(11,7,12,9),(13,7,14,9),(15,7,16,9),(17,7,18,9),(19,7,20,9),(21,7,22,9),(22,7,24,9),(24,7,25,9),(26,7,27,9),(28,7,29,9),(30,7,31,9),(32,7,33,9),(34,7,35,9),(36,7,37,9),(38,7,39,9),(40,7,41,9),(42,7,43,9),(44,7,44,9),(45,7,46,9),(47,7,48,9),(49,7,50,9),(51,7,52,9),(52,7,54,9),(54,7,55,8),(56,7,57,8),(58,7,59,8),(60,7,61,8),(62,7,62,8),(63,7,64,8),(65,7,66,8),(67,7,68,8),(69,7,70,8),(70,7,72,8),(72,7,73,8),(74,7,75,8),(76,7,77,8),(78,7,79,8),(80,7,80,8),(81,7,82,8),(83,7,83,8),(84,6,85,8),(86,6,86,8),(87,6,88,7),(89,6,90,7),(91,6,92,7),(93,6,93,7),(94,6,95,7),(96,6,96,7),(97,6,98,7),(99,6,99,7),(100,6,101,7),(102,6,102,7),(103,5,104,6),(105,5,105,6),(106,5,107,6),(108,5,108,6),(109,5,110,6),(111,5,112,6),(113,5,114,6),],
+[(0,10,1,12),(2,10,4,12),(5,10,6,12),(7,10,8,12),(9,10,10,12),(11,10,12,12),(13,10,14,12),(15,10,16,12),(17,10,18,12),(19,10,20,12),(21,10,21,12),(22,10,23,12),(24,10,25,12),(26,10,27,12),(28,10,29,12),(30,10,31,12),(32,10,33,12),(34,10,35,12),(36,10,37,12),(38,10,39,12),(40,10,41,12),(41,10,43,12),(43,10,44,12),(45,10,46,12),(47,10,48,12),(49,10,50,12),(51,10,51,12),(52,9,53,12),(54,9,55,11),(56,9,57,11),(58,9,59,11),(59,9,61,11),(61,9,62,11),(63,9,64,11),(65,9,66,11),(67,9,68,11),(69,9,69,11),(70,9,71,11),(72,9,73,11),(74,9,75,11),(76,9,77,11),(77,9,79,11),(79,9,80,11),(81,9,82,11),(83,9,83,11),(84,9,85,11),(86,9,86,11),(87,8,88,10),(89,8,89,10),(90,8,91,10),(92,8,93,10),(93,8,95,10),(95,8,96,10),(97,8,98,10),(99,8,99,10),(100,8,101,9),(102,8,102,9),(103,7,104,9),(105,7,105,9),(106,7,107,9),(108,7,108,9),(109,7,110,9),(111,7,111,9),(112,7,113,9),],
+[(0,13,1,15),(2,13,4,15),(5,13,6,15),(7,13,8,15),(9,13,10,15),(11,13,12,15),(13,13,14,15),(15,13,16,15),(17,13,18,15),(18,13,20,15),(20,13,21,15),(22,13,23,15),(24,13,25,15),(26,13,27,15),(28,13,29,15),(30,13,31,15),(32,13,33,15),(34,13,35,15),(36,13,37,15),(37,13,39,15),(39,13,40,15),(41,13,42,15),(43,13,44,15),(45,13,46,15),(47,13,48,15),(48,13,50,15),(50,13,51,15),(52,12,53,14),(54,12,55,14),(56,12,57,14),(58,12,58,14),(59,12,60,14),(61,12,62,14),(63,12,64,14),(65,12,66,14),(66,12,68,14),(68,12,69,14),(70,12,71,14),(72,12,73,14),(74,12,75,14),(76,12,76,14),(77,12,78,14),(79,12,80,13),(81,12,82,13),(83,12,83,13),(84,12,85,13),(86,12,86,13),(87,11,88,13),(89,11,89,12),(90,11,91,12),(92,11,92,12),(93,11,94,12),(95,11,96,12),(97,11,98,12),(99,11,99,12),(100,10,101,11),(102,10,102,11),(103,10,104,11),(105,10,105,11),(106,10,107,11),(108,10,108,11),(109,10,110,11),(111,10,111,11),(112,10,113,11),],
+[(0,16,1,19),(2,16,4,19),(5,16,6,18),(7,16,8,18),(9,16,10,18),(11,16,12,18),(13,16,14,18),(15,16,16,18),(17,16,17,18),(18,16,19,18),(20,16,21,18),(22,16,23,18),(24,16,25,18),(26,16,27,18),(28,16,29,18),(30,16,31,18),(32,16,33,18),(34,16,35,18),(36,16,36,18),(37,16,38,18),(39,16,40,18),(41,16,42,18),(43,16,44,18),(45,16,46,18),(47,16,47,18),(48,16,49,18),(50,16,51,18),(52,15,53,17),(54,15,55,17),(56,15,57,17),(58,15,58,17),(59,15,60,17),(61,15,62,17),(63,15,64,17),(65,15,65,17),(66,15,67,17),(68,15,69,17),(70,15,71,17),(72,15,73,17),(73,15,75,17),(75,15,76,17),(77,14,78,17),(79,14,80,16),(80,14,82,16),(82,14,83,16),(84,14,85,16),(86,14,86,16),(87,14,88,16),(89,13,89,15),(90,13,91,15),(92,13,92,15),(93,13,94,15),(95,13,95,15),(96,13,97,15),(98,13,99,15),(100,12,101,14),(102,12,102,14),(103,12,104,14),(105,12,105,14),(106,12,107,14),(108,12,108,14),(109,12,110,14),(111,12,111,13),(112,12,113,13),],
+[(0,20,1,22),(2,20,4,22),(5,19,6,21),(7,19,8,21),(9,19,10,21),(11,19,12,21),(13,19,14,21),(15,19,16,21),(17,19,17,21),(18,19,19,21),(20,19,21,21),(22,19,23,21),(24,19,25,21),(26,19,27,21),(28,19,29,21),(30,19,31,21),(32,19,32,21),(33,19,34,21),(35,19,36,21),(37,19,38,21),(39,19,40,21),(41,19,42,21),(43,19,44,21),(44,19,46,21),(46,19,47,21),(48,19,49,21),(50,19,51,21),(52,18,53,20),(54,18,54,20),(55,18,56,20),(57,18,58,20),(59,18,60,20),(61,18,62,20),(62,18,64,20),(64,18,65,20),(66,18,67,20),(68,18,69,19),(70,18,71,19),(72,18,72,19),(73,18,74,19),(75,18,76,19),(77,17,78,19),(79,17,79,19),(80,17,81,19),(82,17,83,19),(84,17,85,18),(86,17,86,18),(87,17,88,18),(89,16,89,18),(90,16,91,17),(92,16,92,17),(93,16,94,17),(95,16,95,17),(96,16,97,17),(98,15,99,17),(99,15,101,16),(101,15,102,16),(103,15,104,16),(105,15,105,16),(106,15,107,16),(108,15,108,16),(109,15,110,16),(111,14,111,15),(112,14,113,15),],
+[(0,23,1,25),(2,23,4,25),(5,22,6,25),(7,22,8,25),(9,22,10,25),(11,22,12,25),(13,22,14,25),(14,22,16,25),(16,22,17,25),(18,22,19,25),(20,22,21,25),(22,22,23,25),(24,22,25,25),(26,22,27,24),(28,22,29,24),(29,22,31,24),(31,22,32,24),(33,22,34,24),(35,22,36,24),(37,22,38,24),(39,22,40,24),(41,22,42,24),(43,22,43,24),(44,22,45,24),(46,22,47,24),(48,22,49,24),(50,22,51,24),(51,21,53,24),(54,21,54,23),(55,21,56,23),(57,21,58,23),(59,21,60,23),(61,21,61,23),(62,21,63,23),(64,21,65,23),(66,21,67,23),(68,20,69,22),(70,20,71,22),(72,20,72,22),(73,20,74,22),(75,20,76,22),(77,20,78,22),(79,20,79,22),(80,20,81,22),(82,20,82,22),(83,19,84,21),(85,19,86,21),(86,19,88,21),(88,19,89,21),(90,18,91,20),(92,18,92,20),(93,18,94,20),(95,18,95,20),(96,18,97,20),(98,18,98,20),(99,17,100,19),(101,17,102,19),(102,17,104,19),(104,17,105,19),(106,17,107,19),(108,17,108,18),(109,17,110,18),(111,16,111,18),(112,16,113,18),],
+[(0,26,1,28),(2,26,4,28),(5,26,6,28),(7,26,8,28),(9,26,10,28),(11,26,12,28),(13,26,13,28),(14,26,15,28),(16,26,17,28),(18,26,19,28),(20,26,21,28),(22,26,23,28),(24,25,25,28),(26,25,27,27),(28,25,28,27),(29,25,30,27),(31,25,32,27),(33,25,34,27),(35,25,36,27),(37,25,38,27),(39,25,40,27),(40,25,42,27),(42,25,43,27),(44,25,45,27),(46,25,47,27),(48,25,49,27),(50,25,50,27),(51,24,52,27),(53,24,54,26),(55,24,56,26),(57,24,58,26),(58,24,60,26),(60,24,61,26),(62,24,63,26),(64,24,65,26),(66,24,67,25),(68,23,68,25),(69,23,70,25),(71,23,72,25),(73,23,74,25),(75,23,76,25),(76,23,78,25),(79,23,79,24),(80,23,81,24),(82,23,82,24),(83,22,84,24),(85,22,85,23),(86,22,87,23),(88,22,88,23),(89,21,91,23),(91,21,92,22),(93,21,94,22),(95,21,95,22),(96,21,97,22),(98,21,98,22),(99,20,100,21),(101,20,101,21),(102,20,103,21),(104,20,105,21),(105,20,107,21),(107,19,108,21),(109,19,110,20),(111,19,111,20),(112,19,113,20),],
+[(0,29,1,31),(2,29,4,31),(5,29,6,31),(7,29,8,31),(9,29,10,31),(11,29,12,31),(13,29,13,31),(14,29,15,31),(16,29,17,31),(18,29,19,31),(20,29,21,31),(22,29,23,31),(24,28,25,31),(25,28,27,31),(27,28,28,31),(29,28,30,31),(31,28,32,31),(33,28,34,31),(35,28,36,31),(37,28,38,31),(39,28,39,30),(40,28,41,30),(42,28,43,30),(44,28,45,30),(46,28,47,30),(47,28,49,30),(49,28,50,30),(51,27,52,30),(53,27,54,29),(55,27,56,29),(57,27,57,29),(58,27,59,29),(60,27,61,29),(62,27,63,29),(64,27,65,29),(65,26,67,29),(67,26,68,28),(69,26,70,28),(71,26,72,28),(73,26,74,28),(75,26,75,28),(76,25,77,28),(78,25,79,27),(80,25,81,27),(82,25,82,27),(83,24,84,27),(85,24,85,26),(86,24,87,26),(88,24,88,26),(89,24,90,26),(91,23,91,25),(92,23,93,25),(94,23,95,25),(95,23,97,25),(97,23,98,25),(99,22,100,24),(101,22,101,24),(102,22,103,24),(104,22,104,24),(105,22,106,24),(107,21,108,23),(109,21,110,23),(111,21,111,23),(112,21,113,23),],
+[(0,32,1,34),(2,32,4,34),(5,32,6,34),(7,32,8,34),(9,32,10,34),(10,32,12,34),(12,32,13,34),(14,32,15,34),(16,32,17,34),(18,32,19,34),(20,32,21,34),(21,32,23,34),(23,32,24,34),(25,32,26,34),(27,32,28,34),(29,32,30,34),(31,32,32,34),(33,32,34,34),(35,32,36,34),(36,31,38,34),(38,31,39,33),(40,31,41,33),(42,31,43,33),(44,31,45,33),(46,31,46,33),(47,31,48,33),(49,31,50,33),(51,31,52,33),(53,30,53,32),(54,30,55,32),(56,30,57,32),(58,30,59,32),(60,30,61,32),(61,30,63,32),(63,30,64,32),(65,29,66,32),(67,29,68,31),(69,29,70,31),(71,29,72,31),(72,29,74,31),(74,29,75,31),(76,28,77,31),(78,28,79,30),(79,28,81,30),(81,28,82,29),(82,27,84,29),(84,27,85,29),(86,27,87,28),(88,27,88,28),(89,27,90,28),(91,26,91,27),(92,26,93,27),(94,26,94,27),(95,26,96,27),(97,26,97,27),(98,25,99,26),(100,25,101,26),(102,25,103,26),(104,25,104,26),(105,24,106,26),(107,24,107,25),(108,24,109,25),(110,24,111,25),(112,24,113,25),],
+[(0,35,1,37),(2,35,4,37),(5,35,6,37),(7,35,8,37),(9,35,9,37),(10,35,11,37),(12,35,13,37),(14,35,15,37),(16,35,17,37),(18,35,19,37),(20,35,20,37),(21,35,22,37),(23,35,24,37),(25,35,26,37),(27,35,28,37),(29,35,30,37),(31,35,31,37),(32,35,33,37),(34,35,35,37),(36,34,37,37),(38,34,39,36),(40,34,41,36),(42,34,42,36),(43,34,44,36),(45,34,46,36),(47,34,48,36),(49,34,49,36),(50,34,51,36),(52,33,53,36),(54,33,55,35),(56,33,57,35),(57,33,59,35),(59,33,60,35),(61,33,62,35),(63,33,64,35),(65,32,66,35),(67,32,68,34),(68,32,70,34),(70,32,71,34),(72,32,73,34),(74,32,75,34),(75,31,77,34),(78,31,78,33),(79,31,80,33),(81,30,81,32),(82,30,83,32),(84,30,84,32),(85,29,86,31),(87,29,87,31),(88,29,89,31),(90,28,91,31),(91,28,93,30),(93,28,94,30),(94,28,96,30),(96,28,97,30),(98,27,99,29),(100,27,100,29),(101,27,102,29),(103,27,104,29),(104,26,106,28),(106,26,107,28),(108,26,109,28),(110,26,110,28),(111,26,112,28),],
+[(0,38,1,40),(2,38,4,40),(5,38,6,40),(7,38,8,40),(9,38,9,40),(10,38,11,40),(12,38,13,40),(14,38,15,40),(16,38,17,40),(17,38,19,40),(19,38,20,40),(21,38,22,40),(23,38,24,40),(25,38,26,40),(27,38,28,40),(28,38,30,40),(30,38,31,40),(32,38,33,40),(34,38,35,39),(36,37,37,39),(38,37,39,39),(39,37,41,39),(41,37,42,39),(43,37,44,39),(45,37,46,39),(46,37,48,39),(48,37,49,39),(50,37,51,39),(52,37,53,39),(53,36,55,39),(56,36,56,38),(57,36,58,38),(59,36,60,38),(61,36,62,38),(63,36,63,38),(64,36,65,38),(66,35,67,38),(68,35,69,37),(70,35,71,37),(71,35,73,37),(73,35,74,37),(75,35,76,37),(77,34,77,36),(78,34,79,36),(80,33,81,36),(81,33,83,35),(83,33,84,35),(84,32,86,34),(86,32,87,34),(88,32,89,33),(90,32,90,33),(91,31,92,32),(93,31,93,32),(94,31,95,32),(96,30,97,32),(97,30,99,31),(99,30,100,31),(100,30,102,31),(102,30,103,31),(104,29,105,30),(106,29,106,30),(107,29,108,30),(109,29,109,30),(110,28,112,30),],
+[(0,41,1,44),(2,41,4,44),(5,41,6,43),(7,41,8,43),(9,41,9,43),(10,41,11,43),(12,41,13,43),(14,41,15,43),(16,41,16,43),(17,41,18,43),(19,41,20,43),(21,41,22,43),(23,41,24,43),(24,41,26,43),(26,41,27,43),(28,41,29,43),(30,41,31,43),(32,40,33,43),(34,40,35,42),(35,40,37,42),(37,40,38,42),(39,40,40,42),(41,40,42,42),(42,40,44,42),(44,40,45,42),(46,40,47,42),(48,40,49,42),(49,40,51,42),(51,40,52,42),(53,39,54,42),(55,39,56,42),(56,39,58,42),(59,39,59,41),(60,39,61,41),(62,39,63,41),(64,39,65,41),(66,38,67,41),(67,38,69,41),(69,38,70,41),(71,38,72,40),(73,38,73,40),(74,38,75,40),(76,37,77,40),(77,37,79,39),(79,37,80,39),(80,36,82,39),(83,36,83,38),(84,35,85,37),(86,35,86,37),(87,34,88,36),(89,34,89,36),(90,33,92,36),(92,33,93,35),(93,33,95,35),(95,33,96,35),(97,32,98,34),(99,32,99,34),(100,32,101,34),(102,32,102,34),(103,31,105,33),(105,31,106,33),(106,31,108,33),(108,31,109,33),(109,31,111,33),],
+[(0,45,1,47),(2,45,4,47),(5,44,6,46),(6,44,8,46),(8,44,9,46),(10,44,11,46),(12,44,13,46),(14,44,15,46),(16,44,16,46),(17,44,18,46),(19,44,20,46),(21,44,22,46),(23,44,23,46),(24,44,25,46),(26,44,27,46),(28,44,29,46),(30,44,30,45),(31,43,33,45),(33,43,34,45),(35,43,36,45),(37,43,38,45),(38,43,40,45),(40,43,41,45),(42,43,43,45),(44,43,45,45),(45,43,47,45),(47,43,48,45),(49,43,50,45),(51,43,52,45),(52,43,54,45),(54,43,55,45),(56,42,57,45),(58,42,59,44),(60,42,61,44),(62,42,62,44),(63,42,64,44),(65,42,66,44),(67,42,68,44),(69,41,70,44),(70,41,72,43),(72,41,73,43),(73,41,75,43),(75,41,76,43),(77,40,78,42),(79,40,79,42),(80,39,81,42),(82,39,82,41),(83,38,85,40),(85,38,86,40),(86,37,88,39),(88,37,89,39),(89,36,91,38),(91,36,92,38),(92,36,94,38),(95,36,95,37),(96,35,97,36),(98,35,98,36),(99,35,100,36),(101,35,101,36),(102,34,104,36),(104,34,105,35),(105,34,107,35),(107,34,108,35),(109,33,110,35),],
+[(0,48,1,50),(2,48,4,50),(5,47,5,50),(6,47,7,50),(8,47,9,49),(10,47,11,49),(12,47,12,49),(13,47,14,49),(15,47,16,49),(17,47,18,49),(19,47,20,49),(20,47,22,49),(22,47,23,49),(24,47,25,49),(26,47,27,49),(27,47,29,49),(29,46,30,49),(31,46,32,48),(33,46,34,48),(35,46,36,48),(37,46,37,48),(38,46,39,48),(40,46,41,48),(42,46,43,48),(44,46,44,48),(45,46,46,48),(47,46,48,48),(49,46,50,48),(51,46,51,48),(52,46,53,48),(54,46,55,48),(55,45,57,48),(57,45,58,48),(59,45,60,48),(61,45,62,47),(63,45,64,47),(65,45,65,47),(66,45,67,47),(68,45,69,47),(69,44,71,47),(71,44,72,47),(73,44,74,46),(75,44,75,46),(76,43,78,46),(78,43,79,45),(79,42,81,45),(81,42,82,44),(82,41,84,44),(85,41,85,43),(86,40,87,42),(88,40,88,42),(89,39,90,41),(91,39,91,41),(92,38,93,41),(94,38,94,40),(95,37,97,40),(97,37,98,39),(98,37,100,39),(100,37,101,39),(101,36,103,38),(103,36,104,38),(105,36,106,38),(107,36,107,38),(108,35,110,37),],
+[(0,51,1,53),(2,51,4,53),(5,51,5,53),(6,51,7,53),(8,50,9,52),(10,50,11,52),(12,50,12,52),(13,50,14,52),(15,50,16,52),(17,50,18,52),(19,50,19,52),(20,50,21,52),(22,50,23,52),(24,50,25,52),(26,50,26,52),(27,50,28,52),(29,49,30,51),(31,49,32,51),(33,49,33,51),(34,49,35,51),(36,49,37,51),(38,49,39,51),(40,49,40,51),(41,49,42,51),(43,49,44,51),(45,49,46,51),(47,49,47,51),(48,49,49,51),(50,49,51,51),(52,49,53,51),(54,49,54,51),(55,49,56,51),(57,49,58,51),(59,48,60,51),(61,48,61,50),(62,48,63,50),(64,48,65,50),(66,48,67,50),(68,48,68,50),(69,48,70,50),(71,47,72,50),(72,47,74,49),(74,47,75,49),(76,46,77,49),(78,46,78,48),(79,46,80,48),(81,45,81,47),(82,44,83,47),(84,44,84,46),(85,43,86,45),(87,43,87,45),(88,42,90,44),(90,42,91,44),(91,41,93,43),(93,41,94,43),(94,40,96,42),(96,40,97,42),(98,40,99,41),(100,40,100,41),(101,39,102,40),(103,39,103,40),(104,39,105,40),(106,39,106,40),(107,38,109,40),],
+[(0,54,1,56),(2,54,4,56),(5,54,5,56),(6,54,7,56),(8,53,9,56),(10,53,11,55),(12,53,12,55),(13,53,14,55),(15,53,16,55),(17,53,18,55),(19,53,19,55),(20,53,21,55),(22,53,23,55),(23,53,25,55),(25,53,26,55),(27,53,28,55),(29,52,30,54),(30,52,32,54),(32,52,33,54),(34,52,35,54),(36,52,37,54),(37,52,39,54),(39,52,40,54),(41,52,42,54),(43,52,44,54),(44,52,46,54),(46,52,47,54),(48,52,49,54),(50,52,50,54),(51,52,52,54),(53,52,54,54),(55,52,56,54),(57,52,57,54),(58,51,60,54),(60,51,61,54),(62,51,63,54),(64,51,64,54),(65,51,66,53),(67,51,68,53),(69,51,70,53),(71,51,71,53),(72,50,73,53),(74,50,74,52),(75,49,77,52),(77,49,78,52),(78,48,80,51),(80,48,81,50),(81,47,83,50),(83,47,84,49),(84,46,86,49),(86,46,87,48),(87,45,89,48),(89,45,90,47),(90,44,92,47),(92,44,93,46),(93,43,95,46),(96,43,96,45),(97,42,98,44),(99,42,99,44),(100,41,102,44),(102,41,103,43),(103,41,105,43),(105,41,106,42),(106,40,108,42),],
+[(0,57,1,59),(2,57,4,59),(5,57,5,59),(6,57,7,59),(8,57,9,59),(10,56,11,58),(12,56,12,58),(13,56,14,58),(15,56,15,58),(16,56,17,58),(18,56,19,58),(20,56,21,58),(22,56,22,58),(23,56,24,58),(25,56,26,58),(27,56,28,57),(29,55,29,57),(30,55,31,57),(32,55,33,57),(34,55,35,57),(36,55,36,57),(37,55,38,57),(39,55,40,57),(40,55,42,57),(42,55,43,57),(44,55,45,57),(46,55,46,57),(47,55,48,57),(49,55,50,57),(51,55,52,57),(53,55,53,57),(54,55,55,56),(56,55,57,56),(58,55,59,56),(60,55,60,56),(61,55,62,56),(63,55,64,56),(64,54,66,57),(67,54,67,56),(68,54,69,56),(70,54,71,56),(71,54,73,56),(73,53,74,56),(75,53,76,55),(77,53,77,55),(78,52,79,54),(80,51,80,53),(81,51,82,53),(83,50,83,52),(84,49,85,51),(86,49,86,50),(87,48,88,50),(89,48,89,49),(90,47,91,49),(92,47,92,48),(93,46,94,48),(95,46,95,47),(96,45,98,47),(98,45,99,46),(99,44,101,46),(101,44,102,45),(102,44,104,45),(104,43,105,45),(106,43,107,44),],
+[(0,60,1,62),(2,60,4,62),(5,60,5,62),(6,60,7,62),(8,60,9,62),(9,59,11,62),(11,59,12,61),(13,59,14,61),(15,59,15,61),(16,59,17,61),(18,59,19,61),(19,59,21,61),(21,59,22,61),(23,59,24,61),(25,59,25,61),(26,58,28,61),(28,58,29,60),(30,58,31,60),(32,58,32,60),(33,58,34,60),(35,58,36,60),(37,58,38,60),(39,58,39,60),(40,58,41,60),(42,58,43,60),(43,58,45,60),(45,58,46,60),(47,58,48,60),(49,58,49,60),(50,58,51,60),(52,58,53,60),(53,57,55,60),(56,57,56,59),(57,57,58,59),(59,57,60,59),(61,57,62,59),(63,57,63,59),(64,57,65,60),(66,57,67,60),(67,57,69,60),(69,57,70,60),(71,57,72,60),(73,57,73,59),(74,56,75,59),(76,56,76,58),(77,55,78,58),(79,54,80,57),(80,53,82,56),(82,53,83,55),(83,52,84,55),(85,51,86,54),(86,51,87,53),(88,50,89,53),(89,49,91,52),(91,49,92,51),(92,48,94,51),(94,48,95,50),(95,47,97,50),(97,47,98,49),(98,47,100,49),(101,46,101,48),(102,46,103,48),(104,46,104,47),(105,45,106,47),],
+[(0,63,1,65),(2,63,4,65),(5,63,5,65),(6,63,7,65),(8,63,8,65),(9,63,10,64),(11,62,12,64),(13,62,14,64),(15,62,15,64),(16,62,17,64),(18,62,18,64),(19,62,20,64),(21,62,22,64),(23,62,24,63),(25,62,25,63),(26,61,27,63),(28,61,29,63),(29,61,31,63),(31,61,32,63),(33,61,34,63),(35,61,35,63),(36,61,37,63),(38,61,39,62),(40,61,41,62),(42,61,42,62),(43,61,44,62),(45,61,45,62),(46,61,47,62),(48,61,49,62),(49,61,51,62),(51,61,52,62),(53,60,54,62),(55,60,56,62),(56,60,58,62),(58,60,59,62),(60,60,61,62),(62,60,63,62),(63,61,65,62),(65,61,66,62),(67,61,68,62),(69,61,70,62),(71,60,72,62),(73,60,73,62),(74,60,75,62),(76,59,76,61),(77,58,78,60),(79,58,79,59),(80,57,81,58),(81,56,82,58),(83,55,84,57),(84,55,85,56),(85,54,87,56),(87,54,88,55),(88,53,90,55),(90,52,91,54),(92,52,93,53),(93,51,94,53),(95,51,96,52),(97,50,97,51),(98,50,99,51),(100,49,100,50),(101,49,102,50),(103,48,104,50),(104,48,106,49),],
+[(0,66,1,68),(2,66,4,68),(5,66,5,68),(6,66,7,68),(8,66,8,68),(9,65,10,67),(11,65,12,67),(12,65,14,67),(14,65,15,67),(16,65,17,67),(18,65,18,67),(19,65,20,67),(21,64,22,67),(22,64,24,66),(24,64,25,66),(26,64,27,66),(28,64,28,66),(29,64,30,66),(31,64,32,66),(32,64,34,66),(34,64,35,66),(36,64,37,66),(38,63,38,65),(39,63,40,65),(41,63,42,65),(42,63,44,65),(44,63,45,65),(45,63,47,65),(47,63,48,65),(49,63,50,65),(51,63,52,65),(52,63,54,65),(54,63,55,65),(55,63,57,65),(57,63,59,65),(59,63,61,65),(61,63,62,65),(63,63,64,65),(65,63,66,65),(66,63,68,65),(68,63,69,65),(70,63,71,65),(72,63,72,65),(73,63,74,65),(75,62,75,64),(76,61,77,64),(78,60,78,62),(79,59,80,62),(81,59,81,61),(82,58,83,60),(83,57,84,60),(85,56,86,59),(87,56,87,58),(88,55,89,58),(90,55,90,57),(91,54,92,56),(93,54,93,56),(94,53,95,55),(96,52,97,55),(97,52,98,54),(99,51,100,54),(100,51,102,53),(102,51,103,53),(103,50,105,52),],
+[(0,69,1,70),(2,69,4,70),(5,69,5,70),(6,69,7,70),(8,69,8,70),(9,68,10,70),(11,68,11,70),(12,68,13,70),(14,68,15,69),(15,68,17,69),(17,68,18,69),(19,68,20,69),(21,68,21,69),(22,67,23,69),(24,67,24,69),(25,67,26,69),(27,67,28,69),(28,67,30,69),(31,67,31,68),(32,67,33,68),(34,67,34,68),(35,67,36,68),(37,66,38,68),(38,66,40,68),(40,66,41,67),(42,66,43,67),(44,66,44,67),(45,66,46,67),(47,66,47,67),(48,66,49,67),(50,66,51,67),(51,66,53,67),(53,66,54,67),(55,66,56,67),(57,66,58,67),(58,66,60,67),(60,66,61,67),(62,66,63,67),(64,66,65,67),(65,66,67,67),(67,66,68,67),(69,66,70,67),(71,66,71,67),(72,66,73,67),(74,65,75,66),(75,65,76,66),(77,63,78,65),(78,63,79,64),(80,62,80,63),(81,61,82,63),(83,61,83,62),(84,60,85,61),(86,59,86,60),(87,59,88,60),(89,58,89,59),(90,57,91,59),(92,57,92,58),(93,56,94,58),(95,56,95,57),(96,55,97,57),(98,55,98,56),(99,54,101,56),(101,54,102,55),(102,53,104,55),],
+[(0,71,1,73),(2,71,4,73),(4,71,5,73),(6,71,7,73),(8,71,8,73),(9,71,10,73),(11,71,11,73),(12,71,13,73),(14,70,14,72),(15,70,16,72),(17,70,18,72),(18,70,20,72),(20,70,21,72),(21,70,23,72),(23,70,24,72),(25,70,26,72),(27,70,27,72),(28,69,29,72),(30,69,31,71),(31,69,33,71),(33,69,34,71),(34,69,36,71),(36,69,37,71),(37,68,39,71),(40,68,40,70),(41,68,42,70),(43,68,44,70),(44,68,46,70),(46,68,47,70),(47,68,49,70),(49,68,50,70),(50,68,52,70),(52,68,53,70),(54,68,55,70),(56,68,57,70),(57,68,59,70),(59,68,60,70),(61,68,62,70),(63,68,64,70),(64,68,66,70),(66,68,67,70),(67,68,69,70),(70,68,70,69),(71,68,72,69),(73,67,74,69),(74,67,75,68),(76,66,77,68),(77,65,79,67),(79,64,80,66),(80,63,82,66),(82,63,83,65),(83,62,84,65),(85,61,86,64),(86,61,87,63),(88,60,89,62),(89,59,91,62),(91,59,92,61),(92,58,94,61),(94,58,95,60),(95,57,97,59),(97,57,98,59),(98,56,100,58),(100,56,101,58),(101,55,103,57),],
+[(0,74,1,76),(2,74,3,76),(4,74,5,75),(5,74,7,75),(7,74,8,75),(9,74,10,75),(11,74,11,75),(12,74,13,75),(14,73,14,75),(15,73,16,75),(17,73,17,75),(18,73,19,75),(20,73,20,74),(21,73,22,74),(23,73,23,74),(24,73,25,74),(26,73,27,74),(27,72,29,74),(29,72,30,74),(31,72,32,74),(33,72,33,73),(34,72,35,73),(36,72,36,73),(37,72,38,73),(39,71,40,73),(40,71,42,72),(42,71,43,72),(43,71,45,72),(45,71,46,72),(47,71,48,72),(49,71,49,72),(50,71,51,72),(52,71,52,72),(53,71,54,72),(55,71,56,72),(56,71,58,72),(58,71,59,72),(60,71,61,72),(62,71,63,72),(63,71,65,72),(65,71,66,72),(66,71,68,72),(68,70,69,72),(70,70,71,71),(72,70,72,71),(73,69,74,70),(75,69,75,70),(76,68,77,69),(78,67,79,69),(79,66,81,68),(81,66,82,67),(82,66,83,67),(84,65,84,66),(85,64,86,66),(87,63,88,65),(88,62,90,64),(90,62,91,63),(91,62,92,63),(93,61,93,62),(94,60,95,62),(96,60,96,61),(97,59,99,61),(99,59,100,60),(100,58,102,60),],
+[(0,77,1,79),(2,76,3,79),(4,76,4,78),(5,76,6,78),(7,76,8,78),(8,76,10,78),(10,76,11,78),(11,76,13,78),(13,76,14,78),(14,76,16,78),(16,76,17,78),(17,75,19,78),(20,75,20,77),(21,75,22,77),(23,75,23,77),(24,75,25,77),(26,75,26,77),(27,75,28,77),(29,75,29,77),(30,74,32,77),(32,74,33,76),(33,74,35,76),(35,74,36,76),(36,74,38,76),(38,74,39,76),(39,73,41,76),(41,73,42,75),(42,73,44,75),(44,73,45,75),(46,73,47,75),(48,73,48,75),(49,73,50,75),(51,73,52,75),(52,73,54,75),(54,73,55,74),(56,73,57,74),(58,73,58,74),(59,73,60,74),(61,73,62,74),(62,73,64,74),(64,73,65,74),(65,73,67,74),(67,73,68,74),(68,72,70,74),(71,72,71,73),(72,71,73,73),(74,71,74,72),(75,70,76,72),(77,70,77,71),(78,69,79,71),(80,68,81,70),(81,68,82,69),(83,67,84,69),(84,66,86,68),(86,66,87,67),(87,65,88,67),(89,64,90,66),(90,64,91,66),(92,63,93,65),(93,62,95,64),(95,62,96,64),(96,61,98,63),(98,61,99,62),(99,60,101,62),],
+[(0,80,1,81),(2,79,3,81),(4,79,4,81),(5,79,6,81),(7,79,7,80),(8,79,9,80),(10,79,10,80),(11,79,12,80),(13,79,13,80),(14,79,15,80),(16,79,16,80),(17,79,18,80),(19,78,20,80),(20,78,22,80),(22,78,23,80),(23,78,25,79),(25,78,26,79),(27,78,28,79),(29,78,29,79),(30,78,31,79),(32,77,32,79),(33,77,34,79),(35,77,35,78),(36,77,37,78),(38,77,38,78),(39,76,40,78),(41,76,41,77),(42,76,43,77),(44,76,44,77),(45,76,46,77),(47,76,47,77),(48,76,49,77),(50,76,51,77),(51,75,53,77),(54,75,54,76),(55,75,56,76),(57,75,57,76),(58,75,59,76),(60,75,61,76),(61,75,63,76),(63,75,64,76),(64,75,66,76),(66,75,67,76),(67,74,69,76),(70,74,70,75),(71,74,72,75),(73,73,73,74),(74,73,75,74),(76,72,76,73),(77,72,78,73),(79,71,79,72),(80,70,81,71),(82,70,82,71),(83,69,84,70),(85,68,86,70),(86,68,87,69),(88,67,89,68),(89,66,91,68),(91,66,92,67),(92,65,93,67),(94,65,94,66),(95,64,96,66),(97,63,98,65),(98,63,99,64),],
+[(0,82,1,84),(2,82,3,84),(4,82,4,84),(5,82,6,84),(7,81,7,83),(8,81,9,83),(10,81,10,83),(11,81,12,83),(13,81,13,83),(14,81,15,83),(16,81,16,83),(17,81,18,83),(19,81,19,83),(20,81,21,83),(22,81,22,83),(23,80,24,82),(25,80,25,82),(26,80,27,82),(28,80,28,82),(29,80,30,82),(31,80,31,82),(32,80,33,82),(34,79,35,82),(35,79,37,81),(37,79,38,81),(38,79,39,81),(40,78,41,81),(41,78,43,80),(43,78,44,80),(44,78,46,80),(46,78,47,80),(47,78,49,80),(49,78,50,80),(50,78,52,79),(52,77,53,79),(54,77,55,79),(56,77,57,79),(57,77,59,79),(59,77,60,79),(60,77,62,79),(62,77,63,79),(63,77,65,79),(65,77,66,78),(66,77,68,78),(68,76,69,78),(70,76,71,77),(72,75,72,76),(73,75,74,76),(75,74,75,75),(76,74,77,75),(78,73,78,74),(79,72,80,74),(81,72,81,73),(82,71,83,73),(84,71,84,72),(85,70,86,72),(87,69,88,71),(88,69,89,70),(90,68,91,70),(91,67,93,69),(93,67,94,68),(94,66,96,68),(96,66,97,67),(97,65,98,67),],
+[(0,85,1,86),(2,85,3,86),(4,85,4,86),(5,85,6,86),(7,84,7,86),(8,84,9,86),(10,84,10,86),(11,84,12,85),(13,84,13,85),(14,84,15,85),(16,84,16,85),(17,84,18,85),(19,84,19,85),(20,84,21,85),(22,84,22,85),(23,83,24,85),(25,83,25,85),(26,83,27,85),(27,83,28,85),(29,83,30,84),(31,83,31,84),(32,83,33,84),(34,83,34,84),(35,82,36,84),(37,82,37,83),(38,82,39,83),(40,82,40,83),(41,81,42,83),(42,81,43,83),(44,81,45,82),(46,81,46,82),(47,81,48,82),(49,81,49,82),(50,80,51,81),(52,80,52,81),(53,80,54,81),(55,80,55,81),(56,80,57,81),(58,80,58,81),(59,80,60,81),(61,80,62,81),(62,79,64,81),(65,79,65,80),(66,79,67,80),(67,79,68,80),(68,78,70,80),(71,77,72,78),(72,77,73,78),(74,76,75,77),(75,76,76,77),(77,75,78,76),(78,74,80,76),(80,74,81,75),(81,74,82,75),(83,73,83,74),(84,73,85,74),(86,72,86,73),(87,71,88,73),(89,71,89,72),(90,70,91,71),(92,69,93,71),(93,69,94,70),(95,68,96,69),(96,67,98,69),],
+[(0,87,1,89),(2,87,3,89),(4,87,4,89),(5,87,6,89),(7,87,7,89),(8,87,9,89),(10,87,10,89),(11,86,12,88),(13,86,13,88),(14,86,15,88),(16,86,16,88),(17,86,18,88),(18,86,19,88),(19,86,20,88),(21,86,21,88),(22,86,23,88),(24,86,24,88),(25,86,26,88),(27,86,27,88),(28,85,29,87),(30,85,30,87),(31,85,32,87),(33,85,33,87),(34,84,36,87),(36,84,37,86),(37,84,38,86),(39,84,39,86),(40,84,41,86),(42,84,42,86),(43,83,44,85),(45,83,45,85),(46,83,47,85),(48,83,48,85),(49,82,50,84),(51,82,51,84),(52,82,53,84),(54,82,55,84),(55,82,57,84),(57,82,58,83),(58,82,60,83),(60,82,61,83),(61,82,63,83),(63,81,64,83),(64,81,66,83),(67,81,67,82),(68,80,69,82),(70,79,71,81),(71,79,72,80),(73,78,74,80),(74,78,75,79),(76,77,77,79),(77,77,78,78),(79,76,80,78),(80,75,82,77),(82,75,83,76),(83,74,85,76),(85,74,86,75),(86,74,87,75),(88,73,88,74),(89,72,90,74),(91,72,91,73),(92,71,93,73),(94,70,95,72),(95,70,96,71),],
+[(0,90,1,91),(2,90,3,91),(4,90,4,91),(5,90,6,91),(7,90,7,91),(8,90,9,91),(10,90,10,91),(11,89,12,91),(13,89,13,91),(14,89,14,91),(15,89,15,91),(16,89,17,90),(18,89,18,90),(19,89,20,90),(21,89,21,90),(22,89,23,90),(24,89,24,90),(25,89,26,90),(27,89,27,90),(28,88,29,90),(30,88,30,90),(31,88,32,90),(33,88,33,89),(34,88,35,89),(35,87,36,89),(36,87,38,89),(39,87,39,88),(40,87,41,88),(41,87,42,88),(42,86,44,88),(44,86,45,87),(45,86,46,87),(47,86,47,87),(48,85,50,87),(50,85,51,86),(51,85,53,86),(53,85,54,86),(54,85,55,86),(56,84,57,86),(57,84,59,85),(59,84,60,85),(60,84,62,85),(62,84,63,85),(63,83,65,85),(66,83,66,84),(67,83,68,84),(69,82,69,83),(70,81,71,83),(72,81,72,82),(73,80,74,81),(75,80,75,81),(76,79,77,80),(78,79,78,80),(79,78,80,79),(81,77,82,78),(82,77,83,78),(84,76,85,77),(85,76,86,77),(87,75,88,76),(88,74,90,76),(90,74,91,75),(91,74,92,75),(93,73,93,74),(94,72,95,74),],
+[(0,92,1,94),(2,92,3,94),(4,92,4,94),(5,92,6,94),(7,92,7,94),(8,92,9,94),(10,92,10,94),(11,92,12,94),(13,92,13,94),(14,92,14,94),(15,92,15,94),(16,91,17,93),(18,91,18,93),(19,91,20,93),(21,91,21,93),(22,91,23,93),(24,91,24,93),(25,91,26,93),(26,91,27,93),(27,91,28,93),(29,91,29,93),(30,91,31,93),(32,90,32,92),(33,90,34,92),(35,90,35,92),(36,90,37,92),(38,89,38,91),(39,89,40,91),(41,89,41,91),(42,89,43,91),(44,88,44,90),(45,88,46,90),(47,88,47,90),(48,88,49,90),(49,87,50,90),(51,87,52,89),(53,87,53,89),(54,87,55,89),(55,87,56,89),(57,86,58,88),(58,86,59,88),(59,86,61,88),(61,86,62,88),(63,86,64,87),(64,85,65,87),(66,85,67,86),(67,84,68,86),(69,84,70,85),(70,83,71,85),(72,82,73,84),(74,82,74,83),(75,81,76,83),(77,81,77,82),(78,80,79,82),(80,79,81,81),(81,79,82,80),(83,78,84,80),(84,78,85,79),(86,77,87,79),(87,77,88,78),(89,76,90,78),(90,75,92,77),(92,75,93,76),(93,74,95,76),],
+]
+;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+    fn scale_to_virtual_is_inverse_of_scale_to_physical() {
+        for (virtual_size, physical_size) in [ (SCREEN_WIDTH, VIEW_WIDTH), (SCREEN_HEIGHT, VIEW_HEIGHT),
+            (2 * SCREEN_WIDTH, VIEW_WIDTH), (2 * SCREEN_HEIGHT, VIEW_HEIGHT) ].iter() {
+            for pos in 0..*virtual_size {
+                let (first, last) = scale_to_physical(pos as u8, *virtual_size, *physical_size);
+                assert!(first <= last && (last as usize) < *physical_size);
+                for physical in first..=last {
+                    assert_eq!(scale_to_virtual(physical, *virtual_size, *physical_size), pos as u8,
+                        "physical {} of {} x {}", physical, virtual_size, physical_size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+    fn hires_maps_match_low_resolution_maps() {
+        for (y, row) in HIRES_PHYSICAL_TO_VIRTUAL_MAP.iter().enumerate() {
+            for (x, (hires_x, hires_y)) in row.iter().enumerate() {
+                //  Each high resolution Virtual Pixel is inside the low resolution Virtual Pixel
+                let (low_x, low_y) = PHYSICAL_TO_VIRTUAL_MAP[y][x];
+                assert_eq!((hires_x / 2, hires_y / 2), (low_x, low_y), "physical ({}, {})", x, y);
+                //  The Physical Pixel is inside the Bounding Box of the high resolution Virtual Pixel
+                if (*hires_x as usize) < HIRES_VIRTUAL_TO_PHYSICAL_MAP_WIDTH && (*hires_y as usize) < HIRES_VIRTUAL_TO_PHYSICAL_MAP_HEIGHT {
+                    let (left, top, right, bottom) = HIRES_VIRTUAL_TO_PHYSICAL_MAP[*hires_y as usize][*hires_x as usize];
+                    assert!(left as usize <= x && x <= right as usize && top as usize <= y && y <= bottom as usize,
+                        "physical ({}, {}) outside virtual ({}, {})", x, y, hires_x, hires_y);
+                }
+            }
+        }
+    }
+}
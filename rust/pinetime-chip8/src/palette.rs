@@ -0,0 +1,204 @@
+//! Colour Palettes and Fade Curves for the CHIP8 Virtual Screen. Each Virtual Pixel is stored as 8-bit greyscale:
+//! 200 to 255 when lit (with a bright flash that fades to normal), 0 to 127 when unlit (fading to black).
+//! The `Theme` maps the greyscale to 16-bit colour, the `Fade` decides how quickly the greyscale fades after rendering.
+//! Both are kept in `Settings`, which may be stored per ROM as `SETTINGS_SIZE` bytes.
+//! ```text
+//! Offset  Size  Contents
+//! 0       1     Theme: 0 = Classic, 1 = Amber, 2 = Green, 3 = LCD, 4 = Custom
+//! 1       1     Fade: 0 = Classic, 1 = Slow, 2 = Linear, 3 = None
+//! 2       2     Custom foreground colour (RGB565, little endian)
+//! 4       2     Custom background colour (RGB565, little endian)
+//! ```
+use embedded_graphics::{
+    pixelcolor::Rgb565,
+};
+
+/// Size of the encoded `Settings` in bytes
+pub const SETTINGS_SIZE: usize = 6;
+
+/// Colour palette for the Virtual Screen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Theme {
+    /// Green pixels that flash white when drawn, fading to dark blue
+    Classic,
+    /// Amber monochrome monitor
+    Amber,
+    /// Green phosphor monochrome monitor
+    Green,
+    /// Dark pixels on a greenish LCD
+    Lcd,
+    /// User-defined foreground and background colours in `Settings`
+    Custom,
+}
+
+/// Fade curve for unlit Virtual Pixels, applied each time the pixel is rendered
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fade {
+    /// Halve the brightness
+    Classic,
+    /// Reduce the brightness by 1/8, like a long persistence phosphor
+    Slow,
+    /// Reduce the brightness by a fixed step
+    Linear,
+    /// Switch off immediately, no afterglow
+    None,
+}
+
+/// Colour palette and fade curve for the Virtual Screen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    /// Colour palette
+    pub theme:      Theme,
+    /// Fade curve
+    pub fade:       Fade,
+    /// Foreground colour (RGB565) for `Theme::Custom`
+    pub foreground: u16,
+    /// Background colour (RGB565) for `Theme::Custom`
+    pub background: u16,
+}
+
+/// All themes, in the order of their encoded numbers
+const THEMES: [Theme; 5] = [ Theme::Classic, Theme::Amber, Theme::Green, Theme::Lcd, Theme::Custom ];
+
+/// All fade curves, in the order of their encoded numbers
+const FADES: [Fade; 4] = [ Fade::Classic, Fade::Slow, Fade::Linear, Fade::None ];
+
+/// Brightness step for `Fade::Linear`
+const LINEAR_STEP: u8 = 16;
+
+impl Theme {
+    /// Return the theme named `name` (case insensitive), e.g. "amber"
+    pub fn from_name(name: &str) -> Option<Theme> {
+        THEMES.iter().cloned()
+            .find(|theme| theme.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the name of the theme
+    pub fn name(self) -> &'static str {
+        match self {
+            Theme::Classic => "Classic",
+            Theme::Amber   => "Amber",
+            Theme::Green   => "Green",
+            Theme::Lcd     => "LCD",
+            Theme::Custom  => "Custom",
+        }
+    }
+
+    /// Return the next theme, wrapping around to the first
+    pub fn next(self) -> Theme {
+        THEMES[(self as usize + 1) % THEMES.len()]
+    }
+
+    /// Return the (foreground, background) colours of the theme, or `None` for the classic colours
+    fn colors(self, settings: &Settings) -> Option<(u16, u16)> {
+        match self {
+            Theme::Classic => None,
+            Theme::Amber   => Some(( Rgb565::from(( 0xff, 0xb0, 0x00 )).0, Rgb565::from(( 0x00, 0x00, 0x00 )).0 )),
+            Theme::Green   => Some(( Rgb565::from(( 0x33, 0xff, 0x33 )).0, Rgb565::from(( 0x00, 0x10, 0x00 )).0 )),
+            Theme::Lcd     => Some(( Rgb565::from(( 0x0f, 0x38, 0x0f )).0, Rgb565::from(( 0x9b, 0xbc, 0x0f )).0 )),
+            Theme::Custom  => Some(( settings.foreground, settings.background )),
+        }
+    }
+}
+
+impl Fade {
+    /// Return the fade curve named `name` (case insensitive), e.g. "slow"
+    pub fn from_name(name: &str) -> Option<Fade> {
+        FADES.iter().cloned()
+            .find(|fade| fade.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Return the name of the fade curve
+    pub fn name(self) -> &'static str {
+        match self {
+            Fade::Classic => "Classic",
+            Fade::Slow    => "Slow",
+            Fade::Linear  => "Linear",
+            Fade::None    => "None",
+        }
+    }
+
+    /// Return the next fade curve, wrapping around to the first
+    pub fn next(self) -> Fade {
+        FADES[(self as usize + 1) % FADES.len()]
+    }
+}
+
+impl Settings {
+    /// Classic colours and fade, with white on black for `Theme::Custom`
+    pub const DEFAULT: Settings = Settings {
+        theme:      Theme::Classic,
+        fade:       Fade::Classic,
+        foreground: 0xffff,
+        background: 0x0000,
+    };
+
+    /// Encode the settings into `SETTINGS_SIZE` bytes
+    pub fn to_bytes(&self) -> [u8; SETTINGS_SIZE] {
+        let foreground = self.foreground.to_le_bytes();
+        let background = self.background.to_le_bytes();
+        [ self.theme as u8, self.fade as u8, foreground[0], foreground[1], background[0], background[1] ]
+    }
+
+    /// Decode the settings from `bytes`. Return `None` if `bytes` is too short or the theme or fade is unknown.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Settings> {
+        if bytes.len() < SETTINGS_SIZE { return None; }
+        Some(Settings {
+            theme:      *THEMES.get(bytes[0] as usize) ?,
+            fade:       *FADES.get(bytes[1] as usize) ?,
+            foreground: u16::from_le_bytes([ bytes[2], bytes[3] ]),
+            background: u16::from_le_bytes([ bytes[4], bytes[5] ]),
+        })
+    }
+
+    /// Convert the Virtual Colour (8-bit greyscale) to 16-bit Colour
+    pub fn convert_color(&self, grey: u8) -> u16 {
+        let (foreground, background) = match self.theme.colors(self) {
+            Some(colors) => colors,
+            None => return convert_classic_color(grey),
+        };
+        match grey {
+            128..=255 => foreground,  //  Lit
+            0..128    => blend(background, foreground, grey),  //  Afterglow, up to half brightness
+        }
+    }
+
+    /// Fade the Virtual Colour (8-bit greyscale) to black
+    pub fn update_color(&self, grey: u8) -> u8 {
+        match grey {
+            200..=255 => grey - 2,  //  Initial white flash fade to normal white
+            128..200  => grey,      //  Normal white stays the same
+            0..128    => match self.fade {  //  Dark fade to black
+                Fade::Classic => grey >> 1,
+                Fade::Slow    => grey.saturating_sub((grey >> 3).max(1)),
+                Fade::Linear  => grey.saturating_sub(LINEAR_STEP),
+                Fade::None    => 0,
+            }
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings::DEFAULT
+    }
+}
+
+/// Convert the Virtual Colour (8-bit greyscale) to 16-bit Colour with the classic colours
+fn convert_classic_color(grey: u8) -> u16 {
+    match grey {
+        250..=255 => Rgb565::from(( grey, grey, grey )).0,  //  White
+        128..250 => Rgb565::from(( grey - 100, grey, grey - 100 )).0,  //  Greenish
+        0..128   => Rgb565::from(( 0, 0, grey )).0,  //  Dark Blue
+    }
+}
+
+/// Mix the RGB565 colours `from` and `to` by `amount` / 256
+fn blend(from: u16, to: u16, amount: u8) -> u16 {
+    let mix = |shift: u16, mask: u16| {
+        let a = ((from >> shift) & mask) as i32;
+        let b = ((to   >> shift) & mask) as i32;
+        ((a + (b - a) * amount as i32 / 256) as u16 & mask) << shift
+    };
+    mix(11, 0x1f) | mix(5, 0x3f) | mix(0, 0x1f)
+}
@@ -0,0 +1,39 @@
+//! Services that the CHIP8 Emulator needs from the device. On PineTime they are provided by Mynewt and druid,
+//! on the host they are simulated so that ROMs may be run headlessly.
+use crate::{
+    governor::Stats,
+    render::PixelIterator,
+};
+
+/// Display, time, input and randomness for the CHIP8 Emulator
+pub trait Platform {
+    /// Render the Physical Pixels returned by `pixels` into the display window (left, top, right, bottom),
+    /// row by row. Coordinates are inclusive, in Physical Pixels.
+    fn set_pixels(&mut self, left: u8, top: u8, right: u8, bottom: u8, pixels: &mut PixelIterator);
+
+    /// Sleep for `ms` milliseconds to allow other tasks to run
+    fn sleep_ms(&mut self, ms: u32);
+
+    /// Return the current clock value in nanoseconds. Used for pacing the emulator and the display refresh,
+    /// and for the 60 Hz tick of the CHIP8 delay and sound timers, so it must not wrap around.
+    fn clock_ns(&mut self) -> u64;
+
+    /// Return a random byte
+    fn rand(&mut self) -> u8;
+
+    /// Return true if the CHIP8 key is held, or was tapped since the last check.
+    /// `key` is 0-9 for keys "0" to "9", 0xa-0xf for keys "A" to "F".
+    fn key(&mut self, key: u8) -> bool;
+
+    /// Return true if the running ROM should be stopped
+    fn exit_requested(&mut self) -> bool;
+
+    /// Tickle the watchdog so that the device isn't restarted while the emulator is busy
+    fn tickle_watchdog(&mut self) {}
+
+    /// Play beep sound
+    fn beep(&mut self) {}
+
+    /// Show the emulator statistics, called every second
+    fn show_stats(&mut self, _stats: &Stats) {}
+}
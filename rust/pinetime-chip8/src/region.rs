@@ -0,0 +1,129 @@
+//! Dirty Region API. Tracks the bounding box of the pixels that have changed since the last refresh,
+//! so that only the changed region is sent to the display. Used by the CHIP8 Emulator.
+
+/// Bounding box of the changed pixels. Coordinates are inclusive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DirtyRegion {
+    /// Left column of the changed pixels
+    left:   u8,
+    /// Top row of the changed pixels
+    top:    u8,
+    /// Right column of the changed pixels
+    right:  u8,
+    /// Bottom row of the changed pixels
+    bottom: u8,
+    /// True if any pixel has changed
+    dirty:  bool,
+}
+
+impl Default for DirtyRegion {
+    fn default() -> Self {
+        DirtyRegion::new()
+    }
+}
+
+impl DirtyRegion {
+    /// Return a region with no changed pixels
+    pub const fn new() -> DirtyRegion {
+        DirtyRegion { left: 0, top: 0, right: 0, bottom: 0, dirty: false }
+    }
+
+    /// Return a region covering the rectangle from (`left`, `top`) to (`right`, `bottom`)
+    pub fn from_rect(left: u8, top: u8, right: u8, bottom: u8) -> DirtyRegion {
+        let mut region = DirtyRegion::new();
+        region.add_rect(left, top, right, bottom);
+        region
+    }
+
+    /// Mark the pixel at (`x`, `y`) as changed
+    pub fn add_point(&mut self, x: u8, y: u8) {
+        self.add_rect(x, y, x, y);
+    }
+
+    /// Mark the rectangle from (`left`, `top`) to (`right`, `bottom`) as changed
+    pub fn add_rect(&mut self, left: u8, top: u8, right: u8, bottom: u8) {
+        assert!(left <= right && top <= bottom, "bad rect");
+        if !self.dirty {
+            *self = DirtyRegion { left, top, right, bottom, dirty: true };
+            return;
+        }
+        self.left   = self.left.min(left);
+        self.top    = self.top.min(top);
+        self.right  = self.right.max(right);
+        self.bottom = self.bottom.max(bottom);
+    }
+
+    /// Return true if no pixels have changed
+    pub fn is_empty(&self) -> bool {
+        !self.dirty
+    }
+
+    /// Return the changed region as (left, top, right, bottom), or `None` if no pixels have changed
+    pub fn window(&self) -> Option<(u8, u8, u8, u8)> {
+        if !self.dirty { return None; }
+        Some((self.left, self.top, self.right, self.bottom))
+    }
+
+    /// Return the changed region like `window()` and reset the region
+    pub fn take(&mut self) -> Option<(u8, u8, u8, u8)> {
+        let window = self.window();
+        self.clear();
+        window
+    }
+
+    /// Reset the region to no changed pixels
+    pub fn clear(&mut self) {
+        *self = DirtyRegion::new();
+    }
+
+    /// Split the changed region into blocks of at most `block_width` by `block_height` pixels, left to right
+    /// then top to bottom. Used for rendering large regions without overflowing the SPI buffer.
+    pub fn blocks(&self, block_width: u8, block_height: u8) -> Blocks {
+        assert!(block_width > 0 && block_height > 0, "bad block");
+        Blocks {
+            region: *self,
+            block_width, block_height,
+            x: self.left,
+            y: self.top,
+        }
+    }
+}
+
+/// Iterator over the blocks of a `DirtyRegion`. Returns (left, top, right, bottom) of each block.
+pub struct Blocks {
+    /// Region to be split
+    region:       DirtyRegion,
+    /// Max width of each block
+    block_width:  u8,
+    /// Max height of each block
+    block_height: u8,
+    /// Left column of the next block
+    x:            u8,
+    /// Top row of the next block
+    y:            u8,
+}
+
+impl Iterator for Blocks {
+    /// (left, top, right, bottom) of the block
+    type Item = (u8, u8, u8, u8);
+
+    /// Return the next block
+    fn next(&mut self) -> Option<Self::Item> {
+        let (left, _, right, bottom) = self.region.window() ? ;
+        let block = (
+            self.x,
+            self.y,
+            (self.x as u16 + self.block_width  as u16 - 1).min(right  as u16) as u8,
+            (self.y as u16 + self.block_height as u16 - 1).min(bottom as u16) as u8,
+        );
+        //  Move to the next block on the right, or the next row of blocks
+        if block.2 >= right {
+            self.x = left;
+            if block.3 >= bottom { self.region.clear(); }  //  No more blocks
+            else { self.y = block.3 + 1; }
+        } else {
+            self.x = block.2 + 1;
+        }
+        Some(block)
+    }
+}
@@ -0,0 +1,301 @@
+//! Render the CHIP8 Virtual Screen to the Physical Screen. Each Virtual Pixel is stored as 8-bit greyscale that
+//! fades to black after rendering. Changed regions are split into blocks that fit in the SPI buffer, and each block
+//! is streamed to the `Platform` as 16-bit colours by `PixelIterator`, with the colour palette and fade curve in `palette::Settings`.
+use crate::{
+    map::get_bounding_box,
+    palette::Settings,
+    platform::Platform,
+    region::DirtyRegion,
+    map::map_physical_to_virtual,
+    BLOCK_HEIGHT, BLOCK_WIDTH, HIRES_SCREEN_HEIGHT, HIRES_SCREEN_WIDTH, PENDING_DATA_SIZE,
+    PIXEL_HEIGHT, PIXEL_WIDTH, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+#[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+use crate::{
+    PHYSICAL_HEIGHT, PHYSICAL_WIDTH,
+};
+
+/// Render the Virtual Screen region. Return the number of bytes sent to the display.
+pub fn render_region<P: Platform>(platform: &mut P, left: u8, top: u8, right: u8, bottom: u8) -> usize {
+    //  Get the physical bounding box width and height
+    let physical_box    = get_bounding_box(left, top, right, bottom);  //  Returns (left,top,right,bottom)
+    let physical_width  = (physical_box.2 - physical_box.0 + 1) as usize;
+    let physical_height = (physical_box.3 - physical_box.1 + 1) as usize;
+    //  If the update region is small, render with a single block
+    if physical_width + physical_height <= (BLOCK_WIDTH * PIXEL_WIDTH) + (BLOCK_HEIGHT * PIXEL_HEIGHT) {  //  Will not overflow SPI buffer
+        render_block(platform, left, top, right, bottom)
+    } else {
+        //  If the update region is too big for a single block, break the region into blocks and render
+        let region = DirtyRegion::from_rect(left, top, right, bottom);
+        let (block_width, block_height) = block_size();
+        region.blocks(block_width, block_height)
+            .map(|(block_left, block_top, block_right, block_bottom)|
+                render_block(platform, block_left, block_top, block_right, block_bottom)  //  Will not overflow SPI buffer
+            )
+            .sum()
+    }
+}
+
+/// Render the Virtual Block. Return the number of bytes sent to the display.
+fn render_block<P: Platform>(platform: &mut P, left: u8, top: u8, right: u8, bottom: u8) -> usize {
+    //  Create a new block for the region to be updated
+    let mut block = PixelIterator::new(
+        left, top, 
+        right, bottom,
+    );
+    //  Render the block
+    let (left_physical, top_physical, right_physical, bottom_physical) = block.get_window();
+    let physical_pixels = (right_physical - left_physical + 1) as usize * (bottom_physical - top_physical + 1) as usize;
+    assert!(physical_pixels * 2 <= PENDING_DATA_SIZE, "block overflow");
+    platform.set_pixels(left_physical, top_physical, right_physical, bottom_physical, &mut block);
+    physical_pixels * 2
+}
+
+/// Render the entire Virtual Screen
+pub fn render_screen<P: Platform>(platform: &mut P) {
+    let (width, height) = screen_size();
+    render_region(platform, 0, 0, width as u8 - 1, height as u8 - 1);
+}
+
+/// Return the Virtual Block size (width, height) for the current resolution. In high resolution mode the blocks have
+/// more Virtual Pixels, so that they cover the same Physical Pixels as low resolution blocks.
+fn block_size() -> (u8, u8) {
+    let (width, height) = screen_size();
+    (
+        (BLOCK_WIDTH  * width  / SCREEN_WIDTH)  as u8,
+        (BLOCK_HEIGHT * height / SCREEN_HEIGHT) as u8,
+    )
+}
+
+/// Return the Virtual Screen size (width, height): `SCREEN_WIDTH` x `SCREEN_HEIGHT` in low resolution mode,
+/// `HIRES_SCREEN_WIDTH` x `HIRES_SCREEN_HEIGHT` in high resolution mode
+pub fn screen_size() -> (usize, usize) {
+    unsafe { SCREEN_SIZE }
+}
+
+/// Set the Virtual Screen size to low resolution (`SCREEN_WIDTH` x `SCREEN_HEIGHT`) or high resolution
+/// (`HIRES_SCREEN_WIDTH` x `HIRES_SCREEN_HEIGHT`). The Virtual Screen is cleared if the size has changed.
+/// Return true if the size has changed.
+pub fn set_screen_size(width: usize, height: usize) -> bool {
+    assert!(
+        (width == SCREEN_WIDTH && height == SCREEN_HEIGHT) || 
+        (width == HIRES_SCREEN_WIDTH && height == HIRES_SCREEN_HEIGHT), 
+        "bad size");
+    if screen_size() == (width, height) { return false; }
+    unsafe { SCREEN_SIZE = (width, height); }
+    clear_screen();
+    true
+}
+
+/// Return the colour palette and fade curve for rendering
+pub fn settings() -> Settings {
+    unsafe { SETTINGS }
+}
+
+/// Set the colour palette and fade curve for rendering. Call `render_screen()` to show the new colours.
+pub fn set_settings(settings: Settings) {
+    unsafe { SETTINGS = settings; }
+}
+
+/// Set the Virtual Pixel at (`x`, `y`) to the 8-bit greyscale colour `grey`
+pub fn set_pixel(x: usize, y: usize, grey: u8) {
+    let i = pixel_index(x, y);
+    unsafe { SCREEN_BUFFER[i] = grey; }
+}
+
+/// Return the 8-bit greyscale colour of the Virtual Pixel at (`x`, `y`)
+pub fn get_pixel(x: usize, y: usize) -> u8 {
+    let i = pixel_index(x, y);
+    unsafe { SCREEN_BUFFER[i] }
+}
+
+/// Return the index of the Virtual Pixel at (`x`, `y`) in the Virtual Screen Buffer
+fn pixel_index(x: usize, y: usize) -> usize {
+    let (width, height) = screen_size();
+    assert!(x < width && y < height, "pixel overflow");
+    x + y * width
+}
+
+/// Clear the Virtual Screen to black
+pub fn clear_screen() {
+    unsafe { SCREEN_BUFFER = [0; HIRES_SCREEN_WIDTH * HIRES_SCREEN_HEIGHT]; }
+}
+
+/// Copy the Virtual Screen into `buf`, one byte per Virtual Pixel, row by row at the current `screen_size()`.
+/// `buf` must have at most `width * height` bytes.
+pub fn save_screen(buf: &mut [u8]) {
+    for (i, grey) in buf.iter_mut().enumerate() {
+        *grey = unsafe { SCREEN_BUFFER[i] };
+    }
+}
+
+/// Copy the Virtual Screen from `buf`, saved by `save_screen()` at the same `screen_size()`. Call `render_screen()` to show the restored screen.
+pub fn restore_screen(buf: &[u8]) {
+    for (i, grey) in buf.iter().enumerate() {
+        unsafe { SCREEN_BUFFER[i] = *grey; }
+    }
+}
+
+/// CHIP8 Virtual Screen Buffer, 8-bit greyscale (from black=0 to white=255) per Virtual Pixel, row by row at the
+/// current `screen_size()`. Sized for high resolution mode. The greyscale is mapped to 16-bit colour for display.
+static mut SCREEN_BUFFER: [u8; HIRES_SCREEN_WIDTH * HIRES_SCREEN_HEIGHT] = [0; HIRES_SCREEN_WIDTH * HIRES_SCREEN_HEIGHT];
+
+/// Current Virtual Screen size (width, height)
+static mut SCREEN_SIZE: (usize, usize) = (SCREEN_WIDTH, SCREEN_HEIGHT);
+
+/// Colour palette and fade curve for rendering
+static mut SETTINGS: Settings = Settings::DEFAULT;
+
+/// Iterator for each Virtual Pixels in a Virtual Block. This allows the display driver to iterate and
+/// render each Physical Pixel that corresponds to a Virtual Block.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]  //  Block bounds are kept for debugging
+pub struct PixelIterator {
+    /// Current column number
+    x:           u8,
+    /// Current row number
+    y:           u8,
+    /// Current column offset of Physical Pixel within the Virtual Pixel, e.g. 0 to PIXEL_WIDTH - 1 in low resolution mode
+    x_offset:    u8,
+    /// Current row offset of Physical Pixel within the Virtual Pixel, e.g. 0 to PIXEL_HEIGHT - 1 in low resolution mode
+    y_offset:    u8,
+    /// Current Physical column number
+    x_physical:      u8,
+    /// Current Physical row number
+    y_physical:      u8,
+    /// Start Virtual column number for block
+    block_left:      u8,
+    /// End Virtual column number for block
+    block_right:     u8,
+    /// Start Virtual row number for block
+    block_top:       u8,
+    /// End Virtual row number for block
+    block_bottom:    u8,
+    /// Start Physical column number for block
+    physical_left:      u8,
+    /// End Physical column number for block
+    physical_right:     u8,
+    /// Start Physical row number for block
+    physical_top:       u8,
+    /// End Physical row number for block
+    physical_bottom:    u8,
+}
+
+impl PixelIterator {
+    /// Return a new PixelInterator for the block dimensions
+    pub fn new(
+        //  Start column number for block
+        block_left:      u8,
+        //  Start row number for block
+        block_top:       u8,
+        //  End column number for block
+        block_right:     u8,
+        //  End row number for block
+        block_bottom:    u8,        
+    ) -> PixelIterator {
+        let (physical_left, physical_top, physical_right, physical_bottom) = 
+            get_bounding_box(block_left, block_top, block_right, block_bottom);
+        PixelIterator {
+            x: block_left, 
+            y: block_top,
+            x_offset: 0, 
+            y_offset: 0,
+            x_physical: physical_left,
+            y_physical: physical_top,
+            block_left, block_right, block_top, block_bottom,
+            physical_left, physical_top, physical_right, physical_bottom
+        }
+    }
+
+    /* /// Return true if the Virtual Pixel is in the block
+    pub fn contains(&self, x: u8, y: u8) -> bool {
+        x >= self.block_left && x <= self.block_right &&
+            y >= self.block_top && y <= self.block_bottom
+    } */
+
+    /// Return window of Physical Pixels (left, top, right, bottom) for this Virtual Block
+    pub fn get_window(&self) -> (u8, u8, u8, u8) {
+        ( self.physical_left, self.physical_top, self.physical_right, self.physical_bottom )
+    }
+
+    /// Return the 16-bit colour of the Virtual Pixel
+    fn get_color(&mut self) -> u16 {
+        let i = pixel_index(self.x as usize, self.y as usize);
+        let settings = settings();
+        let color = unsafe { settings.convert_color(SCREEN_BUFFER[i]) };
+        if self.x_offset == 0 && self.y_offset == 0 {  //  Update colours only once per Virtual Pixel
+            unsafe { SCREEN_BUFFER[i] = settings.update_color(SCREEN_BUFFER[i]); }  //  Fade to black
+        }
+        color
+    }    
+}
+
+/// Implement the Iterator for Virtual Pixels in a Virtual Block
+impl Iterator for PixelIterator {
+    /// This Iterator returns Physical Pixel colour words (16-bit)
+    type Item = u16;
+
+    /// Return the next Physical Pixel colour
+    #[cfg(not(feature = "curve"))]  //  If we are not rendering CHIP8 Emulator as curved surface...
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y_physical > self.physical_bottom { return None; }  //  No more Physical Pixels
+
+        //  Map the Physical Pixel to the Virtual Pixel. A Virtual Pixel may span a fractional number of Physical Pixels
+        //  in high resolution mode, so we find the offset from the Physical Bounding Box of the Virtual Pixel.
+        let virtual_pixel = map_physical_to_virtual(self.x_physical, self.y_physical);
+        self.x = virtual_pixel.0;
+        self.y = virtual_pixel.1;
+        let (left, top, _, _) = get_bounding_box(self.x, self.y, self.x, self.y);
+        self.x_offset = self.x_physical - left;
+        self.y_offset = self.y_physical - top;
+
+        //  Get the colour for the Virtual Pixel
+        let color = self.get_color();
+
+        //  Loop over x_physical from physical_left to physical_right
+        self.x_physical += 1;
+        if self.x_physical > self.physical_right {
+            self.x_physical = self.physical_left;
+            //  Loop over y_physical from physical_top to physical_bottom
+            self.y_physical += 1;
+        }
+        //  Return the Physical Pixel color
+        Some(color)
+    }
+
+    /// Return the next Physical Pixel colour
+    #[cfg(feature = "curve")]  //  If we are rendering CHIP8 Emulator as curved surface...
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y_physical > self.physical_bottom { return None; }  //  No more Physical Pixels
+        assert!(self.x_physical < PHYSICAL_WIDTH as u8, "x overflow");
+        assert!(self.y_physical < PHYSICAL_HEIGHT as u8, "y overflow");
+
+        //  Map the Physical Pixel to the Virtual Pixel
+        let virtual_pixel = map_physical_to_virtual(self.x_physical, self.y_physical);
+
+        if self.x == virtual_pixel.0 && self.y == virtual_pixel.1 {
+            //  If rendering the same Virtual Pixel, increment the offset
+            self.x_offset += 1;
+        } else {
+            //  If rendering a different Virtual Pixel, reset the offset
+            self.x = virtual_pixel.0;
+            self.y = virtual_pixel.1;
+            self.x_offset = 0;
+            self.y_offset = 0;
+        }
+
+        //  Get the colour from the Virtual Screen Buffer
+        let color = self.get_color();
+
+        //  Loop over x_physical from physical_left to physical_right
+        self.x_physical += 1;
+        if self.x_physical > self.physical_right {
+            self.x_physical = self.physical_left;
+            //  Loop over y_physical from physical_top to physical_bottom
+            self.y_physical += 1;
+        }
+        
+        //  Return the Physical Pixel color
+        Some(color)
+    }    
+}
@@ -0,0 +1,410 @@
+//! CHIP8 Save States. A save state holds the memory, registers and timers of the emulator, plus the Virtual Screen,
+//! so that a ROM may be paused and resumed later, even after a reboot. The ROM itself is not saved, only its name,
+//! length and CRC32, so that the same ROM can be found and checked when resuming.
+//! ```text
+//! Offset  Size  Contents
+//! 0       4     Magic "C8SS"
+//! 4       2     Version (u16, little endian)
+//! 6       2     ROM length in bytes (u16, little endian)
+//! 8       4     ROM CRC32 (u32, little endian)
+//! 12      1     Length of the ROM name
+//! 13      19    ROM name (UTF-8)
+//! 32      16    Registers V0 to VF
+//! 48      2     I (u16, little endian)
+//! 50      1     Delay timer
+//! 51      1     Sound timer
+//! 52      2     Program counter (u16, little endian)
+//! 54      1     Stack pointer
+//! 55      1     Resolution: 0 for low resolution (64 x 32), 1 for SUPER-CHIP high resolution (128 x 64)
+//! 56      32    Stack (16 x u16, little endian)
+//! 88      8     SUPER-CHIP user flags
+//! 96      4096  Memory
+//! 4192    8192  Virtual Screen, 8-bit greyscale per Virtual Pixel, row by row at the resolution. Unused bytes are 0.
+//! 12384   4     CRC32 of bytes 0 to 12383 (u32, little endian)
+//! ```
+//! Version 1 save states (low resolution only, without user flags) are not supported.
+use libchip8::{ State, FLAGS, MEMS, REGS, STACKS };
+use pinetime_logo::crc32;
+use crate::{
+    render,
+    HIRES_SCREEN_HEIGHT, HIRES_SCREEN_WIDTH, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+
+/// Magic number at the start of a save state: "C8SS"
+pub const MAGIC: [u8; 4] = *b"C8SS";
+
+/// Current version of the save state format. Increment when the format changes.
+pub const VERSION: u16 = 2;
+
+/// Size of the save state header, including the ROM name
+pub const HEADER_SIZE: usize = 32;
+
+/// Max length of the ROM name in bytes
+pub const MAX_NAME_LEN: usize = HEADER_SIZE - NAME_OFFSET;
+
+/// Size of a save state in bytes
+pub const SNAPSHOT_SIZE: usize = CRC_OFFSET + 4;
+
+/// Offset of the ROM name
+const NAME_OFFSET: usize = 13;
+
+/// Offset of the registers
+const REGS_OFFSET: usize = HEADER_SIZE;
+
+/// Offset of the stack
+const STACK_OFFSET: usize = REGS_OFFSET + REGS + 8;
+
+/// Offset of the SUPER-CHIP user flags
+const FLAGS_OFFSET: usize = STACK_OFFSET + STACKS * 2;
+
+/// Offset of the memory
+const MEM_OFFSET: usize = FLAGS_OFFSET + FLAGS;
+
+/// Offset of the Virtual Screen
+const SCREEN_OFFSET: usize = MEM_OFFSET + MEMS;
+
+/// Offset of the CRC32
+const CRC_OFFSET: usize = SCREEN_OFFSET + HIRES_SCREEN_WIDTH * HIRES_SCREEN_HEIGHT;
+
+/// Reason why a save state could not be saved or restored
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapshotError {
+    /// Buffer is smaller than `SNAPSHOT_SIZE`
+    BufferTooSmall,
+    /// Magic number is missing, e.g. the flash is erased
+    BadMagic,
+    /// Save state was created by an unknown version
+    UnsupportedVersion,
+    /// CRC32 doesn't match, the save state is corrupted
+    BadChecksum,
+    /// Program counter, stack pointer or resolution is out of range
+    BadState,
+    /// Save state was created by a different ROM
+    RomMismatch,
+}
+
+/// Description of the ROM in a save state
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotInfo<'a> {
+    /// Name of the ROM, truncated to `MAX_NAME_LEN` bytes
+    pub rom_name: &'a str,
+    /// ROM length in bytes
+    pub rom_len:  u16,
+    /// ROM CRC32
+    pub rom_crc:  u32,
+}
+
+/// Save the emulator `state` and the Virtual Screen into `buf`, for the ROM named `rom_name` with contents `rom`.
+/// Return the number of bytes saved, which is `SNAPSHOT_SIZE`.
+pub fn save(state: &State, rom_name: &str, rom: &[u8], buf: &mut [u8]) -> Result<usize, SnapshotError> {
+    if buf.len() < SNAPSHOT_SIZE { return Err(SnapshotError::BufferTooSmall); }
+    let buf = &mut buf[..SNAPSHOT_SIZE];
+    buf[..HEADER_SIZE].copy_from_slice(&[0; HEADER_SIZE]);
+
+    //  Write the header
+    let name = truncate(rom_name, MAX_NAME_LEN);
+    buf[0..4]  .copy_from_slice(&MAGIC);
+    buf[4..6]  .copy_from_slice(&VERSION.to_le_bytes());
+    buf[6..8]  .copy_from_slice(&(rom.len() as u16).to_le_bytes());
+    buf[8..12] .copy_from_slice(&rom_checksum(rom).to_le_bytes());
+    buf[12] = name.len() as u8;
+    buf[NAME_OFFSET..NAME_OFFSET + name.len()].copy_from_slice(name.as_bytes());
+
+    //  Write the registers, timers and stack
+    buf[REGS_OFFSET..REGS_OFFSET + REGS].copy_from_slice(&state.v);
+    let regs = &mut buf[REGS_OFFSET + REGS..STACK_OFFSET];
+    regs[0..2].copy_from_slice(&state.i.to_le_bytes());
+    regs[2] = state.dt;
+    regs[3] = state.st;
+    regs[4..6].copy_from_slice(&state.pc.to_le_bytes());
+    regs[6] = state.sp;
+    regs[7] = state.hires as u8;
+    for (i, addr) in state.stack.iter().enumerate() {
+        let offset = STACK_OFFSET + i * 2;
+        buf[offset..offset + 2].copy_from_slice(&addr.to_le_bytes());
+    }
+
+    //  Write the user flags, memory and Virtual Screen
+    buf[FLAGS_OFFSET..MEM_OFFSET].copy_from_slice(&state.flags);
+    buf[MEM_OFFSET..SCREEN_OFFSET].copy_from_slice(&state.mem);
+    let screen_len = screen_len(state.hires);
+    assert!(render::screen_size() == screen_dimensions(state.hires), "bad resolution");
+    render::save_screen(&mut buf[SCREEN_OFFSET..SCREEN_OFFSET + screen_len]);
+    for byte in buf[SCREEN_OFFSET + screen_len..CRC_OFFSET].iter_mut() { *byte = 0; }
+
+    //  Write the CRC32
+    let crc = crc32(0, &buf[..CRC_OFFSET]);
+    buf[CRC_OFFSET..].copy_from_slice(&crc.to_le_bytes());
+    Ok(SNAPSHOT_SIZE)
+}
+
+/// Check the save state in `buf` and return the description of its ROM
+pub fn read_info(buf: &[u8]) -> Result<SnapshotInfo<'_>, SnapshotError> {
+    if buf.len() < SNAPSHOT_SIZE { return Err(SnapshotError::BufferTooSmall); }
+    if buf[0..4] != MAGIC { return Err(SnapshotError::BadMagic); }
+    if read_u16(buf, 4) != VERSION { return Err(SnapshotError::UnsupportedVersion); }
+    let crc = u32::from_le_bytes([ buf[CRC_OFFSET], buf[CRC_OFFSET + 1], buf[CRC_OFFSET + 2], buf[CRC_OFFSET + 3] ]);
+    if crc != crc32(0, &buf[..CRC_OFFSET]) { return Err(SnapshotError::BadChecksum); }
+
+    let name_len = (buf[12] as usize).min(MAX_NAME_LEN);
+    let rom_name = core::str::from_utf8(&buf[NAME_OFFSET..NAME_OFFSET + name_len])
+        .map_err(|_| SnapshotError::BadState) ? ;
+    Ok(SnapshotInfo {
+        rom_name,
+        rom_len: read_u16(buf, 6),
+        rom_crc: u32::from_le_bytes([ buf[8], buf[9], buf[10], buf[11] ]),
+    })
+}
+
+/// Restore the emulator `state` and the Virtual Screen from the save state in `buf`, which must have been saved
+/// for the ROM `rom`. `state` and the Virtual Screen are not changed upon error.
+pub fn restore(buf: &[u8], rom: &[u8], state: &mut State) -> Result<(), SnapshotError> {
+    let info = read_info(buf) ? ;
+    if info.rom_len as usize != rom.len() || info.rom_crc != rom_checksum(rom) {
+        return Err(SnapshotError::RomMismatch);
+    }
+
+    //  Check the program counter, stack pointer and resolution, so that the emulator won't access memory out of range
+    let regs = &buf[REGS_OFFSET + REGS..STACK_OFFSET];
+    let pc = read_u16(regs, 4);
+    let sp = regs[6];
+    let resolution = regs[7];
+    if pc as usize > MEMS - 2 || sp as usize > STACKS || resolution > 1 { return Err(SnapshotError::BadState); }
+    let hires = resolution == 1;
+
+    //  Read the registers, timers and stack
+    state.v.copy_from_slice(&buf[REGS_OFFSET..REGS_OFFSET + REGS]);
+    state.i  = read_u16(regs, 0);
+    state.dt = regs[2];
+    state.st = regs[3];
+    state.pc = pc;
+    state.sp = sp;
+    state.hires = hires;
+    for (i, addr) in state.stack.iter_mut().enumerate() {
+        *addr = read_u16(buf, STACK_OFFSET + i * 2);
+    }
+
+    //  Read the user flags, memory and Virtual Screen
+    state.flags.copy_from_slice(&buf[FLAGS_OFFSET..MEM_OFFSET]);
+    state.mem.copy_from_slice(&buf[MEM_OFFSET..SCREEN_OFFSET]);
+    let (width, height) = screen_dimensions(hires);
+    render::set_screen_size(width, height);
+    render::restore_screen(&buf[SCREEN_OFFSET..SCREEN_OFFSET + screen_len(hires)]);
+    Ok(())
+}
+
+/// Return the Virtual Screen size (width, height) for the resolution
+fn screen_dimensions(hires: bool) -> (usize, usize) {
+    if hires { (HIRES_SCREEN_WIDTH, HIRES_SCREEN_HEIGHT) }
+    else     { (SCREEN_WIDTH, SCREEN_HEIGHT) }
+}
+
+/// Return the number of bytes of the Virtual Screen for the resolution
+fn screen_len(hires: bool) -> usize {
+    let (width, height) = screen_dimensions(hires);
+    width * height
+}
+
+/// Return the CRC32 of the ROM, for checking that a save state belongs to the ROM
+pub fn rom_checksum(rom: &[u8]) -> u32 {
+    crc32(0, rom)
+}
+
+/// Truncate `s` to at most `max_len` bytes, without splitting a character
+fn truncate(s: &str, max_len: usize) -> &str {
+    if s.len() <= max_len { return s; }
+    let mut len = max_len;
+    while !s.is_char_boundary(len) { len -= 1; }
+    &s[..len]
+}
+
+/// Read a little-endian `u16` at `offset`
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([ bytes[offset], bytes[offset + 1] ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::{ sync::Mutex, vec, vec::Vec };
+
+    /// Serialises the tests, because the Virtual Screen is shared
+    static SCREEN_LOCK: Mutex<()> = Mutex::new(());
+
+    /// ROM for the tests
+    const ROM: &[u8] = &[0x00, 0xff, 0x12, 0x02];
+
+    /// Return a high resolution state with every field set, and draw a pattern on the Virtual Screen
+    fn test_state() -> State {
+        let mut state = State::new();
+        for (i, v) in state.v.iter_mut().enumerate() { *v = i as u8 * 3; }
+        state.i  = 0x345;
+        state.dt = 12;
+        state.st = 34;
+        state.pc = 0x202;
+        state.sp = 2;
+        state.stack[0] = 0x210;
+        state.stack[1] = 0x2f0;
+        state.flags = [1, 2, 3, 4, 5, 6, 7, 8];
+        state.mem[0x200..0x204].copy_from_slice(ROM);
+        state.mem[MEMS - 1] = 0xaa;
+        state.hires = true;
+        render::set_screen_size(HIRES_SCREEN_WIDTH, HIRES_SCREEN_HEIGHT);
+        render::clear_screen();
+        render::set_pixel(0, 0, 255);
+        render::set_pixel(HIRES_SCREEN_WIDTH - 1, HIRES_SCREEN_HEIGHT - 1, 128);
+        render::set_pixel(70, 40, 1);
+        state
+    }
+
+    /// Return a save state of `test_state()` for `ROM`
+    fn test_snapshot() -> Vec<u8> {
+        let mut buf = vec![0; SNAPSHOT_SIZE];
+        assert_eq!(save(&test_state(), "Test ROM", ROM, &mut buf), Ok(SNAPSHOT_SIZE));
+        buf
+    }
+
+    /// Recompute the CRC32 after changing the save state
+    fn update_crc(buf: &mut [u8]) {
+        let crc = crc32(0, &buf[..CRC_OFFSET]);
+        buf[CRC_OFFSET..].copy_from_slice(&crc.to_le_bytes());
+    }
+
+    /// Restore `buf` into a cleared state and low resolution Virtual Screen. Check that nothing is changed upon error.
+    fn restore_cleared(buf: &[u8], rom: &[u8]) -> Result<State, SnapshotError> {
+        let mut state = State::new();
+        render::set_screen_size(SCREEN_WIDTH, SCREEN_HEIGHT);
+        render::clear_screen();
+        let result = restore(buf, rom, &mut state);
+        if result.is_err() {
+            assert_eq!(state.pc, 0);
+            assert_eq!(render::screen_size(), (SCREEN_WIDTH, SCREEN_HEIGHT));
+            assert_eq!(render::get_pixel(0, 0), 0);
+        }
+        result.map(|_| state)
+    }
+
+    #[test]
+    fn save_and_restore() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        let buf = test_snapshot();
+        let expected = test_state();
+        let state = restore_cleared(&buf, ROM).unwrap();
+        assert_eq!(state.v, expected.v);
+        assert_eq!((state.i, state.dt, state.st, state.pc, state.sp), (0x345, 12, 34, 0x202, 2));
+        assert_eq!(state.stack, expected.stack);
+        assert_eq!(state.flags, expected.flags);
+        assert_eq!(state.mem[..], expected.mem[..]);
+        assert!(state.hires);
+        assert_eq!(render::screen_size(), (HIRES_SCREEN_WIDTH, HIRES_SCREEN_HEIGHT));
+        assert_eq!(render::get_pixel(0, 0), 255);
+        assert_eq!(render::get_pixel(HIRES_SCREEN_WIDTH - 1, HIRES_SCREEN_HEIGHT - 1), 128);
+        assert_eq!(render::get_pixel(70, 40), 1);
+        assert_eq!(render::get_pixel(1, 0), 0);
+
+        //  Saving the restored state produces the same bytes
+        let mut resaved = vec![0; SNAPSHOT_SIZE];
+        save(&state, "Test ROM", ROM, &mut resaved).unwrap();
+        assert_eq!(buf, resaved);
+    }
+
+    #[test]
+    fn low_resolution_screen_is_padded() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        let mut state = test_state();
+        state.hires = false;
+        render::set_screen_size(SCREEN_WIDTH, SCREEN_HEIGHT);
+        render::set_pixel(SCREEN_WIDTH - 1, SCREEN_HEIGHT - 1, 200);
+        let mut buf = vec![0xff; SNAPSHOT_SIZE];
+        save(&state, "Low", ROM, &mut buf).unwrap();
+        assert!(buf[SCREEN_OFFSET + SCREEN_WIDTH * SCREEN_HEIGHT..CRC_OFFSET].iter().all(|b| *b == 0));
+
+        render::set_screen_size(HIRES_SCREEN_WIDTH, HIRES_SCREEN_HEIGHT);
+        let mut restored = State::new();
+        restore(&buf, ROM, &mut restored).unwrap();
+        assert!(!restored.hires);
+        assert_eq!(render::screen_size(), (SCREEN_WIDTH, SCREEN_HEIGHT));
+        assert_eq!(render::get_pixel(SCREEN_WIDTH - 1, SCREEN_HEIGHT - 1), 200);
+    }
+
+    #[test]
+    fn read_info_and_truncate_name() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        let buf = test_snapshot();
+        assert_eq!(read_info(&buf), Ok(SnapshotInfo {
+            rom_name: "Test ROM",
+            rom_len:  ROM.len() as u16,
+            rom_crc:  rom_checksum(ROM),
+        }));
+
+        //  Long names are truncated without splitting a character
+        let mut buf = vec![0; SNAPSHOT_SIZE];
+        save(&test_state(), "Space Invaders \u{00e9}\u{00e9}\u{00e9}", ROM, &mut buf).unwrap();
+        assert_eq!(read_info(&buf).unwrap().rom_name, "Space Invaders \u{00e9}\u{00e9}");
+        assert_eq!(truncate("abc", MAX_NAME_LEN), "abc");
+    }
+
+    #[test]
+    fn bad_crc() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        for offset in [ 6, NAME_OFFSET, MEM_OFFSET + 0x200, SCREEN_OFFSET, CRC_OFFSET ].iter() {
+            let mut buf = test_snapshot();
+            buf[*offset] ^= 0x01;
+            assert_eq!(read_info(&buf), Err(SnapshotError::BadChecksum), "offset {}", offset);
+            assert_eq!(restore_cleared(&buf, ROM).err(), Some(SnapshotError::BadChecksum), "offset {}", offset);
+        }
+    }
+
+    #[test]
+    fn bad_version() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        for version in [ 0, 1, VERSION + 1 ].iter() {
+            let mut buf = test_snapshot();
+            buf[4..6].copy_from_slice(&version.to_le_bytes());
+            update_crc(&mut buf);
+            assert_eq!(restore_cleared(&buf, ROM).err(), Some(SnapshotError::UnsupportedVersion), "version {}", version);
+        }
+    }
+
+    #[test]
+    fn bad_magic() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        //  Erased flash
+        let buf = vec![0xff; SNAPSHOT_SIZE];
+        assert_eq!(restore_cleared(&buf, ROM).err(), Some(SnapshotError::BadMagic));
+        let buf = test_snapshot();
+        assert_eq!(restore_cleared(&buf[..SNAPSHOT_SIZE - 1], ROM).err(), Some(SnapshotError::BufferTooSmall));
+        let mut small = vec![0; SNAPSHOT_SIZE - 1];
+        assert_eq!(save(&test_state(), "Test ROM", ROM, &mut small), Err(SnapshotError::BufferTooSmall));
+    }
+
+    #[test]
+    fn rom_mismatch() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        let buf = test_snapshot();
+        //  Same length, different contents
+        assert_eq!(restore_cleared(&buf, &[0x00, 0xff, 0x12, 0x04]).err(), Some(SnapshotError::RomMismatch));
+        //  Different length
+        assert_eq!(restore_cleared(&buf, &ROM[..2]).err(), Some(SnapshotError::RomMismatch));
+        assert_eq!(restore_cleared(&buf, &[]).err(), Some(SnapshotError::RomMismatch));
+    }
+
+    #[test]
+    fn bad_state() {
+        let _lock = SCREEN_LOCK.lock().unwrap();
+        let regs = REGS_OFFSET + REGS;
+        //  Program counter, stack pointer and resolution out of range
+        let changes: [(usize, &[u8]); 3] = [
+            (regs + 4, &(MEMS as u16 - 1).to_le_bytes()),
+            (regs + 6, &[STACKS as u8 + 1]),
+            (regs + 7, &[2]),
+        ];
+        for (offset, bytes) in changes.iter() {
+            let mut buf = test_snapshot();
+            buf[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+            update_crc(&mut buf);
+            assert_eq!(restore_cleared(&buf, ROM).err(), Some(SnapshotError::BadState), "offset {}", offset);
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! ST7789 Display Controller command layer for PineTime, without Mynewt calls. Used by `mynewt::st7789` to
+//! initialise and configure the display, and checked on the host against the init script replayed by the
+//! bootloader in `libs/pinetime_boot/src/st7789_init.inc`.
+
+#![no_std]  //  Don't link with standard Rust library, so that the commands are sent on PineTime
+
+pub mod st7789;  //  Export `st7789.rs` as Rust module `pinetime_display::st7789`
+
+/// Errors when sending ST7789 commands. `mynewt::st7789` converts them to `MynewtError`:
+/// `Invalid` is `SYS_EINVAL`, `NoMemory` is `SYS_ENOMEM`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// Window or script is invalid
+    Invalid,
+    /// Buffer or recorder is full
+    NoMemory,
+}
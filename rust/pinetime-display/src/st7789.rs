@@ -0,0 +1,661 @@
+//! ST7789 Display Controller commands. Each command is typed, knows its opcode and parameter bytes, and
+//! carries the delay that the controller requires after the command. Commands are sent to a `CommandSink`:
+//! `mynewt::st7789::NonBlockingSpi` for the display, `Recorder` for checking the byte stream without hardware.
+//! The bootloader in C replays the init sequence from a script encoded by `encode_script()`,
+//! see `libs/pinetime_boot/src/st7789_init.inc`. Rotation, mirroring and colours are changed at runtime with
+//! `configure()`. `DisplayConfig` decides how windows and touch coordinates are transformed to match the picture.
+use crate::Error;
+
+//  ST7789 Opcodes. From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/instruction.rs
+pub const NOP:     u8 = 0x00;
+pub const SWRESET: u8 = 0x01;
+pub const SLPIN:   u8 = 0x10;
+pub const SLPOUT:  u8 = 0x11;
+pub const NORON:   u8 = 0x13;
+pub const INVOFF:  u8 = 0x20;
+pub const INVON:   u8 = 0x21;
+pub const DISPOFF: u8 = 0x28;
+pub const DISPON:  u8 = 0x29;
+pub const CASET:   u8 = 0x2A;
+pub const RASET:   u8 = 0x2B;
+pub const RAMWR:   u8 = 0x2C;
+pub const MADCTL:  u8 = 0x36;
+pub const COLMOD:  u8 = 0x3A;
+pub const FRMCTR1: u8 = 0xB1;
+pub const FRMCTR2: u8 = 0xB2;
+pub const FRMCTR3: u8 = 0xB3;
+pub const INVCTR:  u8 = 0xB4;
+pub const PWCTR1:  u8 = 0xC0;
+pub const PWCTR2:  u8 = 0xC1;
+pub const PWCTR3:  u8 = 0xC2;
+pub const PWCTR4:  u8 = 0xC3;
+pub const PWCTR5:  u8 = 0xC4;
+pub const VMCTR1:  u8 = 0xC5;
+
+/// Max number of parameter bytes for a command
+pub const MAX_PARAMS: usize = 6;
+
+/// Delay in milliseconds after SWRESET, SLPOUT and DISPON. Same as the delays in `init_display()` in C.
+pub const RESET_DELAY_MS: u8 = 200;
+
+/// Width of the PineTime display in pixels
+pub const DISPLAY_WIDTH: u16 = 240;
+
+/// Height of the PineTime display in pixels
+pub const DISPLAY_HEIGHT: u16 = 240;
+
+/// Number of rows in the ST7789 frame memory. The display shows only the first `DISPLAY_HEIGHT` rows.
+const MEMORY_ROWS: u16 = 320;
+
+//  MADCTL bits: Row address order (MY), column address order (MX), row / column exchange (MV) and BGR colour order
+const MADCTL_MY:  u8 = 0x80;
+const MADCTL_MX:  u8 = 0x40;
+const MADCTL_MV:  u8 = 0x20;
+const MADCTL_BGR: u8 = 0x08;
+
+/// COLMOD parameter for 16 bits per pixel (RGB565)
+const COLMOD_RGB565: u8 = 0x05;
+
+/// Rotation of the picture, clockwise from the normal PineTime orientation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+impl Rotation {
+    /// Return the MADCTL bits for the rotation. `Deg0` is the Landscape orientation of the ST7789, which matches
+    /// the touch panel. From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/lib.rs#L52-L58
+    fn madctl(self) -> u8 {
+        match self {
+            Rotation::Deg0   => MADCTL_MX | MADCTL_MV,  //  Landscape
+            Rotation::Deg90  => MADCTL_MX | MADCTL_MY,  //  Portrait Swapped
+            Rotation::Deg180 => MADCTL_MY | MADCTL_MV,  //  Landscape Swapped
+            Rotation::Deg270 => 0,                      //  Portrait
+        }
+    }
+}
+
+/// Orientation and colour settings of the display
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DisplayConfig {
+    /// Rotation of the picture
+    pub rotation: Rotation,
+    /// True if the picture is mirrored left to right, after rotation
+    pub mirror:   bool,
+    /// True if the display colours are inverted
+    pub inverted: bool,
+    /// True if the display colours are RGB, false if BGR
+    pub rgb:      bool,
+}
+
+/// Settings of the PineTime display. Same as the ST7789 settings in `display.c`.
+pub const PINETIME_CONFIG: DisplayConfig = DisplayConfig {
+    rotation: Rotation::Deg0,
+    mirror:   false,
+    inverted: true,
+    rgb:      true,
+};
+
+impl DisplayConfig {
+    /// Return the MADCTL parameter for the rotation, mirroring and colour order
+    pub fn madctl(&self) -> u8 {
+        let mut madctl = self.rotation.madctl();
+        if self.mirror {
+            //  After row / column exchange, columns of the picture are rows of the frame memory
+            madctl ^= if madctl & MADCTL_MV != 0 { MADCTL_MY } else { MADCTL_MX };
+        }
+        madctl | self.colour_order()
+    }
+
+    /// Return the (column, row) offset of the display window in the frame memory. When the row address order
+    /// is reversed, the visible rows are at the end of the frame memory: for `Deg90` and `Deg180`, and for `Deg0`
+    /// and `Deg90` when mirrored. `set_window()` applies the offset, and so does `draw.rs` in the app for druid
+    /// and embedded-graphics, which set the display window without the offset.
+    pub fn window_offset(&self) -> (u16, u16) {
+        let madctl = self.madctl();
+        let offset = if madctl & MADCTL_MY != 0 { MEMORY_ROWS - DISPLAY_HEIGHT } else { 0 };
+        if madctl & MADCTL_MV != 0 { (offset, 0) }
+        else { (0, offset) }
+    }
+
+    /// Convert the touch coordinates (`x`, `y`) to the coordinates of the rotated and mirrored picture.
+    /// The touch panel is aligned with `Rotation::Deg0` without mirroring.
+    pub fn transform_touch(&self, x: u16, y: u16) -> (u16, u16) {
+        let x = x.min(DISPLAY_WIDTH - 1);
+        let y = y.min(DISPLAY_HEIGHT - 1);
+        let (panel_x, panel_y) = to_panel(Rotation::Deg0.madctl(), x, y);
+        from_panel(self.madctl(), panel_x, panel_y)
+    }
+
+    /// Convert the coordinates (`x`, `y`) of the rotated and mirrored picture to the touch coordinates.
+    /// Reverses `transform_touch()`, e.g. to find where the touch panel is touched for a target on the picture.
+    pub fn inverse_transform_touch(&self, x: u16, y: u16) -> (u16, u16) {
+        let x = x.min(DISPLAY_WIDTH - 1);
+        let y = y.min(DISPLAY_HEIGHT - 1);
+        let (panel_x, panel_y) = to_panel(self.madctl(), x, y);
+        from_panel(Rotation::Deg0.madctl(), panel_x, panel_y)
+    }
+
+    /// Return the MADCTL bit for the colour order
+    pub fn colour_order(&self) -> u8 {
+        if self.rgb { 0 } else { MADCTL_BGR }
+    }
+}
+
+/// Map the picture coordinates (`x`, `y`) to the display panel for the MADCTL parameter
+fn to_panel(madctl: u8, x: u16, y: u16) -> (u16, u16) {
+    let (x, y) = if madctl & MADCTL_MV != 0 { (y, x) } else { (x, y) };
+    let x = if madctl & MADCTL_MX != 0 { DISPLAY_WIDTH  - 1 - x } else { x };
+    let y = if madctl & MADCTL_MY != 0 { DISPLAY_HEIGHT - 1 - y } else { y };
+    (x, y)
+}
+
+/// Map the display panel coordinates (`x`, `y`) to the picture for the MADCTL parameter. Reverses `to_panel()`.
+fn from_panel(madctl: u8, x: u16, y: u16) -> (u16, u16) {
+    let x = if madctl & MADCTL_MX != 0 { DISPLAY_WIDTH  - 1 - x } else { x };
+    let y = if madctl & MADCTL_MY != 0 { DISPLAY_HEIGHT - 1 - y } else { y };
+    if madctl & MADCTL_MV != 0 { (y, x) } else { (x, y) }
+}
+
+/// ST7789 command with its parameters
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Command {
+    /// No Operation (NOP)
+    Nop,
+    /// Software Reset (SWRESET)
+    SoftwareReset,
+    /// Sleep In (SLPIN)
+    SleepIn,
+    /// Sleep Out (SLPOUT)
+    SleepOut,
+    /// Normal Display Mode On (NORON)
+    NormalMode,
+    /// Display Inversion Off (INVOFF)
+    InvertOff,
+    /// Display Inversion On (INVON)
+    InvertOn,
+    /// Display Off (DISPOFF)
+    DisplayOff,
+    /// Display On (DISPON)
+    DisplayOn,
+    /// Column Address Set (CASET) from `start` to `end` inclusive
+    ColumnAddress { start: u16, end: u16 },
+    /// Row Address Set (RASET) from `start` to `end` inclusive
+    RowAddress { start: u16, end: u16 },
+    /// Memory Write (RAMWR). The pixels follow as data.
+    MemoryWrite,
+    /// Memory Data Access Control (MADCTL)
+    MemoryAccess(u8),
+    /// Interface Pixel Format (COLMOD)
+    PixelFormat(u8),
+    /// Frame Rate Control in Normal Mode (FRMCTR1)
+    FrameRateNormal([u8; 3]),
+    /// Frame Rate Control in Idle Mode (FRMCTR2)
+    FrameRateIdle([u8; 3]),
+    /// Frame Rate Control in Partial Mode (FRMCTR3)
+    FrameRatePartial([u8; 6]),
+    /// Display Inversion Control (INVCTR)
+    InversionControl(u8),
+    /// Power Control 1 (PWCTR1)
+    PowerControl1([u8; 3]),
+    /// Power Control 2 (PWCTR2)
+    PowerControl2(u8),
+    /// Power Control 3 (PWCTR3)
+    PowerControl3([u8; 2]),
+    /// Power Control 4 (PWCTR4)
+    PowerControl4([u8; 2]),
+    /// Power Control 5 (PWCTR5)
+    PowerControl5([u8; 2]),
+    /// VCOM Control 1 (VMCTR1)
+    VcomControl(u8),
+}
+
+impl Command {
+    /// Return the opcode of the command
+    pub fn opcode(&self) -> u8 {
+        match self {
+            Command::Nop                     => NOP,
+            Command::SoftwareReset           => SWRESET,
+            Command::SleepIn                 => SLPIN,
+            Command::SleepOut                => SLPOUT,
+            Command::NormalMode              => NORON,
+            Command::InvertOff               => INVOFF,
+            Command::InvertOn                => INVON,
+            Command::DisplayOff              => DISPOFF,
+            Command::DisplayOn               => DISPON,
+            Command::ColumnAddress { .. }    => CASET,
+            Command::RowAddress { .. }       => RASET,
+            Command::MemoryWrite             => RAMWR,
+            Command::MemoryAccess(_)         => MADCTL,
+            Command::PixelFormat(_)          => COLMOD,
+            Command::FrameRateNormal(_)      => FRMCTR1,
+            Command::FrameRateIdle(_)        => FRMCTR2,
+            Command::FrameRatePartial(_)     => FRMCTR3,
+            Command::InversionControl(_)     => INVCTR,
+            Command::PowerControl1(_)        => PWCTR1,
+            Command::PowerControl2(_)        => PWCTR2,
+            Command::PowerControl3(_)        => PWCTR3,
+            Command::PowerControl4(_)        => PWCTR4,
+            Command::PowerControl5(_)        => PWCTR5,
+            Command::VcomControl(_)          => VMCTR1,
+        }
+    }
+
+    /// Encode the parameters of the command into `buf` and return the encoded bytes. Addresses are big endian.
+    pub fn params<'a>(&self, buf: &'a mut [u8; MAX_PARAMS]) -> &'a [u8] {
+        let len = match self {
+            Command::ColumnAddress { start, end } |
+            Command::RowAddress { start, end } => {
+                buf[..2].copy_from_slice(&start.to_be_bytes());
+                buf[2..4].copy_from_slice(&end.to_be_bytes());
+                4
+            }
+            Command::MemoryAccess(param)     |
+            Command::PixelFormat(param)      |
+            Command::InversionControl(param) |
+            Command::PowerControl2(param)    |
+            Command::VcomControl(param)      => { buf[0] = *param; 1 }
+            Command::FrameRateNormal(params) |
+            Command::FrameRateIdle(params)   |
+            Command::PowerControl1(params)   => copy_params(buf, params),
+            Command::FrameRatePartial(params) => copy_params(buf, params),
+            Command::PowerControl3(params)   |
+            Command::PowerControl4(params)   |
+            Command::PowerControl5(params)   => copy_params(buf, params),
+            _ => 0,
+        };
+        &buf[..len]
+    }
+
+    /// Return the number of milliseconds that the controller needs after the command, before the next command
+    pub fn delay_ms(&self) -> u8 {
+        delay_after(self.opcode())
+    }
+}
+
+/// Return the number of milliseconds that the controller needs after the command with the `opcode`.
+/// Used by the SPI task, which only sees the opcode.
+pub fn delay_after(opcode: u8) -> u8 {
+    match opcode {
+        SWRESET | SLPOUT | DISPON => RESET_DELAY_MS,
+        _ => 0,
+    }
+}
+
+/// Copy `params` into `buf` and return the number of bytes copied
+fn copy_params(buf: &mut [u8; MAX_PARAMS], params: &[u8]) -> usize {
+    buf[..params.len()].copy_from_slice(params);
+    params.len()
+}
+
+/// Destination for ST7789 commands: the display or a mock
+pub trait CommandSink {
+    /// Error returned by the sink. Errors in the commands are converted to this error.
+    type Error: From<Error>;
+    /// Write the Command Byte
+    fn write_command(&mut self, opcode: u8) -> Result<(), Self::Error>;
+    /// Write the Data Bytes that follow the Command Byte
+    fn write_data(&mut self, data: &[u8]) -> Result<(), Self::Error>;
+    /// Wait for `ms` milliseconds before the next command
+    fn delay_ms(&mut self, ms: u8) -> Result<(), Self::Error>;
+}
+
+/// Send the `command` with its parameters, followed by the required delay
+pub fn send<S: CommandSink>(sink: &mut S, command: Command) -> Result<(), S::Error> {
+    let mut buf = [0u8; MAX_PARAMS];
+    let params = command.params(&mut buf);
+    sink.write_command(command.opcode()) ? ;
+    if !params.is_empty() {
+        sink.write_data(params) ? ;
+    }
+    let delay = command.delay_ms();
+    if delay > 0 {
+        sink.delay_ms(delay) ? ;
+    }
+    Ok(())
+}
+
+/// Number of commands in the init sequence
+pub const INIT_SEQUENCE_LEN: usize = 16;
+
+/// Return the commands that initialise the display with the colour settings in `config`.
+/// From https://github.com/lupyuen/st7735-lcd-batch-rs/blob/master/src/lib.rs
+pub fn init_sequence(config: DisplayConfig) -> [Command; INIT_SEQUENCE_LEN] {
+    [
+        Command::SoftwareReset,
+        Command::SleepOut,
+        Command::FrameRateNormal([ 0x01, 0x2C, 0x2D ]),
+        Command::FrameRateIdle([ 0x01, 0x2C, 0x2D ]),
+        Command::FrameRatePartial([ 0x01, 0x2C, 0x2D, 0x01, 0x2C, 0x2D ]),
+        Command::InversionControl(0x07),
+        Command::PowerControl1([ 0xA2, 0x02, 0x84 ]),
+        Command::PowerControl2(0xC5),
+        Command::PowerControl3([ 0x0A, 0x00 ]),
+        Command::PowerControl4([ 0x8A, 0x2A ]),
+        Command::PowerControl5([ 0x8A, 0xEE ]),
+        Command::VcomControl(0x0E),
+        if config.inverted { Command::InvertOn } else { Command::InvertOff },
+        Command::MemoryAccess(config.colour_order()),
+        Command::PixelFormat(COLMOD_RGB565),
+        Command::DisplayOn,
+    ]
+}
+
+/// Initialise the display after a hard reset by sending the init sequence
+pub fn init_display<S: CommandSink>(sink: &mut S, config: DisplayConfig) -> Result<(), S::Error> {
+    for command in init_sequence(config).iter() {
+        send(sink, *command) ? ;
+    }
+    Ok(())
+}
+
+/// Set the display window to the coordinates (`left`, `top`), (`right`, `bottom`) inclusive.
+/// The window is shifted for the rotation and mirroring in `config`. Fail with `Invalid` if the window is empty.
+pub fn set_window<S: CommandSink>(sink: &mut S, config: DisplayConfig, left: u16, top: u16, right: u16, bottom: u16) -> Result<(), S::Error> {
+    if left > right || top > bottom { return Err(Error::Invalid.into()); }
+    let (column_offset, row_offset) = config.window_offset();
+    send(sink, Command::ColumnAddress { start: left + column_offset, end: right + column_offset }) ? ;
+    send(sink, Command::RowAddress { start: top + row_offset, end: bottom + row_offset })
+}
+
+/// Apply the rotation, mirroring, colour inversion and colour order in `config` to the display
+pub fn configure<S: CommandSink>(sink: &mut S, config: DisplayConfig) -> Result<(), S::Error> {
+    send(sink, if config.inverted { Command::InvertOn } else { Command::InvertOff }) ? ;
+    send(sink, Command::MemoryAccess(config.madctl()))
+}
+
+/// Encode the `commands` into `buf` as a script for the bootloader and return the script length.
+/// Each command is encoded as the opcode, the number of parameters, the parameters and the delay in milliseconds.
+/// Fail with `NoMemory` if `buf` is too small.
+pub fn encode_script(commands: &[Command], buf: &mut [u8]) -> Result<usize, Error> {
+    let mut len = 0;
+    for command in commands {
+        let mut params_buf = [0u8; MAX_PARAMS];
+        let params = command.params(&mut params_buf);
+        let end = len + 3 + params.len();
+        if end > buf.len() { return Err(Error::NoMemory); }
+        buf[len] = command.opcode();
+        buf[len + 1] = params.len() as u8;
+        buf[len + 2..end - 1].copy_from_slice(params);
+        buf[end - 1] = command.delay_ms();
+        len = end;
+    }
+    Ok(len)
+}
+
+/// Replay the script encoded by `encode_script()` into the `sink`. Same as `init_display()` in the bootloader.
+/// Fail with `Invalid` if the script is truncated.
+pub fn replay_script<S: CommandSink>(sink: &mut S, script: &[u8]) -> Result<(), S::Error> {
+    let mut pos = 0;
+    while pos < script.len() {
+        if pos + 2 > script.len() { return Err(Error::Invalid.into()); }
+        let opcode = script[pos];
+        let end = pos + 3 + script[pos + 1] as usize;
+        if end > script.len() { return Err(Error::Invalid.into()); }
+        sink.write_command(opcode) ? ;
+        if end - pos > 3 {
+            sink.write_data(&script[pos + 2..end - 1]) ? ;
+        }
+        if script[end - 1] > 0 {
+            sink.delay_ms(script[end - 1]) ? ;
+        }
+        pos = end;
+    }
+    Ok(())
+}
+
+/// Operation recorded by `Recorder`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    /// Command Byte
+    Command(u8),
+    /// Data Byte
+    Data(u8),
+    /// Delay in milliseconds
+    Delay(u8),
+}
+
+/// Max number of operations recorded by `Recorder`
+type RecorderSize = heapless::consts::U256;
+
+/// Records the byte stream and delays instead of sending them, for checking command sequences without hardware
+pub struct Recorder {
+    /// Operations recorded so far
+    ops: heapless::Vec<Op, RecorderSize>,
+}
+
+impl Recorder {
+    /// Create an empty recorder
+    pub fn new() -> Recorder {
+        Recorder { ops: heapless::Vec::new() }
+    }
+
+    /// Return the operations recorded so far
+    pub fn ops(&self) -> &[Op] {
+        &self.ops
+    }
+
+    /// Forget the operations recorded so far
+    pub fn clear(&mut self) {
+        //  Replace the vector because `clear()` in heapless 0.5 indexes past the end of the buffer
+        self.ops = heapless::Vec::new();
+    }
+
+    /// Record the operation. Fail with `NoMemory` if the recorder is full.
+    fn record(&mut self, op: Op) -> Result<(), Error> {
+        self.ops.push(op).map_err(|_| Error::NoMemory)
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Recorder {
+        Recorder::new()
+    }
+}
+
+impl CommandSink for Recorder {
+    type Error = Error;
+
+    fn write_command(&mut self, opcode: u8) -> Result<(), Error> {
+        self.record(Op::Command(opcode))
+    }
+
+    fn write_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        for byte in data {
+            self.record(Op::Data(*byte)) ? ;
+        }
+        Ok(())
+    }
+
+    fn delay_ms(&mut self, ms: u8) -> Result<(), Error> {
+        self.record(Op::Delay(ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec::Vec;
+    use super::*;
+
+    /// Init script replayed by the bootloader
+    const INIT_SCRIPT: &str = include_str!("../../../libs/pinetime_boot/src/st7789_init.inc");
+
+    /// Max size of the encoded init script
+    const SCRIPT_SIZE: usize = 128;
+
+    /// Return the bytes in the C array initialiser `script`, skipping the comments
+    fn parse_script(script: &str) -> Vec<u8> {
+        script.lines()
+            .map(|line| line.split("//").next().unwrap())
+            .flat_map(|line| line.split(','))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(|token| u8::from_str_radix(token.trim_start_matches("0x"), 16).unwrap())
+            .collect()
+    }
+
+    /// Return the operations for the `opcode` with `params`, followed by the delay if any
+    fn command_ops(opcode: u8, params: &[u8], delay: u8) -> Vec<Op> {
+        let mut ops = std::vec![ Op::Command(opcode) ];
+        ops.extend(params.iter().map(|param| Op::Data(*param)));
+        if delay > 0 { ops.push(Op::Delay(delay)); }
+        ops
+    }
+
+    #[test]
+    fn init_sequence_matches_bootloader_script() {
+        let mut buf = [0u8; SCRIPT_SIZE];
+        let len = encode_script(&init_sequence(PINETIME_CONFIG), &mut buf).unwrap();
+        assert_eq!(&buf[..len], parse_script(INIT_SCRIPT).as_slice(),
+            "libs/pinetime_boot/src/st7789_init.inc is out of date, regenerate it with encode_script()");
+    }
+
+    #[test]
+    fn records_init_sequence() {
+        let mut recorder = Recorder::new();
+        init_display(&mut recorder, PINETIME_CONFIG).unwrap();
+        let ops = recorder.ops();
+        assert_eq!(&ops[..3], [ Op::Command(SWRESET), Op::Delay(RESET_DELAY_MS), Op::Command(SLPOUT) ]);
+        assert_eq!(&ops[ops.len() - 7..], command_ops(INVON, &[], 0).iter()
+            .chain(command_ops(MADCTL, &[ 0x00 ], 0).iter())
+            .chain(command_ops(COLMOD, &[ COLMOD_RGB565 ], 0).iter())
+            .chain(command_ops(DISPON, &[], RESET_DELAY_MS).iter())
+            .cloned().collect::<Vec<_>>().as_slice());
+
+        //  Only SWRESET, SLPOUT and DISPON are followed by a delay
+        let delays = ops.iter().filter(|op| **op == Op::Delay(RESET_DELAY_MS)).count();
+        assert_eq!(delays, 3);
+        assert_eq!(ops.iter().filter(|op| matches!(op, Op::Command(_))).count(), INIT_SEQUENCE_LEN);
+
+        //  Colour settings change the init sequence
+        let config = DisplayConfig { inverted: false, rgb: false, ..PINETIME_CONFIG };
+        recorder.clear();
+        init_display(&mut recorder, config).unwrap();
+        let ops = recorder.ops();
+        assert!(ops.contains(&Op::Command(INVOFF)) && !ops.contains(&Op::Command(INVON)));
+        assert!(ops.windows(2).any(|pair| pair == [ Op::Command(MADCTL), Op::Data(MADCTL_BGR) ]));
+    }
+
+    #[test]
+    fn replays_script_like_init_sequence() {
+        let mut buf = [0u8; SCRIPT_SIZE];
+        let len = encode_script(&init_sequence(PINETIME_CONFIG), &mut buf).unwrap();
+        let mut replayed = Recorder::new();
+        replay_script(&mut replayed, &buf[..len]).unwrap();
+        let mut sent = Recorder::new();
+        init_display(&mut sent, PINETIME_CONFIG).unwrap();
+        assert_eq!(replayed.ops(), sent.ops());
+
+        //  Truncated script fails
+        assert_eq!(replay_script(&mut Recorder::new(), &buf[..len - 1]), Err(Error::Invalid));
+        assert_eq!(replay_script(&mut Recorder::new(), &buf[..1]), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn encode_fails_when_full() {
+        let mut buf = [0u8; 8];
+        assert_eq!(encode_script(&init_sequence(PINETIME_CONFIG), &mut buf), Err(Error::NoMemory));
+        let mut recorder = Recorder::new();
+        for _ in 0..recorder.ops.capacity() { send(&mut recorder, Command::Nop).unwrap(); }
+        assert_eq!(send(&mut recorder, Command::Nop), Err(Error::NoMemory));
+    }
+
+    #[test]
+    fn encodes_window_and_rejects_empty_window() {
+        let mut recorder = Recorder::new();
+        set_window(&mut recorder, PINETIME_CONFIG, 1, 2, 0x123, 4).unwrap();
+        assert_eq!(recorder.ops(), command_ops(CASET, &[ 0x00, 0x01, 0x01, 0x23 ], 0).iter()
+            .chain(command_ops(RASET, &[ 0x00, 0x02, 0x00, 0x04 ], 0).iter())
+            .cloned().collect::<Vec<_>>().as_slice());
+        assert_eq!(set_window(&mut recorder, PINETIME_CONFIG, 2, 0, 1, 0), Err(Error::Invalid));
+        assert_eq!(set_window(&mut recorder, PINETIME_CONFIG, 0, 2, 0, 1), Err(Error::Invalid));
+    }
+
+    /// All rotations, with and without mirroring
+    fn all_configs() -> Vec<DisplayConfig> {
+        [ Rotation::Deg0, Rotation::Deg90, Rotation::Deg180, Rotation::Deg270 ].iter()
+            .flat_map(|rotation| [ false, true ].iter()
+                .map(move |mirror| DisplayConfig { rotation: *rotation, mirror: *mirror, ..PINETIME_CONFIG }))
+            .collect()
+    }
+
+    #[test]
+    fn madctl_for_rotation_mirror_and_colour_order() {
+        let madctl = |rotation, mirror, rgb| DisplayConfig { rotation, mirror, inverted: true, rgb }.madctl();
+        assert_eq!(madctl(Rotation::Deg0,   false, true), MADCTL_MX | MADCTL_MV);
+        assert_eq!(madctl(Rotation::Deg90,  false, true), MADCTL_MX | MADCTL_MY);
+        assert_eq!(madctl(Rotation::Deg180, false, true), MADCTL_MY | MADCTL_MV);
+        assert_eq!(madctl(Rotation::Deg270, false, true), 0);
+
+        //  Mirroring flips the columns of the picture, which are rows of the frame memory after row / column exchange
+        assert_eq!(madctl(Rotation::Deg0,   true, true), MADCTL_MX | MADCTL_MY | MADCTL_MV);
+        assert_eq!(madctl(Rotation::Deg270, true, true), MADCTL_MX);
+        assert_eq!(madctl(Rotation::Deg0,   false, false), MADCTL_MX | MADCTL_MV | MADCTL_BGR);
+
+        //  Every setting has a different MADCTL
+        let configs = all_configs();
+        for (i, a) in configs.iter().enumerate() {
+            for b in configs[i + 1..].iter() { assert_ne!(a.madctl(), b.madctl()); }
+        }
+    }
+
+    #[test]
+    fn window_offset_when_rows_reversed() {
+        let offset = MEMORY_ROWS - DISPLAY_HEIGHT;
+        for config in all_configs() {
+            let madctl = config.madctl();
+            let expected =
+                if madctl & MADCTL_MY == 0 { (0, 0) }
+                else if madctl & MADCTL_MV != 0 { (offset, 0) }
+                else { (0, offset) };
+            assert_eq!(config.window_offset(), expected, "{:?}", config);
+        }
+        assert_eq!(PINETIME_CONFIG.window_offset(), (0, 0));
+        let rotated = |rotation| DisplayConfig { rotation, ..PINETIME_CONFIG };
+        assert_eq!(rotated(Rotation::Deg90).window_offset(), (0, offset));
+        assert_eq!(rotated(Rotation::Deg180).window_offset(), (offset, 0));
+
+        //  Window is shifted by the offset
+        let mut recorder = Recorder::new();
+        set_window(&mut recorder, rotated(Rotation::Deg90), 0, 0, 239, 239).unwrap();
+        assert_eq!(&recorder.ops()[5..], [ Op::Command(RASET), Op::Data(0), Op::Data(80), Op::Data(1), Op::Data(63) ]);
+    }
+
+    #[test]
+    fn transforms_touch_for_rotation() {
+        let rotated = |rotation, mirror| DisplayConfig { rotation, mirror, ..PINETIME_CONFIG };
+        let last = DISPLAY_WIDTH - 1;
+
+        //  Touch panel is aligned with Deg0
+        assert_eq!(PINETIME_CONFIG.transform_touch(10, 20), (10, 20));
+        //  Picture rotated by 90 degrees clockwise: its top left corner is at the top right corner of the panel,
+        //  its bottom left corner is at the top left corner of the panel
+        assert_eq!(rotated(Rotation::Deg90, false).transform_touch(last, 0), (0, 0));
+        assert_eq!(rotated(Rotation::Deg90, false).transform_touch(0, 0), (0, last));
+        assert_eq!(rotated(Rotation::Deg90, false).transform_touch(10, 20), (20, last - 10));
+        assert_eq!(rotated(Rotation::Deg180, false).transform_touch(10, 20), (last - 10, last - 20));
+        assert_eq!(rotated(Rotation::Deg270, false).transform_touch(0, last), (0, 0));
+        assert_eq!(rotated(Rotation::Deg270, false).transform_touch(10, 20), (last - 20, 10));
+        assert_eq!(rotated(Rotation::Deg0, true).transform_touch(10, 20), (last - 10, 20));
+
+        //  Coordinates outside the panel are clamped
+        assert_eq!(PINETIME_CONFIG.transform_touch(300, 500), (last, DISPLAY_HEIGHT - 1));
+    }
+
+    #[test]
+    fn inverse_transform_reverses_transform() {
+        for config in all_configs() {
+            for &(x, y) in [ (0, 0), (239, 0), (0, 239), (239, 239), (10, 20), (120, 7) ].iter() {
+                let (picture_x, picture_y) = config.transform_touch(x, y);
+                assert_eq!(config.inverse_transform_touch(picture_x, picture_y), (x, y), "{:?}", config);
+            }
+        }
+        let rotated = DisplayConfig { rotation: Rotation::Deg90, ..PINETIME_CONFIG };
+        //  Top right corner of the picture rotated by 90 degrees clockwise is at the bottom right corner of the panel
+        assert_eq!(rotated.inverse_transform_touch(DISPLAY_WIDTH - 1, 0), (DISPLAY_WIDTH - 1, DISPLAY_HEIGHT - 1));
+    }
+}
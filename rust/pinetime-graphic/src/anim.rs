@@ -0,0 +1,222 @@
+//! Encode an animation as an animation container. The first frame covers the entire display, each following
+//! frame stores only the dirty rectangle that changed since the previous frame. The animation header and frame
+//! header are shared with the decoder on PineTime in `rust/pinetime-logo/src/anim.rs`, and must match
+//! `libs/pinetime_boot/src/display.c`.
+use crate::{
+    container::{ self, Codec },
+    convert::{ BYTES_PER_PIXEL, DISPLAY_HEIGHT, DISPLAY_WIDTH },
+};
+use pinetime_logo::anim::{ AnimHeader, FrameHeader, ANIM_HEADER_SIZE };
+
+/// Dirty rectangle of a frame: (left, top, right, bottom), inclusive
+type Rect = (usize, usize, usize, usize);
+
+/// Max delay of a frame in milliseconds
+const MAX_DELAY_MS: u32 = 0xffff;
+
+/// Encode the 240 x 240 RGB565 frames, each with the delay after the frame in milliseconds.
+/// Frames without any changed pixels are merged into the previous frame by adding up the delays.
+pub fn encode(frames: &[(Vec<u8>, u32)], codec: Codec) -> Vec<u8> {
+    assert!(!frames.is_empty(), "no frames");
+    let width  = DISPLAY_WIDTH  as usize;
+    let height = DISPLAY_HEIGHT as usize;
+
+    //  Compute the dirty rectangle of each frame and merge the unchanged frames
+    let mut dirty_frames: Vec<(usize, Option<Rect>, u32)> = Vec::new();
+    for (i, (pixels, delay_ms)) in frames.iter().enumerate() {
+        assert_eq!(pixels.len(), width * height * BYTES_PER_PIXEL, "bad frame size");
+        let rect =
+            if i == 0 { Some((0, 0, width - 1, height - 1)) }
+            else { dirty_rect(&frames[i - 1].0, pixels, width, height) };
+        match (rect, dirty_frames.last_mut()) {
+            (None, Some(last)) if last.2 + delay_ms <= MAX_DELAY_MS => last.2 += delay_ms,
+            _ => dirty_frames.push((i, rect, (*delay_ms).min(MAX_DELAY_MS))),
+        }
+    }
+
+    //  Encode each frame: Frame Header followed by the pixels in the dirty rectangle
+    let mut payload = Vec::new();
+    for (i, rect, delay_ms) in &dirty_frames {
+        let (left, top, right, bottom) = rect.unwrap_or((0, 0, 0, 0));
+        let data = match rect {
+            Some(_) => {
+                let pixels = crop(&frames[*i].0, width, (left, top, right, bottom));
+                match codec {
+                    Codec::Raw => pixels,
+                    Codec::Rle => container::encode_rle(&pixels),
+                }
+            }
+            None => Vec::new(),  //  No pixels changed
+        };
+        let frame = FrameHeader {
+            delay_ms: *delay_ms as u16,
+            left:     left as u8,
+            top:      top as u8,
+            right:    right as u8,
+            bottom:   bottom as u8,
+            codec,
+            data_len: data.len() as u32,
+        };
+        payload.extend_from_slice(&frame.to_bytes());
+        payload.extend_from_slice(&data);
+    }
+
+    //  Animation Header followed by the frames
+    let header = AnimHeader::new(width as u16, height as u16, dirty_frames.len() as u16, &payload);
+    let mut anim = Vec::with_capacity(ANIM_HEADER_SIZE + payload.len());
+    anim.extend_from_slice(&header.to_bytes());
+    anim.extend_from_slice(&payload);
+    anim
+}
+
+/// Return the bounding box (left, top, right, bottom) of the pixels that differ between the frames,
+/// or `None` if the frames are identical
+fn dirty_rect(previous: &[u8], current: &[u8], width: usize, height: usize) -> Option<Rect> {
+    let mut rect: Option<Rect> = None;
+    for y in 0..height {
+        for x in 0..width {
+            let i = (y * width + x) * BYTES_PER_PIXEL;
+            if previous[i..i + BYTES_PER_PIXEL] == current[i..i + BYTES_PER_PIXEL] { continue; }
+            rect = Some(match rect {
+                None => (x, y, x, y),
+                Some((left, top, right, bottom)) => (left.min(x), top.min(y), right.max(x), bottom.max(y)),
+            });
+        }
+    }
+    rect
+}
+
+/// Return the pixels inside the rectangle (left, top, right, bottom), row by row
+fn crop(pixels: &[u8], width: usize, rect: Rect) -> Vec<u8> {
+    let (left, top, right, bottom) = rect;
+    let mut cropped = Vec::with_capacity((right - left + 1) * (bottom - top + 1) * BYTES_PER_PIXEL);
+    for y in top..=bottom {
+        let start = (y * width + left) * BYTES_PER_PIXEL;
+        let end   = (y * width + right + 1) * BYTES_PER_PIXEL;
+        cropped.extend_from_slice(&pixels[start..end]);
+    }
+    cropped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pinetime_logo::{
+        anim::{ read_frame, render_animation, verify_animation },
+        render::{ Buffers, LogoDisplay },
+        Error,
+    };
+
+    /// Width of the display in pixels
+    const WIDTH: usize = DISPLAY_WIDTH as usize;
+
+    /// Display that records the pixels in a frame buffer, like the ST7789 display on PineTime
+    struct FrameBuffer(Vec<u8>);
+
+    impl LogoDisplay for FrameBuffer {
+        type Error = Error;
+
+        fn write_row(&mut self, left: u8, y: u8, _right: u8, pixels: &[u8]) -> Result<(), Error> {
+            let start = (y as usize * WIDTH + left as usize) * BYTES_PER_PIXEL;
+            self.0[start..start + pixels.len()].copy_from_slice(pixels);
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<(), Error> { Ok(()) }
+    }
+
+    /// Return a 240 x 240 frame filled with the RGB565 `color`, with the rectangle (left, top, right, bottom)
+    /// filled with the colour `rect_color`
+    fn test_frame(color: u16, rect: Rect, rect_color: u16) -> Vec<u8> {
+        let (left, top, right, bottom) = rect;
+        let mut pixels = Vec::new();
+        for y in 0..WIDTH {
+            for x in 0..WIDTH {
+                let inside = x >= left && x <= right && y >= top && y <= bottom;
+                pixels.extend_from_slice(&if inside { rect_color } else { color }.to_be_bytes());
+            }
+        }
+        pixels
+    }
+
+    /// Return a closure that reads from the animation container
+    fn reader(anim: &[u8]) -> impl FnMut(u32, &mut [u8]) -> Result<(), Error> + '_ {
+        move |offset, buf| {
+            let start = offset as usize;
+            buf.copy_from_slice(anim.get(start..start + buf.len()).ok_or(Error::OutOfRange)?);
+            Ok(())
+        }
+    }
+
+    /// Return the frame headers of the animation container
+    fn frame_headers(anim: &[u8]) -> Vec<FrameHeader> {
+        let header = verify_animation(0, &mut reader(anim), &mut [0; 256]).unwrap();
+        let mut frames = Vec::new();
+        let mut frame_offset = 0;
+        for _ in 0..header.frame_count {
+            let frame: FrameHeader = read_frame(0, frame_offset, &header, &mut reader(anim)).unwrap();
+            frame_offset += pinetime_logo::anim::FRAME_HEADER_SIZE + frame.data_len as usize;
+            frames.push(frame);
+        }
+        frames
+    }
+
+    #[test]
+    fn encodes_dirty_rectangles() {
+        let first  = test_frame(0x0000, (0, 0, 0, 0), 0x0000);
+        let second = test_frame(0x0000, (10, 20, 30, 25), 0xf800);
+        let frames = vec![ (first.clone(), 100), (first, 50), (second, 30) ];
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            let anim = encode(&frames, codec);
+            let headers = frame_headers(&anim);
+
+            //  Unchanged frame is merged into the first frame, second frame covers only the changed pixels
+            assert_eq!(headers.len(), 2);
+            assert_eq!((headers[0].left, headers[0].top, headers[0].right, headers[0].bottom), (0, 0, 239, 239));
+            assert_eq!((headers[1].left, headers[1].top, headers[1].right, headers[1].bottom), (10, 20, 30, 25));
+            assert_eq!((headers[0].delay_ms, headers[1].delay_ms), (150, 30));
+            assert!(headers.iter().all(|frame| frame.codec == codec));
+        }
+    }
+
+    #[test]
+    fn decoded_animation_matches_last_frame() {
+        let frames = vec![
+            (test_frame(0x001f, (0, 0, 0, 0), 0x001f), 100),
+            (test_frame(0x001f, (100, 100, 139, 139), 0x07e0), 100),
+            (test_frame(0x001f, (0, 200, 239, 239), 0xffff), 100),
+        ];
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            let anim = encode(&frames, codec);
+            let header = verify_animation(0, &mut reader(&anim), &mut [0; 256]).unwrap();
+            let mut display = FrameBuffer(vec![0; frames[0].0.len()]);
+            let mut delays = Vec::new();
+            render_animation(
+                &mut display, 0, &header, &mut reader(&anim), &mut |ms| delays.push(ms), &mut Buffers::new()
+            ).unwrap();
+            assert_eq!(delays, vec![ 100, 100, 100 ]);
+            assert!(display.0 == frames[2].0, "{:?}: decoded animation differs from the last frame", codec);
+        }
+    }
+
+    #[test]
+    fn splits_long_delays() {
+        let frame = test_frame(0xf800, (0, 0, 0, 0), 0xf800);
+        let frames = vec![ (frame.clone(), 60_000), (frame, 10_000) ];
+        let headers = frame_headers(&encode(&frames, Codec::Rle));
+
+        //  Delays don't fit in one frame, so the unchanged frame is kept without any pixels
+        assert_eq!(headers.len(), 2);
+        assert_eq!((headers[0].delay_ms, headers[1].delay_ms), (60_000, 10_000));
+        assert_eq!(headers[1].data_len, 0);
+    }
+
+    #[test]
+    fn finds_dirty_rectangle() {
+        let previous = test_frame(0, (0, 0, 0, 0), 0);
+        assert_eq!(dirty_rect(&previous, &previous, WIDTH, WIDTH), None);
+        let current = test_frame(0, (5, 6, 7, 8), 0xffff);
+        assert_eq!(dirty_rect(&previous, &current, WIDTH, WIDTH), Some((5, 6, 7, 8)));
+        assert_eq!(crop(&current, WIDTH, (5, 6, 7, 8)), vec![ 0xff; 3 * 3 * BYTES_PER_PIXEL ]);
+    }
+}
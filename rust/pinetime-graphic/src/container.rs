@@ -0,0 +1,38 @@
+//! Encode the boot graphic as a boot logo container. The header and the RLE codec are shared with the decoder
+//! on PineTime in `rust/pinetime-logo`.
+pub use pinetime_logo::Codec;
+use pinetime_logo::{
+    header::HEADER_SIZE,
+    rle,
+    LogoHeader,
+};
+
+/// Parse the codec name given on the command line
+pub fn codec_from_name(name: &str) -> Option<Codec> {
+    match name {
+        "raw" => Some(Codec::Raw),
+        "rle" => Some(Codec::Rle),
+        _     => None,
+    }
+}
+
+/// Wrap the RGB565 pixels (`width` x `height`, big-endian) in a container: header followed by the encoded payload
+pub fn encode(pixels: &[u8], width: u16, height: u16, codec: Codec) -> Vec<u8> {
+    assert_eq!(pixels.len(), width as usize * height as usize * 2, "bad pixel count");
+    let payload = match codec {
+        Codec::Raw => pixels.to_vec(),
+        Codec::Rle => encode_rle(pixels),
+    };
+    let mut container = Vec::with_capacity(HEADER_SIZE + payload.len());
+    container.extend_from_slice(&LogoHeader::new(width, height, codec, &payload).to_bytes());
+    container.extend_from_slice(&payload);
+    container
+}
+
+/// Run-length encode the RGB565 pixels. Runs of 2 or more identical pixels become Run Packets
+/// (`0x80 | (n - 1)` + pixel), everything else is packed into Literal Packets (`n - 1` + pixels).
+pub fn encode_rle(pixels: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    rle::encode_rle(pixels, |bytes| encoded.extend_from_slice(bytes));
+    encoded
+}
@@ -0,0 +1,118 @@
+//! Convert an image file to the PineTime boot graphic layout: 240 x 240 pixels, RGB565, big-endian
+use image::{
+    gif::GifDecoder,
+    imageops::{ self, FilterType },
+    AnimationDecoder, Rgba, RgbaImage,
+};
+use std::{
+    fs::File,
+    io::BufReader,
+    path::Path,
+};
+
+/// Width of the PineTime display, in pixels
+pub const DISPLAY_WIDTH: u32 = 240;
+
+/// Height of the PineTime display, in pixels
+pub const DISPLAY_HEIGHT: u32 = 240;
+
+/// Number of bytes per RGB565 pixel
+pub const BYTES_PER_PIXEL: usize = 2;
+
+/// Size of the converted boot graphic: 240 * 240 * 2 = 115,200 bytes
+pub const GRAPHIC_SIZE: usize = DISPLAY_WIDTH as usize * DISPLAY_HEIGHT as usize * BYTES_PER_PIXEL;
+
+/// Load the image file (PNG, BMP or GIF) and convert to the boot graphic layout.
+/// The image is scaled to fit the display (preserving the aspect ratio) and centred on the background colour.
+pub fn convert_file(path: &Path, background: [u8; 3]) -> image::ImageResult<Vec<u8>> {
+    let image = image::open(path)?
+        .to_rgba8();
+    Ok(convert_image(&image, background))
+}
+
+/// Delay for GIF frames without a delay, in milliseconds. Same as most web browsers.
+const DEFAULT_DELAY_MS: u32 = 100;
+
+/// Load the animated GIF file and convert each frame to the boot graphic layout.
+/// Returns the converted frames with the delay after each frame in milliseconds.
+pub fn convert_animation(path: &Path, background: [u8; 3]) -> image::ImageResult<Vec<(Vec<u8>, u32)>> {
+    let file = BufReader::new(File::open(path)?);
+    let frames = GifDecoder::new(file)?
+        .into_frames()
+        .collect_frames()?;
+    //  Each decoded frame is the complete canvas, with the previous frames already composed
+    let converted = frames.iter()
+        .map(|frame| {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay_ms = if numer == 0 { DEFAULT_DELAY_MS } else { numer / denom.max(1) };
+            (convert_image(frame.buffer(), background), delay_ms)
+        })
+        .collect();
+    Ok(converted)
+}
+
+/// Convert the decoded image to the boot graphic layout
+pub fn convert_image(image: &RgbaImage, background: [u8; 3]) -> Vec<u8> {
+    let canvas = letterbox(image, background);
+    let mut graphic = Vec::with_capacity(GRAPHIC_SIZE);
+    //  Pixels are stored row by row, starting from the top left
+    for pixel in canvas.pixels() {
+        let color = to_rgb565(pixel.0[0], pixel.0[1], pixel.0[2]);
+        //  ST7789 expects the high byte first
+        graphic.extend_from_slice(&color.to_be_bytes());
+    }
+    assert_eq!(graphic.len(), GRAPHIC_SIZE, "bad graphic size");
+    graphic
+}
+
+/// Scale the image to fit 240 x 240 without cropping and draw it in the centre of the background.
+/// Transparent pixels are blended with the background.
+pub fn letterbox(image: &RgbaImage, background: [u8; 3]) -> RgbaImage {
+    let (width, height) = image.dimensions();
+    assert!(width > 0 && height > 0, "empty image");
+
+    //  Compute the scaled size, keeping the aspect ratio
+    let (scaled_width, scaled_height) =
+        if width * DISPLAY_HEIGHT >= height * DISPLAY_WIDTH {
+            //  Wide image: Fit to display width, add bars at top and bottom
+            (DISPLAY_WIDTH, (height * DISPLAY_WIDTH / width).max(1))
+        } else {
+            //  Tall image: Fit to display height, add bars at left and right
+            ((width * DISPLAY_HEIGHT / height).max(1), DISPLAY_HEIGHT)
+        };
+
+    //  Resize only if necessary, so that 240 x 240 images are converted exactly
+    let scaled =
+        if (scaled_width, scaled_height) == (width, height) { image.clone() }
+        else { imageops::resize(image, scaled_width, scaled_height, FilterType::Lanczos3) };
+
+    //  Blend the scaled image onto the background
+    let [bg_red, bg_green, bg_blue] = background;
+    let mut canvas = RgbaImage::from_pixel(DISPLAY_WIDTH, DISPLAY_HEIGHT,
+        Rgba([ bg_red, bg_green, bg_blue, 0xff ]));
+    let left = (DISPLAY_WIDTH  - scaled_width)  / 2;
+    let top  = (DISPLAY_HEIGHT - scaled_height) / 2;
+    for (x, y, pixel) in scaled.enumerate_pixels() {
+        let [red, green, blue, alpha] = pixel.0;
+        canvas.put_pixel(left + x, top + y, Rgba([
+            blend(red,   bg_red,   alpha),
+            blend(green, bg_green, alpha),
+            blend(blue,  bg_blue,  alpha),
+            0xff,
+        ]));
+    }
+    canvas
+}
+
+/// Convert 24-bit colour to 16-bit RGB565 colour. Same as `Rgb565::from((r, g, b))` in `embedded-graphics`.
+pub fn to_rgb565(red: u8, green: u8, blue: u8) -> u16 {
+    ((red as u16 & 0xf8) << 8) |
+    ((green as u16 & 0xfc) << 3) |
+    (blue as u16 >> 3)
+}
+
+/// Blend the foreground colour component with the background according to the alpha value
+fn blend(foreground: u8, background: u8, alpha: u8) -> u8 {
+    let alpha = alpha as u32;
+    ((foreground as u32 * alpha + background as u32 * (255 - alpha) + 127) / 255) as u8
+}
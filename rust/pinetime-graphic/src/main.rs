@@ -0,0 +1,180 @@
+//! Convert an image file to the PineTime boot graphic. Replaces the hand-made `write_graphic.inc`.
+//! ```bash
+//! cargo run -p pinetime-graphic --target x86_64-unknown-linux-gnu -- logos/pinetime-green.png --format inc
+//! ```
+mod anim;       //  Declare `anim.rs` as Rust module `anim` for encoding the animation container
+mod container;  //  Declare `container.rs` as Rust module `container` for encoding the boot logo container
+mod convert;    //  Declare `convert.rs` as Rust module `convert` for converting images to RGB565
+mod output;     //  Declare `output.rs` as Rust module `output` for writing the converted graphic
+
+use container::Codec;
+use output::Format;
+use std::{
+    env,
+    path::PathBuf,
+    process,
+};
+
+/// Usage message shown when the command line is invalid
+const USAGE: &str = "usage: pinetime-graphic INPUT [--format inc|bin|rs] [--codec raw|rle] [--output PATH] [--background RRGGBB] [--animate]";
+
+/// Size of a boot logo slot in SPI Flash. Must match `SLOT_SIZE` in `rust/pinetime-logo/src/slots.rs`
+const SLOT_SIZE: usize = 0x1D000;
+
+/// Command line options
+struct Options {
+    /// PNG, BMP or GIF file to be converted
+    input:      PathBuf,
+    /// Output format
+    format:     Format,
+    /// If specified, wrap the pixels in a boot logo container with this codec
+    codec:      Option<Codec>,
+    /// Output file
+    output:     PathBuf,
+    /// Colour of letterbox bars and transparent pixels
+    background: [u8; 3],
+    /// If true, convert all frames of the GIF file to an animation container
+    animate:    bool,
+}
+
+fn main() {
+    let options = parse_args(env::args().skip(1))
+        .unwrap_or_else(|err| {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(1);
+        });
+    let graphic =
+        if options.animate { convert_animation(&options) }
+        else { convert_logo(&options) };
+    if (options.codec.is_some() || options.animate) && graphic.len() > SLOT_SIZE {
+        eprintln!("warning: {} bytes won't fit in a boot logo slot ({} bytes)", graphic.len(), SLOT_SIZE);
+    }
+    output::write_graphic(&graphic, options.format, &options.output)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", options.output.display(), err);
+            process::exit(1);
+        });
+    println!("{} -> {} ({} bytes)", options.input.display(), options.output.display(), graphic.len());
+}
+
+/// Convert the input file to the boot graphic, wrapped in a boot logo container if requested
+fn convert_logo(options: &Options) -> Vec<u8> {
+    let graphic = convert::convert_file(&options.input, options.background)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", options.input.display(), err);
+            process::exit(1);
+        });
+    match options.codec {
+        Some(codec) => container::encode(&graphic,
+            convert::DISPLAY_WIDTH as u16, convert::DISPLAY_HEIGHT as u16, codec),
+        None => graphic,
+    }
+}
+
+/// Convert all frames of the input GIF file to an animation container
+fn convert_animation(options: &Options) -> Vec<u8> {
+    let frames = convert::convert_animation(&options.input, options.background)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", options.input.display(), err);
+            process::exit(1);
+        });
+    let codec = options.codec.unwrap_or(Codec::Rle);  //  Raw frames rarely fit in a slot
+    anim::encode(&frames, codec)
+}
+
+/// Parse the command line arguments
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut input = None;
+    let mut format = Format::Inc;
+    let mut codec = None;
+    let mut output = None;
+    let mut background = [0, 0, 0];
+    let mut animate = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let name = args.next().ok_or("missing format")?;
+                format = Format::from_name(&name)
+                    .ok_or_else(|| format!("unknown format: {}", name))?;
+            }
+            "--codec" => {
+                let name = args.next().ok_or("missing codec")?;
+                codec = Some(container::codec_from_name(&name)
+                    .ok_or_else(|| format!("unknown codec: {}", name))?);
+            }
+            "--output" => {
+                output = Some(PathBuf::from(args.next().ok_or("missing output")?));
+            }
+            "--background" => {
+                let color = args.next().ok_or("missing background")?;
+                background = parse_color(&color)
+                    .ok_or_else(|| format!("bad background: {}", color))?;
+            }
+            "--animate" => animate = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ if input.is_none() => input = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+    let input = input.ok_or("missing input")?;
+    //  Derive the output file name from the input file name
+    let output = output.unwrap_or_else(|| input.with_extension(format.extension()));
+    Ok(Options { input, format, codec, output, background, animate })
+}
+
+/// Parse a colour like `ff8000` into `[0xff, 0x80, 0x00]`
+fn parse_color(color: &str) -> Option<[u8; 3]> {
+    let color = color.trim_start_matches('#');
+    if color.len() != 6 { return None; }
+    let value = u32::from_str_radix(color, 16).ok()?;
+    Some([ (value >> 16) as u8, (value >> 8) as u8, value as u8 ])
+}
+
+/// Golden file tests: convert each logo in `logos` to an RLE boot logo container and compare with the expected
+/// container in `golden`. To update the expected containers after changing the converter, run
+/// `UPDATE_GOLDEN=1 cargo test -p pinetime-graphic --target x86_64-unknown-linux-gnu`
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{ fs, path::Path };
+
+    /// Logos converted by the golden file tests
+    const LOGOS: &[&str] = &[
+        "its-booting-pinecone", "its-booting", "pallette-fun", "pine64-rainbow",
+        "pinecone-blue", "pinecone", "pinefruit-blue", "pinefruit",
+        "pinetime-green", "test-pattern-stand-by", "test-pattern", "xp",
+    ];
+
+    #[test]
+    fn logos_match_golden_files() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let update = env::var_os("UPDATE_GOLDEN").is_some();
+        for name in LOGOS {
+            let input = manifest.join("../../logos").join(format!("{}.png", name));
+            let golden = manifest.join("golden").join(format!("{}.bin", name));
+            let graphic = convert::convert_file(&input, [0, 0, 0])
+                .unwrap_or_else(|err| panic!("{}: {}", input.display(), err));
+            let container = container::encode(&graphic,
+                convert::DISPLAY_WIDTH as u16, convert::DISPLAY_HEIGHT as u16, Codec::Rle);
+            if update { fs::write(&golden, &container).unwrap(); continue; }
+            let expected = fs::read(&golden)
+                .unwrap_or_else(|err| panic!("{}: {}", golden.display(), err));
+            assert!(container == expected, "{}: converted logo differs from {}", name, golden.display());
+            assert!(container.len() <= SLOT_SIZE, "{}: doesn't fit in a boot logo slot", name);
+        }
+    }
+
+    #[test]
+    fn test_pattern_is_not_resized() {
+        //  240 x 240 logos are converted pixel for pixel, so the raw container holds the exact pixels
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let input = manifest.join("../../logos/test-pattern.png");
+        let graphic = convert::convert_file(&input, [0, 0, 0]).unwrap();
+        let image = image::open(&input).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (convert::DISPLAY_WIDTH, convert::DISPLAY_HEIGHT));
+        for (index, pixel) in image.pixels().enumerate() {
+            let color = convert::to_rgb565(pixel.0[0], pixel.0[1], pixel.0[2]);
+            assert_eq!(&graphic[index * 2..index * 2 + 2], &color.to_be_bytes(), "pixel {}", index);
+        }
+    }
+}
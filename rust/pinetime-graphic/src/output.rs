@@ -0,0 +1,85 @@
+//! Write the converted boot graphic as a C include file, a raw binary file or a Rust source file
+use std::{
+    fs,
+    io::{ self, Write },
+    path::Path,
+};
+
+/// Output formats supported by the converter
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Format {
+    /// C array for `#include`, like `apps/my_sensor_app/src/write_graphic.inc`
+    Inc,
+    /// Raw binary file, like `scripts/write-graphic/boot-graphic.bin`
+    Bin,
+    /// Raw binary file plus a Rust source file that loads it with `include_bytes!`
+    Rs,
+}
+
+impl Format {
+    /// Parse the format name given on the command line
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "inc" => Some(Format::Inc),
+            "bin" => Some(Format::Bin),
+            "rs"  => Some(Format::Rs),
+            _     => None,
+        }
+    }
+
+    /// Default file extension for the format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Inc => "inc",
+            Format::Bin => "bin",
+            Format::Rs  => "rs",
+        }
+    }
+}
+
+/// Header line for generated files. Same as the header of the existing `.inc` files.
+const HEADER: &str = "//  Converted from PNG file by https://github.com/lupyuen/pinetime-graphic";
+
+/// Number of bytes per line in the `.inc` file
+const BYTES_PER_LINE: usize = 16;
+
+/// Write the boot graphic to `path` in the specified format
+pub fn write_graphic(graphic: &[u8], format: Format, path: &Path) -> io::Result<()> {
+    match format {
+        Format::Inc => fs::write(path, to_inc(graphic)),
+        Format::Bin => fs::write(path, graphic),
+        Format::Rs  => {
+            //  Write the raw pixels next to the Rust source file and include them
+            let bin_path = path.with_extension("bin");
+            fs::write(&bin_path, graphic)?;
+            let bin_name = bin_path.file_name()
+                .expect("no file name")
+                .to_string_lossy()
+                .into_owned();
+            fs::write(path, to_rs(graphic.len(), &bin_name))
+        }
+    }
+}
+
+/// Format the boot graphic as a C array body, 16 bytes per line
+pub fn to_inc(graphic: &[u8]) -> Vec<u8> {
+    let mut inc = Vec::with_capacity(graphic.len() * 6 + HEADER.len() + 1);
+    writeln!(inc, "{}", HEADER).unwrap();
+    for line in graphic.chunks(BYTES_PER_LINE) {
+        let bytes: Vec<String> = line.iter()
+            .map(|b| format!("0x{:02x},", b))
+            .collect();
+        writeln!(inc, "{}", bytes.join(" ")).unwrap();
+    }
+    inc
+}
+
+/// Format a Rust source file that includes the raw boot graphic `bin_name` with `include_bytes!`
+pub fn to_rs(len: usize, bin_name: &str) -> Vec<u8> {
+    format!(
+        "{header}\n\
+        /// Boot graphic: 240 x 240 pixels, RGB565, big-endian\n\
+        pub static BOOT_GRAPHIC: &[u8; {len}] = include_bytes!(\"{bin_name}\");\n",
+        header = HEADER, len = len, bin_name = bin_name
+    ).into_bytes()
+}
@@ -0,0 +1,433 @@
+//! Animated boot logo. The animation container holds a sequence of frames. The first frame covers the entire
+//! animation, each following frame contains only the dirty rectangle that changed since the previous frame.
+//! Must match the container written by `rust/pinetime-graphic` and the bootloader in `libs/pinetime_boot`.
+//! The animation is played on PineTime by `mynewt::logo::anim`, which renders the frames with `render_animation()`.
+//! All fields are little-endian, pixels are RGB565 big-endian:
+//! ```text
+//! Animation Header:
+//! Offset  Size  Field
+//!      0     4  Magic: "PTAN"
+//!      4     2  Version: 1
+//!      6     2  Width in pixels
+//!      8     2  Height in pixels
+//!     10     2  Number of frames
+//!     12     4  Payload Length in bytes: all frames
+//!     16     4  CRC32 of the payload
+//!
+//! Frame Header, followed by the frame data:
+//! Offset  Size  Field
+//!      0     2  Delay in milliseconds after displaying the frame
+//!      2     1  Left column of the dirty rectangle, relative to the animation
+//!      3     1  Top row of the dirty rectangle
+//!      4     1  Right column of the dirty rectangle (inclusive)
+//!      5     1  Bottom row of the dirty rectangle (inclusive)
+//!      6     1  Codec: 0 for Raw, 1 for RLE
+//!      7     1  Reserved: 0
+//!      8     4  Data Length in bytes. 0 if no pixels changed.
+//! ```
+use crate::{
+    crc32,
+    header::Codec,
+    render::{ crc_payload, render_pixels, Buffers, LogoDisplay },
+    Error, BYTES_PER_PIXEL, MAX_HEIGHT, MAX_WIDTH,
+};
+
+/// Magic number at the start of the animation container: "PTAN"
+pub const ANIM_MAGIC: [u8; 4] = *b"PTAN";
+
+/// Current version of the animation container
+pub const ANIM_VERSION: u16 = 1;
+
+/// Size of the animation header in bytes. The frames follow the header.
+pub const ANIM_HEADER_SIZE: usize = 20;
+
+/// Size of the frame header in bytes. The frame data follows the frame header.
+pub const FRAME_HEADER_SIZE: usize = 12;
+
+/// Header of the animation container
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimHeader {
+    /// Container version
+    pub version:     u16,
+    /// Width in pixels
+    pub width:       u16,
+    /// Height in pixels
+    pub height:      u16,
+    /// Number of frames
+    pub frame_count: u16,
+    /// Size of all frames in bytes
+    pub payload_len: u32,
+    /// CRC32 of all frames
+    pub crc32:       u32,
+}
+
+impl AnimHeader {
+    /// Create the header for the animation frames in `payload`. The payload length and CRC32 are computed from `payload`.
+    pub fn new(width: u16, height: u16, frame_count: u16, payload: &[u8]) -> AnimHeader {
+        AnimHeader {
+            version:     ANIM_VERSION,
+            width, height, frame_count,
+            payload_len: payload.len() as u32,
+            crc32:       crc32(0, payload),
+        }
+    }
+
+    /// Parse the header bytes. Fail with `NotFound` if the magic number is missing, `Unsupported` if the
+    /// version is unknown, `Invalid` if the dimensions or number of frames are invalid.
+    pub fn parse(bytes: &[u8]) -> Result<AnimHeader, Error> {
+        if bytes.len() < ANIM_HEADER_SIZE {
+            return Err(Error::Invalid);
+        }
+        if bytes[0..4] != ANIM_MAGIC {
+            return Err(Error::NotFound);
+        }
+        let version = u16::from_le_bytes([ bytes[4], bytes[5] ]);
+        if version != ANIM_VERSION {
+            return Err(Error::Unsupported);
+        }
+        let width       = u16::from_le_bytes([ bytes[6], bytes[7] ]);
+        let height      = u16::from_le_bytes([ bytes[8], bytes[9] ]);
+        let frame_count = u16::from_le_bytes([ bytes[10], bytes[11] ]);
+        if width == 0 || height == 0 || frame_count == 0 ||
+            width as usize > MAX_WIDTH || height as usize > MAX_HEIGHT {
+            return Err(Error::Invalid);
+        }
+        Ok(AnimHeader {
+            version, width, height, frame_count,
+            payload_len: u32::from_le_bytes([ bytes[12], bytes[13], bytes[14], bytes[15] ]),
+            crc32:       u32::from_le_bytes([ bytes[16], bytes[17], bytes[18], bytes[19] ]),
+        })
+    }
+
+    /// Return the header as bytes, ready to be written to flash
+    pub fn to_bytes(&self) -> [u8; ANIM_HEADER_SIZE] {
+        let mut bytes = [0u8; ANIM_HEADER_SIZE];
+        bytes[0..4].copy_from_slice(&ANIM_MAGIC);
+        bytes[4..6].copy_from_slice(&self.version.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.width.to_le_bytes());
+        bytes[8..10].copy_from_slice(&self.height.to_le_bytes());
+        bytes[10..12].copy_from_slice(&self.frame_count.to_le_bytes());
+        bytes[12..16].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[16..20].copy_from_slice(&self.crc32.to_le_bytes());
+        bytes
+    }
+}
+
+/// Header of an animation frame
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameHeader {
+    /// Delay in milliseconds after displaying the frame
+    pub delay_ms: u16,
+    /// Left column of the dirty rectangle, relative to the animation
+    pub left:     u8,
+    /// Top row of the dirty rectangle
+    pub top:      u8,
+    /// Right column of the dirty rectangle (inclusive)
+    pub right:    u8,
+    /// Bottom row of the dirty rectangle (inclusive)
+    pub bottom:   u8,
+    /// Compression method for the frame data
+    pub codec:    Codec,
+    /// Size of the frame data in bytes. 0 if no pixels changed.
+    pub data_len: u32,
+}
+
+impl FrameHeader {
+    /// Parse the frame header bytes. Fail with `Unsupported` if the codec is unknown,
+    /// `Invalid` if the dirty rectangle is invalid.
+    pub fn parse(bytes: &[u8]) -> Result<FrameHeader, Error> {
+        if bytes.len() < FRAME_HEADER_SIZE {
+            return Err(Error::Invalid);
+        }
+        let codec = match bytes[6] {
+            0 => Codec::Raw,
+            1 => Codec::Rle,
+            _ => return Err(Error::Unsupported),
+        };
+        let frame = FrameHeader {
+            delay_ms: u16::from_le_bytes([ bytes[0], bytes[1] ]),
+            left:     bytes[2],
+            top:      bytes[3],
+            right:    bytes[4],
+            bottom:   bytes[5],
+            codec,
+            data_len: u32::from_le_bytes([ bytes[8], bytes[9], bytes[10], bytes[11] ]),
+        };
+        if frame.left > frame.right || frame.top > frame.bottom {
+            return Err(Error::Invalid);
+        }
+        Ok(frame)
+    }
+
+    /// Return the frame header as bytes, ready to be written to flash
+    pub fn to_bytes(&self) -> [u8; FRAME_HEADER_SIZE] {
+        let mut bytes = [0u8; FRAME_HEADER_SIZE];
+        bytes[0..2].copy_from_slice(&self.delay_ms.to_le_bytes());
+        bytes[2] = self.left;
+        bytes[3] = self.top;
+        bytes[4] = self.right;
+        bytes[5] = self.bottom;
+        bytes[6] = self.codec as u8;
+        bytes[8..12].copy_from_slice(&self.data_len.to_le_bytes());
+        bytes
+    }
+
+    /// Return the width and height of the dirty rectangle
+    pub fn size(&self) -> (usize, usize) {
+        (
+            (self.right  - self.left) as usize + 1,
+            (self.bottom - self.top)  as usize + 1,
+        )
+    }
+}
+
+/// Verify the animation container that begins at `offset` in flash and return the header. `buf` is used for
+/// reading the payload in batches. Every frame must fit inside the animation. Fail with `NotFound` if there is
+/// no animation, `Unsupported` if the container is unsupported, `Invalid` if the frames are inconsistent or
+/// the CRC32 doesn't match.
+pub fn verify_animation<R, E>(offset: u32, read: &mut R, buf: &mut [u8]) -> Result<AnimHeader, E>
+where
+    R: FnMut(u32, &mut [u8]) -> Result<(), E>,
+    E: From<Error>,
+{
+    //  Read and validate the header
+    let mut header_bytes = [0u8; ANIM_HEADER_SIZE];
+    read(offset, &mut header_bytes) ? ;
+    let header = AnimHeader::parse(&header_bytes) ? ;
+
+    //  Compute the CRC32 of the payload
+    let payload_len = header.payload_len as usize;
+    let crc = crc_payload(offset + ANIM_HEADER_SIZE as u32, payload_len, read, buf) ? ;
+    if crc != header.crc32 {
+        return Err(Error::Invalid.into());
+    }
+
+    //  Walk the frames and check that they fill the payload exactly
+    let mut frame_offset = 0;
+    for _ in 0..header.frame_count {
+        let frame = read_frame(offset, frame_offset, &header, read) ? ;
+        frame_offset += FRAME_HEADER_SIZE + frame.data_len as usize;
+    }
+    if frame_offset != payload_len {
+        return Err(Error::Invalid.into());
+    }
+    Ok(header)
+}
+
+/// Render the frames of the verified animation with the `header` that begins at `offset` in flash, centred on the
+/// display. The display is flushed after each frame that changed, then `delay` is called with the frame delay in
+/// milliseconds.
+pub fn render_animation<D, R, W>(display: &mut D, offset: u32, header: &AnimHeader, read: &mut R, delay: &mut W,
+    buffers: &mut Buffers) -> Result<(), D::Error>
+where
+    D: LogoDisplay,
+    R: FnMut(u32, &mut [u8]) -> Result<(), D::Error>,
+    W: FnMut(u32),
+{
+    //  Centre the animation on the display
+    let left = ((MAX_WIDTH  - header.width  as usize) / 2) as u8;
+    let top  = ((MAX_HEIGHT - header.height as usize) / 2) as u8;
+
+    let mut frame_offset = 0;
+    for _ in 0..header.frame_count {
+        //  Render the dirty rectangle of the frame, unless no pixels changed
+        let frame = read_frame(offset, frame_offset, header, read) ? ;
+        let data_offset = offset + (ANIM_HEADER_SIZE + frame_offset + FRAME_HEADER_SIZE) as u32;
+        if frame.data_len > 0 {
+            let (width, height) = frame.size();
+            render_pixels(
+                display, data_offset, frame.data_len as usize, frame.codec,
+                (left + frame.left, top + frame.top, width, height), read, buffers
+            ) ? ;
+            display.flush() ? ;
+        }
+        delay(frame.delay_ms as u32);
+        frame_offset += FRAME_HEADER_SIZE + frame.data_len as usize;
+    }
+    Ok(())
+}
+
+/// Read and validate the frame header at `frame_offset` in the payload of the animation at `offset`
+pub fn read_frame<R, E>(offset: u32, frame_offset: usize, header: &AnimHeader, read: &mut R) -> Result<FrameHeader, E>
+where
+    R: FnMut(u32, &mut [u8]) -> Result<(), E>,
+    E: From<Error>,
+{
+    if frame_offset + FRAME_HEADER_SIZE > header.payload_len as usize {
+        return Err(Error::Invalid.into());  //  Frame header outside payload
+    }
+    let mut frame_bytes = [0u8; FRAME_HEADER_SIZE];
+    read(offset + (ANIM_HEADER_SIZE + frame_offset) as u32, &mut frame_bytes) ? ;
+    let frame = FrameHeader::parse(&frame_bytes) ? ;
+
+    //  Dirty rectangle must fit inside the animation. Raw data must contain exactly all the pixels.
+    let (width, height) = frame.size();
+    if frame.right as u16 >= header.width || frame.bottom as u16 >= header.height {
+        return Err(Error::Invalid.into());
+    }
+    if frame.codec == Codec::Raw && frame.data_len > 0 &&
+        frame.data_len as usize != width * height * BYTES_PER_PIXEL {
+        return Err(Error::Invalid.into());
+    }
+    if frame_offset + FRAME_HEADER_SIZE + frame.data_len as usize > header.payload_len as usize {
+        return Err(Error::Invalid.into());  //  Frame data outside payload
+    }
+    Ok(frame)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::{ vec, vec::Vec };
+    use crate::{
+        rle::encode_rle,
+        testing::{ reader, TestDisplay },
+    };
+    use super::*;
+
+    /// Offset of the animation in the test flash
+    const OFFSET: u32 = 0x1000;
+
+    /// Return the header of a frame with the dirty rectangle (left, top, right, bottom)
+    fn frame(delay_ms: u16, rect: (u8, u8, u8, u8), codec: Codec, data: &[u8]) -> (FrameHeader, Vec<u8>) {
+        let (left, top, right, bottom) = rect;
+        let header = FrameHeader { delay_ms, left, top, right, bottom, codec, data_len: data.len() as u32 };
+        (header, data.to_vec())
+    }
+
+    /// Return `count` pixels of the RGB565 `color`, big-endian
+    fn pixels(color: u16, count: usize) -> Vec<u8> {
+        color.to_be_bytes().repeat(count)
+    }
+
+    /// Return the payload with the `frames`
+    fn payload(frames: &[(FrameHeader, Vec<u8>)]) -> Vec<u8> {
+        let mut payload = Vec::new();
+        for (header, data) in frames {
+            payload.extend_from_slice(&header.to_bytes());
+            payload.extend_from_slice(data);
+        }
+        payload
+    }
+
+    /// Return the flash contents with the animation `header` and `payload` at `OFFSET`
+    fn flash_with_animation(header: &AnimHeader, payload: &[u8]) -> Vec<u8> {
+        let mut flash = vec![0xff; OFFSET as usize];
+        flash.extend_from_slice(&header.to_bytes());
+        flash.extend_from_slice(payload);
+        flash.resize(flash.len() + 4096, 0xff);
+        flash
+    }
+
+    /// Return a 4 x 3 animation: red frame, then a green 2 x 2 square (RLE), then no change
+    fn test_frames() -> Vec<(FrameHeader, Vec<u8>)> {
+        let mut square = Vec::new();
+        encode_rle(&pixels(0x07e0, 4), |bytes| square.extend_from_slice(bytes));
+        vec![
+            frame(100, (0, 0, 3, 2), Codec::Raw, &pixels(0xf800, 12)),
+            frame(50,  (1, 1, 2, 2), Codec::Rle, &square),
+            frame(200, (0, 0, 0, 0), Codec::Raw, &[]),
+        ]
+    }
+
+    /// Verify the animation in `flash` at `OFFSET`
+    fn verify(flash: &[u8]) -> Result<AnimHeader, Error> {
+        verify_animation(OFFSET, &mut reader(flash), &mut [0; 256])
+    }
+
+    #[test]
+    fn headers_round_trip() {
+        let header = AnimHeader::new(4, 3, 3, &payload(&test_frames()));
+        assert_eq!(AnimHeader::parse(&header.to_bytes()), Ok(header));
+        for (frame, _) in test_frames() {
+            assert_eq!(FrameHeader::parse(&frame.to_bytes()), Ok(frame));
+        }
+        assert_eq!(test_frames()[1].0.size(), (2, 2));
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        let bytes = AnimHeader::new(4, 3, 3, &payload(&test_frames())).to_bytes();
+        let patched = |offset: usize, value: &[u8]| {
+            let mut bytes = bytes;
+            bytes[offset..offset + value.len()].copy_from_slice(value);
+            AnimHeader::parse(&bytes)
+        };
+        assert_eq!(AnimHeader::parse(&[0xff; ANIM_HEADER_SIZE]), Err(Error::NotFound));  //  Erased flash
+        assert_eq!(AnimHeader::parse(&bytes[..ANIM_HEADER_SIZE - 1]), Err(Error::Invalid));
+        assert_eq!(patched(4, &2u16.to_le_bytes()), Err(Error::Unsupported));   //  Version
+        assert_eq!(patched(6, &241u16.to_le_bytes()), Err(Error::Invalid));      //  Width
+        assert_eq!(patched(8, &0u16.to_le_bytes()), Err(Error::Invalid));        //  Height
+        assert_eq!(patched(10, &0u16.to_le_bytes()), Err(Error::Invalid));       //  Frame count
+
+        let frame = test_frames()[1].0.to_bytes();
+        let mut bad_codec = frame;
+        bad_codec[6] = 2;
+        assert_eq!(FrameHeader::parse(&bad_codec), Err(Error::Unsupported));
+        let mut bad_rect = frame;
+        bad_rect[2] = 3;  //  Left after right
+        assert_eq!(FrameHeader::parse(&bad_rect), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn renders_frames_with_delays() {
+        let payload = payload(&test_frames());
+        let header = AnimHeader::new(4, 3, 3, &payload);
+        let flash = flash_with_animation(&header, &payload);
+        assert_eq!(verify(&flash), Ok(header));
+
+        let mut display = TestDisplay::new();
+        let mut delays = Vec::new();
+        render_animation(
+            &mut display, OFFSET, &header, &mut reader(&flash), &mut |ms| delays.push(ms), &mut Buffers::new()
+        ).unwrap();
+        assert_eq!(delays, vec![ 100, 50, 200 ]);
+        assert_eq!((display.rows, display.flushes), (3 + 2, 2));  //  Unchanged frame is not rendered or flushed
+
+        //  Animation is centred at (118, 118)
+        for y in 117..122 {
+            for x in 117..123 {
+                let expected =
+                    if !(118..=121).contains(&x) || !(118..=120).contains(&y) { 0 }
+                    else if (119..=120).contains(&x) && (119..=120).contains(&y) { 0x07e0 }
+                    else { 0xf800 };
+                assert_eq!(display.pixel(x, y), expected, "pixel {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_inconsistent_frames() {
+        let check = |frames: &[(FrameHeader, Vec<u8>)], frame_count: u16| {
+            let payload = payload(frames);
+            verify(&flash_with_animation(&AnimHeader::new(4, 3, frame_count, &payload), &payload))
+        };
+        let frames = test_frames();
+        assert!(check(&frames, 3).is_ok());
+        assert_eq!(check(&frames, 2), Err(Error::Invalid));  //  Payload has extra frames
+        assert_eq!(check(&frames, 4), Err(Error::Invalid));  //  Frame header outside payload
+
+        //  Dirty rectangle outside the animation
+        let outside = [ frame(0, (2, 0, 4, 0), Codec::Raw, &pixels(0, 3)) ];
+        assert_eq!(check(&outside, 1), Err(Error::Invalid));
+
+        //  Raw data must contain all the pixels
+        let short = [ frame(0, (0, 0, 3, 2), Codec::Raw, &pixels(0, 11)) ];
+        assert_eq!(check(&short, 1), Err(Error::Invalid));
+
+        //  Frame data outside payload
+        let mut truncated = payload(&frames);
+        truncated.pop();
+        let flash = flash_with_animation(&AnimHeader::new(4, 3, 3, &truncated), &truncated);
+        assert_eq!(verify(&flash), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn rejects_corrupted_payload() {
+        let payload = payload(&test_frames());
+        let mut flash = flash_with_animation(&AnimHeader::new(4, 3, 3, &payload), &payload);
+        flash[OFFSET as usize + ANIM_HEADER_SIZE + FRAME_HEADER_SIZE] ^= 1;
+        assert_eq!(verify(&flash), Err(Error::Invalid));
+        assert_eq!(verify(&flash[..OFFSET as usize + 8]), Err(Error::OutOfRange));
+    }
+}
@@ -0,0 +1,37 @@
+//! CRC32 for the boot logo container, the animation container and the CHIP8 save states.
+//! Same as `pinetime_logo_crc32()` in `libs/pinetime_logo`.
+
+/// Compute the CRC32 (IEEE 802.3, same as zlib) of `data`, continuing from the previous value `crc`.
+/// Start with `crc = 0`. Computed bit by bit to avoid a lookup table in ROM.
+pub fn crc32(crc: u32, data: &[u8]) -> u32 {
+    let mut crc = !crc;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (!(crc & 1)).wrapping_add(1);  //  0xffffffff if lowest bit is set, else 0
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        //  Check value of CRC-32/ISO-HDLC, same as zlib
+        assert_eq!(crc32(0, b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(0, b""), 0);
+    }
+
+    #[test]
+    fn continues_from_previous_value() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        for split in 0..data.len() {
+            assert_eq!(crc32(crc32(0, &data[..split]), &data[split..]), crc32(0, data));
+        }
+        assert_eq!(crc32(0, data), 0x414F_A339);
+    }
+}
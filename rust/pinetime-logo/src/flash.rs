@@ -0,0 +1,254 @@
+//! Flash storage for the logo slots. `LogoFlash` is implemented by `mynewt::hal::Flash` for External SPI Flash
+//! on PineTime, and by `RamFlash` for running the slot logic on the host. `RamFlash` follows the NOR Flash
+//! semantics of `mynewt::hal::Flash`: erases are widened to whole sectors and bytes must be erased before writing.
+//! Both implement the `embedded-storage` NOR Flash traits.
+use core::ops::Range;
+use embedded_storage::nor_flash::{
+    ErrorType, NorFlash, NorFlashError, NorFlashErrorKind, ReadNorFlash,
+};
+use crate::Error;
+
+/// Size of an erasable sector. Internal Flash ROM and External SPI Flash on PineTime both have 4 KB sectors.
+pub const SECTOR_SIZE: u32 = 4096;
+
+/// Value of an erased byte on NOR Flash
+pub const ERASED_VALUE: u8 = 0xff;
+
+/// Flash storage for the logo slots. Offsets are relative to the start of the Bootloader Assets area.
+pub trait LogoFlash {
+    /// Error returned by the flash. Errors in the logo containers are converted to this error.
+    type Error: From<Error> + PartialEq;
+
+    /// Read `buf.len()` bytes at `offset` into `buf`
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Write `data` at `offset`. The bytes must have been erased.
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Self::Error>;
+
+    /// Erase `len` bytes at `offset`. Both must be aligned to `SECTOR_SIZE`.
+    fn erase(&mut self, offset: u32, len: u32) -> Result<(), Self::Error>;
+}
+
+/// In-memory stand-in for flash. Erased bytes are `0xFF` and bytes must be erased before writing,
+/// like `mynewt::hal::Flash`.
+pub struct RamFlash<'a> {
+    /// Contents of the flash
+    memory: &'a mut [u8],
+}
+
+impl<'a> RamFlash<'a> {
+    /// Create an erased flash backed by `memory`. The size of `memory` should be a multiple of `SECTOR_SIZE`.
+    pub fn new(memory: &'a mut [u8]) -> RamFlash<'a> {
+        for byte in memory.iter_mut() { *byte = ERASED_VALUE; }
+        RamFlash { memory }
+    }
+
+    /// Read `buf.len()` bytes at `offset` into `buf`. Fail with `OutOfRange` if outside the flash.
+    pub fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error> {
+        let range = self.range(offset, buf.len()) ? ;
+        buf.copy_from_slice(&self.memory[range]);
+        Ok(())
+    }
+
+    /// Erase the sectors covering `len` bytes at `offset`, like `mynewt::hal::Flash::erase_range`.
+    /// Returns the range of offsets that were erased. Fail with `OutOfRange` if outside the flash.
+    pub fn erase_range(&mut self, offset: u32, len: u32) -> Result<Range<u32>, Error> {
+        self.range(offset, len as usize) ? ;
+        let range = align_to_sectors(offset, len);
+        let end = (range.end as usize).min(self.memory.len());
+        for byte in self.memory[range.start as usize..end].iter_mut() { *byte = ERASED_VALUE; }
+        Ok(range)
+    }
+
+    /// Write `data` at `offset`. Fail with `OutOfRange` if outside the flash, `AlreadyWritten` if the bytes
+    /// have not been erased.
+    pub fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Error> {
+        let range = self.range(offset, data.len()) ? ;
+        let bytes = &mut self.memory[range];
+        if bytes.iter().any(|byte| *byte != ERASED_VALUE) { return Err(Error::AlreadyWritten); }
+        bytes.copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Return the range of bytes at `offset`. Fail with `OutOfRange` if outside the flash.
+    fn range(&self, offset: u32, len: usize) -> Result<Range<usize>, Error> {
+        let start = offset as usize;
+        if start + len > self.memory.len() { return Err(Error::OutOfRange); }
+        Ok(start..start + len)
+    }
+}
+
+/// Logo slots in RAM, for running the slot logic without hardware
+impl<'a> LogoFlash for RamFlash<'a> {
+    type Error = Error;
+
+    fn read(&mut self, offset: u32, buf: &mut [u8]) -> Result<(), Error> {
+        RamFlash::read(self, offset, buf)
+    }
+
+    fn write(&mut self, offset: u32, data: &[u8]) -> Result<(), Error> {
+        RamFlash::write(self, offset, data)
+    }
+
+    fn erase(&mut self, offset: u32, len: u32) -> Result<(), Error> {
+        NorFlash::erase(self, offset, offset + len)
+    }
+}
+
+impl<'a> ErrorType for RamFlash<'a> {
+    type Error = Error;
+}
+
+impl<'a> ReadNorFlash for RamFlash<'a> {
+    const READ_SIZE: usize = 1;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        RamFlash::read(self, offset, bytes)
+    }
+
+    fn capacity(&self) -> usize {
+        self.memory.len()
+    }
+}
+
+impl<'a> NorFlash for RamFlash<'a> {
+    const WRITE_SIZE: usize = 1;
+    const ERASE_SIZE: usize = SECTOR_SIZE as usize;
+
+    /// Erase the sectors from `from` to `to`. Both must be aligned to `SECTOR_SIZE`.
+    fn erase(&mut self, from: u32, to: u32) -> Result<(), Self::Error> {
+        check_erase_range(from, to) ? ;
+        self.erase_range(from, to - from) ? ;
+        Ok(())
+    }
+
+    fn write(&mut self, offset: u32, bytes: &[u8]) -> Result<(), Self::Error> {
+        RamFlash::write(self, offset, bytes)
+    }
+}
+
+/// Map the errors to NOR Flash error kinds
+impl NorFlashError for Error {
+    fn kind(&self) -> NorFlashErrorKind {
+        match self {
+            Error::Invalid    => NorFlashErrorKind::NotAligned,
+            Error::OutOfRange => NorFlashErrorKind::OutOfBounds,
+            _                 => NorFlashErrorKind::Other,
+        }
+    }
+}
+
+/// Return the range of offsets covering `len` bytes at `offset`, widened to sector boundaries
+pub fn align_to_sectors(offset: u32, len: u32) -> Range<u32> {
+    if len == 0 { return offset..offset; }
+    let start = offset / SECTOR_SIZE * SECTOR_SIZE;
+    let end = (offset + len + SECTOR_SIZE - 1) / SECTOR_SIZE * SECTOR_SIZE;
+    start..end
+}
+
+/// Check that the range from `from` to `to` is aligned to sectors. Fail with `Invalid` if not.
+pub fn check_erase_range(from: u32, to: u32) -> Result<(), Error> {
+    if from > to || from % SECTOR_SIZE != 0 || to % SECTOR_SIZE != 0 { return Err(Error::Invalid); }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Size of the test flash: 2 sectors
+    const FLASH_SIZE: usize = 2 * SECTOR_SIZE as usize;
+
+    #[test]
+    fn new_erases_memory() {
+        let mut memory = [0u8; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        assert_eq!(ReadNorFlash::capacity(&flash), FLASH_SIZE);
+        let mut buf = [0u8; 16];
+        RamFlash::read(&mut flash, SECTOR_SIZE - 8, &mut buf).unwrap();
+        assert_eq!(buf, [ERASED_VALUE; 16]);
+    }
+
+    #[test]
+    fn write_requires_erase() {
+        let mut memory = [0u8; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        RamFlash::write(&mut flash, 10, &[1, 2, 3]).unwrap();
+        //  Writing over written bytes fails and leaves them unchanged
+        assert_eq!(RamFlash::write(&mut flash, 12, &[4, 5]), Err(Error::AlreadyWritten));
+        let mut buf = [0u8; 4];
+        RamFlash::read(&mut flash, 10, &mut buf).unwrap();
+        assert_eq!(buf, [1, 2, 3, ERASED_VALUE]);
+        //  Writing next to written bytes is fine
+        RamFlash::write(&mut flash, 13, &[4]).unwrap();
+        //  Writing after an erase is fine
+        flash.erase_range(10, 1).unwrap();
+        RamFlash::write(&mut flash, 12, &[4, 5]).unwrap();
+    }
+
+    #[test]
+    fn erase_range_widens_to_sectors() {
+        let mut memory = [0u8; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        RamFlash::write(&mut flash, 0, &[1]).unwrap();
+        RamFlash::write(&mut flash, SECTOR_SIZE - 1, &[2, 3]).unwrap();
+        //  Erasing 1 byte in the second sector erases the whole second sector only
+        assert_eq!(flash.erase_range(SECTOR_SIZE + 100, 1), Ok(SECTOR_SIZE..2 * SECTOR_SIZE));
+        let mut buf = [0u8; 2];
+        RamFlash::read(&mut flash, SECTOR_SIZE - 1, &mut buf).unwrap();
+        assert_eq!(buf, [2, ERASED_VALUE]);
+        //  Erasing across the sector boundary erases both sectors
+        assert_eq!(flash.erase_range(SECTOR_SIZE - 1, 2), Ok(0..2 * SECTOR_SIZE));
+        RamFlash::read(&mut flash, 0, &mut buf).unwrap();
+        assert_eq!(buf, [ERASED_VALUE; 2]);
+        //  Erasing nothing erases nothing
+        assert_eq!(flash.erase_range(5, 0), Ok(5..5));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let mut memory = [0u8; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let mut buf = [0u8; 2];
+        let end = FLASH_SIZE as u32;
+        assert_eq!(RamFlash::read(&mut flash, end - 1, &mut buf), Err(Error::OutOfRange));
+        assert_eq!(RamFlash::write(&mut flash, end - 1, &buf), Err(Error::OutOfRange));
+        assert_eq!(flash.erase_range(end, 1), Err(Error::OutOfRange));
+        assert_eq!(NorFlash::erase(&mut flash, SECTOR_SIZE, 3 * SECTOR_SIZE), Err(Error::OutOfRange));
+        assert_eq!(Error::OutOfRange.kind(), NorFlashErrorKind::OutOfBounds);
+        //  The last byte is in range
+        RamFlash::write(&mut flash, end - 1, &buf[..1]).unwrap();
+    }
+
+    #[test]
+    fn nor_flash_erase_must_be_aligned() {
+        let mut memory = [0u8; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        RamFlash::write(&mut flash, 0, &[1]).unwrap();
+        assert_eq!(NorFlash::erase(&mut flash, 1, SECTOR_SIZE), Err(Error::Invalid));
+        assert_eq!(NorFlash::erase(&mut flash, 0, SECTOR_SIZE + 1), Err(Error::Invalid));
+        assert_eq!(NorFlash::erase(&mut flash, SECTOR_SIZE, 0), Err(Error::Invalid));
+        assert_eq!(Error::Invalid.kind(), NorFlashErrorKind::NotAligned);
+        //  Failed erases leave the flash unchanged
+        let mut buf = [0u8; 1];
+        RamFlash::read(&mut flash, 0, &mut buf).unwrap();
+        assert_eq!(buf, [1]);
+        NorFlash::erase(&mut flash, 0, SECTOR_SIZE).unwrap();
+        RamFlash::read(&mut flash, 0, &mut buf).unwrap();
+        assert_eq!(buf, [ERASED_VALUE]);
+        //  LogoFlash erases must be aligned too
+        assert_eq!(LogoFlash::erase(&mut flash, 0, 1), Err(Error::Invalid));
+        LogoFlash::erase(&mut flash, SECTOR_SIZE, SECTOR_SIZE).unwrap();
+    }
+
+    #[test]
+    fn sector_alignment() {
+        assert_eq!(align_to_sectors(0, SECTOR_SIZE), 0..SECTOR_SIZE);
+        assert_eq!(align_to_sectors(1, 1), 0..SECTOR_SIZE);
+        assert_eq!(align_to_sectors(SECTOR_SIZE - 1, 2), 0..2 * SECTOR_SIZE);
+        assert_eq!(align_to_sectors(SECTOR_SIZE, 0), SECTOR_SIZE..SECTOR_SIZE);
+        assert_eq!(check_erase_range(0, 0), Ok(()));
+        assert_eq!(check_erase_range(SECTOR_SIZE, 3 * SECTOR_SIZE), Ok(()));
+        assert_eq!(check_erase_range(2 * SECTOR_SIZE, SECTOR_SIZE), Err(Error::Invalid));
+    }
+}
@@ -0,0 +1,174 @@
+//! Header of the boot logo container. Must match the container written by `rust/pinetime-graphic`.
+//! All fields are little-endian:
+//! ```text
+//! Offset  Size  Field
+//!      0     4  Magic: "PTLG"
+//!      4     2  Version: 1
+//!      6     2  Width in pixels
+//!      8     2  Height in pixels
+//!     10     1  Pixel Format: 0 for RGB565 big-endian
+//!     11     1  Codec: 0 for Raw, 1 for RLE
+//!     12     4  Payload Length in bytes
+//!     16     4  CRC32 of the payload
+//! ```
+use crate::{ crc32, Error, MAX_HEIGHT, MAX_WIDTH };
+
+/// Magic number at the start of the container: "PTLG"
+pub const LOGO_MAGIC: [u8; 4] = *b"PTLG";
+
+/// Current version of the container
+pub const LOGO_VERSION: u16 = 1;
+
+/// Size of the header in bytes. The payload follows the header.
+pub const HEADER_SIZE: usize = 20;
+
+/// Pixel format of the decoded payload
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PixelFormat {
+    /// 16-bit RGB565, high byte first. Same as the ST7789 display memory.
+    Rgb565Be = 0,
+}
+
+/// Compression method for the payload
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Codec {
+    /// Uncompressed pixels
+    Raw = 0,
+    /// Run-length encoded pixels, see `pinetime_logo::rle`
+    Rle = 1,
+}
+
+/// Header of the boot logo container
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogoHeader {
+    /// Container version
+    pub version:     u16,
+    /// Width in pixels
+    pub width:       u16,
+    /// Height in pixels
+    pub height:      u16,
+    /// Pixel format of the decoded payload
+    pub format:      PixelFormat,
+    /// Compression method for the payload
+    pub codec:       Codec,
+    /// Size of the payload in bytes
+    pub payload_len: u32,
+    /// CRC32 of the payload
+    pub crc32:       u32,
+}
+
+impl LogoHeader {
+    /// Create the header for an RGB565 `payload` with the `width`, `height` and `codec`.
+    /// The payload length and CRC32 are computed from `payload`.
+    pub fn new(width: u16, height: u16, codec: Codec, payload: &[u8]) -> LogoHeader {
+        LogoHeader {
+            version:     LOGO_VERSION,
+            width, height,
+            format:      PixelFormat::Rgb565Be,
+            codec,
+            payload_len: payload.len() as u32,
+            crc32:       crc32(0, payload),
+        }
+    }
+
+    /// Parse the header bytes. Fail with `NotFound` if the magic number is missing (e.g. erased flash),
+    /// `Unsupported` if the version, pixel format or codec is unknown, `Invalid` if the dimensions are invalid.
+    pub fn parse(bytes: &[u8]) -> Result<LogoHeader, Error> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(Error::Invalid);
+        }
+        if bytes[0..4] != LOGO_MAGIC {
+            return Err(Error::NotFound);
+        }
+        let version = read_u16(bytes, 4);
+        if version != LOGO_VERSION {
+            return Err(Error::Unsupported);
+        }
+        let width  = read_u16(bytes, 6);
+        let height = read_u16(bytes, 8);
+        if width == 0 || height == 0 ||
+            width as usize > MAX_WIDTH || height as usize > MAX_HEIGHT {
+            return Err(Error::Invalid);
+        }
+        let format = match bytes[10] {
+            0 => PixelFormat::Rgb565Be,
+            _ => return Err(Error::Unsupported),
+        };
+        let codec = match bytes[11] {
+            0 => Codec::Raw,
+            1 => Codec::Rle,
+            _ => return Err(Error::Unsupported),
+        };
+        Ok(LogoHeader {
+            version, width, height, format, codec,
+            payload_len: read_u32(bytes, 12),
+            crc32:       read_u32(bytes, 16),
+        })
+    }
+
+    /// Return the header as bytes, ready to be written to flash
+    pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut bytes = [0u8; HEADER_SIZE];
+        bytes[0..4].copy_from_slice(&LOGO_MAGIC);
+        bytes[4..6].copy_from_slice(&self.version.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.width.to_le_bytes());
+        bytes[8..10].copy_from_slice(&self.height.to_le_bytes());
+        bytes[10] = self.format as u8;
+        bytes[11] = self.codec as u8;
+        bytes[12..16].copy_from_slice(&self.payload_len.to_le_bytes());
+        bytes[16..20].copy_from_slice(&self.crc32.to_le_bytes());
+        bytes
+    }
+}
+
+/// Read a little-endian `u16` at `offset`
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([ bytes[offset], bytes[offset + 1] ])
+}
+
+/// Read a little-endian `u32` at `offset`
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([ bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3] ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let payload = [ 0x12, 0x34, 0x56, 0x78 ];
+        let header = LogoHeader::new(2, 1, Codec::Raw, &payload);
+        assert_eq!(header.payload_len, 4);
+        assert_eq!(header.crc32, crc32(0, &payload));
+        let bytes = header.to_bytes();
+        assert_eq!(&bytes[0..4], b"PTLG");
+        assert_eq!(LogoHeader::parse(&bytes), Ok(header));
+
+        let header = LogoHeader::new(240, 240, Codec::Rle, &payload);
+        assert_eq!(LogoHeader::parse(&header.to_bytes()), Ok(header));
+    }
+
+    #[test]
+    fn erased_flash_is_not_found() {
+        assert_eq!(LogoHeader::parse(&[0xff; HEADER_SIZE]), Err(Error::NotFound));
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        let bytes = LogoHeader::new(120, 60, Codec::Rle, &[0x80, 0, 0]).to_bytes();
+        assert_eq!(LogoHeader::parse(&bytes[..HEADER_SIZE - 1]), Err(Error::Invalid));
+
+        //  Patch one field at a time
+        let patched = |offset: usize, value: &[u8]| {
+            let mut bytes = bytes;
+            bytes[offset..offset + value.len()].copy_from_slice(value);
+            LogoHeader::parse(&bytes)
+        };
+        assert_eq!(patched(4, &2u16.to_le_bytes()), Err(Error::Unsupported));  //  Version
+        assert_eq!(patched(6, &0u16.to_le_bytes()), Err(Error::Invalid));      //  Zero width
+        assert_eq!(patched(8, &241u16.to_le_bytes()), Err(Error::Invalid));    //  Taller than the display
+        assert_eq!(patched(10, &[1]), Err(Error::Unsupported));                //  Pixel format
+        assert_eq!(patched(11, &[2]), Err(Error::Unsupported));                //  Codec
+    }
+}
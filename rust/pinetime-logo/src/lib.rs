@@ -0,0 +1,66 @@
+//! Boot logo container for PineTime, without Mynewt calls. Used by `mynewt::logo` on PineTime and by
+//! `rust/pinetime-graphic` on the host, so that the encoder and decoder share the same container layout.
+//! `header` contains the header of the boot logo container, `rle` the run-length codec for RGB565 pixels and
+//! `crc` the CRC32 that checks the payload. `render` verifies the container in flash and renders it to a display,
+//! or renders the fallback screen if the container is missing or corrupted. `anim` contains the animation container.
+//! `slots` manages the logo slots and the slot table in flash, accessed through the `LogoFlash` trait in `flash`.
+//! `upload` receives a container over Bluetooth LE in chunks and writes it into a slot.
+
+#![no_std]  //  Don't link with standard Rust library, so that the container is decoded on PineTime
+
+pub mod anim;    //  Export `anim.rs` as Rust module `pinetime_logo::anim`
+
+pub mod crc;     //  Export `crc.rs` as Rust module `pinetime_logo::crc`
+
+pub mod flash;   //  Export `flash.rs` as Rust module `pinetime_logo::flash`
+
+pub mod header;  //  Export `header.rs` as Rust module `pinetime_logo::header`
+
+pub mod render;  //  Export `render.rs` as Rust module `pinetime_logo::render`
+
+pub mod rle;     //  Export `rle.rs` as Rust module `pinetime_logo::rle`
+
+pub mod slots;   //  Export `slots.rs` as Rust module `pinetime_logo::slots`
+
+pub mod upload;  //  Export `upload.rs` as Rust module `pinetime_logo::upload`
+
+#[cfg(test)]
+mod testing;     //  Declare `testing.rs` as Rust module `testing` for the test display and flash reader
+
+pub use crc::crc32;  //  Export the CRC32
+
+pub use flash::{ LogoFlash, RamFlash };  //  Export the flash storage for the logo slots
+
+pub use header::{ Codec, LogoHeader, PixelFormat };  //  Export the boot logo header
+
+pub use render::{ LogoDisplay, FALLBACK_COLOR };  //  Export the display for rendering the logo
+
+pub use rle::RleDecoder;  //  Export the RLE decoder
+
+/// Max width of the logo in pixels, which is the width of the PineTime display
+pub const MAX_WIDTH: usize = 240;
+
+/// Max height of the logo in pixels, which is the height of the PineTime display
+pub const MAX_HEIGHT: usize = 240;
+
+/// Number of bytes per RGB565 pixel
+pub const BYTES_PER_PIXEL: usize = 2;
+
+/// Errors when decoding a boot logo container. `mynewt::logo` converts them to `MynewtError`:
+/// `NotFound` is `SYS_ENOENT`, `Unsupported` is `SYS_ENOTSUP`, `Invalid` is `SYS_EINVAL`, `OutOfRange` is `SYS_ERANGE`,
+/// `AlreadyWritten` is `SYS_EALREADY`, `NoMemory` is `SYS_ENOMEM`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// Magic number is missing, e.g. erased flash
+    NotFound,
+    /// Version, pixel format or codec is unknown
+    Unsupported,
+    /// Container is inconsistent or corrupted
+    Invalid,
+    /// Offset is outside the flash
+    OutOfRange,
+    /// Flash bytes have not been erased before writing
+    AlreadyWritten,
+    /// Container doesn't fit in the slot
+    NoMemory,
+}
@@ -0,0 +1,605 @@
+//! Verify the boot logo container stored in flash and render the decoded rows to a `LogoDisplay`. On PineTime,
+//! `mynewt::logo` renders to the ST7789 display window through the Non-Blocking SPI API. If the logo is missing
+//! or corrupted, a solid screen of `FALLBACK_COLOR` is displayed instead. Flash is read by a closure
+//! `read(offset, buf)` in batches of `BATCH_SIZE` bytes, so the logo is never loaded into RAM.
+//! `blit_region()` redraws a rectangle of the logo in a slot, e.g. to restore the logo behind a toast.
+use crate::{
+    crc32,
+    flash::LogoFlash,
+    header::{ Codec, LogoHeader, HEADER_SIZE },
+    rle::RleDecoder,
+    slots,
+    Error, BYTES_PER_PIXEL, MAX_HEIGHT, MAX_WIDTH,
+};
+
+/// Max number of bytes to be read from flash in a batch. Same as `BATCH_SIZE` in `pinetime_boot_display_image()`
+pub const BATCH_SIZE: usize = 256;
+
+/// Number of bytes in a row of the display
+pub const ROW_SIZE: usize = MAX_WIDTH * BYTES_PER_PIXEL;
+
+/// Colour of the fallback screen in RGB565: dark blue. Same as `FALLBACK_COLOR` in `pinetime_boot_display_image()`
+pub const FALLBACK_COLOR: u16 = 0x0010;
+
+/// Display that receives the decoded rows of the logo
+pub trait LogoDisplay {
+    /// Error returned by the display, which is also returned by the flash `read` closure
+    type Error: From<Error>;
+
+    /// Write the RGB565 `pixels` (big-endian) to row `y` of the display, from column `left` to `right` inclusive
+    fn write_row(&mut self, left: u8, y: u8, right: u8, pixels: &[u8]) -> Result<(), Self::Error>;
+
+    /// Wait until all rows have been sent to the display
+    fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Buffers for reading the logo from flash and decoding one row. Declared `static` on PineTime to keep them
+/// off the stack.
+pub struct Buffers {
+    /// Buffer for reading a batch of the logo from flash
+    pub batch: [u8; BATCH_SIZE],
+    /// Buffer for one decoded row of pixels
+    pub row:   [u8; ROW_SIZE],
+}
+
+impl Buffers {
+    /// Create the buffers
+    pub const fn new() -> Buffers {
+        Buffers { batch: [0; BATCH_SIZE], row: [0; ROW_SIZE] }
+    }
+}
+
+impl Default for Buffers {
+    fn default() -> Self {
+        Buffers::new()
+    }
+}
+
+/// Decoder for the logo payload. Raw payloads are copied as is.
+pub enum Decoder {
+    /// Uncompressed RGB565 pixels
+    Raw,
+    /// Run-length encoded RGB565 pixels
+    Rle(RleDecoder),
+}
+
+impl Decoder {
+    /// Create a decoder for the codec in the logo header
+    pub fn new(codec: Codec) -> Decoder {
+        match codec {
+            Codec::Raw => Decoder::Raw,
+            Codec::Rle => Decoder::Rle(RleDecoder::new()),
+        }
+    }
+
+    /// Decode the `input` bytes into `output`. Returns the number of bytes consumed from `input`
+    /// and the number of bytes written to `output`. Call again with the remaining input when `output` is full.
+    pub fn decode(&mut self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+        match self {
+            Decoder::Raw => {
+                let len = input.len().min(output.len());
+                output[..len].copy_from_slice(&input[..len]);
+                (len, len)
+            }
+            Decoder::Rle(decoder) => decoder.decode(input, output),
+        }
+    }
+}
+
+/// Display the logo container that begins at `offset` in flash. If the logo is missing or corrupted,
+/// display a solid screen of `FALLBACK_COLOR` instead. Returns the verification error, if any.
+pub fn display_logo_or_fallback<D, R>(display: &mut D, offset: u32, read: &mut R, buffers: &mut Buffers) -> Result<(), D::Error>
+where
+    D: LogoDisplay,
+    R: FnMut(u32, &mut [u8]) -> Result<(), D::Error>,
+{
+    match verify_logo(offset, read, &mut buffers.batch) {
+        Ok(header) => render_logo(display, offset, &header, read, buffers),
+        Err(err) => {
+            display_fallback(display, FALLBACK_COLOR, &mut buffers.row) ? ;
+            Err(err)
+        }
+    }
+}
+
+/// Display the logo container that begins at `offset` in flash. Each decoded row is sent to the display.
+/// Nothing is displayed if the logo fails verification.
+pub fn display_logo<D, R>(display: &mut D, offset: u32, read: &mut R, buffers: &mut Buffers) -> Result<(), D::Error>
+where
+    D: LogoDisplay,
+    R: FnMut(u32, &mut [u8]) -> Result<(), D::Error>,
+{
+    let header = verify_logo(offset, read, &mut buffers.batch) ? ;
+    render_logo(display, offset, &header, read, buffers)
+}
+
+/// Verify the logo container that begins at `offset` in flash and return the header. `buf` is used for reading
+/// the payload in batches. Fail with `NotFound` if there is no logo, `Unsupported` if the container is unsupported,
+/// `Invalid` if the payload length is inconsistent or the CRC32 doesn't match.
+pub fn verify_logo<R, E>(offset: u32, read: &mut R, buf: &mut [u8]) -> Result<LogoHeader, E>
+where
+    R: FnMut(u32, &mut [u8]) -> Result<(), E>,
+    E: From<Error>,
+{
+    //  Read and validate the header
+    let mut header_bytes = [0u8; HEADER_SIZE];
+    read(offset, &mut header_bytes) ? ;
+    let header = LogoHeader::parse(&header_bytes) ? ;
+
+    //  Raw payload must contain exactly all the pixels. RLE payload must not be empty.
+    let pixels_len = header.width as usize * header.height as usize * BYTES_PER_PIXEL;
+    let payload_len = header.payload_len as usize;
+    let valid_len = match header.codec {
+        Codec::Raw => payload_len == pixels_len,
+        Codec::Rle => payload_len > 0,
+    };
+    if !valid_len {
+        return Err(Error::Invalid.into());
+    }
+
+    //  Compute the CRC32 of the payload
+    let crc = crc_payload(offset + HEADER_SIZE as u32, payload_len, read, buf) ? ;
+    if crc != header.crc32 {
+        return Err(Error::Invalid.into());
+    }
+    Ok(header)
+}
+
+/// Compute the CRC32 of the `len` bytes at `offset` in flash, reading in batches of up to `buf.len()` bytes
+pub fn crc_payload<R, E>(offset: u32, len: usize, read: &mut R, buf: &mut [u8]) -> Result<u32, E>
+where R: FnMut(u32, &mut [u8]) -> Result<(), E> {
+    let mut crc = 0;
+    let mut pos = 0;
+    while pos < len {
+        let batch_len = (len - pos).min(buf.len());
+        let batch = &mut buf[..batch_len];
+        read(offset + pos as u32, batch) ? ;
+        crc = crc32(crc, batch);
+        pos += batch_len;
+    }
+    Ok(crc)
+}
+
+/// Fill the entire display with the RGB565 `color`. `row` is used for one row of pixels.
+/// Shown when the logo is missing or corrupted.
+pub fn display_fallback<D: LogoDisplay>(display: &mut D, color: u16, row: &mut [u8; ROW_SIZE]) -> Result<(), D::Error> {
+    for pixel in row.chunks_exact_mut(BYTES_PER_PIXEL) {
+        pixel.copy_from_slice(&color.to_be_bytes());
+    }
+    for y in 0..MAX_HEIGHT {
+        display.write_row(0, y as u8, (MAX_WIDTH - 1) as u8, row) ? ;
+    }
+    display.flush()
+}
+
+/// Return the position (left, top) of the logo with the `header` on the display. The logo is centred on the display.
+pub fn logo_origin(header: &LogoHeader) -> (u8, u8) {
+    let left = (MAX_WIDTH  - header.width  as usize) / 2;
+    let top  = (MAX_HEIGHT - header.height as usize) / 2;
+    (left as u8, top as u8)
+}
+
+/// Render the verified logo with the `header` that begins at `offset` in flash, centred on the display
+pub fn render_logo<D, R>(display: &mut D, offset: u32, header: &LogoHeader, read: &mut R, buffers: &mut Buffers) -> Result<(), D::Error>
+where
+    D: LogoDisplay,
+    R: FnMut(u32, &mut [u8]) -> Result<(), D::Error>,
+{
+    let (left, top) = logo_origin(header);
+    render_pixels(
+        display,
+        offset + HEADER_SIZE as u32, header.payload_len as usize, header.codec,
+        (left, top, header.width as usize, header.height as usize), read, buffers
+    ) ? ;
+    display.flush()
+}
+
+/// Decode the `len` bytes of pixels at `offset` in flash with the `codec`, and write the decoded pixels
+/// row by row into the display window `rect`, which is (left, top, width, height). Doesn't flush the display.
+pub fn render_pixels<D, R>(display: &mut D, offset: u32, len: usize, codec: Codec, rect: (u8, u8, usize, usize),
+    read: &mut R, buffers: &mut Buffers) -> Result<(), D::Error>
+where
+    D: LogoDisplay,
+    R: FnMut(u32, &mut [u8]) -> Result<(), D::Error>,
+{
+    let (left, top, width, height) = rect;
+    let row = &mut buffers.row[..width * BYTES_PER_PIXEL];
+    let mut reader = PixelReader::new(offset, len, codec);
+    for y in 0..height {
+        reader.read_row(row, read, &mut buffers.batch) ? ;
+        display.write_row(left, top + y as u8, left + width as u8 - 1, row) ? ;
+    }
+    Ok(())
+}
+
+/// Copy the rectangle `src_rect` (left, top, right, bottom inclusive, in logo pixels) of the logo in `slot`
+/// to the display at `dst_point` (left, top, in display pixels). The rectangle is clipped to the logo and to
+/// the display. Raw logos are read row by row from the rectangle only. Run-length encoded logos are decoded
+/// from the first row, because RLE packets may cross rows, and decoding stops after the last row of the rectangle.
+/// The header is checked but the CRC32 is not, so call `slots::slot_status()` once before blitting repeatedly.
+/// Fail with `NotFound` if the slot contains no logo (animations can't be blitted), `Invalid` if the rectangle
+/// is inverted or lies entirely outside the logo or the display.
+pub fn blit_region<D, F>(display: &mut D, flash: &mut F, slot: usize, src_rect: (u8, u8, u8, u8), dst_point: (u8, u8),
+    buffers: &mut Buffers) -> Result<(), F::Error>
+where
+    D: LogoDisplay<Error = F::Error>,
+    F: LogoFlash + ?Sized,
+{
+    let offset = slots::slot_offset(slot) ? ;
+    let mut header_bytes = [0u8; HEADER_SIZE];
+    flash.read(offset, &mut header_bytes) ? ;
+    let header = LogoHeader::parse(&header_bytes) ? ;
+    let (left, top, right, bottom) = clip_rect(&header, src_rect, dst_point) ? ;
+
+    let (dst_left, dst_top) = dst_point;
+    let width = right - left + 1;
+    let dst_right = (dst_left as usize + width - 1) as u8;
+    let payload_offset = offset + HEADER_SIZE as u32;
+    let payload_len = header.payload_len as usize;
+    let row_len = header.width as usize * BYTES_PER_PIXEL;
+    let mut read = |offset, buf: &mut [u8]| flash.read(offset, buf);
+    let Buffers { batch, row } = buffers;
+    match header.codec {
+        Codec::Raw => {
+            //  Read only the pixels inside the rectangle
+            let len = width * BYTES_PER_PIXEL;
+            for y in top..=bottom {
+                let pixels_offset = y * row_len + left * BYTES_PER_PIXEL;
+                if pixels_offset + len > payload_len { return Err(Error::Invalid.into()); }
+                let pixels = &mut row[..len];
+                read(payload_offset + pixels_offset as u32, pixels) ? ;
+                display.write_row(dst_left, dst_top + (y - top) as u8, dst_right, pixels) ? ;
+            }
+        }
+        Codec::Rle => {
+            //  Decode every row up to the bottom of the rectangle, and send the columns inside the rectangle
+            let mut reader = PixelReader::new(payload_offset, payload_len, header.codec);
+            let start = left * BYTES_PER_PIXEL;
+            let end = start + width * BYTES_PER_PIXEL;
+            for y in 0..=bottom {
+                reader.read_row(&mut row[..row_len], &mut read, batch) ? ;
+                if y < top { continue; }
+                display.write_row(dst_left, dst_top + (y - top) as u8, dst_right, &row[start..end]) ? ;
+            }
+        }
+    }
+    display.flush()
+}
+
+/// Clip the rectangle `src_rect` (left, top, right, bottom inclusive) to the logo with the `header`, and to
+/// the display at `dst_point`. Returns the clipped rectangle (left, top, right, bottom) in logo pixels.
+/// Fail with `Invalid` if the rectangle is inverted or nothing is left after clipping.
+pub fn clip_rect(header: &LogoHeader, src_rect: (u8, u8, u8, u8), dst_point: (u8, u8)) -> Result<(usize, usize, usize, usize), Error> {
+    let (left, top, right, bottom) = src_rect;
+    let (dst_left, dst_top) = (dst_point.0 as usize, dst_point.1 as usize);
+    if left > right || top > bottom { return Err(Error::Invalid); }
+    let (left, top) = (left as usize, top as usize);
+    if left >= header.width as usize || top >= header.height as usize ||
+        dst_left >= MAX_WIDTH || dst_top >= MAX_HEIGHT {
+        return Err(Error::Invalid);  //  Outside the logo or the display
+    }
+    //  Clip the right and bottom edges to the logo and to the display
+    let right  = (right  as usize).min(header.width  as usize - 1).min(left + MAX_WIDTH  - 1 - dst_left);
+    let bottom = (bottom as usize).min(header.height as usize - 1).min(top  + MAX_HEIGHT - 1 - dst_top);
+    Ok((left, top, right, bottom))
+}
+
+/// Reads the raw or encoded pixels from flash in batches and decodes them row by row.
+/// The batch buffer is passed to `read_row()`, and must be the same buffer for every row.
+pub struct PixelReader {
+    /// Offset of the pixels in flash
+    offset:      u32,
+    /// Number of bytes of pixels in flash
+    len:         usize,
+    /// Decoder for the codec
+    decoder:     Decoder,
+    /// Offset of the next batch in the data
+    data_offset: usize,
+    /// Number of bytes in the batch buffer
+    batch_len:   usize,
+    /// Next byte to be decoded in the batch buffer
+    batch_pos:   usize,
+}
+
+impl PixelReader {
+    /// Create a reader for the `len` bytes of pixels at `offset` in flash, encoded with the `codec`
+    pub fn new(offset: u32, len: usize, codec: Codec) -> PixelReader {
+        PixelReader {
+            offset, len,
+            decoder:     Decoder::new(codec),
+            data_offset: 0,
+            batch_len:   0,
+            batch_pos:   0,
+        }
+    }
+
+    /// Decode the next row of pixels into `row`, reading from flash into `batch` when the batch is used up.
+    /// Fail with `Invalid` if the data is too short.
+    pub fn read_row<R, E>(&mut self, row: &mut [u8], read: &mut R, batch: &mut [u8]) -> Result<(), E>
+    where
+        R: FnMut(u32, &mut [u8]) -> Result<(), E>,
+        E: From<Error>,
+    {
+        let mut row_pos = 0;  //  Number of decoded bytes in the row buffer
+        while row_pos < row.len() {
+            //  Decode the rest of the batch into the row buffer. A pending run may be decoded without any input.
+            let (consumed, produced) = self.decoder.decode(
+                &batch[self.batch_pos..self.batch_len],
+                &mut row[row_pos..]
+            );
+            self.batch_pos += consumed;
+            row_pos += produced;
+            //  If the row is incomplete and the batch is used up, read the next batch from flash
+            if row_pos < row.len() && self.batch_pos >= self.batch_len {
+                if self.data_offset >= self.len {
+                    return Err(Error::Invalid.into());  //  Data too short
+                }
+                self.batch_len = batch.len().min(self.len - self.data_offset);
+                self.batch_pos = 0;
+                read(self.offset + self.data_offset as u32, &mut batch[..self.batch_len]) ? ;
+                self.data_offset += self.batch_len;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::{ vec, vec::Vec };
+    use crate::{
+        flash::RamFlash,
+        rle::encode_rle,
+        slots::{ write_slot, SLOT_COUNT, SLOT_SIZE },
+        testing::{ reader, TestDisplay },
+    };
+    use super::*;
+
+    /// Offset of the logo in the test flash
+    const OFFSET: u32 = 0x1000;
+
+    /// Return the colour of the test image at (`x`, `y`): horizontal stripes with a diagonal line
+    fn test_color(x: usize, y: usize) -> u16 {
+        if x == y { 0xffff } else { [ 0xf800, 0x07e0, 0x001f ][y / 8 % 3] }
+    }
+
+    /// Return the payload of a synthetic logo of `width` x `height` encoded with `codec`
+    fn logo_payload(width: u16, height: u16, codec: Codec) -> Vec<u8> {
+        let mut pixels = Vec::new();
+        for y in 0..height as usize {
+            for x in 0..width as usize { pixels.extend_from_slice(&test_color(x, y).to_be_bytes()); }
+        }
+        match codec {
+            Codec::Raw => pixels,
+            Codec::Rle => {
+                let mut encoded = Vec::new();
+                encode_rle(&pixels, |bytes| encoded.extend_from_slice(bytes));
+                encoded
+            }
+        }
+    }
+
+    /// Return the flash contents with a synthetic logo of `width` x `height` encoded with `codec` at `OFFSET`
+    fn flash_with_logo(width: u16, height: u16, codec: Codec) -> Vec<u8> {
+        let payload = logo_payload(width, height, codec);
+        let mut flash = vec![0xff; OFFSET as usize];
+        flash.extend_from_slice(&LogoHeader::new(width, height, codec, &payload).to_bytes());
+        flash.extend_from_slice(&payload);
+        flash.resize(flash.len() + 4096, 0xff);
+        flash
+    }
+
+    /// Display the logo in `flash` and return the display and the result
+    fn display(flash: &[u8]) -> (TestDisplay, Result<(), Error>) {
+        let mut display = TestDisplay::new();
+        let result = display_logo_or_fallback(&mut display, OFFSET, &mut reader(flash), &mut Buffers::new());
+        (display, result)
+    }
+
+    /// Check that the display shows only the fallback colour
+    fn assert_fallback(display: &TestDisplay) {
+        assert_eq!(display.rows, MAX_HEIGHT);
+        assert_eq!(display.flushes, 1);
+        for y in 0..MAX_HEIGHT {
+            for x in 0..MAX_WIDTH { assert_eq!(display.pixel(x, y), FALLBACK_COLOR, "pixel {}, {}", x, y); }
+        }
+    }
+
+    #[test]
+    fn displays_valid_logos_centred() {
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            let flash = flash_with_logo(100, 60, codec);
+            let (display, result) = display(&flash);
+            assert_eq!(result, Ok(()));
+            assert_eq!((display.rows, display.flushes), (60, 1));
+            let (left, top) = (70, 90);
+            for y in 0..MAX_HEIGHT {
+                for x in 0..MAX_WIDTH {
+                    let inside = x >= left && x < left + 100 && y >= top && y < top + 60;
+                    let expected = if inside { test_color(x - left, y - top) } else { 0 };
+                    assert_eq!(display.pixel(x, y), expected, "{:?} pixel {}, {}", codec, x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corrupted_payload_displays_fallback() {
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            let mut flash = flash_with_logo(240, 240, codec);
+            //  Flip one bit in the last byte of the payload
+            let header = LogoHeader::parse(&flash[OFFSET as usize..]).unwrap();
+            let last = OFFSET as usize + HEADER_SIZE + header.payload_len as usize - 1;
+            flash[last] ^= 0x10;
+            assert_eq!(verify_logo(OFFSET, &mut reader(&flash), &mut [0; BATCH_SIZE]), Err(Error::Invalid));
+            let (display, result) = display(&flash);
+            assert_eq!(result, Err(Error::Invalid));
+            assert_fallback(&display);
+        }
+    }
+
+    #[test]
+    fn corrupted_header_displays_fallback() {
+        let flash = flash_with_logo(240, 240, Codec::Rle);
+        let patched = |offset: usize, value: &[u8]| {
+            let mut flash = flash.clone();
+            flash[OFFSET as usize + offset..][..value.len()].copy_from_slice(value);
+            flash
+        };
+        let cases = [
+            (patched(0, b"PTLX"), Error::NotFound),                           //  Magic
+            (patched(4, &7u16.to_le_bytes()), Error::Unsupported),            //  Version
+            (patched(11, &[9]), Error::Unsupported),                          //  Codec
+            (patched(12, &0u32.to_le_bytes()), Error::Invalid),               //  Empty payload
+            (patched(16, &0x1234_5678u32.to_le_bytes()), Error::Invalid),     //  CRC32
+            (vec![0xff; OFFSET as usize + 0x2000], Error::NotFound),          //  Erased flash
+        ];
+        for (flash, expected) in cases.iter() {
+            let (display, result) = display(flash);
+            assert_eq!(result, Err(*expected));
+            assert_fallback(&display);
+        }
+    }
+
+    #[test]
+    fn raw_payload_must_have_every_pixel() {
+        //  Raw payload one pixel short, with a matching CRC32
+        let flash = flash_with_logo(10, 10, Codec::Raw);
+        let payload = &flash[OFFSET as usize + HEADER_SIZE..][..198];
+        let mut short = vec![0xff; OFFSET as usize];
+        short.extend_from_slice(&LogoHeader::new(10, 10, Codec::Raw, payload).to_bytes());
+        short.extend_from_slice(payload);
+        let (display, result) = display(&short);
+        assert_eq!(result, Err(Error::Invalid));
+        assert_fallback(&display);
+    }
+
+    #[test]
+    fn truncated_rle_payload_fails_while_rendering() {
+        //  RLE payload that passes verification but decodes to fewer pixels than the logo
+        let mut payload = Vec::new();
+        encode_rle(&[0x12; 2 * 50], |bytes| payload.extend_from_slice(bytes));
+        let mut flash = vec![0xff; OFFSET as usize];
+        flash.extend_from_slice(&LogoHeader::new(10, 10, Codec::Rle, &payload).to_bytes());
+        flash.extend_from_slice(&payload);
+        let mut display = TestDisplay::new();
+        let result = display_logo(&mut display, OFFSET, &mut reader(&flash), &mut Buffers::new());
+        assert_eq!(result, Err(Error::Invalid));
+        assert_eq!(display.rows, 5);
+    }
+
+    #[test]
+    fn read_errors_are_returned() {
+        let flash = flash_with_logo(240, 240, Codec::Raw);
+        let mut reads = 0;
+        let mut failing = |offset: u32, buf: &mut [u8]| {
+            reads += 1;
+            if reads > 3 { return Err(Error::OutOfRange); }
+            reader(&flash)(offset, buf)
+        };
+        let mut display = TestDisplay::new();
+        let result = display_logo_or_fallback(&mut display, OFFSET, &mut failing, &mut Buffers::new());
+        assert_eq!(result, Err(Error::OutOfRange));
+        assert_fallback(&display);
+    }
+
+    #[test]
+    fn crc_payload_reads_in_batches() {
+        let flash = flash_with_logo(240, 240, Codec::Raw);
+        let header = LogoHeader::parse(&flash[OFFSET as usize..]).unwrap();
+        let mut sizes = Vec::new();
+        let mut read = |offset: u32, buf: &mut [u8]| { sizes.push(buf.len()); reader(&flash)(offset, buf) };
+        let crc = crc_payload(OFFSET + HEADER_SIZE as u32, 1000, &mut read, &mut [0; BATCH_SIZE]);
+        assert_eq!(sizes, [ 256, 256, 256, 232 ]);
+        assert_eq!(crc, Ok(crc32(0, &flash[OFFSET as usize + HEADER_SIZE..][..1000])));
+        assert_ne!(header.crc32, 0);
+    }
+
+    /// Write a synthetic logo of `width` x `height` encoded with `codec` into slot 1 of the `flash`
+    fn write_logo_slot(flash: &mut RamFlash, width: u16, height: u16, codec: Codec) {
+        let payload = logo_payload(width, height, codec);
+        write_slot(flash, 1, &LogoHeader::new(width, height, codec, &payload), &payload).unwrap();
+    }
+
+    /// Blit the rectangle of the logo in `slot` and return the display and the result
+    fn blit(flash: &mut RamFlash, slot: usize, src_rect: (u8, u8, u8, u8), dst_point: (u8, u8)) -> (TestDisplay, Result<(), Error>) {
+        let mut display = TestDisplay::new();
+        let result = blit_region(&mut display, flash, slot, src_rect, dst_point, &mut Buffers::new());
+        (display, result)
+    }
+
+    /// Check that the display shows the logo rectangle from (`left`, `top`) of `width` x `height` at (`dst_left`, `dst_top`)
+    fn assert_blitted(display: &TestDisplay, (left, top): (usize, usize), (width, height): (usize, usize), (dst_left, dst_top): (usize, usize)) {
+        assert_eq!((display.rows, display.flushes), (height, 1));
+        for y in 0..MAX_HEIGHT {
+            for x in 0..MAX_WIDTH {
+                let inside = x >= dst_left && x < dst_left + width && y >= dst_top && y < dst_top + height;
+                let expected = if inside { test_color(x - dst_left + left, y - dst_top + top) } else { 0 };
+                assert_eq!(display.pixel(x, y), expected, "pixel {}, {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn blits_partial_rect() {
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            let mut memory = vec![0; SLOT_COUNT * SLOT_SIZE as usize];
+            let mut flash = RamFlash::new(&mut memory);
+            write_logo_slot(&mut flash, 100, 60, codec);
+            let (display, result) = blit(&mut flash, 1, (10, 5, 29, 14), (50, 70));
+            assert_eq!(result, Ok(()), "{:?}", codec);
+            assert_blitted(&display, (10, 5), (20, 10), (50, 70));
+        }
+    }
+
+    #[test]
+    fn blit_clips_rect_to_logo_and_display() {
+        for &codec in &[ Codec::Raw, Codec::Rle ] {
+            //  Clipped to the bottom right corner of the logo
+            let mut memory = vec![0; SLOT_COUNT * SLOT_SIZE as usize];
+            let mut flash = RamFlash::new(&mut memory);
+            write_logo_slot(&mut flash, 100, 60, codec);
+            let (display, result) = blit(&mut flash, 1, (90, 50, 200, 200), (0, 0));
+            assert_eq!(result, Ok(()), "{:?}", codec);
+            assert_blitted(&display, (90, 50), (10, 10), (0, 0));
+
+            //  Clipped to the bottom right corner of the display
+            let (display, result) = blit(&mut flash, 1, (0, 0, 49, 49), (230, 235));
+            assert_eq!(result, Ok(()), "{:?}", codec);
+            assert_blitted(&display, (0, 0), (10, 5), (230, 235));
+        }
+    }
+
+    #[test]
+    fn blit_rejects_rects_out_of_bounds() {
+        let mut memory = vec![0; SLOT_COUNT * SLOT_SIZE as usize];
+        let mut flash = RamFlash::new(&mut memory);
+        write_logo_slot(&mut flash, 100, 60, Codec::Rle);
+        let cases = [
+            ((20, 5, 10, 14),    (0, 0)),    //  Inverted columns
+            ((10, 14, 20, 5),    (0, 0)),    //  Inverted rows
+            ((100, 0, 120, 10),  (0, 0)),    //  Right of the logo
+            ((0, 60, 10, 70),    (0, 0)),    //  Below the logo
+            ((0, 0, 10, 10),     (240, 0)),  //  Right of the display
+            ((0, 0, 10, 10),     (0, 250)),  //  Below the display
+        ];
+        for &(src_rect, dst_point) in cases.iter() {
+            let (display, result) = blit(&mut flash, 1, src_rect, dst_point);
+            assert_eq!(result, Err(Error::Invalid), "{:?} at {:?}", src_rect, dst_point);
+            assert_eq!((display.rows, display.flushes), (0, 0));
+        }
+    }
+
+    #[test]
+    fn blit_fails_without_logo() {
+        let mut memory = vec![0; SLOT_COUNT * SLOT_SIZE as usize];
+        let mut flash = RamFlash::new(&mut memory);
+        write_logo_slot(&mut flash, 100, 60, Codec::Raw);
+        let (display, result) = blit(&mut flash, 0, (0, 0, 9, 9), (0, 0));
+        assert_eq!(result, Err(Error::NotFound));
+        assert_eq!(display.rows, 0);
+        let (_, result) = blit(&mut flash, SLOT_COUNT, (0, 0, 9, 9), (0, 0));
+        assert_eq!(result, Err(Error::OutOfRange));
+    }
+}
@@ -0,0 +1,228 @@
+//! Streaming decoder for run-length encoded RGB565 pixels. `encode_rle()` is the encoder used by
+//! `rust/pinetime-graphic`.
+//! The payload is a sequence of packets, each starting with a Control Byte:
+//! - `0x80 | (n - 1)`: Run of `n` identical pixels (1 to 128), followed by one 2-byte pixel
+//! - `n - 1`: Literal of `n` pixels (1 to 128), followed by `n` 2-byte pixels
+//!
+//! The encoder packs runs of 2 or more identical pixels into Run Packets and everything else into Literal Packets.
+//! The decoder may be fed any number of bytes at a time, so packets may span batches read from flash.
+
+/// Max number of pixels in a Run Packet or Literal Packet
+pub const MAX_PACKET_PIXELS: usize = 128;
+
+/// Decoder state between calls to `decode()`
+#[derive(Clone, Copy, Debug)]
+enum State {
+    /// Expecting a Control Byte
+    Control,
+    /// Reading the pixel to be repeated. `high` is the high byte, if already read.
+    RunPixel { bytes: u16, high: Option<u8> },
+    /// Writing the repeated pixel. `bytes` is the number of bytes remaining.
+    Run { bytes: u16, pixel: [u8; 2] },
+    /// Copying literal pixels. `bytes` is the number of bytes remaining.
+    Literal { bytes: u16 },
+}
+
+/// Streaming decoder for run-length encoded RGB565 pixels
+#[derive(Clone, Debug)]
+pub struct RleDecoder {
+    /// Current decoder state
+    state: State,
+}
+
+impl Default for RleDecoder {
+    fn default() -> Self {
+        RleDecoder::new()
+    }
+}
+
+impl RleDecoder {
+    /// Create a new decoder, expecting a Control Byte
+    pub fn new() -> RleDecoder {
+        RleDecoder { state: State::Control }
+    }
+
+    /// Decode the `input` bytes into `output`. Returns the number of bytes consumed from `input`
+    /// and the number of bytes written to `output`. Stops when `input` is exhausted or `output` is full.
+    pub fn decode(&mut self, input: &[u8], output: &mut [u8]) -> (usize, usize) {
+        let mut i = 0;  //  Next input byte
+        let mut o = 0;  //  Next output byte
+        while o < output.len() {
+            match self.state {
+                State::Control => {
+                    if i >= input.len() { break; }
+                    let control = input[i];
+                    i += 1;
+                    let bytes = ((control & 0x7f) as u16 + 1) * 2;  //  Number of pixels * 2
+                    self.state =
+                        if control & 0x80 != 0 { State::RunPixel { bytes, high: None } }
+                        else { State::Literal { bytes } };
+                }
+                State::RunPixel { bytes, high: None } => {
+                    if i >= input.len() { break; }
+                    self.state = State::RunPixel { bytes, high: Some(input[i]) };
+                    i += 1;
+                }
+                State::RunPixel { bytes, high: Some(high) } => {
+                    if i >= input.len() { break; }
+                    self.state = State::Run { bytes, pixel: [ high, input[i] ] };
+                    i += 1;
+                }
+                State::Run { bytes, pixel } => {
+                    //  Even count means high byte, odd count means low byte
+                    output[o] = pixel[(bytes & 1) as usize];
+                    o += 1;
+                    self.state =
+                        if bytes > 1 { State::Run { bytes: bytes - 1, pixel } }
+                        else { State::Control };
+                }
+                State::Literal { bytes } => {
+                    if i >= input.len() { break; }
+                    let len = (bytes as usize)
+                        .min(input.len() - i)
+                        .min(output.len() - o);
+                    output[o..o + len].copy_from_slice(&input[i..i + len]);
+                    i += len;
+                    o += len;
+                    self.state =
+                        if bytes as usize > len { State::Literal { bytes: bytes - len as u16 } }
+                        else { State::Control };
+                }
+            }
+        }
+        (i, o)
+    }
+}
+
+/// Run-length encode the RGB565 `pixels`. `emit` is called with each Control Byte and the pixels that follow,
+/// so that the encoder doesn't need to allocate memory.
+pub fn encode_rle<F>(pixels: &[u8], mut emit: F)
+where F: FnMut(&[u8]) {
+    assert_eq!(pixels.len() % 2, 0, "odd number of bytes");
+    let pixel = |i: usize| &pixels[i * 2..i * 2 + 2];
+    let count = pixels.len() / 2;
+    let mut literal_start = 0;  //  First pixel of the pending Literal Packet
+    let mut i = 0;
+    while i < count {
+        //  Count the identical pixels starting at `i`
+        let mut run = 1;
+        while i + run < count && run < MAX_PACKET_PIXELS && pixel(i + run) == pixel(i) {
+            run += 1;
+        }
+        if run >= 2 {
+            //  Flush the pending literals, then write the run
+            emit_literals(&pixels[literal_start * 2..i * 2], &mut emit);
+            emit(&[ 0x80 | (run - 1) as u8 ]);
+            emit(pixel(i));
+            i += run;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    emit_literals(&pixels[literal_start * 2..], &mut emit);
+}
+
+/// Emit the pixels as Literal Packets of up to 128 pixels
+fn emit_literals<F>(pixels: &[u8], emit: &mut F)
+where F: FnMut(&[u8]) {
+    for packet in pixels.chunks(MAX_PACKET_PIXELS * 2) {
+        emit(&[ (packet.len() / 2 - 1) as u8 ]);
+        emit(packet);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::vec::Vec;
+    use super::*;
+
+    /// Encode the pixels into a vector
+    fn encode(pixels: &[u8]) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        encode_rle(pixels, |bytes| encoded.extend_from_slice(bytes));
+        encoded
+    }
+
+    /// Decode the `encoded` pixels, feeding `input_batch` bytes and accepting `output_batch` bytes at a time
+    fn decode(encoded: &[u8], len: usize, input_batch: usize, output_batch: usize) -> Vec<u8> {
+        let mut decoder = RleDecoder::new();
+        let mut decoded = Vec::new();
+        let mut output = [0u8; 512];
+        let mut pos = 0;
+        while decoded.len() < len {
+            let input = &encoded[pos..(pos + input_batch).min(encoded.len())];
+            let (consumed, produced) = decoder.decode(input, &mut output[..output_batch]);
+            assert!(consumed > 0 || produced > 0, "decoder stalled at input {}", pos);
+            pos += consumed;
+            decoded.extend_from_slice(&output[..produced]);
+        }
+        assert_eq!(pos, encoded.len(), "input not consumed");
+        decoded
+    }
+
+    /// Return a test image with runs of every length up to 300 pixels, separated by literals
+    fn test_pixels() -> Vec<u8> {
+        let mut pixels = Vec::new();
+        for run in 1..300u16 {
+            for _ in 0..run { pixels.extend_from_slice(&run.to_be_bytes()); }
+            for i in 0..run % 7 { pixels.extend_from_slice(&(0x8000 | run << 3 | i).to_be_bytes()); }
+        }
+        pixels
+    }
+
+    #[test]
+    fn encodes_runs_and_literals() {
+        let pixels = [ 0x12, 0x34, 0x12, 0x34, 0x12, 0x34, 0xab, 0xcd, 0x00, 0x01 ];
+        assert_eq!(encode(&pixels), [ 0x82, 0x12, 0x34, 0x01, 0xab, 0xcd, 0x00, 0x01 ]);
+        assert!(encode(&[]).is_empty());
+    }
+
+    #[test]
+    fn splits_long_packets() {
+        //  300 identical pixels become runs of 128, 128 and 44 pixels
+        let pixels: Vec<u8> = [ 0xf8, 0x00 ].iter().cycle().take(600).copied().collect();
+        assert_eq!(encode(&pixels), [ 0xff, 0xf8, 0x00, 0xff, 0xf8, 0x00, 0xab, 0xf8, 0x00 ]);
+
+        //  200 different pixels become literals of 128 and 72 pixels
+        let pixels: Vec<u8> = (0..200u16).flat_map(|i| i.to_be_bytes()).collect();
+        let encoded = encode(&pixels);
+        assert_eq!(encoded.len(), 2 + 400);
+        assert_eq!(encoded[0], 127);
+        assert_eq!(encoded[1 + 256], 71);
+        assert_eq!(decode(&encoded, pixels.len(), encoded.len(), 512), pixels);
+    }
+
+    #[test]
+    fn round_trip() {
+        let pixels = test_pixels();
+        let encoded = encode(&pixels);
+        assert!(encoded.len() < pixels.len());
+        assert_eq!(decode(&encoded, pixels.len(), encoded.len(), 512), pixels);
+    }
+
+    #[test]
+    fn round_trip_split_across_batches() {
+        //  Packets span the batches read from flash and the rows decoded, at every alignment
+        let pixels = test_pixels();
+        let encoded = encode(&pixels);
+        for &input_batch in &[ 1, 2, 3, 5, 255, 256 ] {
+            for &output_batch in &[ 1, 2, 3, 7, 480 ] {
+                assert_eq!(decode(&encoded, pixels.len(), input_batch, output_batch), pixels,
+                    "input batch {}, output batch {}", input_batch, output_batch);
+            }
+        }
+    }
+
+    #[test]
+    fn pending_run_needs_no_input() {
+        //  The rest of a run is decoded after the input is used up
+        let mut decoder = RleDecoder::new();
+        let mut output = [0u8; 4];
+        assert_eq!(decoder.decode(&[ 0x83, 0xab, 0xcd ], &mut output), (3, 4));
+        assert_eq!(decoder.decode(&[], &mut output), (0, 4));
+        assert_eq!(output, [ 0xab, 0xcd, 0xab, 0xcd ]);
+        assert_eq!(decoder.decode(&[], &mut output), (0, 0));
+    }
+}
@@ -0,0 +1,338 @@
+//! Boot logo slots in SPI Flash. The Bootloader Assets area at the start of SPI Flash (256 KB, see
+//! `FLASH_AREA_BOOTLOADER_ASSET` in `hw/bsp/nrf52/bsp.yml`) is divided into slots, each holding one
+//! logo or animation container. The slot table in the last sector of the area records the active slot.
+//! Must match the slot definitions in `libs/pinetime_boot/include/pinetime_boot/pinetime_boot.h`.
+//! ```text
+//! Offset    Size      Contents
+//! 0x00000   0x1D000   Slot 0: Logo or animation container (also the legacy single logo location)
+//! 0x1D000   0x1D000   Slot 1: Logo or animation container
+//! 0x3F000   0x01000   Slot Table: Magic "PTST", Version (u16), Active Slot (u8), Slot Count (u8)
+//! ```
+//! The slot logic accesses flash through the `LogoFlash` trait, implemented by `mynewt::hal::Flash` for SPI Flash
+//! (device 1) and by `RamFlash` for testing the slot logic on the host.
+use crate::{
+    anim::{ self, AnimHeader },
+    crc32,
+    flash::LogoFlash,
+    header::LogoHeader,
+    render::{ verify_logo, BATCH_SIZE },
+    Error,
+};
+
+/// Number of logo slots
+pub const SLOT_COUNT: usize = 2;
+
+/// Size of each slot in bytes. Fits a raw 240 x 240 logo (115,220 bytes) rounded up to sectors.
+pub const SLOT_SIZE: u32 = 0x1D000;
+
+/// Offset of the slot table in SPI Flash: the last sector of the Bootloader Assets area
+pub const TABLE_OFFSET: u32 = 0x3F000;
+
+pub use crate::flash::SECTOR_SIZE;  //  Size of an erasable SPI Flash sector
+
+/// Magic number at the start of the slot table: "PTST"
+pub const TABLE_MAGIC: [u8; 4] = *b"PTST";
+
+/// Current version of the slot table
+pub const TABLE_VERSION: u16 = 1;
+
+/// Size of the slot table in bytes
+pub const TABLE_SIZE: usize = 8;
+
+/// Status of a logo slot
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SlotStatus {
+    /// Slot has been erased or never written
+    Empty,
+    /// Slot contains a logo that failed verification
+    Corrupted,
+    /// Slot contains a verified logo with this header
+    Valid(LogoHeader),
+    /// Slot contains a verified animation with this header
+    Animation(AnimHeader),
+}
+
+impl SlotStatus {
+    /// Return true if the slot contains a verified logo or animation
+    pub fn is_valid(&self) -> bool {
+        match self {
+            SlotStatus::Valid(_) | SlotStatus::Animation(_) => true,
+            SlotStatus::Empty | SlotStatus::Corrupted => false,
+        }
+    }
+}
+
+/// Return the flash offset of the logo container in `slot`. Fail with `OutOfRange` if the slot doesn't exist.
+pub fn slot_offset(slot: usize) -> Result<u32, Error> {
+    if slot >= SLOT_COUNT { return Err(Error::OutOfRange); }
+    Ok(slot as u32 * SLOT_SIZE)
+}
+
+/// Write the logo `payload` with its `header` into `slot`, replacing the previous logo.
+/// The header is written last, so an interrupted write leaves the slot empty. Fail with `Invalid` if the header
+/// doesn't match the payload, `NoMemory` if the logo doesn't fit in the slot.
+pub fn write_slot<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize, header: &LogoHeader, payload: &[u8]) -> Result<(), F::Error> {
+    if header.payload_len as usize != payload.len() ||
+        header.crc32 != crc32(0, payload) {
+        return Err(Error::Invalid.into());
+    }
+    write_container(flash, slot, &header.to_bytes(), payload)
+}
+
+/// Write the animation `payload` (all frames) with its `header` into `slot`, replacing the previous logo.
+/// The header is written last, so an interrupted write leaves the slot empty.
+pub fn write_animation_slot<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize, header: &AnimHeader, payload: &[u8]) -> Result<(), F::Error> {
+    if header.payload_len as usize != payload.len() ||
+        header.crc32 != crc32(0, payload) {
+        return Err(Error::Invalid.into());
+    }
+    write_container(flash, slot, &header.to_bytes(), payload)
+}
+
+/// Erase the sectors used by the container in `slot`, then write the `payload` followed by the `header_bytes`
+fn write_container<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize, header_bytes: &[u8], payload: &[u8]) -> Result<(), F::Error> {
+    let offset = slot_offset(slot) ? ;
+    let total = (header_bytes.len() + payload.len()) as u32;
+    if total > SLOT_SIZE { return Err(Error::NoMemory.into()); }
+
+    let erase_len = (total + SECTOR_SIZE - 1) / SECTOR_SIZE * SECTOR_SIZE;
+    flash.erase(offset, erase_len) ? ;
+    flash.write(offset + header_bytes.len() as u32, payload) ? ;
+    flash.write(offset, header_bytes) ? ;
+    Ok(())
+}
+
+/// Erase the logo in `slot`. Only the sector containing the header is erased.
+pub fn erase_slot<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize) -> Result<(), F::Error> {
+    let offset = slot_offset(slot) ? ;
+    flash.erase(offset, SECTOR_SIZE)
+}
+
+/// Return the status of `slot`. Valid logos and animations are verified against the CRC32 of the payload.
+pub fn slot_status<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize) -> Result<SlotStatus, F::Error> {
+    let offset = slot_offset(slot) ? ;
+    let not_found = F::Error::from(Error::NotFound);
+    let mut buf = [0u8; BATCH_SIZE];
+    let mut read = |offset, buf: &mut [u8]| flash.read(offset, buf);
+    match verify_logo(offset, &mut read, &mut buf) {
+        Ok(header) => return Ok(SlotStatus::Valid(header)),
+        Err(err) if err == not_found => {}  //  Not a logo, check for animation
+        Err(_) => return Ok(SlotStatus::Corrupted),
+    }
+    match anim::verify_animation(offset, &mut read, &mut buf) {
+        Ok(header) => Ok(SlotStatus::Animation(header)),
+        Err(err) if err == not_found => Ok(SlotStatus::Empty),
+        Err(_) => Ok(SlotStatus::Corrupted),
+    }
+}
+
+/// Return the status of every slot
+pub fn list_slots<F: LogoFlash + ?Sized>(flash: &mut F) -> Result<[SlotStatus; SLOT_COUNT], F::Error> {
+    let mut slots = [SlotStatus::Empty; SLOT_COUNT];
+    for (slot, status) in slots.iter_mut().enumerate() {
+        *status = slot_status(flash, slot) ? ;
+    }
+    Ok(slots)
+}
+
+/// Return the active slot recorded in the slot table. Slot 0 is active if the table is missing or invalid.
+pub fn active_slot<F: LogoFlash + ?Sized>(flash: &mut F) -> Result<usize, F::Error> {
+    let mut table = [0u8; TABLE_SIZE];
+    flash.read(TABLE_OFFSET, &mut table) ? ;
+    let version = u16::from_le_bytes([ table[4], table[5] ]);
+    let active  = table[6] as usize;
+    if table[0..4] != TABLE_MAGIC || version != TABLE_VERSION || active >= SLOT_COUNT {
+        return Ok(0);
+    }
+    Ok(active)
+}
+
+/// Record `slot` as the active slot in the slot table
+pub fn set_active_slot<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize) -> Result<(), F::Error> {
+    slot_offset(slot) ? ;
+    let mut table = [0u8; TABLE_SIZE];
+    table[0..4].copy_from_slice(&TABLE_MAGIC);
+    table[4..6].copy_from_slice(&TABLE_VERSION.to_le_bytes());
+    table[6] = slot as u8;
+    table[7] = SLOT_COUNT as u8;
+    flash.erase(TABLE_OFFSET, SECTOR_SIZE) ? ;
+    flash.write(TABLE_OFFSET, &table)
+}
+
+/// Return the next slot after `slot` that contains a valid logo, wrapping around to slot 0.
+/// Returns `slot` itself if no other slot is valid, `None` if no slot is valid.
+pub fn next_slot<F: LogoFlash + ?Sized>(flash: &mut F, slot: usize) -> Result<Option<usize>, F::Error> {
+    for i in 1..=SLOT_COUNT {
+        let next = (slot + i) % SLOT_COUNT;
+        if slot_status(flash, next)?.is_valid() {
+            return Ok(Some(next));
+        }
+    }
+    Ok(None)
+}
+
+/// Return the slot to be displayed at boot: the active slot if valid, else the next valid slot.
+/// Returns `None` if no slot is valid.
+pub fn select_slot<F: LogoFlash + ?Sized>(flash: &mut F) -> Result<Option<usize>, F::Error> {
+    let active = active_slot(flash) ? ;
+    if slot_status(flash, active)?.is_valid() {
+        return Ok(Some(active));
+    }
+    next_slot(flash, active)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::{ vec, vec::Vec };
+    use crate::{
+        anim::{ FrameHeader, ANIM_HEADER_SIZE, FRAME_HEADER_SIZE },
+        header::{ Codec, HEADER_SIZE },
+        RamFlash,
+    };
+    use super::*;
+
+    /// Size of the test flash: the Bootloader Assets area including the slot table
+    const FLASH_SIZE: usize = (TABLE_OFFSET + SECTOR_SIZE) as usize;
+
+    /// Return a raw 2 x 2 logo with all pixels set to `color`
+    fn logo(color: u16) -> (LogoHeader, Vec<u8>) {
+        let payload: Vec<u8> = (0..4).flat_map(|_| color.to_be_bytes()).collect();
+        (LogoHeader::new(2, 2, Codec::Raw, &payload), payload)
+    }
+
+    /// Return a 2 x 2 animation with 2 frames. The second frame changes one pixel.
+    fn animation() -> (AnimHeader, Vec<u8>) {
+        let first = FrameHeader { delay_ms: 100, left: 0, top: 0, right: 1, bottom: 1, codec: Codec::Raw, data_len: 8 };
+        let second = FrameHeader { delay_ms: 100, left: 1, top: 1, right: 1, bottom: 1, codec: Codec::Raw, data_len: 2 };
+        let mut payload = Vec::new();
+        payload.extend_from_slice(&first.to_bytes());
+        payload.extend_from_slice(&[ 0xf8, 0x00 ].repeat(4));
+        payload.extend_from_slice(&second.to_bytes());
+        payload.extend_from_slice(&[ 0x07, 0xe0 ]);
+        (AnimHeader::new(2, 2, 2, &payload), payload)
+    }
+
+    #[test]
+    fn erased_flash_has_no_logos() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        assert_eq!(list_slots(&mut flash), Ok([ SlotStatus::Empty; SLOT_COUNT ]));
+        assert_eq!(active_slot(&mut flash), Ok(0));
+        assert_eq!(next_slot(&mut flash, 0), Ok(None));
+        assert_eq!(select_slot(&mut flash), Ok(None));
+    }
+
+    #[test]
+    fn writes_and_erases_slots() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, payload) = logo(0xf800);
+        write_slot(&mut flash, 1, &header, &payload).unwrap();
+        assert_eq!(list_slots(&mut flash), Ok([ SlotStatus::Empty, SlotStatus::Valid(header) ]));
+
+        //  Rewriting a slot erases the previous logo
+        let (header, payload) = logo(0x07e0);
+        write_slot(&mut flash, 1, &header, &payload).unwrap();
+        assert_eq!(slot_status(&mut flash, 1), Ok(SlotStatus::Valid(header)));
+
+        let (header, payload) = animation();
+        write_animation_slot(&mut flash, 0, &header, &payload).unwrap();
+        assert_eq!(slot_status(&mut flash, 0), Ok(SlotStatus::Animation(header)));
+
+        erase_slot(&mut flash, 1).unwrap();
+        assert_eq!(slot_status(&mut flash, 1), Ok(SlotStatus::Empty));
+        assert_eq!(slot_status(&mut flash, 0), Ok(SlotStatus::Animation(header)));
+    }
+
+    #[test]
+    fn rejects_bad_writes() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, payload) = logo(0xf800);
+        assert_eq!(write_slot(&mut flash, SLOT_COUNT, &header, &payload), Err(Error::OutOfRange));
+        assert_eq!(write_slot(&mut flash, 0, &header, &payload[1..]), Err(Error::Invalid));
+
+        //  CRC32 of the payload must match the header
+        let mut bad_payload = payload.clone();
+        bad_payload[0] ^= 1;
+        assert_eq!(write_slot(&mut flash, 0, &header, &bad_payload), Err(Error::Invalid));
+
+        //  Logo must fit in the slot
+        let big_payload = vec![0; SLOT_SIZE as usize];
+        let big_header = LogoHeader::new(240, 240, Codec::Rle, &big_payload);
+        assert_eq!(write_slot(&mut flash, 0, &big_header, &big_payload), Err(Error::NoMemory));
+        assert_eq!(list_slots(&mut flash), Ok([ SlotStatus::Empty; SLOT_COUNT ]));
+    }
+
+    #[test]
+    fn detects_corrupted_and_interrupted_slots() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+
+        //  Corrupt a pixel of the logo and a pixel of the animation after computing the CRC32
+        let (header, mut payload) = logo(0xf800);
+        payload[0] ^= 1;
+        flash.write(HEADER_SIZE as u32, &payload).unwrap();
+        flash.write(0, &header.to_bytes()).unwrap();
+        let (header, mut payload) = animation();
+        payload[FRAME_HEADER_SIZE] ^= 1;
+        flash.write(SLOT_SIZE + ANIM_HEADER_SIZE as u32, &payload).unwrap();
+        flash.write(SLOT_SIZE, &header.to_bytes()).unwrap();
+        assert_eq!(list_slots(&mut flash), Ok([ SlotStatus::Corrupted; SLOT_COUNT ]));
+        assert_eq!(select_slot(&mut flash), Ok(None));
+
+        //  Write interrupted before the header: the slot is empty
+        erase_slot(&mut flash, 0).unwrap();
+        let (_, payload) = logo(0x001f);
+        flash.write(HEADER_SIZE as u32, &payload).unwrap();
+        assert_eq!(slot_status(&mut flash, 0), Ok(SlotStatus::Empty));
+    }
+
+    #[test]
+    fn records_active_slot() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        assert_eq!(set_active_slot(&mut flash, SLOT_COUNT), Err(Error::OutOfRange));
+        set_active_slot(&mut flash, 1).unwrap();
+        assert_eq!(active_slot(&mut flash), Ok(1));
+        set_active_slot(&mut flash, 0).unwrap();
+        assert_eq!(active_slot(&mut flash), Ok(0));
+
+        //  Table with a bad version or slot selects slot 0
+        set_active_slot(&mut flash, 1).unwrap();
+        let mut table = [0u8; TABLE_SIZE];
+        flash.read(TABLE_OFFSET, &mut table).unwrap();
+        for (index, value) in [ (4, TABLE_VERSION as u8 + 1), (6, SLOT_COUNT as u8) ].iter() {
+            let mut bad_table = table;
+            bad_table[*index] = *value;
+            flash.erase(TABLE_OFFSET, SECTOR_SIZE).unwrap();
+            flash.write(TABLE_OFFSET, &bad_table).unwrap();
+            assert_eq!(active_slot(&mut flash), Ok(0));
+        }
+    }
+
+    #[test]
+    fn selects_next_valid_slot() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, payload) = logo(0xf800);
+        write_slot(&mut flash, 1, &header, &payload).unwrap();
+
+        //  Active slot 0 is empty, so slot 1 is selected. Slot 1 is the only valid slot, so it's next to itself.
+        assert_eq!(select_slot(&mut flash), Ok(Some(1)));
+        assert_eq!(next_slot(&mut flash, 1), Ok(Some(1)));
+        assert_eq!(next_slot(&mut flash, 0), Ok(Some(1)));
+
+        //  Next slot wraps around to slot 0
+        write_slot(&mut flash, 0, &header, &payload).unwrap();
+        assert_eq!(next_slot(&mut flash, 1), Ok(Some(0)));
+        assert_eq!(select_slot(&mut flash), Ok(Some(0)));
+        set_active_slot(&mut flash, 1).unwrap();
+        assert_eq!(select_slot(&mut flash), Ok(Some(1)));
+
+        //  Active slot is corrupted, so the next valid slot is selected
+        erase_slot(&mut flash, 1).unwrap();
+        assert_eq!(select_slot(&mut flash), Ok(Some(0)));
+    }
+}
@@ -0,0 +1,55 @@
+//! Test doubles shared by the tests of the renderer and the animation player: a display that records the pixels
+//! in a frame buffer, and a flash reader for flash contents in memory.
+extern crate std;
+use std::{ vec, vec::Vec };
+use crate::{ render::LogoDisplay, Error, BYTES_PER_PIXEL, MAX_HEIGHT, MAX_WIDTH };
+
+/// Display that records the pixels in a frame buffer
+pub struct TestDisplay {
+    /// RGB565 pixels, big-endian, row by row
+    pub frame:   Vec<u8>,
+    /// Number of rows written
+    pub rows:    usize,
+    /// Number of flushes
+    pub flushes: usize,
+}
+
+impl TestDisplay {
+    pub fn new() -> TestDisplay {
+        TestDisplay { frame: vec![0; MAX_WIDTH * MAX_HEIGHT * BYTES_PER_PIXEL], rows: 0, flushes: 0 }
+    }
+
+    /// Return the pixel at (`x`, `y`)
+    pub fn pixel(&self, x: usize, y: usize) -> u16 {
+        let i = (y * MAX_WIDTH + x) * BYTES_PER_PIXEL;
+        u16::from_be_bytes([ self.frame[i], self.frame[i + 1] ])
+    }
+}
+
+impl LogoDisplay for TestDisplay {
+    type Error = Error;
+
+    fn write_row(&mut self, left: u8, y: u8, right: u8, pixels: &[u8]) -> Result<(), Error> {
+        assert!(left <= right && (right as usize) < MAX_WIDTH && (y as usize) < MAX_HEIGHT, "row outside display");
+        assert_eq!(pixels.len(), (right - left + 1) as usize * BYTES_PER_PIXEL, "bad row length");
+        let start = (y as usize * MAX_WIDTH + left as usize) * BYTES_PER_PIXEL;
+        self.frame[start..start + pixels.len()].copy_from_slice(pixels);
+        self.rows += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.flushes += 1;
+        Ok(())
+    }
+}
+
+/// Return a closure that reads from the flash contents. Fail with `OutOfRange` if outside the flash.
+pub fn reader(flash: &[u8]) -> impl FnMut(u32, &mut [u8]) -> Result<(), Error> + '_ {
+    move |offset, buf| {
+        let start = offset as usize;
+        let bytes = flash.get(start..start + buf.len()).ok_or(Error::OutOfRange)?;
+        buf.copy_from_slice(bytes);
+        Ok(())
+    }
+}
@@ -0,0 +1,478 @@
+//! Upload a boot logo or animation container into a logo slot over Bluetooth LE. Called by the Logo Upload
+//! GATT service in `apps/my_sensor_app/src/ble_gatt_svr.c` through `rust/app/src/logo_upload.rs`.
+//! Chunks may arrive out of order or more than once.
+//! The container header is kept in RAM and written last on commit, so the slot never holds a partial container.
+//! All fields are little-endian:
+//! ```text
+//! Control Characteristic (write):
+//!   Begin:  0x01, Slot (u8), Chunk Size (u16), Total Length (u32), CRC32 of the container (u32)
+//!   Commit: 0x02
+//!   Abort:  0x03
+//!
+//! Data Characteristic (write): Offset (u32), CRC32 of the chunk data (u32), Chunk data
+//!   Offset must be a multiple of Chunk Size. Every chunk has Chunk Size bytes, except the last chunk.
+//!
+//! Status Characteristic (read):
+//!   State (u8), Slot (u8), Chunks Received (u16), Chunk Count (u16), First Missing Chunk (u16, 0xFFFF if none)
+//! ```
+//! The first sector of the slot is erased on Begin, so the slot stays empty until the upload is committed.
+//! Upload into the inactive slot to keep the current logo until the new logo is committed.
+use crate::{
+    crc32,
+    flash::LogoFlash,
+    header::HEADER_SIZE,
+    slots::{ self, SECTOR_SIZE, SLOT_SIZE },
+    Error,
+};
+
+/// Control command: Begin an upload
+pub const CMD_BEGIN: u8 = 1;
+
+/// Control command: Commit the upload
+pub const CMD_COMMIT: u8 = 2;
+
+/// Control command: Abort the upload
+pub const CMD_ABORT: u8 = 3;
+
+/// Size of the Begin command in bytes
+pub const BEGIN_SIZE: usize = 12;
+
+/// Size of the chunk header in bytes: Offset and CRC32
+pub const CHUNK_HEADER_SIZE: usize = 8;
+
+/// Size of the status in bytes
+pub const STATUS_SIZE: usize = 8;
+
+/// Min number of bytes per chunk. Fits in the default ATT MTU of 23 bytes.
+pub const MIN_CHUNK_SIZE: u16 = 12;
+
+/// Max number of bytes per chunk. Fits in the max ATT MTU of 527 bytes.
+pub const MAX_CHUNK_SIZE: u16 = 512;
+
+/// Max number of chunks in an upload
+const MAX_CHUNKS: usize = SLOT_SIZE as usize / MIN_CHUNK_SIZE as usize + 1;
+
+/// Number of sectors in a slot. Each sector is tracked by a bit in `erased`.
+const SLOT_SECTORS: u32 = SLOT_SIZE / SECTOR_SIZE;
+
+/// Size of the buffer for reading back the uploaded bytes from flash
+const READ_SIZE: usize = 64;
+
+/// State of the upload
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UploadState {
+    /// No upload in progress
+    Idle      = 0,
+    /// Receiving chunks
+    Receiving = 1,
+    /// Last upload was committed
+    Committed = 2,
+}
+
+/// Upload of a container into a logo slot
+pub struct LogoUpload {
+    /// State of the upload
+    state:       UploadState,
+    /// Slot that receives the container
+    slot:        usize,
+    /// Number of bytes per chunk
+    chunk_size:  u16,
+    /// Size of the container in bytes
+    total_len:   u32,
+    /// CRC32 of the container
+    crc32:       u32,
+    /// Number of distinct chunks received
+    received:    u16,
+    /// Bitmap of received chunks
+    chunks:      [u8; (MAX_CHUNKS + 7) / 8],
+    /// Bitmap of the slot sectors that have been erased
+    erased:      u32,
+    /// Container header, written to flash on commit
+    header:      [u8; HEADER_SIZE],
+}
+
+impl Default for LogoUpload {
+    fn default() -> Self {
+        LogoUpload::new()
+    }
+}
+
+impl LogoUpload {
+    /// Create an idle upload
+    pub const fn new() -> LogoUpload {
+        LogoUpload {
+            state:      UploadState::Idle,
+            slot:       0,
+            chunk_size: 0,
+            total_len:  0,
+            crc32:      0,
+            received:   0,
+            chunks:     [0; (MAX_CHUNKS + 7) / 8],
+            erased:     0,
+            header:     [0; HEADER_SIZE],
+        }
+    }
+
+    /// Handle a write to the Control Characteristic. Fail with `Invalid` if the command is invalid.
+    pub fn control<F: LogoFlash + ?Sized>(&mut self, flash: &mut F, command: &[u8]) -> Result<(), F::Error> {
+        match command.first() {
+            Some(&CMD_BEGIN) if command.len() == BEGIN_SIZE => {
+                let chunk_size = u16::from_le_bytes([ command[2], command[3] ]);
+                let total_len  = u32::from_le_bytes([ command[4], command[5], command[6], command[7] ]);
+                let crc32      = u32::from_le_bytes([ command[8], command[9], command[10], command[11] ]);
+                self.begin(flash, command[1] as usize, chunk_size, total_len, crc32)
+            }
+            Some(&CMD_COMMIT) if command.len() == 1 => self.commit(flash),
+            Some(&CMD_ABORT)  if command.len() == 1 => { self.abort(); Ok(()) }
+            _ => Err(Error::Invalid.into()),
+        }
+    }
+
+    /// Begin uploading a container of `total_len` bytes with checksum `crc32` into `slot`, in chunks of
+    /// `chunk_size` bytes. Restarts any upload in progress. Erases the first sector of the slot, so the slot
+    /// is empty until the upload is committed. Fail with `OutOfRange` if the slot doesn't exist,
+    /// `Invalid` if the chunk size is invalid, `NoMemory` if the container won't fit in the slot.
+    pub fn begin<F: LogoFlash + ?Sized>(&mut self, flash: &mut F, slot: usize, chunk_size: u16, total_len: u32, crc32: u32) -> Result<(), F::Error> {
+        let offset = slots::slot_offset(slot) ? ;
+        if !(MIN_CHUNK_SIZE..=MAX_CHUNK_SIZE).contains(&chunk_size) { return Err(Error::Invalid.into()); }
+        if total_len <= HEADER_SIZE as u32 { return Err(Error::Invalid.into()); }
+        if total_len > SLOT_SIZE { return Err(Error::NoMemory.into()); }
+
+        *self = LogoUpload::new();
+        flash.erase(offset, SECTOR_SIZE) ? ;
+        self.state      = UploadState::Receiving;
+        self.slot       = slot;
+        self.chunk_size = chunk_size;
+        self.total_len  = total_len;
+        self.crc32      = crc32;
+        self.erased     = 1;  //  First sector erased
+        self.header     = [0xff; HEADER_SIZE];
+        Ok(())
+    }
+
+    /// Handle a write to the Data Characteristic: a chunk header followed by the chunk data.
+    /// Duplicate chunks are accepted if they match the chunk received earlier. Fail with `Invalid` if no
+    /// upload is in progress or the chunk is invalid, `AlreadyWritten` if a duplicate chunk has different data.
+    pub fn write_chunk<F: LogoFlash + ?Sized>(&mut self, flash: &mut F, chunk: &[u8]) -> Result<(), F::Error> {
+        if self.state != UploadState::Receiving || chunk.len() < CHUNK_HEADER_SIZE {
+            return Err(Error::Invalid.into());
+        }
+        let offset = u32::from_le_bytes([ chunk[0], chunk[1], chunk[2], chunk[3] ]);
+        let chunk_crc = u32::from_le_bytes([ chunk[4], chunk[5], chunk[6], chunk[7] ]);
+        let data      = &chunk[CHUNK_HEADER_SIZE..];
+
+        //  Offset must be at a chunk boundary and the chunk must have the expected length
+        if offset % self.chunk_size as u32 != 0 || offset >= self.total_len {
+            return Err(Error::Invalid.into());
+        }
+        let expected_len = (self.total_len - offset).min(self.chunk_size as u32);
+        if data.len() as u32 != expected_len || crc32(0, data) != chunk_crc {
+            return Err(Error::Invalid.into());
+        }
+
+        //  Accept a duplicate chunk only if it matches
+        let index = (offset / self.chunk_size as u32) as usize;
+        if self.is_received(index) {
+            if !self.matches(flash, offset, data) ? { return Err(Error::AlreadyWritten.into()); }
+            return Ok(());
+        }
+
+        //  Keep the bytes of the container header in RAM, write the rest to flash
+        let header_len = (HEADER_SIZE as u32).saturating_sub(offset).min(data.len() as u32) as usize;
+        if header_len > 0 {
+            self.header[offset as usize..offset as usize + header_len].copy_from_slice(&data[..header_len]);
+        }
+        let flash_data = &data[header_len..];
+        if !flash_data.is_empty() {
+            let flash_offset = offset + header_len as u32;
+            self.erase_sectors(flash, flash_offset, flash_data.len() as u32) ? ;
+            flash.write(slots::slot_offset(self.slot)? + flash_offset, flash_data) ? ;
+        }
+        self.chunks[index / 8] |= 1 << (index % 8);
+        self.received += 1;
+        Ok(())
+    }
+
+    /// Commit the upload: check that all chunks have been received and the CRC32 of the container matches,
+    /// then write the container header and make the slot active. Fail with `Invalid` if no upload is in
+    /// progress or the container is incomplete or corrupted. A corrupted upload is discarded.
+    pub fn commit<F: LogoFlash + ?Sized>(&mut self, flash: &mut F) -> Result<(), F::Error> {
+        if self.state != UploadState::Receiving || self.first_missing().is_some() {
+            return Err(Error::Invalid.into());
+        }
+        let offset = slots::slot_offset(self.slot) ? ;
+
+        //  Compute the CRC32 of the header in RAM and the rest of the container in flash
+        let mut crc = crc32(0, &self.header);
+        let mut buf = [0u8; READ_SIZE];
+        let mut pos = HEADER_SIZE as u32;
+        while pos < self.total_len {
+            let len = ((self.total_len - pos) as usize).min(READ_SIZE);
+            flash.read(offset + pos, &mut buf[..len]) ? ;
+            crc = crc32(crc, &buf[..len]);
+            pos += len as u32;
+        }
+        if crc != self.crc32 {
+            self.abort();
+            return Err(Error::Invalid.into());
+        }
+
+        //  Write the header last, then check that the slot contains a valid logo or animation
+        flash.write(offset, &self.header) ? ;
+        if !slots::slot_status(flash, self.slot)?.is_valid() {
+            self.abort();
+            slots::erase_slot(flash, self.slot) ? ;
+            return Err(Error::Invalid.into());
+        }
+        slots::set_active_slot(flash, self.slot) ? ;
+        self.state = UploadState::Committed;
+        Ok(())
+    }
+
+    /// Abort the upload. The slot is left empty if any chunks were received.
+    pub fn abort(&mut self) {
+        *self = LogoUpload::new();
+    }
+
+    /// Return the state of the upload
+    pub fn state(&self) -> UploadState {
+        self.state
+    }
+
+    /// Return the status for the Status Characteristic
+    pub fn status(&self) -> [u8; STATUS_SIZE] {
+        let mut status = [0u8; STATUS_SIZE];
+        status[0] = self.state as u8;
+        status[1] = self.slot as u8;
+        status[2..4].copy_from_slice(&self.received.to_le_bytes());
+        status[4..6].copy_from_slice(&(self.chunk_count() as u16).to_le_bytes());
+        let missing = self.first_missing().map(|index| index as u16).unwrap_or(0xffff);
+        status[6..8].copy_from_slice(&missing.to_le_bytes());
+        status
+    }
+
+    /// Return the number of chunks in the upload
+    pub fn chunk_count(&self) -> usize {
+        if self.state != UploadState::Receiving { return 0; }
+        ((self.total_len + self.chunk_size as u32 - 1) / self.chunk_size as u32) as usize
+    }
+
+    /// Return the index of the first chunk that has not been received, or `None` if all chunks have been received
+    pub fn first_missing(&self) -> Option<usize> {
+        (0..self.chunk_count()).find(|index| !self.is_received(*index))
+    }
+
+    /// Return true if the chunk at `index` has been received
+    fn is_received(&self, index: usize) -> bool {
+        self.chunks[index / 8] & (1 << (index % 8)) != 0
+    }
+
+    /// Return true if `data` matches the bytes received earlier at `offset` of the container
+    fn matches<F: LogoFlash + ?Sized>(&self, flash: &mut F, offset: u32, data: &[u8]) -> Result<bool, F::Error> {
+        //  Bytes of the header are in RAM, the rest are in flash
+        let header_len = (HEADER_SIZE as u32).saturating_sub(offset).min(data.len() as u32) as usize;
+        if header_len > 0 && self.header[offset as usize..offset as usize + header_len] != data[..header_len] {
+            return Ok(false);
+        }
+        let flash_offset = slots::slot_offset(self.slot)? + offset + header_len as u32;
+        let mut buf = [0u8; READ_SIZE];
+        for (i, expected) in data[header_len..].chunks(READ_SIZE).enumerate() {
+            let actual = &mut buf[..expected.len()];
+            flash.read(flash_offset + (i * READ_SIZE) as u32, actual) ? ;
+            if actual != expected { return Ok(false); }
+        }
+        Ok(true)
+    }
+
+    /// Erase the sectors of the slot covering `len` bytes at `offset` of the container, unless already erased
+    fn erase_sectors<F: LogoFlash + ?Sized>(&mut self, flash: &mut F, offset: u32, len: u32) -> Result<(), F::Error> {
+        let slot_offset = slots::slot_offset(self.slot) ? ;
+        let first = offset / SECTOR_SIZE;
+        let last  = (offset + len - 1) / SECTOR_SIZE;
+        for sector in first..=last {
+            assert!(sector < SLOT_SECTORS, "bad sector");
+            if self.erased & (1 << sector) != 0 { continue; }
+            flash.erase(slot_offset + sector * SECTOR_SIZE, SECTOR_SIZE) ? ;
+            self.erased |= 1 << sector;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::{ vec, vec::Vec };
+    use crate::{
+        header::{ Codec, LogoHeader },
+        slots::{ active_slot, slot_status, SlotStatus, TABLE_OFFSET },
+        RamFlash,
+    };
+    use super::*;
+
+    /// Size of the test flash: the Bootloader Assets area including the slot table
+    const FLASH_SIZE: usize = (TABLE_OFFSET + SECTOR_SIZE) as usize;
+
+    /// Number of bytes per chunk in the tests
+    const CHUNK_SIZE: u16 = 64;
+
+    /// Return a raw 40 x 2 logo container with pixels of different colors, and its header
+    fn container() -> (LogoHeader, Vec<u8>) {
+        let payload: Vec<u8> = (0..80u16).flat_map(|color| (color * 0x123).to_be_bytes()).collect();
+        let header = LogoHeader::new(40, 2, Codec::Raw, &payload);
+        let mut container = header.to_bytes().to_vec();
+        container.extend_from_slice(&payload);
+        (header, container)
+    }
+
+    /// Return the chunk at `index` of `container` for the Data Characteristic
+    fn chunk(container: &[u8], index: usize) -> Vec<u8> {
+        let start = index * CHUNK_SIZE as usize;
+        let data = &container[start..(start + CHUNK_SIZE as usize).min(container.len())];
+        let mut chunk = (start as u32).to_le_bytes().to_vec();
+        chunk.extend_from_slice(&crc32(0, data).to_le_bytes());
+        chunk.extend_from_slice(data);
+        chunk
+    }
+
+    /// Return the Begin command for uploading `container` into `slot`
+    fn begin_command(slot: u8, container: &[u8]) -> Vec<u8> {
+        let mut command = vec![ CMD_BEGIN, slot ];
+        command.extend_from_slice(&CHUNK_SIZE.to_le_bytes());
+        command.extend_from_slice(&(container.len() as u32).to_le_bytes());
+        command.extend_from_slice(&crc32(0, container).to_le_bytes());
+        command
+    }
+
+    #[test]
+    fn uploads_chunks_out_of_order() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, container) = container();
+        let mut upload = LogoUpload::new();
+        upload.control(&mut flash, &begin_command(1, &container)).unwrap();
+        let count = upload.chunk_count();
+        assert_eq!(count, 3);
+
+        //  Last chunk first, then the header chunk. The slot stays empty until committed.
+        upload.write_chunk(&mut flash, &chunk(&container, 2)).unwrap();
+        upload.write_chunk(&mut flash, &chunk(&container, 0)).unwrap();
+        assert_eq!(upload.first_missing(), Some(1));
+        assert_eq!(upload.status(), [ UploadState::Receiving as u8, 1, 2, 0, 3, 0, 1, 0 ]);
+        assert_eq!(upload.commit(&mut flash), Err(Error::Invalid));
+        assert_eq!(slot_status(&mut flash, 1), Ok(SlotStatus::Empty));
+
+        upload.write_chunk(&mut flash, &chunk(&container, 1)).unwrap();
+        assert_eq!(upload.first_missing(), None);
+        upload.control(&mut flash, &[ CMD_COMMIT ]).unwrap();
+        assert_eq!(upload.state(), UploadState::Committed);
+        assert_eq!(slot_status(&mut flash, 1), Ok(SlotStatus::Valid(header)));
+        assert_eq!(active_slot(&mut flash), Ok(1));
+    }
+
+    #[test]
+    fn accepts_matching_duplicate_chunks() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, container) = container();
+        let mut upload = LogoUpload::new();
+        upload.control(&mut flash, &begin_command(0, &container)).unwrap();
+
+        //  Resending the header chunk (kept in RAM) and a data chunk (in flash) is fine
+        for index in [ 0, 1, 0, 1, 2, 2 ].iter() {
+            upload.write_chunk(&mut flash, &chunk(&container, *index)).unwrap();
+        }
+        assert_eq!(upload.status()[2..4], [ 3, 0 ]);
+        upload.commit(&mut flash).unwrap();
+        assert_eq!(slot_status(&mut flash, 0), Ok(SlotStatus::Valid(header)));
+    }
+
+    #[test]
+    fn rejects_conflicting_duplicate_chunks() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (header, container) = container();
+        let mut upload = LogoUpload::new();
+        upload.control(&mut flash, &begin_command(0, &container)).unwrap();
+        for index in 0..3 {
+            upload.write_chunk(&mut flash, &chunk(&container, index)).unwrap();
+        }
+
+        //  Duplicate chunks with different data are rejected, in the header and in flash
+        for index in 0..2 {
+            let mut other = container.clone();
+            other[index * CHUNK_SIZE as usize + 20] ^= 1;
+            assert_eq!(upload.write_chunk(&mut flash, &chunk(&other, index)), Err(Error::AlreadyWritten));
+        }
+
+        //  The original chunks are kept
+        upload.commit(&mut flash).unwrap();
+        assert_eq!(slot_status(&mut flash, 0), Ok(SlotStatus::Valid(header)));
+    }
+
+    #[test]
+    fn rejects_bad_chunks() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (_, container) = container();
+        let mut upload = LogoUpload::new();
+
+        //  No upload in progress
+        assert_eq!(upload.write_chunk(&mut flash, &chunk(&container, 0)), Err(Error::Invalid));
+        upload.control(&mut flash, &begin_command(0, &container)).unwrap();
+
+        //  Bad CRC32, offset not at a chunk boundary, offset past the end, short chunk
+        let mut bad_crc = chunk(&container, 1);
+        bad_crc[4] ^= 1;
+        let mut bad_offset = chunk(&container, 1);
+        bad_offset[0] += 1;
+        let mut past_end = chunk(&container, 2);
+        past_end[0..4].copy_from_slice(&(3 * CHUNK_SIZE as u32).to_le_bytes());
+        let mut short = chunk(&container, 1);
+        short.pop();
+        for bad in [ bad_crc, bad_offset, past_end, short ].iter() {
+            assert_eq!(upload.write_chunk(&mut flash, bad), Err(Error::Invalid));
+        }
+        assert_eq!(upload.status()[2..4], [ 0, 0 ]);
+    }
+
+    #[test]
+    fn discards_corrupted_upload() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (_, container) = container();
+        let mut upload = LogoUpload::new();
+
+        //  Container CRC32 in the Begin command doesn't match the chunks
+        let mut command = begin_command(1, &container);
+        command[8] ^= 1;
+        upload.control(&mut flash, &command).unwrap();
+        for index in 0..3 {
+            upload.write_chunk(&mut flash, &chunk(&container, index)).unwrap();
+        }
+        assert_eq!(upload.commit(&mut flash), Err(Error::Invalid));
+        assert_eq!(upload.state(), UploadState::Idle);
+        assert_eq!(slot_status(&mut flash, 1), Ok(SlotStatus::Empty));
+        assert_eq!(active_slot(&mut flash), Ok(0));
+    }
+
+    #[test]
+    fn rejects_bad_commands() {
+        let mut memory = vec![0; FLASH_SIZE];
+        let mut flash = RamFlash::new(&mut memory);
+        let (_, container) = container();
+        let mut upload = LogoUpload::new();
+        assert_eq!(upload.control(&mut flash, &begin_command(2, &container)), Err(Error::OutOfRange));
+        assert_eq!(upload.control(&mut flash, &begin_command(0, &container)[..BEGIN_SIZE - 1]), Err(Error::Invalid));
+        assert_eq!(upload.control(&mut flash, &[ 0 ]), Err(Error::Invalid));
+        assert_eq!(upload.begin(&mut flash, 0, MIN_CHUNK_SIZE - 1, 100, 0), Err(Error::Invalid));
+        assert_eq!(upload.begin(&mut flash, 0, MAX_CHUNK_SIZE + 1, 100, 0), Err(Error::Invalid));
+        assert_eq!(upload.begin(&mut flash, 0, CHUNK_SIZE, SLOT_SIZE + 1, 0), Err(Error::NoMemory));
+
+        //  Abort returns to idle
+        upload.control(&mut flash, &begin_command(0, &container)).unwrap();
+        upload.control(&mut flash, &[ CMD_ABORT ]).unwrap();
+        assert_eq!(upload.state(), UploadState::Idle);
+        assert_eq!(upload.control(&mut flash, &[ CMD_COMMIT ]), Err(Error::Invalid));
+    }
+}
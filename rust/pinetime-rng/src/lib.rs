@@ -0,0 +1,154 @@
+//! Xorshift32 pseudorandom generator for PineTime, without Mynewt calls. Used by `mynewt::hal::Rng` as the
+//! fallback source of random numbers, and by `chip8-host` for the CHIP8 RND instruction.
+
+#![no_std]  //  Don't link with standard Rust library, so that the generator runs on PineTime
+
+/// Xorshift32 pseudorandom generator. Not suitable for cryptography.
+pub struct XorShiftRng {
+    /// Generator state, never 0
+    state: u32,
+}
+
+impl XorShiftRng {
+    /// Seed used when the given seed is 0, because xorshift gets stuck at 0
+    pub const DEFAULT_SEED: u32 = 0x2545_f491;
+
+    /// Return a generator with the `seed`
+    pub fn new(seed: u32) -> XorShiftRng {
+        XorShiftRng {
+            state: if seed == 0 { Self::DEFAULT_SEED } else { seed },
+        }
+    }
+
+    /// Return the next pseudorandom 32-bit number, never 0
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// Return the next pseudorandom byte: the lowest byte of the next 32-bit number
+    pub fn next_u8(&mut self) -> u8 {
+        self.next_u32() as u8
+    }
+
+    /// Fill `buf` with pseudorandom bytes, 4 bytes per 32-bit number in little endian order
+    pub fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of samples for the distribution tests
+    const SAMPLES: usize = 256 * 1000;
+
+    #[test]
+    fn zero_seed_is_replaced() {
+        let mut rng = XorShiftRng::new(0);
+        let mut expected = XorShiftRng::new(XorShiftRng::DEFAULT_SEED);
+        for _ in 0..100 {
+            let x = rng.next_u32();
+            assert_ne!(x, 0);
+            assert_eq!(x, expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut a = XorShiftRng::new(1);
+        let mut b = XorShiftRng::new(1);
+        let mut c = XorShiftRng::new(2);
+        //  First numbers for seed 1, as computed by the xorshift32 reference implementation
+        assert_eq!(a.next_u32(), 270369);
+        assert_eq!(b.next_u32(), 270369);
+        assert_eq!(a.next_u32(), 67634689);
+        assert_eq!(b.next_u32(), 67634689);
+        let differ = (0..100).filter(|_| a.next_u32() != c.next_u32()).count();
+        assert_eq!(differ, 100);
+    }
+
+    #[test]
+    fn bytes_are_evenly_distributed() {
+        let mut rng = XorShiftRng::new(1);
+        let mut counts = [0usize; 256];
+        for _ in 0..SAMPLES { counts[rng.next_u8() as usize] += 1; }
+        //  Each byte value is expected 1000 times. Allow 15%, which is more than 4 standard deviations.
+        let expected = SAMPLES / 256;
+        for (value, count) in counts.iter().enumerate() {
+            assert!(*count > expected * 85 / 100 && *count < expected * 115 / 100,
+                "byte {} occurs {} times", value, count);
+        }
+    }
+
+    #[test]
+    fn nibbles_are_evenly_distributed() {
+        //  CHIP8 ROMs usually mask the random byte, e.g. `RND V0, 0x0F`
+        let mut rng = XorShiftRng::new(12345);
+        let mut counts = [0usize; 16];
+        for _ in 0..SAMPLES { counts[(rng.next_u8() & 0x0f) as usize] += 1; }
+        let expected = SAMPLES / 16;
+        for (value, count) in counts.iter().enumerate() {
+            assert!(*count > expected * 97 / 100 && *count < expected * 103 / 100,
+                "nibble {} occurs {} times", value, count);
+        }
+    }
+
+    #[test]
+    fn bits_are_balanced() {
+        let mut rng = XorShiftRng::new(0xdead_beef);
+        let mut counts = [0usize; 32];
+        for _ in 0..SAMPLES {
+            let x = rng.next_u32();
+            for (bit, count) in counts.iter_mut().enumerate() {
+                if x & (1 << bit) != 0 { *count += 1; }
+            }
+        }
+        let expected = SAMPLES / 2;
+        for (bit, count) in counts.iter().enumerate() {
+            assert!(*count > expected * 99 / 100 && *count < expected * 101 / 100,
+                "bit {} is set {} times", bit, count);
+        }
+    }
+
+    #[test]
+    fn consecutive_bytes_are_independent() {
+        //  Pairs of consecutive bytes split into 4x4 buckets by their top 2 bits should be evenly distributed
+        let mut rng = XorShiftRng::new(7);
+        let mut counts = [[0usize; 4]; 4];
+        let mut prev = rng.next_u8();
+        for _ in 0..SAMPLES {
+            let next = rng.next_u8();
+            counts[(prev >> 6) as usize][(next >> 6) as usize] += 1;
+            prev = next;
+        }
+        let expected = SAMPLES / 16;
+        for row in counts.iter() {
+            for count in row.iter() {
+                assert!(*count > expected * 97 / 100 && *count < expected * 103 / 100, "{:?}", counts);
+            }
+        }
+    }
+
+    #[test]
+    fn fill_bytes_uses_little_endian_numbers() {
+        let mut rng = XorShiftRng::new(1);
+        let mut expected = XorShiftRng::new(1);
+        let mut buf = [0u8; 7];
+        rng.fill_bytes(&mut buf);
+        let first = expected.next_u32().to_le_bytes();
+        let second = expected.next_u32().to_le_bytes();
+        assert_eq!(buf[..4], first);
+        assert_eq!(buf[4..], second[..3]);
+        //  The unused byte of the last number is dropped
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+}
@@ -0,0 +1,347 @@
+//! Touch calibration for the touch panel. An affine transform maps the raw coordinates from the touch controller to
+//! the coordinates of the display panel, which corrects the offset, scale, inverted axes and swapped axes of the touch
+//! panel. The transform is computed from raw touch points at known targets on the display, by least squares, so that
+//! one more target than the 3 needed catches a bad touch. No Mynewt calls, so the calibration is checked on the host
+//! with `rust/touch-host` and the unit tests, which replay the calibration files in `rust/touch-host/calibrations`.
+//!
+//! The transform is in fixed point, with `SCALE_BITS` fractional bits, because PineTime doesn't use floating point:
+//! ```text
+//! panel_x = (xx * raw_x + xy * raw_y + x0) >> SCALE_BITS
+//! panel_y = (yx * raw_x + yy * raw_y + y0) >> SCALE_BITS
+//! ```
+
+/// Width and height of the display panel in pixels. Calibrated coordinates are clamped to the panel.
+pub const PANEL_SIZE: u16 = 240;
+
+/// Number of fractional bits in the coefficients of the transform
+pub const SCALE_BITS: u32 = 16;
+
+/// Size of the calibration encoded by `Calibration::to_bytes()`
+pub const CALIBRATION_SIZE: usize = 24;
+
+/// Max distance in pixels between a touch after calibration and its target, for `Calibration::from_touches()`.
+/// Least squares spreads the error of a bad touch over all the touches: with 4 targets at the corners of a square,
+/// a target touched `d` pixels away leaves an error of `d / 4` at every touch. So a target touched more than
+/// 12 pixels away is rejected.
+pub const MAX_ERROR: u16 = 3;
+
+/// Coefficient for 1.0
+const SCALE: i64 = 1 << SCALE_BITS;
+
+/// Affine transform from raw touch coordinates to display panel coordinates, in fixed point
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Calibration {
+    /// Panel x for each raw x
+    pub xx: i32,
+    /// Panel x for each raw y
+    pub xy: i32,
+    /// Panel x offset
+    pub x0: i32,
+    /// Panel y for each raw x
+    pub yx: i32,
+    /// Panel y for each raw y
+    pub yy: i32,
+    /// Panel y offset
+    pub y0: i32,
+}
+
+impl Calibration {
+    /// Return the calibration that doesn't change the raw coordinates, for an uncalibrated touch panel
+    pub const fn identity() -> Calibration {
+        Calibration { xx: SCALE as i32, xy: 0, x0: 0, yx: 0, yy: SCALE as i32, y0: 0 }
+    }
+
+    /// Compute the calibration that maps the `raw` touch points closest to the `targets` on the display panel,
+    /// by least squares. Return `None` if there are fewer than 3 points, the numbers of points differ, or the
+    /// points are on a line, e.g. because the same target was touched twice.
+    pub fn from_points(raw: &[(u16, u16)], targets: &[(u16, u16)]) -> Option<Calibration> {
+        if raw.len() < 3 || raw.len() != targets.len() { return None; }
+
+        //  Normal equations for panel = a * raw_x + b * raw_y + c, the same matrix for panel x and panel y
+        let mut m = [[0i128; 3]; 3];
+        let mut rhs_x = [0i128; 3];
+        let mut rhs_y = [0i128; 3];
+        for (&(x, y), &(target_x, target_y)) in raw.iter().zip(targets.iter()) {
+            let row = [x as i128, y as i128, 1];
+            for i in 0..3 {
+                for j in 0..3 { m[i][j] += row[i] * row[j]; }
+                rhs_x[i] += row[i] * target_x as i128;
+                rhs_y[i] += row[i] * target_y as i128;
+            }
+        }
+        let det = determinant(&m);
+        if det == 0 { return None; }
+
+        //  Solve with Cramer's rule, replacing each column by the right hand side
+        let solve = |rhs: &[i128; 3], column: usize| -> Option<i32> {
+            let mut replaced = m;
+            for i in 0..3 { replaced[i][column] = rhs[i]; }
+            let coefficient = div_round(determinant(&replaced) * SCALE as i128, det);
+            if coefficient < core::i32::MIN as i128 || coefficient > core::i32::MAX as i128 { return None; }
+            Some(coefficient as i32)
+        };
+        let calibration = Calibration {
+            xx: solve(&rhs_x, 0) ? , xy: solve(&rhs_x, 1) ? , x0: solve(&rhs_x, 2) ? ,
+            yx: solve(&rhs_y, 0) ? , yy: solve(&rhs_y, 1) ? , y0: solve(&rhs_y, 2) ? ,
+        };
+        //  Targets on a line can't be reached from every raw point
+        if !calibration.is_valid() { return None; }
+        Some(calibration)
+    }
+
+    /// Compute the calibration that maps the `raw` touch points at the `targets` like `from_points()`, and check that
+    /// every touch point after calibration is within `MAX_ERROR` pixels of its target. Return `None` if the points
+    /// can't be calibrated, or if a target was touched in the wrong place.
+    pub fn from_touches(raw: &[(u16, u16)], targets: &[(u16, u16)]) -> Option<Calibration> {
+        let calibration = Calibration::from_points(raw, targets) ? ;
+        if calibration.max_error(raw, targets) > MAX_ERROR { return None; }
+        Some(calibration)
+    }
+
+    /// Return the display panel coordinates for the raw touch coordinates (`x`, `y`), clamped to the panel
+    pub fn apply(&self, x: u16, y: u16) -> (u16, u16) {
+        let (x, y) = (x as i64, y as i64);
+        let transform = |a: i32, b: i32, c: i32| -> u16 {
+            let value = (a as i64 * x + b as i64 * y + c as i64 + SCALE / 2) >> SCALE_BITS;
+            value.max(0).min(PANEL_SIZE as i64 - 1) as u16
+        };
+        (transform(self.xx, self.xy, self.x0), transform(self.yx, self.yy, self.y0))
+    }
+
+    /// Return the largest distance in pixels, horizontally or vertically, between a `raw` touch point
+    /// after calibration and its target in `targets`
+    pub fn max_error(&self, raw: &[(u16, u16)], targets: &[(u16, u16)]) -> u16 {
+        raw.iter().zip(targets.iter())
+            .map(|(&(x, y), &(target_x, target_y))| {
+                let (x, y) = self.apply(x, y);
+                let dx = if x > target_x { x - target_x } else { target_x - x };
+                let dy = if y > target_y { y - target_y } else { target_y - y };
+                dx.max(dy)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return true if every display panel point can be reached, i.e. the transform doesn't flatten the touch panel
+    /// into a line
+    pub fn is_valid(&self) -> bool {
+        self.xx as i64 * self.yy as i64 != self.xy as i64 * self.yx as i64
+    }
+
+    /// Encode the calibration as 6 coefficients (i32, little endian): `xx`, `xy`, `x0`, `yx`, `yy`, `y0`
+    pub fn to_bytes(&self) -> [u8; CALIBRATION_SIZE] {
+        let mut bytes = [0u8; CALIBRATION_SIZE];
+        let coefficients = [ self.xx, self.xy, self.x0, self.yx, self.yy, self.y0 ];
+        for (chunk, coefficient) in bytes.chunks_mut(4).zip(coefficients.iter()) {
+            chunk.copy_from_slice(&coefficient.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode the calibration encoded by `to_bytes()`. Return `None` if too short or invalid, e.g. erased flash.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Calibration> {
+        if bytes.len() < CALIBRATION_SIZE { return None; }
+        let coefficient = |index: usize| {
+            let offset = index * 4;
+            i32::from_le_bytes([ bytes[offset], bytes[offset + 1], bytes[offset + 2], bytes[offset + 3] ])
+        };
+        let calibration = Calibration {
+            xx: coefficient(0), xy: coefficient(1), x0: coefficient(2),
+            yx: coefficient(3), yy: coefficient(4), y0: coefficient(5),
+        };
+        if !calibration.is_valid() { return None; }
+        Some(calibration)
+    }
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Calibration::identity()
+    }
+}
+
+/// Return the determinant of the 3x3 matrix `m`
+fn determinant(m: &[[i128; 3]; 3]) -> i128 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+/// Return `numerator / denominator` rounded to the nearest integer
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    if (numerator < 0) == (denominator < 0) { (numerator + denominator / 2) / denominator }
+    else { (numerator - denominator / 2) / denominator }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::vec::Vec;
+    use pinetime_display::st7789::{ DisplayConfig, Rotation, PINETIME_CONFIG };
+
+    /// Targets drawn by the calibration routine in `app/src/calibration.rs`, in picture coordinates
+    const TARGETS: [(u16, u16); 4] = [ (30, 30), (209, 30), (209, 209), (30, 209) ];
+
+    /// Raw coordinates with the display panel coordinates expected after calibration
+    struct Map {
+        /// Raw x and y
+        raw:      (u16, u16),
+        /// Display panel x and y expected
+        expected: (u16, u16),
+        /// Line number in the calibration file
+        line:     usize,
+    }
+
+    /// Calibration file in the format of `rust/touch-host/calibrations`
+    #[derive(Default)]
+    struct CalibrationFile {
+        /// Raw touch points
+        raw:      Vec<(u16, u16)>,
+        /// Targets on the display panel for the raw touch points
+        targets:  Vec<(u16, u16)>,
+        /// Raw coordinates to be mapped after calibration
+        maps:     Vec<Map>,
+        /// Largest distance between a calibrated touch and its target
+        error:    Option<u16>,
+        /// True if the touches can't be calibrated
+        invalid:  bool,
+        /// True if the calibration is rejected because a target was touched in the wrong place
+        rejected: bool,
+    }
+
+    /// Parse the calibration file `text`
+    fn parse(text: &str) -> CalibrationFile {
+        let mut file = CalibrationFile::default();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            let words: Vec<_> = line.split_whitespace().collect();
+            let coord = |word: &str| word.parse::<u16>().unwrap();
+            match words.as_slice() {
+                [] => {}
+                [ "touch", raw_x, raw_y, x, y ] => {
+                    file.raw.push((coord(raw_x), coord(raw_y)));
+                    file.targets.push((coord(x), coord(y)));
+                }
+                [ "map", raw_x, raw_y, x, y ] => file.maps.push(Map {
+                    raw: (coord(raw_x), coord(raw_y)), expected: (coord(x), coord(y)), line: index + 1,
+                }),
+                [ "error", error ] => file.error = Some(coord(error)),
+                [ "invalid" ] => file.invalid = true,
+                [ "rejected" ] => file.rejected = true,
+                _ => panic!("line {}: bad line {}", index + 1, line),
+            }
+        }
+        file
+    }
+
+    /// Compute the calibration from the touches in the calibration file `text`, like `touch-host --calibration`.
+    /// Panic if the error, the mapped coordinates or the rejection differ from the file, or if the calibration
+    /// changes after encoding for SPI Flash. Return the calibration, `None` if the touches can't be calibrated.
+    fn replay(text: &str) -> Option<Calibration> {
+        let file = parse(text);
+        let calibration = Calibration::from_points(&file.raw, &file.targets);
+        if file.invalid {
+            assert_eq!(calibration, None);
+            assert_eq!(Calibration::from_touches(&file.raw, &file.targets), None);
+            return None;
+        }
+        let calibration = calibration.expect("no calibration");
+        if let Some(error) = file.error {
+            assert_eq!(calibration.max_error(&file.raw, &file.targets), error, "{:?}", calibration);
+        }
+        for map in file.maps.iter() {
+            assert_eq!(calibration.apply(map.raw.0, map.raw.1), map.expected, "line {}: {:?}", map.line, calibration);
+        }
+        let accepted = Calibration::from_touches(&file.raw, &file.targets);
+        assert_eq!(accepted, if file.rejected { None } else { Some(calibration) });
+        assert_eq!(Calibration::from_bytes(&calibration.to_bytes()), Some(calibration));
+        Some(calibration)
+    }
+
+    #[test]
+    fn calibration_identity() {
+        assert_eq!(replay(include_str!("../../touch-host/calibrations/identity.txt")), Some(Calibration::identity()));
+    }
+
+    #[test]
+    fn calibration_offset() {
+        replay(include_str!("../../touch-host/calibrations/offset.txt"));
+    }
+
+    #[test]
+    fn calibration_inverted() {
+        replay(include_str!("../../touch-host/calibrations/inverted.txt"));
+    }
+
+    #[test]
+    fn calibration_swapped() {
+        replay(include_str!("../../touch-host/calibrations/swapped.txt"));
+    }
+
+    #[test]
+    fn calibration_collinear() {
+        assert_eq!(replay(include_str!("../../touch-host/calibrations/collinear.txt")), None);
+    }
+
+    #[test]
+    fn calibration_bad_touch_is_rejected() {
+        let text = include_str!("../../touch-host/calibrations/bad-touch.txt");
+        replay(text);
+        //  Target touched 24 pixels away: error 6 at every touch, more than `MAX_ERROR`
+        let file = parse(text);
+        assert!(Calibration::from_points(&file.raw, &file.targets).is_some());
+        assert_eq!(Calibration::from_touches(&file.raw, &file.targets), None);
+    }
+
+    #[test]
+    fn calibration_expect_fails() {
+        //  A wrong expectation is caught, so the calibration files aren't passing vacuously
+        let text = std::format!("{}map 120 120 121 120\n", include_str!("../../touch-host/calibrations/identity.txt"));
+        assert!(std::panic::catch_unwind(|| replay(&text)).is_err());
+    }
+
+    #[test]
+    fn from_points_needs_3_points() {
+        assert_eq!(Calibration::from_points(&TARGETS[..2], &TARGETS[..2]), None);
+        assert_eq!(Calibration::from_points(&TARGETS, &TARGETS[..3]), None);
+        assert_eq!(Calibration::from_points(&TARGETS[..3], &TARGETS[..3]), Some(Calibration::identity()));
+    }
+
+    #[test]
+    fn encodes_coefficients_for_flash() {
+        let calibration = Calibration { xx: 0x10000, xy: -2, x0: 0x123456, yx: 3, yy: -0x10000, y0: -1 };
+        let bytes = calibration.to_bytes();
+        assert_eq!(&bytes[..8], &[ 0x00, 0x00, 0x01, 0x00, 0xfe, 0xff, 0xff, 0xff ]);
+        assert_eq!(&bytes[20..], &[ 0xff, 0xff, 0xff, 0xff ]);
+        assert_eq!(Calibration::from_bytes(&bytes), Some(calibration));
+        //  Too short, erased flash and flattened transforms are not loaded
+        assert_eq!(Calibration::from_bytes(&bytes[..CALIBRATION_SIZE - 1]), None);
+        assert_eq!(Calibration::from_bytes(&[0xff; CALIBRATION_SIZE]), None);
+        assert_eq!(Calibration::from_bytes(&[0; CALIBRATION_SIZE]), None);
+    }
+
+    #[test]
+    fn calibration_ignores_display_rotation() {
+        //  Touch panel of `offset.txt`: raw = 0.9 * panel + 10 horizontally, raw = 0.9 * panel + 4 vertically
+        let to_raw = |(x, y): (u16, u16)| (x * 9 / 10 + 10, y * 9 / 10 + 4);
+        let mut calibrations = Vec::new();
+        for &rotation in [ Rotation::Deg0, Rotation::Deg90, Rotation::Deg180, Rotation::Deg270 ].iter() {
+            for &mirror in [ false, true ].iter() {
+                let config = DisplayConfig { rotation, mirror, ..PINETIME_CONFIG };
+                //  Targets are drawn on the rotated and mirrored picture, and touched on the display panel
+                let targets: Vec<_> = TARGETS.iter().map(|&(x, y)| config.inverse_transform_touch(x, y)).collect();
+                let raw: Vec<_> = targets.iter().map(|&target| to_raw(target)).collect();
+                let calibration = Calibration::from_touches(&raw, &targets).unwrap();
+                //  Touches are calibrated, then rotated and mirrored to the picture
+                for (&(x, y), &raw) in TARGETS.iter().zip(raw.iter()) {
+                    let (panel_x, panel_y) = calibration.apply(raw.0, raw.1);
+                    assert_eq!(config.transform_touch(panel_x, panel_y), (x, y), "{:?}", config);
+                }
+                calibrations.push(calibration);
+            }
+        }
+        //  Same calibration for every rotation, so it stays valid when the rotation changes
+        assert!(calibrations.iter().all(|calibration| *calibration == calibrations[0]), "{:?}", calibrations);
+    }
+}
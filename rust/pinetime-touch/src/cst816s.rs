@@ -0,0 +1,352 @@
+//! Driver for the Hynitron CST816S touch controller. Ported from https://github.com/lupyuen/hynitron_i2c_cst0xxse
+//! The controller sleeps when the screen is not touched and doesn't acknowledge I2C transfers (NACK), so the
+//! touch data should only be read after the touch interrupt. The driver owns the I2C bus, the reset pin and the delay.
+//! Touch data is read from 63 registers starting at register 0:
+//! ```text
+//! Offset      Size  Contents
+//! 1           1     Gesture
+//! 2           1     Number of touch points (lower 4 bits)
+//! 3 + 6 * i   6     Touch point `i`, up to 10 points:
+//!                   0: Action (upper 2 bits: 0 = down, 1 = up, 2 = contact), X (upper 4 bits, in lower 4 bits)
+//!                   1: X (lower 8 bits)
+//!                   2: Finger (upper 4 bits, 15 if no more points), Y (upper 4 bits, in lower 4 bits)
+//!                   3: Y (lower 8 bits)
+//!                   4: Pressure
+//!                   5: Area (upper 4 bits)
+//! ```
+use embedded_hal::{
+    blocking::{
+        delay::DelayMs,
+        i2c::WriteRead,
+    },
+    digital::v2::OutputPin,
+};
+
+/// I2C address of the touch controller
+pub const ADDRESS: u8 = 0x15;
+
+/// Max touch points returned by the driver
+pub const MAX_TOUCH_POINTS: usize = 5;
+
+/// Number of touch data registers to be read, for the max touch channels of the touch controller
+pub const TOUCH_DATA_SIZE: usize = TOUCH_POINT_POS + TOUCH_STEP * MAX_CHANNELS;
+
+/// Max touch channels of the touch controller
+const MAX_CHANNELS: usize = 10;
+
+/// Finger ID that marks the end of the touch points
+const MAX_ID: u8 = 0x0F;
+
+//  Touch data register offsets. Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.h
+const POINT_NUM_POS: usize   = 2;
+const TOUCH_POINT_POS: usize = 3;
+const TOUCH_STEP: usize      = 6;
+const EVENT_POS: usize       = 0;
+const X_H_POS: usize         = 0;
+const X_L_POS: usize         = 1;
+const ID_POS: usize          = 2;
+const Y_H_POS: usize         = 2;
+const Y_L_POS: usize         = 3;
+const PRESSURE_POS: usize    = 4;
+const AREA_POS: usize        = 5;
+
+/// Error returned by the driver, with the error types of the I2C bus and the reset pin
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error<I2cError, PinError> {
+    /// I2C transfer failed, e.g. the touch controller is asleep and doesn't acknowledge
+    I2c(I2cError),
+    /// Reset pin couldn't be set
+    Pin(PinError),
+}
+
+/// Touch action reported by the touch controller for each finger
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TouchAction {
+    /// Finger touched the screen
+    Down,
+    /// Finger was lifted from the screen
+    Up,
+    /// Finger is still touching the screen
+    Contact,
+}
+
+impl TouchAction {
+    /// Return the touch action for the 2-bit event code, `None` if unknown
+    fn from_event(event: u8) -> Option<TouchAction> {
+        match event {
+            0 => Some(TouchAction::Down),
+            1 => Some(TouchAction::Up),
+            2 => Some(TouchAction::Contact),
+            _ => None,
+        }
+    }
+}
+
+/// Touch data for a single finger
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TouchPoint {
+    /// X coordinate, before rotating to match the display
+    pub x:        u16,
+    /// Y coordinate, before rotating to match the display
+    pub y:        u16,
+    /// Touch action
+    pub action:   TouchAction,
+    /// Which finger touched
+    pub finger:   u8,
+    /// Pressure of touch
+    pub pressure: u8,
+    /// Area touched
+    pub area:     u8,
+}
+
+/// Touch data for multiple fingers
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TouchData {
+    /// Number of touch points reported by the touch controller
+    pub point_num: u8,
+    /// Touch points, valid up to `count`
+    points:        [TouchPoint; MAX_TOUCH_POINTS],
+    /// Number of valid touch points
+    count:         usize,
+}
+
+impl TouchData {
+    /// Return touch data with no touch points
+    pub const fn new() -> TouchData {
+        TouchData {
+            point_num: 0,
+            points:    [TouchPoint { x: 0, y: 0, action: TouchAction::Up, finger: 0, pressure: 0, area: 0 }; MAX_TOUCH_POINTS],
+            count:     0,
+        }
+    }
+
+    /// Return the touch points. Points with unknown actions are skipped. Some points may be at (0, 0) when the
+    /// touch controller returns an unusual response.
+    pub fn points(&self) -> &[TouchPoint] {
+        &self.points[..self.count]
+    }
+
+    /// Decode the touch data from the `TOUCH_DATA_SIZE` registers in `regs`, starting at register 0.
+    /// Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.c#L407-L466
+    pub fn parse(regs: &[u8; TOUCH_DATA_SIZE]) -> TouchData {
+        let mut data = TouchData::new();
+        data.point_num = regs[POINT_NUM_POS] & 0x0F;
+
+        //  Decode the first 5 touch points
+        for i in 0..MAX_TOUCH_POINTS {
+            let point = &regs[TOUCH_POINT_POS + TOUCH_STEP * i..TOUCH_POINT_POS + TOUCH_STEP * (i + 1)];
+            let finger = point[ID_POS] >> 4;
+            if finger >= MAX_ID { break; }
+            let event = point[EVENT_POS] >> 6;
+            if let Some(action) = TouchAction::from_event(event) {
+                data.points[data.count] = TouchPoint {
+                    x: ((point[X_H_POS] & 0x0F) as u16) << 8 | point[X_L_POS] as u16,
+                    y: ((point[Y_H_POS] & 0x0F) as u16) << 8 | point[Y_L_POS] as u16,
+                    action,
+                    finger,
+                    pressure: point[PRESSURE_POS],
+                    area:     point[AREA_POS] >> 4,
+                };
+                data.count += 1;
+            }
+            //  If no more touch points, stop
+            if (event == 0 || event == 2) && data.point_num == 0 { break; }
+        }
+        data
+    }
+}
+
+impl Default for TouchData {
+    fn default() -> Self {
+        TouchData::new()
+    }
+}
+
+/// CST816S touch controller on the I2C bus `I2C`, with reset pin `RST` and delay `DELAY`
+pub struct Cst816s<I2C, RST, DELAY> {
+    /// I2C bus
+    i2c:   I2C,
+    /// Reset pin, active when low
+    reset: RST,
+    /// Delay for resetting
+    delay: DELAY,
+    /// Buffer for the touch data registers
+    regs:  [u8; TOUCH_DATA_SIZE],
+}
+
+impl<I2C, RST, DELAY, I2cError, PinError> Cst816s<I2C, RST, DELAY>
+where
+    I2C:   WriteRead<Error = I2cError>,
+    RST:   OutputPin<Error = PinError>,
+    DELAY: DelayMs<u8>,
+{
+    /// Return a driver for the touch controller at `ADDRESS` on the I2C bus `i2c`, with the `reset` pin and `delay`.
+    /// Call `reset()` before reading touch data.
+    pub fn new(i2c: I2C, reset: RST, delay: DELAY) -> Self {
+        Cst816s { i2c, reset, delay, regs: [0; TOUCH_DATA_SIZE] }
+    }
+
+    /// Reset the touch controller by switching the reset pin low then high with pauses.
+    /// Based on https://github.com/lupyuen/hynitron_i2c_cst0xxse/blob/master/cst0xx_core.c#L1017-L1167
+    pub fn reset(&mut self) -> Result<(), Error<I2cError, PinError>> {
+        self.reset.set_low().map_err(Error::Pin) ? ;
+        self.delay.delay_ms(20);
+        self.reset.set_high().map_err(Error::Pin) ? ;
+        self.delay.delay_ms(200); self.delay.delay_ms(200);
+        Ok(())
+    }
+
+    /// Read the touch data. This only works when the screen has been tapped and the touch controller wakes up,
+    /// otherwise the I2C error for the NACK is returned.
+    pub fn read_touch_data(&mut self) -> Result<TouchData, Error<I2cError, PinError>> {
+        self.i2c.write_read(ADDRESS, &[0], &mut self.regs)
+            .map_err(Error::I2c) ? ;
+        Ok(TouchData::parse(&self.regs))
+    }
+
+    /// Read the registers starting at `start_register` into `buf`, e.g. for dumping the registers
+    pub fn read_registers(&mut self, start_register: u8, buf: &mut [u8]) -> Result<(), Error<I2cError, PinError>> {
+        self.i2c.write_read(ADDRESS, &[start_register], buf)
+            .map_err(Error::I2c)
+    }
+
+    /// Return the I2C bus, the reset pin and the delay
+    pub fn release(self) -> (I2C, RST, DELAY) {
+        (self.i2c, self.reset, self.delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::vec::Vec;
+    use crate::mock::{ MockDelay, MockError, MockPin, ScriptedI2c, Transaction };
+
+    /// Total time of the reset pulse and the pauses, in milliseconds
+    const RESET_MS: u32 = 420;
+
+    /// Touch controller response to a touch interrupt in a register dump
+    struct Response {
+        /// Register values, `None` if the touch controller didn't acknowledge
+        regs:     Option<Vec<u8>>,
+        /// Action, finger, x and y of the touch points expected, in order
+        expected: Vec<(TouchAction, u8, u16, u16)>,
+        /// Line number of the response in the dump
+        line:     usize,
+    }
+
+    /// Parse the register dump `text` in the format of `rust/touch-host/dumps`
+    fn parse_dump(text: &str) -> Vec<Response> {
+        let mut responses: Vec<Response> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            let words: Vec<_> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                [ "regs", regs @ .. ] => {
+                    let regs: Vec<u8> = regs.iter().map(|word| u8::from_str_radix(word, 16).unwrap()).collect();
+                    assert_eq!(regs.len(), TOUCH_DATA_SIZE, "line {}", index + 1);
+                    responses.push(Response { regs: Some(regs), expected: Vec::new(), line: index + 1 });
+                }
+                [ "nack" ] => responses.push(Response { regs: None, expected: Vec::new(), line: index + 1 }),
+                [ "expect", "none" ] | [ "expect", "nack" ] => {}
+                [ "expect", action, finger, x, y ] => {
+                    let action = match *action {
+                        "down"    => TouchAction::Down,
+                        "up"      => TouchAction::Up,
+                        "contact" => TouchAction::Contact,
+                        _ => panic!("line {}: unknown action {}", index + 1, action),
+                    };
+                    responses.last_mut().expect("expect without regs").expected
+                        .push((action, finger.parse().unwrap(), x.parse().unwrap(), y.parse().unwrap()));
+                }
+                _ => panic!("line {}: bad line {}", index + 1, line),
+            }
+        }
+        responses
+    }
+
+    /// Replay the register dump `text` in the format of `rust/touch-host/dumps` through the driver with the scripted
+    /// I2C bus, like `touch-host`. Panic if the touch points differ from the `expect` lines, or if the reset pulse
+    /// or the I2C transfers are not as expected.
+    fn replay(text: &str) {
+        let responses = parse_dump(text);
+        let script: Vec<Transaction> = responses.iter()
+            .map(|response| match &response.regs {
+                Some(regs) => Transaction::WriteRead { address: ADDRESS, write: &[0], read: regs },
+                None       => Transaction::Nack { address: ADDRESS },
+            })
+            .collect();
+        let mut driver = Cst816s::new(ScriptedI2c::new(&script), MockPin::new(true), MockDelay::new());
+        driver.reset().unwrap();
+        for response in responses.iter() {
+            match (driver.read_touch_data(), &response.regs) {
+                (Ok(data), Some(_)) => {
+                    let points: Vec<_> = data.points().iter()
+                        .map(|point| (point.action, point.finger, point.x, point.y))
+                        .collect();
+                    assert_eq!(points, response.expected, "line {}", response.line);
+                }
+                (Err(Error::I2c(MockError::Nack)), None) => {}
+                (result, _) => panic!("line {}: unexpected {:?}", response.line, result),
+            }
+        }
+        //  Reset pin must be pulsed low and left high, and every transaction must be done
+        let (i2c, reset, delay) = driver.release();
+        assert_eq!(reset, MockPin { high: true, changes: 2 });
+        assert_eq!(delay.total_ms, RESET_MS);
+        assert_eq!(i2c.remaining(), 0);
+    }
+
+    #[test]
+    fn dump_tap() {
+        replay(include_str!("../../touch-host/dumps/tap.txt"));
+    }
+
+    #[test]
+    fn dump_unusual() {
+        replay(include_str!("../../touch-host/dumps/unusual.txt"));
+    }
+
+    #[test]
+    fn dump_two_fingers() {
+        replay(include_str!("../../touch-host/dumps/two-fingers.txt"));
+    }
+
+    #[test]
+    fn dump_nack() {
+        replay(include_str!("../../touch-host/dumps/nack.txt"));
+    }
+
+    #[test]
+    fn dump_expect_fails() {
+        //  A wrong expectation is caught, so the dumps aren't passing vacuously
+        let regs = include_str!("../../touch-host/dumps/tap.txt").lines()
+            .find(|line| line.starts_with("regs")).unwrap();
+        let result = std::panic::catch_unwind(|| replay(&std::format!("{}\nexpect down 0 1 1\n", regs)));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn unknown_actions_are_skipped() {
+        let mut regs = [0xff; TOUCH_DATA_SIZE];
+        regs[POINT_NUM_POS] = 2;
+        //  Point 0 has the unknown event 3, point 1 touches down at (300, 20)
+        regs[TOUCH_POINT_POS..TOUCH_POINT_POS + 2 * TOUCH_STEP]
+            .copy_from_slice(&[ 0xc0, 0x10, 0x00, 0x10, 0x40, 0x10, 0x01, 0x2c, 0x10, 0x14, 0x40, 0x10 ]);
+        let data = TouchData::parse(&regs);
+        assert_eq!(data.point_num, 2);
+        assert_eq!(data.points(), &[ TouchPoint { x: 300, y: 20, action: TouchAction::Down, finger: 1, pressure: 0x40, area: 1 } ]);
+    }
+
+    #[test]
+    fn read_registers_from_start_register() {
+        let script = [ Transaction::WriteRead { address: ADDRESS, write: &[0xa7], read: &[ 0x12, 0x34 ] } ];
+        let mut driver = Cst816s::new(ScriptedI2c::new(&script), MockPin::new(true), MockDelay::new());
+        let mut buf = [0; 2];
+        assert_eq!(driver.read_registers(0xa7, &mut buf), Ok(()));
+        assert_eq!(buf, [ 0x12, 0x34 ]);
+        assert_eq!(driver.read_touch_data(), Err(Error::I2c(MockError::Unexpected)));  //  Script has ended
+    }
+}
@@ -0,0 +1,255 @@
+//! Subscriber table for touch, button and gesture events. Events are typed enums, delivered to any number of
+//! subscribers (apps, debug overlay) in order of priority. A subscriber may consume the event, so that subscribers
+//! with lower priority don't receive it, or pass it through. Each subscriber receives only the event kinds in its mask.
+//! On PineTime, [`events.rs`](../../app/src/events.rs) keeps the table in a static and publishes the events in the
+//! task that runs the Default Event Queue.
+use crate::{ Gesture, TouchAction };
+
+/// Event published to the subscribers
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Event {
+    /// Touch action by a finger, with coordinates transformed to match the display.
+    /// Touch up is always published so that the finger is released.
+    Touch { finger: u8, action: TouchAction, x: u16, y: u16 },
+    /// Side button action
+    Button(ButtonAction),
+    /// Gesture recognised from the touch actions of the first finger
+    Gesture(Gesture),
+}
+
+/// Side button action
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ButtonAction {
+    /// Button was pressed
+    Press,
+}
+
+impl Event {
+    /// Return the kind of event: `TOUCH`, `BUTTON` or `GESTURE`
+    pub fn kind(&self) -> u8 {
+        match self {
+            Event::Touch { .. } => TOUCH,
+            Event::Button(_)    => BUTTON,
+            Event::Gesture(_)   => GESTURE,
+        }
+    }
+}
+
+/// Event kinds that may be combined for `subscribe()`
+pub const TOUCH:   u8 = 1 << 0;
+pub const BUTTON:  u8 = 1 << 1;
+pub const GESTURE: u8 = 1 << 2;
+pub const ALL:     u8 = TOUCH | BUTTON | GESTURE;
+
+/// Priority for overlays that watch events before the apps, e.g. for debugging
+pub const PRIORITY_OVERLAY:  u8 = 200;
+/// Priority for apps
+pub const PRIORITY_APP:      u8 = 100;
+/// Priority for handling events that were not consumed by any app
+pub const PRIORITY_FALLBACK: u8 = 0;
+
+/// Returned by a subscriber to stop or continue delivering the event
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Propagation {
+    /// Event was consumed, don't deliver it to subscribers with lower priority
+    Consume,
+    /// Deliver the event to subscribers with lower priority
+    PassThrough,
+}
+
+/// Function called to handle an event
+pub type Handler = fn(event: &Event) -> Propagation;
+
+/// Identifies a subscriber for `unsubscribe()`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SubscriberId(u16);
+
+/// Error returned by the subscriber table
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// Too many subscribers
+    NoMemory,
+    /// Subscriber not found
+    NotFound,
+}
+
+/// Max number of subscribers
+pub const MAX_SUBSCRIBERS: usize = 8;
+
+/// Subscriber to events
+#[derive(Clone, Copy)]
+pub struct Subscriber {
+    /// Identifies the subscriber
+    pub id:       SubscriberId,
+    /// Name of the subscriber, for debugging
+    pub name:     &'static str,
+    /// Subscribers with higher priority receive the events first
+    pub priority: u8,
+    /// Event kinds to be delivered
+    pub kinds:    u8,
+    /// Function that handles the event
+    pub handler:  Handler,
+}
+
+/// Subscribers sorted by priority, highest first. Subscribers with the same priority are sorted by subscription time.
+#[derive(Clone, Copy)]
+pub struct Subscribers {
+    /// Subscribers in order of delivery, followed by the free entries
+    subscribers: [Option<Subscriber>; MAX_SUBSCRIBERS],
+    /// ID of the last subscriber
+    last_id:     u16,
+}
+
+impl Subscribers {
+    /// Return a table with no subscribers
+    pub const fn new() -> Subscribers {
+        Subscribers { subscribers: [None; MAX_SUBSCRIBERS], last_id: 0 }
+    }
+
+    /// Subscribe the `handler` to the event `kinds`, e.g. `TOUCH | GESTURE`, with the `priority`.
+    /// Fail with `NoMemory` if there are too many subscribers.
+    pub fn subscribe(&mut self, name: &'static str, priority: u8, kinds: u8, handler: Handler) -> Result<SubscriberId, Error> {
+        let subscribers = &mut self.subscribers;
+        if subscribers[MAX_SUBSCRIBERS - 1].is_some() { return Err(Error::NoMemory); }
+        self.last_id = self.last_id.wrapping_add(1);
+        let id = SubscriberId(self.last_id);
+        //  Insert after the subscribers with the same or higher priority
+        let index = subscribers.iter()
+            .position(|subscriber| match subscriber {
+                Some(subscriber) => subscriber.priority < priority,
+                None => true,
+            })
+            .unwrap_or(MAX_SUBSCRIBERS - 1);
+        subscribers[index..].rotate_right(1);
+        subscribers[index] = Some(Subscriber { id, name, priority, kinds, handler });
+        Ok(id)
+    }
+
+    /// Stop delivering events to the subscriber `id`. Fail with `NotFound` if not subscribed.
+    pub fn unsubscribe(&mut self, id: SubscriberId) -> Result<(), Error> {
+        let subscribers = &mut self.subscribers;
+        let index = subscribers.iter()
+            .position(|subscriber| matches!(subscriber, Some(subscriber) if subscriber.id == id))
+            .ok_or(Error::NotFound) ? ;
+        subscribers[index] = None;
+        subscribers[index..].rotate_left(1);
+        Ok(())
+    }
+
+    /// Deliver the `event` to the subscribers of its kind in order of priority, until a subscriber consumes it.
+    /// Return true if consumed. If a handler may subscribe or unsubscribe, publish with a copy of the table.
+    pub fn publish(&self, event: &Event) -> bool {
+        let kind = event.kind();
+        for subscriber in self.iter() {
+            if subscriber.kinds & kind == 0 { continue; }
+            if (subscriber.handler)(event) == Propagation::Consume { return true; }
+        }
+        false
+    }
+
+    /// Return the subscribers in order of priority
+    pub fn iter(&self) -> impl Iterator<Item = &Subscriber> {
+        self.subscribers.iter().flatten()
+    }
+}
+
+impl Default for Subscribers {
+    fn default() -> Self {
+        Subscribers::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::{ cell::RefCell, thread_local, vec::Vec };
+
+    thread_local! {
+        /// Names of the handlers called, in order
+        static CALLS: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+    }
+
+    /// Record the call to the handler `name` and return `propagation`
+    fn record(name: &'static str, propagation: Propagation) -> Propagation {
+        CALLS.with(|calls| calls.borrow_mut().push(name));
+        propagation
+    }
+
+    fn overlay(_: &Event) -> Propagation { record("overlay", Propagation::PassThrough) }
+    fn app(_: &Event) -> Propagation { record("app", Propagation::Consume) }
+    fn app2(_: &Event) -> Propagation { record("app2", Propagation::PassThrough) }
+    fn fallback(_: &Event) -> Propagation { record("fallback", Propagation::Consume) }
+
+    /// Publish the `event` and return whether it was consumed, and the handlers called
+    fn publish(subscribers: &Subscribers, event: Event) -> (bool, Vec<&'static str>) {
+        CALLS.with(|calls| calls.borrow_mut().clear());
+        let consumed = subscribers.publish(&event);
+        (consumed, CALLS.with(|calls| calls.borrow().clone()))
+    }
+
+    const TOUCH_EVENT: Event = Event::Touch { finger: 0, action: TouchAction::Down, x: 10, y: 20 };
+    const BUTTON_EVENT: Event = Event::Button(ButtonAction::Press);
+    const GESTURE_EVENT: Event = Event::Gesture(Gesture::Tap { x: 10, y: 20 });
+
+    #[test]
+    fn delivers_in_order_of_priority() {
+        let mut subscribers = Subscribers::new();
+        subscribers.subscribe("fallback", PRIORITY_FALLBACK, ALL, fallback).unwrap();
+        subscribers.subscribe("app2", PRIORITY_APP, ALL, app2).unwrap();
+        subscribers.subscribe("overlay", PRIORITY_OVERLAY, ALL, overlay).unwrap();
+        subscribers.subscribe("app", PRIORITY_APP, ALL, app).unwrap();
+        //  Same priority in order of subscription
+        let names: Vec<_> = subscribers.iter().map(|subscriber| subscriber.name).collect();
+        assert_eq!(names, [ "overlay", "app2", "app", "fallback" ]);
+        //  Passed through by the overlay and app2, consumed by app
+        assert_eq!(publish(&subscribers, TOUCH_EVENT), (true, std::vec![ "overlay", "app2", "app" ]));
+    }
+
+    #[test]
+    fn pass_through_reaches_every_subscriber() {
+        let mut subscribers = Subscribers::new();
+        subscribers.subscribe("overlay", PRIORITY_OVERLAY, ALL, overlay).unwrap();
+        subscribers.subscribe("app2", PRIORITY_APP, ALL, app2).unwrap();
+        assert_eq!(publish(&subscribers, BUTTON_EVENT), (false, std::vec![ "overlay", "app2" ]));
+        assert_eq!(publish(&Subscribers::new(), BUTTON_EVENT), (false, Vec::new()));
+    }
+
+    #[test]
+    fn delivers_only_subscribed_kinds() {
+        let mut subscribers = Subscribers::new();
+        subscribers.subscribe("overlay", PRIORITY_OVERLAY, GESTURE, overlay).unwrap();
+        subscribers.subscribe("app", PRIORITY_APP, TOUCH | BUTTON, app).unwrap();
+        subscribers.subscribe("fallback", PRIORITY_FALLBACK, TOUCH | GESTURE, fallback).unwrap();
+        assert_eq!(publish(&subscribers, TOUCH_EVENT), (true, std::vec![ "app" ]));
+        assert_eq!(publish(&subscribers, BUTTON_EVENT), (true, std::vec![ "app" ]));
+        assert_eq!(publish(&subscribers, GESTURE_EVENT), (true, std::vec![ "overlay", "fallback" ]));
+    }
+
+    #[test]
+    fn unsubscribe_stops_delivery() {
+        let mut subscribers = Subscribers::new();
+        subscribers.subscribe("overlay", PRIORITY_OVERLAY, ALL, overlay).unwrap();
+        let id = subscribers.subscribe("app", PRIORITY_APP, ALL, app).unwrap();
+        subscribers.subscribe("fallback", PRIORITY_FALLBACK, ALL, fallback).unwrap();
+        assert_eq!(subscribers.unsubscribe(id), Ok(()));
+        assert_eq!(subscribers.unsubscribe(id), Err(Error::NotFound));
+        assert_eq!(publish(&subscribers, TOUCH_EVENT), (true, std::vec![ "overlay", "fallback" ]));
+    }
+
+    #[test]
+    fn table_is_bounded() {
+        let mut subscribers = Subscribers::new();
+        let ids: Vec<_> = (0..MAX_SUBSCRIBERS)
+            .map(|_| subscribers.subscribe("app2", PRIORITY_APP, ALL, app2).unwrap())
+            .collect();
+        assert_eq!(subscribers.subscribe("app", PRIORITY_OVERLAY, ALL, app).map(|_| ()), Err(Error::NoMemory));
+        //  IDs are unique, and a freed entry may be reused
+        for (i, id) in ids.iter().enumerate() { assert!(!ids[i + 1..].contains(id)); }
+        subscribers.unsubscribe(ids[3]).unwrap();
+        let id = subscribers.subscribe("app", PRIORITY_OVERLAY, ALL, app).unwrap();
+        assert!(!ids.contains(&id));
+        assert_eq!(subscribers.iter().count(), MAX_SUBSCRIBERS);
+        assert_eq!(publish(&subscribers, TOUCH_EVENT), (true, std::vec![ "app" ]));
+    }
+}
@@ -0,0 +1,462 @@
+//! Gesture recogniser for the touch panel. Consumes the touch down, contact and up actions of one finger, with
+//! timestamps in milliseconds, and recognises taps, double taps, long presses, swipes and drags. No Mynewt calls,
+//! so the recogniser is checked on the host by replaying touch traces with `rust/touch-host`.
+//!
+//! The touch controller sends contact actions repeatedly while the screen is touched, but the touch up action is
+//! sometimes missed. So a gap of `release_ms` between touch actions is also treated as touch up. Call `poll()` when
+//! `deadline()` is reached, so that long presses, releases and pending taps are recognised without touch actions.
+//!
+//! A touch that moves further than `move_distance` becomes a drag, and `Drag` is emitted for every touch action
+//! after that. If the drag is fast and long enough, a swipe is emitted when the finger is lifted. A tap is emitted
+//! after `double_tap_ms`, unless the screen is tapped again nearby, which emits `DoubleTap` instead.
+use crate::cst816s::TouchAction;
+
+/// Gesture recognised from the touch actions. Coordinates are the same as the touch actions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Gesture {
+    /// Short touch without moving
+    Tap { x: u16, y: u16 },
+    /// Two taps in quick succession at about the same point. Coordinates of the second tap.
+    DoubleTap { x: u16, y: u16 },
+    /// Touch held for `long_press_ms` without moving. Emitted while the finger is still touching.
+    LongPress { x: u16, y: u16 },
+    /// Fast drag towards the top, emitted when the finger is lifted. Coordinates of the touch down.
+    SwipeUp { x: u16, y: u16 },
+    /// Fast drag towards the bottom, emitted when the finger is lifted. Coordinates of the touch down.
+    SwipeDown { x: u16, y: u16 },
+    /// Fast drag towards the left, emitted when the finger is lifted. Coordinates of the touch down.
+    SwipeLeft { x: u16, y: u16 },
+    /// Fast drag towards the right, emitted when the finger is lifted. Coordinates of the touch down.
+    SwipeRight { x: u16, y: u16 },
+    /// Finger moved to (`x`, `y`), which is (`dx`, `dy`) from the touch down
+    Drag { x: u16, y: u16, dx: i16, dy: i16 },
+}
+
+/// Thresholds for recognising gestures. Distances are in pixels, durations in milliseconds.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Thresholds {
+    /// Max distance moved by a tap or long press. Touches that move further are drags.
+    pub move_distance:       u16,
+    /// Min distance moved by a swipe, horizontally or vertically
+    pub swipe_distance:      u16,
+    /// Max duration of a swipe, from touch down to touch up
+    pub swipe_ms:            u32,
+    /// Min duration of a long press. Shorter touches are taps.
+    pub long_press_ms:       u32,
+    /// Max time from the first tap to the touch down of the second tap of a double tap. 0 to disable double taps,
+    /// so that taps are emitted without waiting.
+    pub double_tap_ms:       u32,
+    /// Max distance between the two taps of a double tap
+    pub double_tap_distance: u16,
+    /// Gap between touch actions that is treated as touch up, because the touch up action may be missed
+    pub release_ms:          u32,
+}
+
+impl Thresholds {
+    /// Return the default thresholds for the PineTime touch panel
+    pub const fn new() -> Thresholds {
+        Thresholds {
+            move_distance:       12,
+            swipe_distance:      50,
+            swipe_ms:            500,
+            long_press_ms:       800,
+            double_tap_ms:       300,
+            double_tap_distance: 30,
+            release_ms:          200,
+        }
+    }
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds::new()
+    }
+}
+
+/// Max gestures waiting to be fetched by `next_gesture()`. The oldest gesture is dropped when full.
+const MAX_GESTURES: usize = 8;
+
+/// Touch that is in progress
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Touch {
+    /// Coordinates of the touch down
+    start_x:      u16,
+    start_y:      u16,
+    /// Time of the touch down
+    start_ms:     u32,
+    /// Latest coordinates
+    x:            u16,
+    y:            u16,
+    /// Time of the latest touch action
+    last_ms:      u32,
+    /// True if the touch has moved further than `move_distance`
+    moved:        bool,
+    /// True if `LongPress` has been emitted
+    long_pressed: bool,
+}
+
+/// Tap that may become the first tap of a double tap
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PendingTap {
+    x:     u16,
+    y:     u16,
+    /// Time of the touch up
+    up_ms: u32,
+}
+
+/// Gesture recogniser for the touch actions of one finger
+pub struct GestureRecognizer {
+    /// Thresholds for recognising gestures
+    thresholds: Thresholds,
+    /// Touch in progress, `None` if the finger is lifted
+    touch:      Option<Touch>,
+    /// Tap waiting for a second tap
+    pending:    Option<PendingTap>,
+    /// Gestures waiting to be fetched, starting at `head`
+    gestures:   [Gesture; MAX_GESTURES],
+    head:       usize,
+    count:      usize,
+}
+
+impl GestureRecognizer {
+    /// Return a gesture recogniser with the `thresholds`
+    pub const fn new(thresholds: Thresholds) -> GestureRecognizer {
+        GestureRecognizer {
+            thresholds,
+            touch:    None,
+            pending:  None,
+            gestures: [Gesture::Tap { x: 0, y: 0 }; MAX_GESTURES],
+            head:     0,
+            count:    0,
+        }
+    }
+
+    /// Return the thresholds
+    pub fn thresholds(&self) -> &Thresholds {
+        &self.thresholds
+    }
+
+    /// Change the thresholds. Takes effect for the next touch action.
+    pub fn set_thresholds(&mut self, thresholds: Thresholds) {
+        self.thresholds = thresholds;
+    }
+
+    /// Forget the touch in progress, the pending tap and the gestures not fetched
+    pub fn reset(&mut self) {
+        self.touch = None;
+        self.pending = None;
+        self.count = 0;
+    }
+
+    /// Handle the touch `action` at (`x`, `y`) at time `now_ms`. The coordinates of touch up are ignored, because
+    /// the touch controller may report touch up at (0, 0). Fetch the recognised gestures with `next_gesture()`.
+    pub fn update(&mut self, action: TouchAction, x: u16, y: u16, now_ms: u32) {
+        self.expire(now_ms);
+        match action {
+            TouchAction::Down => {
+                //  Touch up of the previous touch was missed
+                if let Some(touch) = self.touch { self.release(touch, now_ms); }
+                self.press(x, y, now_ms);
+            }
+            TouchAction::Contact => {
+                match self.touch {
+                    Some(touch) => self.contact(touch, x, y, now_ms),
+                    //  Touch down was missed
+                    None => self.press(x, y, now_ms),
+                }
+            }
+            TouchAction::Up => {
+                if let Some(touch) = self.touch { self.release(touch, now_ms); }
+            }
+        }
+    }
+
+    /// Recognise the gestures that depend only on time: long press, release after `release_ms` without touch
+    /// actions, and the tap after `double_tap_ms` without a second tap
+    pub fn poll(&mut self, now_ms: u32) {
+        self.expire(now_ms);
+        if let Some(mut touch) = self.touch {
+            if self.is_long_press(&touch, now_ms) {
+                touch.long_pressed = true;
+                self.touch = Some(touch);
+                self.flush_pending();
+                self.push(Gesture::LongPress { x: touch.x, y: touch.y });
+            }
+        }
+    }
+
+    /// Return the time when `poll()` should be called next, `None` if nothing is in progress
+    pub fn deadline(&self) -> Option<u32> {
+        let release = self.touch.map(|touch| {
+            let release = touch.last_ms.wrapping_add(self.thresholds.release_ms + 1);
+            let long_press = touch.start_ms.wrapping_add(self.thresholds.long_press_ms);
+            if touch.moved || touch.long_pressed || !is_before(long_press, release) { release }
+            else { long_press }
+        });
+        let tap = self.pending.map(|pending| pending.up_ms.wrapping_add(self.thresholds.double_tap_ms + 1));
+        match (release, tap) {
+            (Some(release), Some(tap)) => Some(if is_before(tap, release) { tap } else { release }),
+            (release, tap) => release.or(tap),
+        }
+    }
+
+    /// Return the oldest gesture recognised, `None` if none
+    pub fn next_gesture(&mut self) -> Option<Gesture> {
+        if self.count == 0 { return None; }
+        let gesture = self.gestures[self.head];
+        self.head = (self.head + 1) % MAX_GESTURES;
+        self.count -= 1;
+        Some(gesture)
+    }
+
+    /// Release the touch after `release_ms` without touch actions, and emit the pending tap after `double_tap_ms`
+    /// without a second tap
+    fn expire(&mut self, now_ms: u32) {
+        if let Some(touch) = self.touch {
+            if now_ms.wrapping_sub(touch.last_ms) > self.thresholds.release_ms {
+                //  Released at the last touch action
+                self.release(touch, touch.last_ms);
+            }
+        }
+        if let Some(pending) = self.pending {
+            if now_ms.wrapping_sub(pending.up_ms) > self.thresholds.double_tap_ms {
+                self.flush_pending();
+            }
+        }
+    }
+
+    /// Start a touch at (`x`, `y`). A pending tap that is too far away can't become a double tap.
+    fn press(&mut self, x: u16, y: u16, now_ms: u32) {
+        if let Some(pending) = self.pending {
+            if distance(pending.x, pending.y, x, y) > self.thresholds.double_tap_distance as u32 {
+                self.flush_pending();
+            }
+        }
+        self.touch = Some(Touch {
+            start_x: x, start_y: y, start_ms: now_ms,
+            x, y, last_ms: now_ms,
+            moved: false, long_pressed: false,
+        });
+    }
+
+    /// Move the `touch` to (`x`, `y`) and emit `Drag` or `LongPress`
+    fn contact(&mut self, mut touch: Touch, x: u16, y: u16, now_ms: u32) {
+        touch.x = x;
+        touch.y = y;
+        touch.last_ms = now_ms;
+        if !touch.moved
+            && distance(touch.start_x, touch.start_y, x, y) > self.thresholds.move_distance as u32 {
+            //  Touch is not a tap, so it can't be the second tap of a double tap
+            touch.moved = true;
+            self.flush_pending();
+        }
+        if touch.moved {
+            self.push(Gesture::Drag {
+                x, y,
+                dx: x as i16 - touch.start_x as i16,
+                dy: y as i16 - touch.start_y as i16,
+            });
+        } else if self.is_long_press(&touch, now_ms) {
+            touch.long_pressed = true;
+            self.flush_pending();
+            self.push(Gesture::LongPress { x, y });
+        }
+        self.touch = Some(touch);
+    }
+
+    /// End the `touch` at time `now_ms` and emit a swipe, long press, tap or double tap
+    fn release(&mut self, touch: Touch, now_ms: u32) {
+        self.touch = None;
+        let duration = now_ms.wrapping_sub(touch.start_ms);
+        let (x, y) = (touch.start_x, touch.start_y);
+        if touch.moved {
+            //  Swipe if fast and long enough, in the direction that moved furthest
+            let dx = touch.x as i32 - x as i32;
+            let dy = touch.y as i32 - y as i32;
+            if duration > self.thresholds.swipe_ms
+                || dx.abs().max(dy.abs()) < self.thresholds.swipe_distance as i32 { return; }
+            self.push(
+                if dx.abs() > dy.abs() {
+                    if dx < 0 { Gesture::SwipeLeft { x, y } } else { Gesture::SwipeRight { x, y } }
+                } else if dy < 0 { Gesture::SwipeUp { x, y } } else { Gesture::SwipeDown { x, y } }
+            );
+        } else if touch.long_pressed {
+            //  Long press has been emitted
+        } else if duration >= self.thresholds.long_press_ms {
+            //  Long press without any touch actions or `poll()` in between
+            self.flush_pending();
+            self.push(Gesture::LongPress { x: touch.x, y: touch.y });
+        } else if self.pending.take().is_some() {
+            //  Second tap nearby, see `press()`
+            self.push(Gesture::DoubleTap { x: touch.x, y: touch.y });
+        } else if self.thresholds.double_tap_ms == 0 {
+            self.push(Gesture::Tap { x: touch.x, y: touch.y });
+        } else {
+            //  Wait for a second tap
+            self.pending = Some(PendingTap { x: touch.x, y: touch.y, up_ms: now_ms });
+        }
+    }
+
+    /// Return true if the `touch` has been held long enough for a long press that hasn't been emitted
+    fn is_long_press(&self, touch: &Touch, now_ms: u32) -> bool {
+        !touch.moved && !touch.long_pressed
+            && now_ms.wrapping_sub(touch.start_ms) >= self.thresholds.long_press_ms
+    }
+
+    /// Emit the pending tap as a tap, because it can't become a double tap
+    fn flush_pending(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            self.push(Gesture::Tap { x: pending.x, y: pending.y });
+        }
+    }
+
+    /// Add the `gesture` to the gestures to be fetched. Drop the oldest gesture if full.
+    fn push(&mut self, gesture: Gesture) {
+        if self.count == MAX_GESTURES {
+            self.head = (self.head + 1) % MAX_GESTURES;
+            self.count -= 1;
+        }
+        self.gestures[(self.head + self.count) % MAX_GESTURES] = gesture;
+        self.count += 1;
+    }
+}
+
+impl Default for GestureRecognizer {
+    fn default() -> Self {
+        GestureRecognizer::new(Thresholds::new())
+    }
+}
+
+/// Return the distance from (`x0`, `y0`) to (`x1`, `y1`), horizontally or vertically, whichever is longer
+fn distance(x0: u16, y0: u16, x1: u16, y1: u16) -> u32 {
+    let dx = (x1 as i32 - x0 as i32).abs() as u32;
+    let dy = (y1 as i32 - y0 as i32).abs() as u32;
+    dx.max(dy)
+}
+
+/// Return true if time `a` is before time `b`, allowing for the millisecond clock to wrap around
+fn is_before(a: u32, b: u32) -> bool {
+    (a.wrapping_sub(b) as i32) < 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    extern crate std;
+    use std::{ format, string::String, vec::Vec };
+
+    /// Replay the touch trace `text` in the format of `rust/touch-host/traces`, like `touch-host --gestures`.
+    /// Panic if the gestures differ from the `expect` lines, if a poll recognises a gesture before the deadline,
+    /// or if the trace ends with a gesture in progress.
+    fn replay(text: &str) {
+        let mut recognizer = GestureRecognizer::default();
+        //  Gestures recognised after the last touch action or poll, and the gestures expected
+        let mut gestures: Vec<String> = Vec::new();
+        let mut expected: Vec<String> = Vec::new();
+        let mut step = 0;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            let words: Vec<_> = line.split_whitespace().collect();
+            let number = |word: &str| word.parse::<u32>().unwrap();
+            match words.as_slice() {
+                [] => continue,
+                [ "set", name, value ] => {
+                    let mut thresholds = *recognizer.thresholds();
+                    match *name {
+                        "swipe_ms"      => thresholds.swipe_ms = number(value),
+                        "long_press_ms" => thresholds.long_press_ms = number(value),
+                        "double_tap_ms" => thresholds.double_tap_ms = number(value),
+                        "release_ms"    => thresholds.release_ms = number(value),
+                        _ => panic!("line {}: unknown threshold {}", index + 1, name),
+                    }
+                    recognizer.set_thresholds(thresholds);
+                    continue;
+                }
+                [ "expect", gesture @ .. ] => { expected.push(gesture.join(" ")); continue; }
+                _ => {}
+            }
+            assert_eq!(gestures, expected, "before line {}", index + 1);
+            expected.clear();
+            let deadline = recognizer.deadline();
+            let early = match words.as_slice() {
+                [ "poll", ms ] => {
+                    recognizer.poll(number(ms));
+                    deadline.map(|deadline| is_before(number(ms), deadline)).unwrap_or(true)
+                }
+                [ action, x, y, ms ] => {
+                    let action = match *action {
+                        "down"    => TouchAction::Down,
+                        "contact" => TouchAction::Contact,
+                        "up"      => TouchAction::Up,
+                        _ => panic!("line {}: unknown action {}", index + 1, action),
+                    };
+                    recognizer.update(action, number(x) as u16, number(y) as u16, number(ms));
+                    false
+                }
+                _ => panic!("line {}: bad line {}", index + 1, line),
+            };
+            gestures = core::iter::from_fn(|| recognizer.next_gesture()).map(describe).collect();
+            assert!(!early || gestures.is_empty(), "line {}: gestures recognised before the deadline {:?}", index + 1, deadline);
+            step += 1;
+        }
+        assert_eq!(gestures, expected, "at the end");
+        assert!(step > 0, "no touch actions");
+        assert_eq!(recognizer.deadline(), None, "trace ended with gesture in progress");
+    }
+
+    /// Return the `gesture` in the format of the `expect` lines
+    fn describe(gesture: Gesture) -> String {
+        match gesture {
+            Gesture::Tap { x, y }        => format!("tap {} {}", x, y),
+            Gesture::DoubleTap { x, y }  => format!("double-tap {} {}", x, y),
+            Gesture::LongPress { x, y }  => format!("long-press {} {}", x, y),
+            Gesture::SwipeUp { x, y }    => format!("swipe-up {} {}", x, y),
+            Gesture::SwipeDown { x, y }  => format!("swipe-down {} {}", x, y),
+            Gesture::SwipeLeft { x, y }  => format!("swipe-left {} {}", x, y),
+            Gesture::SwipeRight { x, y } => format!("swipe-right {} {}", x, y),
+            Gesture::Drag { x, y, dx, dy } => format!("drag {} {} {} {}", x, y, dx, dy),
+        }
+    }
+
+    #[test]
+    fn trace_tap() {
+        replay(include_str!("../../touch-host/traces/tap.txt"));
+    }
+
+    #[test]
+    fn trace_tap_immediate() {
+        replay(include_str!("../../touch-host/traces/tap-immediate.txt"));
+    }
+
+    #[test]
+    fn trace_double_tap() {
+        replay(include_str!("../../touch-host/traces/double-tap.txt"));
+    }
+
+    #[test]
+    fn trace_long_press() {
+        replay(include_str!("../../touch-host/traces/long-press.txt"));
+    }
+
+    #[test]
+    fn trace_swipe() {
+        replay(include_str!("../../touch-host/traces/swipe.txt"));
+    }
+
+    #[test]
+    fn trace_drag() {
+        replay(include_str!("../../touch-host/traces/drag.txt"));
+    }
+
+    #[test]
+    fn trace_missed_up() {
+        replay(include_str!("../../touch-host/traces/missed-up.txt"));
+    }
+
+    #[test]
+    fn trace_expect_fails() {
+        //  A wrong expectation is caught, so the traces aren't passing vacuously
+        let result = std::panic::catch_unwind(|| replay("down 120 100 1000\nup 120 100 1050\npoll 1351\nexpect tap 1 1\n"));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,27 @@
+//! Touch panel support for PineTime, without Mynewt calls. The CST816S touch controller driver is generic over the
+//! `embedded_hal` I2C, GPIO and delay traits, so it runs on PineTime with `mynewt::I2C` and on the host with the
+//! scripted bus in `mock`, which is used by `rust/touch-host` to replay captured register dumps.
+//! The gesture recogniser in `gesture` turns the touch actions into taps, swipes and drags. `samples` contains the
+//! ring buffer of touch samples and the latency statistics for the touch task. `calibration` maps the raw touch
+//! coordinates to the display panel with an affine transform computed from touches at known targets. `events`
+//! contains the subscriber table that delivers the touch, button and gesture events to the apps in order of priority.
+
+#![no_std]  //  Don't link with standard Rust library, so that the driver runs on PineTime
+
+pub mod calibration;  //  Export `calibration.rs` as Rust module `pinetime_touch::calibration`
+
+pub mod cst816s;  //  Export `cst816s.rs` as Rust module `pinetime_touch::cst816s`
+
+pub mod events;   //  Export `events.rs` as Rust module `pinetime_touch::events`
+
+pub mod gesture;  //  Export `gesture.rs` as Rust module `pinetime_touch::gesture`
+
+pub mod mock;     //  Export `mock.rs` as Rust module `pinetime_touch::mock`
+
+pub mod samples;  //  Export `samples.rs` as Rust module `pinetime_touch::samples`
+
+pub use calibration::Calibration;  //  Export the touch calibration
+
+pub use cst816s::{ Cst816s, Error, TouchAction, TouchData, TouchPoint };  //  Export the CST816S driver
+
+pub use gesture::{ Gesture, GestureRecognizer, Thresholds };  //  Export the gesture recogniser
@@ -0,0 +1,191 @@
+//! Scripted I2C bus, reset pin and delay for running the touch controller driver on the host. The I2C bus replays
+//! a script of transactions, e.g. register dumps captured on PineTime, and fails if the driver does something
+//! that's not in the script.
+use embedded_hal::{
+    blocking::{
+        delay::DelayMs,
+        i2c::WriteRead,
+    },
+    digital::v2::OutputPin,
+};
+
+/// I2C transaction expected by `ScriptedI2c`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transaction<'a> {
+    /// Expect `write` to be written to `address`, then return `read`
+    WriteRead { address: u8, write: &'a [u8], read: &'a [u8] },
+    /// Expect a transfer to `address`, which is not acknowledged, like a sleeping touch controller
+    Nack { address: u8 },
+}
+
+/// Error returned by `ScriptedI2c`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MockError {
+    /// Device didn't acknowledge, as scripted by `Transaction::Nack`
+    Nack,
+    /// Transfer doesn't match the next transaction in the script, or the script has ended
+    Unexpected,
+}
+
+/// I2C bus that replays a script of transactions
+pub struct ScriptedI2c<'a> {
+    /// Transactions expected, in order
+    script: &'a [Transaction<'a>],
+    /// Index of the next transaction
+    next:   usize,
+}
+
+impl<'a> ScriptedI2c<'a> {
+    /// Return an I2C bus that expects the transactions in `script`
+    pub fn new(script: &'a [Transaction<'a>]) -> ScriptedI2c<'a> {
+        ScriptedI2c { script, next: 0 }
+    }
+
+    /// Return the number of transactions in the script that haven't been done
+    pub fn remaining(&self) -> usize {
+        self.script.len() - self.next
+    }
+}
+
+impl<'a> WriteRead for ScriptedI2c<'a> {
+    /// Check the transfer against the next transaction in the script and return the scripted data
+    fn write_read(&mut self, address: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+        let transaction = self.script.get(self.next).cloned()
+            .ok_or(MockError::Unexpected) ? ;
+        self.next += 1;
+        match transaction {
+            Transaction::WriteRead { address: expected, write, read } => {
+                if address != expected || bytes != write || buffer.len() != read.len() {
+                    return Err(MockError::Unexpected);
+                }
+                buffer.copy_from_slice(read);
+                Ok(())
+            }
+            Transaction::Nack { address: expected } => {
+                if address != expected { return Err(MockError::Unexpected); }
+                Err(MockError::Nack)
+            }
+        }
+    }
+
+    type Error = MockError;
+}
+
+/// Output pin that remembers its level
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MockPin {
+    /// True if the pin is high
+    pub high: bool,
+    /// Number of times the level has changed
+    pub changes: u32,
+}
+
+impl MockPin {
+    /// Return a pin that is high if `high` is true, low otherwise
+    pub const fn new(high: bool) -> MockPin {
+        MockPin { high, changes: 0 }
+    }
+
+    /// Set the level of the pin
+    fn set(&mut self, high: bool) {
+        if self.high != high { self.changes += 1; }
+        self.high = high;
+    }
+}
+
+impl OutputPin for MockPin {
+    /// Set the pin to low
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.set(false);
+        Ok(())
+    }
+
+    /// Set the pin to high
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.set(true);
+        Ok(())
+    }
+
+    /// Setting the pin never fails
+    type Error = ();
+}
+
+/// Delay that doesn't sleep, but adds up the time slept
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MockDelay {
+    /// Total time slept, in milliseconds
+    pub total_ms: u32,
+}
+
+impl MockDelay {
+    /// Return a delay that hasn't slept
+    pub const fn new() -> MockDelay {
+        MockDelay { total_ms: 0 }
+    }
+}
+
+impl DelayMs<u8> for MockDelay {
+    /// Add `ms` milliseconds to the time slept
+    fn delay_ms(&mut self, ms: u8) {
+        self.total_ms += ms as u32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripted_i2c_replays_script() {
+        let script = [
+            Transaction::WriteRead { address: 0x15, write: &[0], read: &[ 1, 2, 3 ] },
+            Transaction::Nack { address: 0x15 },
+        ];
+        let mut i2c = ScriptedI2c::new(&script);
+        let mut buf = [0; 3];
+        assert_eq!(i2c.remaining(), 2);
+        assert_eq!(i2c.write_read(0x15, &[0], &mut buf), Ok(()));
+        assert_eq!(buf, [ 1, 2, 3 ]);
+        assert_eq!(i2c.remaining(), 1);
+        assert_eq!(i2c.write_read(0x15, &[0], &mut buf), Err(MockError::Nack));
+        assert_eq!(i2c.remaining(), 0);
+        assert_eq!(i2c.write_read(0x15, &[0], &mut buf), Err(MockError::Unexpected));  //  Script has ended
+    }
+
+    #[test]
+    fn scripted_i2c_rejects_unexpected_transfers() {
+        let script = [ Transaction::WriteRead { address: 0x15, write: &[0], read: &[ 1, 2, 3 ] } ];
+        let cases: [ (u8, &[u8], usize); 3 ] = [
+            (0x16, &[0], 3),  //  Wrong address
+            (0x15, &[1], 3),  //  Wrong register
+            (0x15, &[0], 2),  //  Wrong length
+        ];
+        for &(address, write, len) in cases.iter() {
+            let mut i2c = ScriptedI2c::new(&script);
+            let mut buf = [0; 3];
+            assert_eq!(i2c.write_read(address, write, &mut buf[..len]), Err(MockError::Unexpected));
+            assert_eq!(buf, [0; 3]);
+        }
+        let nack = [ Transaction::Nack { address: 0x15 } ];
+        assert_eq!(ScriptedI2c::new(&nack).write_read(0x16, &[0], &mut [0; 3]), Err(MockError::Unexpected));
+    }
+
+    #[test]
+    fn mock_pin_counts_changes() {
+        let mut pin = MockPin::new(true);
+        pin.set_high().unwrap();
+        assert_eq!(pin, MockPin { high: true, changes: 0 });
+        pin.set_low().unwrap();
+        pin.set_low().unwrap();
+        pin.set_high().unwrap();
+        assert_eq!(pin, MockPin { high: true, changes: 2 });
+    }
+
+    #[test]
+    fn mock_delay_adds_up() {
+        let mut delay = MockDelay::new();
+        delay.delay_ms(20);
+        delay.delay_ms(200);
+        assert_eq!(delay.total_ms, 220);
+    }
+}
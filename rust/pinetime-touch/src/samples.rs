@@ -0,0 +1,236 @@
+//! Bounded ring buffer of touch samples, written by the touch task and drained by any number of consumers.
+//! Each consumer reads with its own `SampleCursor`, so consumers don't take samples from each other.
+//! When a consumer falls behind by more than `SAMPLE_CAPACITY` samples, the oldest samples are lost for that
+//! consumer and counted in its cursor. The ring buffer doesn't lock, the caller must prevent concurrent access.
+use crate::cst816s::TouchAction;
+
+/// Number of touch samples kept in the ring buffer
+pub const SAMPLE_CAPACITY: usize = 32;
+
+/// Touch point read from the touch controller
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TouchSample {
+    /// Which finger touched
+    pub finger:  u8,
+    /// Touch action
+    pub action:  TouchAction,
+    /// X coordinate
+    pub x:       u16,
+    /// Y coordinate
+    pub y:       u16,
+    /// Time of the touch interrupt, in milliseconds
+    pub time_ms: u32,
+}
+
+/// Ring buffer of the latest `SAMPLE_CAPACITY` touch samples
+pub struct SampleRing {
+    /// Touch samples. Sample number `n` is at index `n % SAMPLE_CAPACITY`.
+    samples: [TouchSample; SAMPLE_CAPACITY],
+    /// Number of samples written since the ring buffer was created, wrapping around
+    written: u32,
+}
+
+impl SampleRing {
+    /// Return an empty ring buffer
+    pub const fn new() -> SampleRing {
+        SampleRing {
+            samples: [TouchSample { finger: 0, action: TouchAction::Up, x: 0, y: 0, time_ms: 0 }; SAMPLE_CAPACITY],
+            written: 0,
+        }
+    }
+
+    /// Add the `sample`, overwriting the oldest sample if full
+    pub fn push(&mut self, sample: TouchSample) {
+        self.samples[self.written as usize % SAMPLE_CAPACITY] = sample;
+        self.written = self.written.wrapping_add(1);
+    }
+
+    /// Return the number of samples written since the ring buffer was created, wrapping around
+    pub fn written(&self) -> u32 {
+        self.written
+    }
+
+    /// Return the next sample for the `cursor` and advance the cursor, `None` if the cursor has read every sample.
+    /// Samples that have been overwritten since the last read are skipped and added to `cursor.lost`.
+    pub fn read(&self, cursor: &mut SampleCursor) -> Option<TouchSample> {
+        let unread = self.written.wrapping_sub(cursor.next);
+        if unread == 0 { return None; }
+        if unread > SAMPLE_CAPACITY as u32 {
+            //  Skip to the oldest sample in the ring buffer
+            let lost = unread - SAMPLE_CAPACITY as u32;
+            cursor.lost = cursor.lost.wrapping_add(lost);
+            cursor.next = cursor.next.wrapping_add(lost);
+        }
+        let sample = self.samples[cursor.next as usize % SAMPLE_CAPACITY];
+        cursor.next = cursor.next.wrapping_add(1);
+        Some(sample)
+    }
+}
+
+impl Default for SampleRing {
+    fn default() -> Self {
+        SampleRing::new()
+    }
+}
+
+/// Read position of a consumer in the `SampleRing`
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SampleCursor {
+    /// Number of the next sample to be read
+    next:     u32,
+    /// Number of samples that were overwritten before they were read
+    pub lost: u32,
+}
+
+impl SampleCursor {
+    /// Return a cursor that reads from the first sample written to the ring buffer
+    pub const fn new() -> SampleCursor {
+        SampleCursor { next: 0, lost: 0 }
+    }
+
+    /// Skip the samples in the `ring` that have not been read, so that only new samples are read
+    pub fn skip_to_latest(&mut self, ring: &SampleRing) {
+        self.next = ring.written();
+    }
+}
+
+/// Latency statistics in microseconds, e.g. from the touch interrupt to the touch data being read
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LatencyStats {
+    /// Number of latencies recorded
+    pub count:    u32,
+    /// Shortest latency
+    pub min_us:   u32,
+    /// Longest latency
+    pub max_us:   u32,
+    /// Sum of the latencies, for computing the mean
+    pub total_us: u64,
+}
+
+impl LatencyStats {
+    /// Return statistics with no latencies recorded
+    pub const fn new() -> LatencyStats {
+        LatencyStats { count: 0, min_us: 0, max_us: 0, total_us: 0 }
+    }
+
+    /// Record the latency `us` in microseconds
+    pub fn record(&mut self, us: u32) {
+        if self.count == 0 || us < self.min_us { self.min_us = us; }
+        if us > self.max_us { self.max_us = us; }
+        self.count = self.count.saturating_add(1);
+        self.total_us += us as u64;
+    }
+
+    /// Return the mean latency in microseconds, 0 if none recorded
+    pub fn mean_us(&self) -> u32 {
+        if self.count == 0 { return 0; }
+        (self.total_us / self.count as u64) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Return a touch sample for finger 0, numbered `n` in the coordinates and time
+    fn sample(n: u32) -> TouchSample {
+        TouchSample { finger: 0, action: TouchAction::Contact, x: n as u16, y: n as u16 + 1, time_ms: n * 10 }
+    }
+
+    #[test]
+    fn empty_ring() {
+        let ring = SampleRing::new();
+        let mut cursor = SampleCursor::new();
+        assert_eq!(ring.written(), 0);
+        assert_eq!(ring.read(&mut cursor), None);
+        assert_eq!(cursor, SampleCursor::new());
+    }
+
+    #[test]
+    fn read_in_order() {
+        let mut ring = SampleRing::new();
+        let mut cursor = SampleCursor::new();
+        for n in 0..3 { ring.push(sample(n)); }
+        for n in 0..3 { assert_eq!(ring.read(&mut cursor), Some(sample(n))); }
+        assert_eq!(ring.read(&mut cursor), None);
+        ring.push(sample(3));
+        assert_eq!(ring.read(&mut cursor), Some(sample(3)));
+        assert_eq!(cursor.lost, 0);
+    }
+
+    #[test]
+    fn consumers_are_independent() {
+        let mut ring = SampleRing::new();
+        let mut first = SampleCursor::new();
+        let mut second = SampleCursor::new();
+        ring.push(sample(0));
+        ring.push(sample(1));
+        assert_eq!(ring.read(&mut first), Some(sample(0)));
+        assert_eq!(ring.read(&mut first), Some(sample(1)));
+        //  The first consumer doesn't take the samples from the second consumer
+        assert_eq!(ring.read(&mut second), Some(sample(0)));
+        assert_eq!(ring.read(&mut first), None);
+        assert_eq!(ring.read(&mut second), Some(sample(1)));
+    }
+
+    #[test]
+    fn skip_to_latest() {
+        let mut ring = SampleRing::new();
+        for n in 0..5 { ring.push(sample(n)); }
+        let mut cursor = SampleCursor::new();
+        cursor.skip_to_latest(&ring);
+        assert_eq!(ring.read(&mut cursor), None);
+        ring.push(sample(5));
+        assert_eq!(ring.read(&mut cursor), Some(sample(5)));
+    }
+
+    #[test]
+    fn overwritten_samples_are_lost() {
+        let mut ring = SampleRing::new();
+        let mut cursor = SampleCursor::new();
+        let total = SAMPLE_CAPACITY as u32 + 5;
+        for n in 0..total { ring.push(sample(n)); }
+        //  The oldest 5 samples were overwritten, the rest are read in order
+        for n in 5..total { assert_eq!(ring.read(&mut cursor), Some(sample(n))); }
+        assert_eq!(ring.read(&mut cursor), None);
+        assert_eq!(cursor.lost, 5);
+        //  A full ring buffer loses nothing
+        for n in 0..SAMPLE_CAPACITY as u32 { ring.push(sample(n)); }
+        for n in 0..SAMPLE_CAPACITY as u32 { assert_eq!(ring.read(&mut cursor), Some(sample(n))); }
+        assert_eq!(cursor.lost, 5);
+    }
+
+    #[test]
+    fn written_count_wraps_around() {
+        let mut ring = SampleRing::new();
+        ring.written = u32::MAX - 1;
+        let mut cursor = SampleCursor { next: u32::MAX - 1, lost: 0 };
+        for n in 0..4 { ring.push(sample(n)); }
+        assert_eq!(ring.written(), 2);
+        for n in 0..4 { assert_eq!(ring.read(&mut cursor), Some(sample(n))); }
+        assert_eq!(ring.read(&mut cursor), None);
+        assert_eq!(cursor.lost, 0);
+    }
+
+    #[test]
+    fn latency_stats() {
+        let mut stats = LatencyStats::new();
+        assert_eq!(stats.mean_us(), 0);
+        stats.record(300);
+        assert_eq!((stats.count, stats.min_us, stats.max_us, stats.mean_us()), (1, 300, 300, 300));
+        stats.record(100);
+        stats.record(500);
+        assert_eq!((stats.count, stats.min_us, stats.max_us, stats.mean_us()), (3, 100, 500, 300));
+        //  Zero latency is the minimum, not "nothing recorded"
+        stats.record(0);
+        assert_eq!((stats.count, stats.min_us, stats.max_us, stats.total_us), (4, 0, 500, 900));
+    }
+
+    #[test]
+    fn latency_stats_large_totals() {
+        let mut stats = LatencyStats::new();
+        for _ in 0..4 { stats.record(u32::MAX); }
+        assert_eq!(stats.total_us, 4 * u32::MAX as u64);
+        assert_eq!(stats.mean_us(), u32::MAX);
+    }
+}
@@ -0,0 +1,70 @@
+//! Touch calibration files for the affine touch calibration. A calibration file contains the raw touch points at
+//! the targets on the display panel, like the calibration routine in `calibration.rs` on PineTime, and the raw
+//! coordinates expected to be mapped to display panel coordinates after calibration:
+//! ```text
+//! # Comment
+//! touch 12 18 30 30        Raw x, y touched at the target x, y on the display panel
+//! map 120 120 118 116      Raw x, y expected to be calibrated to x, y
+//! error 2                  Largest distance in pixels between a calibrated touch and its target
+//! invalid                  Touches can't be calibrated, e.g. they are on a line
+//! rejected                 Calibration is rejected, because a target was touched in the wrong place
+//! ```
+
+/// Raw coordinates with the display panel coordinates expected after calibration
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Map {
+    /// Raw x and y
+    pub raw:      (u16, u16),
+    /// Display panel x and y expected
+    pub expected: (u16, u16),
+    /// Line number in the calibration file
+    pub line:     usize,
+}
+
+/// Calibration file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CalibrationFile {
+    /// Raw touch points
+    pub raw:       Vec<(u16, u16)>,
+    /// Targets on the display panel for the raw touch points
+    pub targets:   Vec<(u16, u16)>,
+    /// Raw coordinates to be mapped after calibration
+    pub maps:      Vec<Map>,
+    /// Largest distance between a calibrated touch and its target
+    pub error:     Option<u16>,
+    /// True if the touches can't be calibrated
+    pub invalid:   bool,
+    /// True if the calibration is rejected because a target was touched in the wrong place
+    pub rejected:  bool,
+}
+
+/// Parse the calibration file `text`
+pub fn parse(text: &str) -> Result<CalibrationFile, String> {
+    let mut file = CalibrationFile::default();
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap_or("").trim();
+        let words: Vec<_> = line.split_whitespace().collect();
+        let err = |msg: &str| format!("line {}: {}", line_number, msg);
+        let coord = |word: &str| word.parse::<u16>().map_err(|_| err(&format!("bad coordinate {}", word)));
+        match words.as_slice() {
+            [] => continue,
+            [ "touch", raw_x, raw_y, x, y ] => {
+                file.raw.push((coord(raw_x) ? , coord(raw_y) ? ));
+                file.targets.push((coord(x) ? , coord(y) ? ));
+            }
+            [ "map", raw_x, raw_y, x, y ] => {
+                let raw = (coord(raw_x) ? , coord(raw_y) ? );
+                file.maps.push(Map { raw, expected: (coord(x) ? , coord(y) ? ), line: line_number });
+            }
+            [ "error", error ] => file.error = Some(coord(error) ? ),
+            [ "invalid" ] => file.invalid = true,
+            [ "rejected" ] => file.rejected = true,
+            [ word, .. ] => return Err(err(&format!("unknown keyword or bad arguments: {}", word))),
+        }
+    }
+    if file.invalid && (!file.maps.is_empty() || file.error.is_some() || file.rejected) {
+        return Err("invalid calibration can't have map, error or rejected".to_string());
+    }
+    Ok(file)
+}
@@ -0,0 +1,91 @@
+//! Register dumps of the CST816S touch controller, with the touch points expected from each dump.
+//! A dump file contains one line per touch interrupt, followed by the expected touch points:
+//! ```text
+//! # Comment
+//! regs 00 00 01 00 7c 00 be ...    63 bytes read from register 0
+//! expect down 0 124 190            Action, finger, x and y of each touch point, in order
+//! nack                             Touch controller didn't acknowledge
+//! expect nack
+//! ```
+use pinetime_touch::{
+    cst816s::TOUCH_DATA_SIZE,
+    TouchAction,
+};
+
+/// Touch point expected from a register dump
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Expected {
+    /// Touch action
+    pub action: TouchAction,
+    /// Which finger touched
+    pub finger: u8,
+    /// X coordinate
+    pub x:      u16,
+    /// Y coordinate
+    pub y:      u16,
+}
+
+/// Touch controller response to a touch interrupt
+#[derive(Clone, Debug, PartialEq)]
+pub struct Read {
+    /// Register values, `None` if the touch controller didn't acknowledge
+    pub regs:     Option<Vec<u8>>,
+    /// Touch points expected, in order
+    pub expected: Vec<Expected>,
+    /// Line number of the response in the dump file
+    pub line:     usize,
+}
+
+/// Parse the dump file `text` into touch controller responses
+pub fn parse(text: &str) -> Result<Vec<Read>, String> {
+    let mut reads: Vec<Read> = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap_or("").trim();
+        let mut words = line.split_whitespace();
+        let err = |msg: &str| format!("line {}: {}", line_number, msg);
+        match words.next() {
+            None => continue,
+            Some("regs") => {
+                let regs = words
+                    .map(|word| u8::from_str_radix(word, 16))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| err("bad register value")) ? ;
+                if regs.len() != TOUCH_DATA_SIZE {
+                    return Err(err(&format!("expected {} registers, found {}", TOUCH_DATA_SIZE, regs.len())));
+                }
+                reads.push(Read { regs: Some(regs), expected: Vec::new(), line: line_number });
+            }
+            Some("nack") => {
+                reads.push(Read { regs: None, expected: Vec::new(), line: line_number });
+            }
+            Some("expect") => {
+                let read = reads.last_mut()
+                    .ok_or_else(|| err("expect without regs or nack")) ? ;
+                let words: Vec<_> = words.collect();
+                match words.as_slice() {
+                    [ "none" ] if read.regs.is_some() => {}
+                    [ "nack" ] if read.regs.is_none() => {}
+                    [ "none" ] | [ "nack" ] => return Err(err("expect none after regs, expect nack after nack")),
+                    [ action, finger, x, y ] => {
+                        let action = match *action {
+                            "down"    => TouchAction::Down,
+                            "up"      => TouchAction::Up,
+                            "contact" => TouchAction::Contact,
+                            _ => return Err(err("bad action")),
+                        };
+                        read.expected.push(Expected {
+                            action,
+                            finger: finger.parse().map_err(|_| err("bad finger")) ? ,
+                            x:      x.parse().map_err(|_| err("bad x")) ? ,
+                            y:      y.parse().map_err(|_| err("bad y")) ? ,
+                        });
+                    }
+                    _ => return Err(err("expected: expect ACTION FINGER X Y")),
+                }
+            }
+            Some(word) => return Err(err(&format!("unknown keyword {}", word))),
+        }
+    }
+    Ok(reads)
+}
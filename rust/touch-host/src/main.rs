@@ -0,0 +1,201 @@
+//! Replay register dumps of the CST816S touch controller through the PineTime touch driver, on the host computer.
+//! Each dump is replayed with a scripted I2C bus: the driver resets the touch controller, reads the touch data once
+//! per touch interrupt, and the decoded touch points are compared with the expected touch points in the dump.
+//! With `--gestures`, touch traces are replayed through the gesture recogniser and the gestures are compared instead.
+//! With `--calibration`, the touch calibration is computed from the touches in each file and checked.
+//! ```bash
+//! cargo run -p touch-host --target x86_64-unknown-linux-gnu -- rust/touch-host/dumps/*.txt
+//! cargo run -p touch-host --target x86_64-unknown-linux-gnu -- --gestures rust/touch-host/traces/*.txt
+//! cargo run -p touch-host --target x86_64-unknown-linux-gnu -- --calibration rust/touch-host/calibrations/*.txt
+//! ```
+mod calibration;  //  Declare `calibration.rs` as Rust module `calibration` for parsing calibration files
+
+mod dump;   //  Declare `dump.rs` as Rust module `dump` for parsing register dumps
+
+mod trace;  //  Declare `trace.rs` as Rust module `trace` for parsing touch traces
+
+use dump::{ Expected, Read };
+use pinetime_touch::{
+    cst816s::ADDRESS,
+    mock::{ MockDelay, MockError, MockPin, ScriptedI2c, Transaction },
+    Calibration, Cst816s, Error, Gesture, GestureRecognizer,
+};
+use std::{
+    env,
+    fs,
+    process,
+};
+
+/// Usage message shown when the command line is invalid
+const USAGE: &str = "usage: touch-host DUMP...\n       touch-host --gestures TRACE...\n       touch-host --calibration FILE...";
+
+/// Time that the driver should sleep while resetting the touch controller, in milliseconds
+const RESET_MS: u32 = 420;
+
+fn main() {
+    let mut paths: Vec<String> = env::args().skip(1).collect();
+    let mode = match paths.first().map(|arg| arg.as_str()) {
+        Some(mode @ "--gestures") | Some(mode @ "--calibration") => mode.to_string(),
+        _ => String::new(),
+    };
+    if !mode.is_empty() { paths.remove(0); }
+    if paths.is_empty() {
+        eprintln!("{}", USAGE);
+        process::exit(1);
+    }
+    let mut failed = false;
+    for path in &paths {
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|err| {
+                eprintln!("{}: {}", path, err);
+                process::exit(1);
+            });
+        let result = match mode.as_str() {
+            "--gestures"    => check_trace(path, &text),
+            "--calibration" => check_calibration(path, &text),
+            _               => check_dump(path, &text),
+        };
+        match result {
+            Ok(msg) => println!("{}: {}", path, msg),
+            Err(err) => {
+                eprintln!("{}: {}", path, err);
+                failed = true;
+            }
+        }
+    }
+    //  Exit with status 2 if any dump, trace or calibration doesn't match, like `chip8-host` with a golden image
+    if failed { process::exit(2); }
+}
+
+/// Parse and replay the register dump `text` from file `path`. Exit with status 1 if the dump is invalid.
+fn check_dump(path: &str, text: &str) -> Result<String, String> {
+    let reads = dump::parse(text)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        });
+    replay(&reads) ? ;
+    Ok(format!("{} reads ok", reads.len()))
+}
+
+/// Parse and replay the touch trace `text` from file `path`. Exit with status 1 if the trace is invalid.
+fn check_trace(path: &str, text: &str) -> Result<String, String> {
+    let trace = trace::parse(text)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        });
+    let count = replay_trace(&trace) ? ;
+    Ok(format!("{} gestures ok", count))
+}
+
+/// Parse the calibration file `text` from file `path` and check the calibration computed from the touches.
+/// Exit with status 1 if the file is invalid.
+fn check_calibration(path: &str, text: &str) -> Result<String, String> {
+    let file = calibration::parse(text)
+        .unwrap_or_else(|err| {
+            eprintln!("{}: {}", path, err);
+            process::exit(1);
+        });
+    let calibration = match Calibration::from_points(&file.raw, &file.targets) {
+        Some(_) if file.invalid => return Err("expected invalid calibration".to_string()),
+        Some(calibration) => calibration,
+        None if file.invalid => return Ok("invalid ok".to_string()),
+        None => return Err(format!("no calibration for {} touches", file.raw.len())),
+    };
+    //  Check the distance from each touch to its target
+    let error = calibration.max_error(&file.raw, &file.targets);
+    if let Some(expected) = file.error {
+        if error != expected { return Err(format!("expected error {}, got {}: {:?}", expected, error, calibration)); }
+    }
+    //  Check the raw coordinates mapped after calibration
+    for map in &file.maps {
+        let mapped = calibration.apply(map.raw.0, map.raw.1);
+        if mapped != map.expected {
+            return Err(format!("line {}: expected {:?}, got {:?}: {:?}", map.line, map.expected, mapped, calibration));
+        }
+    }
+    //  Check that the calibration is rejected if a target was touched in the wrong place, like on PineTime
+    let accepted = Calibration::from_touches(&file.raw, &file.targets).is_some();
+    if accepted == file.rejected {
+        return Err(format!("expected calibration {}: {:?}", if file.rejected { "rejected" } else { "accepted" }, calibration));
+    }
+    //  Check that the calibration is saved and loaded without change, like in SPI Flash on PineTime
+    if Calibration::from_bytes(&calibration.to_bytes()) != Some(calibration) {
+        return Err(format!("calibration changed after encoding: {:?}", calibration));
+    }
+    Ok(format!("{} touches, error {}, {} maps ok", file.raw.len(), error, file.maps.len()))
+}
+
+/// Reset the touch controller and read the touch data for each of the `reads`, with a scripted I2C bus.
+/// Fail if the touch points differ from the expected touch points.
+fn replay(reads: &[Read]) -> Result<(), String> {
+    //  Script the I2C transactions for the reads
+    let script: Vec<Transaction> = reads.iter()
+        .map(|read| match &read.regs {
+            Some(regs) => Transaction::WriteRead { address: ADDRESS, write: &[0], read: regs },
+            None       => Transaction::Nack { address: ADDRESS },
+        })
+        .collect();
+    let mut driver = Cst816s::new(ScriptedI2c::new(&script), MockPin::new(true), MockDelay::new());
+
+    //  Reset the touch controller
+    driver.reset()
+        .map_err(|err| format!("reset failed: {:?}", err)) ? ;
+
+    //  Read the touch data and compare with the expected touch points
+    for read in reads {
+        match driver.read_touch_data() {
+            Ok(data) => {
+                if read.regs.is_none() { return Err(format!("line {}: expected nack", read.line)); }
+                let points: Vec<Expected> = data.points().iter()
+                    .map(|point| Expected { action: point.action, finger: point.finger, x: point.x, y: point.y })
+                    .collect();
+                if points != read.expected {
+                    return Err(format!("line {}: expected {:?}, got {:?}", read.line, read.expected, points));
+                }
+            }
+            Err(Error::I2c(MockError::Nack)) if read.regs.is_none() => {}
+            Err(err) => return Err(format!("line {}: read failed: {:?}", read.line, err)),
+        }
+    }
+
+    //  Check that the reset pin was pulsed low and left high, and that every transaction was done
+    let (i2c, reset, delay) = driver.release();
+    if !reset.high || reset.changes != 2 { return Err(format!("bad reset pulse: {:?}", reset)); }
+    if delay.total_ms != RESET_MS { return Err(format!("reset took {} ms, expected {} ms", delay.total_ms, RESET_MS)); }
+    if i2c.remaining() > 0 { return Err(format!("{} reads not done", i2c.remaining())); }
+    Ok(())
+}
+
+/// Feed the touch actions and polls of the `trace` to the gesture recogniser. Fail if the gestures differ from
+/// the expected gestures, or if a poll recognises a gesture before the deadline, because PineTime polls only at the
+/// deadline. Return the number of gestures recognised.
+fn replay_trace(trace: &trace::Trace) -> Result<usize, String> {
+    let mut recognizer = GestureRecognizer::new(trace.thresholds);
+    let mut count = 0;
+    for step in &trace.steps {
+        let deadline = recognizer.deadline();
+        let early = match step.input {
+            trace::Input::Touch { action, x, y, ms } => { recognizer.update(action, x, y, ms); false }
+            trace::Input::Poll { ms } => {
+                recognizer.poll(ms);
+                deadline.map(|deadline| (ms.wrapping_sub(deadline) as i32) < 0).unwrap_or(true)
+            }
+        };
+        let mut gestures: Vec<Gesture> = Vec::new();
+        while let Some(gesture) = recognizer.next_gesture() { gestures.push(gesture); }
+        if gestures != step.expected {
+            return Err(format!("line {}: expected {:?}, got {:?}", step.line, step.expected, gestures));
+        }
+        if early && !gestures.is_empty() {
+            return Err(format!("line {}: gestures recognised before the deadline {:?}", step.line, deadline));
+        }
+        count += gestures.len();
+    }
+    //  The trace should end with the finger lifted and no pending tap
+    if let Some(deadline) = recognizer.deadline() {
+        return Err(format!("trace ended with gesture in progress, poll at {}", deadline));
+    }
+    Ok(count)
+}
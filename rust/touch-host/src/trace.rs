@@ -0,0 +1,117 @@
+//! Touch traces for the gesture recogniser, with the gestures expected after each touch action. A trace file
+//! contains one line per touch action, in the format logged by `touch_sensor.rs` on PineTime:
+//! ```text
+//! # Comment
+//! set double_tap_ms 0      Change a threshold, before the first touch action
+//! down 120 100 5000        Touch action (down, contact or up), x, y and time in milliseconds
+//! contact 121 100 5030
+//! up 121 100 5060
+//! poll 5400                Call `poll()` at the time in milliseconds, like the gesture callout on PineTime
+//! expect tap 121 100       Gestures expected after the previous line, in order
+//! ```
+use pinetime_touch::{
+    Gesture, Thresholds, TouchAction,
+};
+
+/// Input to the gesture recogniser
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Input {
+    /// Touch action at (`x`, `y`) at time `ms`
+    Touch { action: TouchAction, x: u16, y: u16, ms: u32 },
+    /// Poll at time `ms`
+    Poll { ms: u32 },
+}
+
+/// Input to the gesture recogniser, with the gestures expected
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step {
+    /// Touch action or poll
+    pub input:    Input,
+    /// Gestures expected, in order
+    pub expected: Vec<Gesture>,
+    /// Line number of the input in the trace file
+    pub line:     usize,
+}
+
+/// Touch trace
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trace {
+    /// Thresholds for the gesture recogniser
+    pub thresholds: Thresholds,
+    /// Inputs to the gesture recogniser, in order
+    pub steps:      Vec<Step>,
+}
+
+/// Parse the trace file `text`
+pub fn parse(text: &str) -> Result<Trace, String> {
+    let mut trace = Trace { thresholds: Thresholds::new(), steps: Vec::new() };
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.split('#').next().unwrap_or("").trim();
+        let words: Vec<_> = line.split_whitespace().collect();
+        let err = |msg: &str| format!("line {}: {}", line_number, msg);
+        let number = |word: &str| word.parse::<u32>().map_err(|_| err(&format!("bad number {}", word)));
+        let coord = |word: &str| word.parse::<u16>().map_err(|_| err(&format!("bad coordinate {}", word)));
+        match words.as_slice() {
+            [] => continue,
+            [ "set", name, value ] => {
+                if !trace.steps.is_empty() { return Err(err("set after touch action")); }
+                let value = number(value) ? ;
+                let thresholds = &mut trace.thresholds;
+                match *name {
+                    "move_distance"       => thresholds.move_distance = value as u16,
+                    "swipe_distance"      => thresholds.swipe_distance = value as u16,
+                    "swipe_ms"            => thresholds.swipe_ms = value,
+                    "long_press_ms"       => thresholds.long_press_ms = value,
+                    "double_tap_ms"       => thresholds.double_tap_ms = value,
+                    "double_tap_distance" => thresholds.double_tap_distance = value as u16,
+                    "release_ms"          => thresholds.release_ms = value,
+                    _ => return Err(err(&format!("unknown threshold {}", name))),
+                }
+            }
+            [ action, x, y, ms ] if matches!(*action, "down" | "contact" | "up") => {
+                let action = match *action {
+                    "down"    => TouchAction::Down,
+                    "contact" => TouchAction::Contact,
+                    _         => TouchAction::Up,
+                };
+                let input = Input::Touch { action, x: coord(x) ? , y: coord(y) ? , ms: number(ms) ? };
+                trace.steps.push(Step { input, expected: Vec::new(), line: line_number });
+            }
+            [ "poll", ms ] => {
+                let input = Input::Poll { ms: number(ms) ? };
+                trace.steps.push(Step { input, expected: Vec::new(), line: line_number });
+            }
+            [ "expect", gesture @ .. ] => {
+                let step = trace.steps.last_mut()
+                    .ok_or_else(|| err("expect without touch action or poll")) ? ;
+                let gesture = match gesture {
+                    [ "none" ] => continue,
+                    [ name, x, y ] => {
+                        let (x, y) = (coord(x) ? , coord(y) ? );
+                        match *name {
+                            "tap"         => Gesture::Tap { x, y },
+                            "double-tap"  => Gesture::DoubleTap { x, y },
+                            "long-press"  => Gesture::LongPress { x, y },
+                            "swipe-up"    => Gesture::SwipeUp { x, y },
+                            "swipe-down"  => Gesture::SwipeDown { x, y },
+                            "swipe-left"  => Gesture::SwipeLeft { x, y },
+                            "swipe-right" => Gesture::SwipeRight { x, y },
+                            _ => return Err(err(&format!("bad gesture {}", name))),
+                        }
+                    }
+                    [ "drag", x, y, dx, dy ] => Gesture::Drag {
+                        x:  coord(x) ? ,
+                        y:  coord(y) ? ,
+                        dx: dx.parse().map_err(|_| err("bad dx")) ? ,
+                        dy: dy.parse().map_err(|_| err("bad dy")) ? ,
+                    },
+                    _ => return Err(err("expected: expect GESTURE X Y, expect drag X Y DX DY or expect none")),
+                };
+                step.expected.push(gesture);
+            }
+            [ word, .. ] => return Err(err(&format!("unknown keyword or bad arguments: {}", word))),
+        }
+    }
+    Ok(trace)
+}
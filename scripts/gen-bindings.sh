@@ -106,6 +106,7 @@ function generate_bindings_kernel() {
         --raw-line use \
         --raw-line super::*; \
         --whitelist-var      (?i)SYS_E.* \
+        --whitelist-var      MYNEWT_VAL_OS_CPUTIME_FREQ \
         --whitelist-function (?i)${prefixname}_.* \
         --whitelist-type     (?i)${prefixname}_.* \
         --whitelist-var      (?i)${prefixname}_.*